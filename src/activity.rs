@@ -0,0 +1,123 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::jira::{adf, parse_jira_datetime, JiraClient};
+
+// Recent events in a project or an epic for `qq jira activity-feed`: issues created, status
+// changes from the changelog, and comments, newest first
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    Created,
+    Status { from: String, to: String },
+    Comment { text: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedEvent {
+    pub at: DateTime<Utc>,
+    pub key: String,
+    pub summary: String,
+    pub author: String,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+impl FeedEvent {
+    pub fn describe(&self) -> String {
+        match &self.kind {
+            EventKind::Created => "created".to_string(),
+            EventKind::Status { from, to } => format!("{} → {}", from, to),
+            EventKind::Comment { text } => format!("commented: {}", text),
+        }
+    }
+}
+
+pub enum Scope {
+    Project(String),
+    // The epic itself and the issues under it
+    Epic(String),
+}
+
+impl Scope {
+    pub fn label(&self) -> String {
+        match self {
+            Scope::Project(project) => format!("project {}", project.to_uppercase()),
+            Scope::Epic(epic) => format!("epic {}", epic.to_uppercase()),
+        }
+    }
+
+    fn jql(&self, days: i64) -> String {
+        let scope = match self {
+            Scope::Project(project) => format!("project = {}", crate::jql::quote(project)),
+            Scope::Epic(epic) => format!("(key = {0} OR parent = {0})", crate::jql::quote(epic)),
+        };
+        // Anything with an event in the window was updated in it
+        format!("{} AND updated >= -{}d ORDER BY updated DESC", scope, days)
+    }
+}
+
+// Hands over the events of each page of matching issues as it arrives, until `on_page` returns
+// false. Issues come most recently updated first, so the newest events arrive early.
+pub fn load(client: &JiraClient, scope: &Scope, days: i64, mut on_page: impl FnMut(Vec<FeedEvent>) -> bool) -> Result<()> {
+    let since = Utc::now() - Duration::days(days);
+    client.search_raw_pages(&scope.jql(days), "summary,created,reporter,comment", Some("changelog"), |page| {
+        let mut events: Vec<FeedEvent> = page.iter()
+            .flat_map(events_of)
+            .filter(|event| event.at >= since)
+            .collect();
+        events.sort_by_key(|event| std::cmp::Reverse(event.at));
+        Ok(on_page(events))
+    })
+}
+
+fn events_of(issue: &Value) -> Vec<FeedEvent> {
+    let text = |value: Option<&Value>| value.and_then(Value::as_str).unwrap_or_default().to_string();
+    let time = |value: Option<&Value>| {
+        value.and_then(Value::as_str)
+            .and_then(parse_jira_datetime)
+            .map(|at| at.with_timezone(&Utc))
+    };
+    let key = text(issue.get("key"));
+    let summary = text(issue.pointer("/fields/summary"));
+    let event = |at: DateTime<Utc>, author: String, kind: EventKind| FeedEvent {
+        at,
+        key: key.clone(),
+        summary: summary.clone(),
+        author,
+        kind,
+    };
+
+    let mut events = Vec::new();
+    if let Some(at) = time(issue.pointer("/fields/created")) {
+        events.push(event(at, text(issue.pointer("/fields/reporter/displayName")), EventKind::Created));
+    }
+
+    let histories = issue.pointer("/changelog/histories").and_then(Value::as_array);
+    for history in histories.into_iter().flatten() {
+        let Some(at) = time(history.get("created")) else {
+            continue;
+        };
+        let items = history.get("items").and_then(Value::as_array);
+        for item in items.into_iter().flatten().filter(|item| item.get("field").and_then(Value::as_str) == Some("status")) {
+            let kind = EventKind::Status { from: text(item.get("fromString")), to: text(item.get("toString")) };
+            events.push(event(at, text(history.pointer("/author/displayName")), kind));
+        }
+    }
+
+    // Search results carry the issue's latest comments, which covers a recent window
+    let comments = issue.pointer("/fields/comment/comments").and_then(Value::as_array);
+    for comment in comments.into_iter().flatten() {
+        let Some(at) = time(comment.get("created")) else {
+            continue;
+        };
+        let body = comment.get("body").map(adf::to_markdown).unwrap_or_default();
+        let first_line = body.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+        let kind = EventKind::Comment { text: first_line.to_string() };
+        events.push(event(at, text(comment.pointer("/author/displayName")), kind));
+    }
+    events
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::palette;
+
+// A shortcut for one qq command line, or a list of them run in order, e.g.
+//   [alias]
+//   standup = "jira search --saved standup"
+//   ship = ["jira transition --to review", "jira comment 'Ready for review'"]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Alias {
+    Command(String),
+    Steps(Vec<String>),
+}
+
+impl Alias {
+    pub fn from_steps(mut steps: Vec<String>) -> Self {
+        if steps.len() == 1 {
+            Alias::Command(steps.remove(0))
+        } else {
+            Alias::Steps(steps)
+        }
+    }
+
+    pub fn steps(&self) -> Vec<&str> {
+        match self {
+            Alias::Command(command) => vec![command.as_str()],
+            Alias::Steps(steps) => steps.iter().map(|step| step.as_str()).collect(),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        self.steps().join(" && ")
+    }
+}
+
+// The alias named by the first argument, unless it's a built-in command; those always win
+pub fn lookup<'a>(aliases: &'a HashMap<String, Alias>, root: &clap::Command, name: &str) -> Option<&'a Alias> {
+    if is_builtin(root, name) {
+        return None;
+    }
+    aliases.get(name)
+}
+
+pub fn is_builtin(root: &clap::Command, name: &str) -> bool {
+    name == "help" || root.find_subcommand(name).is_some()
+}
+
+// Global flags that can come before an alias name, as in `qq --no-color standup`
+const GLOBAL_FLAGS: &[&str] = &["--no-color", "--json"];
+
+// Splits the arguments after `qq` into the leading global flags and everything from the
+// command or alias name on
+pub fn split_globals(args: &[String]) -> (&[String], &[String]) {
+    let count = args.iter().take_while(|arg| GLOBAL_FLAGS.contains(&arg.as_str())).count();
+    args.split_at(count)
+}
+
+// Arguments for each step after `qq`. Global flags given before the alias go on every step,
+// and arguments typed after it go on the last step, so `qq standup --limit 5` works like
+// typing the full command.
+pub fn expand(alias: &Alias, globals: &[String], extra: &[String]) -> Vec<Vec<String>> {
+    let steps = alias.steps();
+    let last = steps.len().saturating_sub(1);
+    steps.iter()
+        .enumerate()
+        .map(|(i, step)| {
+            let mut args = globals.to_vec();
+            args.extend(palette::split_args(step));
+            if i == last {
+                args.extend(extra.iter().cloned());
+            }
+            args
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde_json::Value;
+use std::io::Write;
+
+use crate::jira::JiraClient;
+
+// Issues whose comments or changelog need more requests are fetched this many at a time
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+// JQL for `qq jira export`: issues the person was ever assigned, oldest first. "me" is the
+// signed-in user; anyone else is an account ID or whatever name Jira resolves.
+pub fn jql(assignee: &str, since: Option<NaiveDate>) -> String {
+    let assignee = if assignee.eq_ignore_ascii_case("me") {
+        "currentUser()".to_string()
+    } else {
+        crate::jql::quote(assignee)
+    };
+    let mut jql = format!("assignee was {}", assignee);
+    if let Some(since) = since {
+        jql.push_str(&format!(" AND updated >= \"{}\"", since));
+    }
+    jql.push_str(" ORDER BY created ASC");
+    jql
+}
+
+// Writes each matching issue as one JSON line: every field as Jira returns it, with all of its
+// comments and its full changelog. Returns how many issues were written.
+pub fn export(client: &JiraClient, jql: &str, out: &mut impl Write, mut progress: impl FnMut(usize)) -> Result<usize> {
+    let mut written = 0;
+    client.search_raw_pages(jql, "*all", Some("changelog"), |page| {
+        let issues = crate::pool::run_parallel(page, MAX_CONCURRENT_REQUESTS, |issue| complete(client, issue));
+        for issue in issues {
+            serde_json::to_writer(&mut *out, &issue?)?;
+            out.write_all(b"\n")?;
+            written += 1;
+        }
+        progress(written);
+        Ok(true)
+    })?;
+    out.flush().context("Failed to write the export")?;
+    Ok(written)
+}
+
+// Search results cut comments and changelogs short on busy issues; fetch the rest
+fn complete(client: &JiraClient, mut issue: Value) -> Result<Value> {
+    let key = issue.get("key").and_then(Value::as_str).unwrap_or_default().to_string();
+
+    if truncated(issue.pointer("/fields/comment"), "comments") {
+        let comments = client.get_all_comments_raw(&key)
+            .context(format!("Failed to fetch comments of {}", key))?;
+        if let Some(field) = issue.pointer_mut("/fields/comment") {
+            *field = serde_json::json!({ "comments": comments, "total": comments.len() });
+        }
+    }
+    if truncated(issue.get("changelog"), "histories") {
+        let histories = client.get_changelog(&key)
+            .context(format!("Failed to fetch the changelog of {}", key))?;
+        issue["changelog"] = serde_json::json!({ "histories": histories, "total": histories.len() });
+    }
+    Ok(issue)
+}
+
+fn truncated(list: Option<&Value>, items: &str) -> bool {
+    let Some(list) = list else {
+        return false;
+    };
+    let total = list.get("total").and_then(Value::as_u64).unwrap_or(0);
+    let included = list.get(items).and_then(Value::as_array).map_or(0, |items| items.len() as u64);
+    total > included
+}
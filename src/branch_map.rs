@@ -0,0 +1,73 @@
+use anyhow::{Context, Result};
+use git2::Repository;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::store;
+
+// Tickets for branches whose names don't mention one (`main`, `quick-fix`), set with
+// `qq jira relate-branch`. Keyed by repository so `main` in one repo doesn't pick up the
+// ticket related in another; linked worktrees share their repository's entries.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BranchMap {
+    // Repository git dir -> branch -> ticket key
+    #[serde(default)]
+    repos: HashMap<String, HashMap<String, String>>,
+}
+
+impl BranchMap {
+    pub fn load() -> Self {
+        Self::store_path()
+            .map(|path| store::read_json(&path))
+            .unwrap_or_default()
+    }
+
+    pub fn lookup(&self, repo: &Repository, branch: &str) -> Option<&str> {
+        self.repos.get(&repo_key(repo))?
+            .get(branch)
+            .map(|ticket| ticket.as_str())
+    }
+
+    // Returns the ticket the branch was related to before, if any
+    pub fn relate(repo: &Repository, branch: &str, ticket: &str) -> Result<Option<String>> {
+        store::edit_json(&Self::store_path()?, |map: &mut Self| {
+            map.repos.entry(repo_key(repo))
+                .or_default()
+                .insert(branch.to_string(), ticket.to_string())
+        })
+    }
+
+    pub fn unrelate(repo: &Repository, branch: &str) -> Result<Option<String>> {
+        store::edit_json(&Self::store_path()?, |map: &mut Self| {
+            let key = repo_key(repo);
+            let branches = map.repos.get_mut(&key)?;
+            let previous = branches.remove(branch);
+            if branches.is_empty() {
+                map.repos.remove(&key);
+            }
+            previous
+        })
+    }
+
+    fn store_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .context("Failed to determine data directory")?;
+        Ok(data_dir.join("qq").join("branch_tickets.json"))
+    }
+}
+
+// The main git dir, even from a linked worktree (.git/worktrees/<name>), canonicalized since
+// the same repository can be reached through symlinks
+pub fn repo_key(repo: &Repository) -> String {
+    let mut git_dir = repo.path();
+    if repo.is_worktree()
+        && let Some(main) = git_dir.parent().and_then(|worktrees| worktrees.parent())
+    {
+        git_dir = main;
+    }
+    git_dir.canonicalize()
+        .unwrap_or_else(|_| git_dir.to_path_buf())
+        .to_string_lossy()
+        .into_owned()
+}
@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::jira::{Comment, JiraIssue};
+use crate::store;
+use crate::style;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub assignee: Option<String>,
+    pub cached_at: DateTime<Utc>,
+    // As Markdown, for `qq jira find`. Kept from an earlier fetch when a list didn't include it.
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub comments: Vec<String>,
+}
+
+impl CachedIssue {
+    pub fn one_line(&self) -> String {
+        let assignee = self.assignee.as_deref().unwrap_or("Unassigned");
+        format!("{} [{}] {} — {}", style::issue(&self.key), style::warn(&self.status), self.summary, style::muted(assignee))
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IssueCache {
+    #[serde(default)]
+    issues: HashMap<String, CachedIssue>,
+}
+
+impl IssueCache {
+    // A missing or unreadable cache is treated as empty so callers on the fast path never fail
+    pub fn load() -> Self {
+        Self::cache_path()
+            .map(|path| store::read_json(&path))
+            .unwrap_or_default()
+    }
+
+    // Applies a change under the cache lock so concurrent qq processes don't lose updates
+    pub fn edit<R>(change: impl FnOnce(&mut Self) -> R) -> Result<R> {
+        store::edit_json(&Self::cache_path()?, change)
+    }
+
+    pub fn get(&self, issue_key: &str) -> Option<&CachedIssue> {
+        self.issues.get(issue_key)
+    }
+
+    // Most recently fetched first
+    pub fn recent(&self, limit: usize) -> Vec<&CachedIssue> {
+        let mut issues: Vec<&CachedIssue> = self.issues.values().collect();
+        issues.sort_by(|a, b| b.cached_at.cmp(&a.cached_at).then_with(|| a.key.cmp(&b.key)));
+        issues.truncate(limit);
+        issues
+    }
+
+    pub fn update(&mut self, issue: &JiraIssue) {
+        let previous = self.issues.remove(&issue.key);
+        let description = match &issue.fields.description {
+            Some(description) => Some(crate::jira::adf::to_markdown(description)),
+            None => previous.as_ref().and_then(|p| p.description.clone()),
+        };
+        let cached = CachedIssue {
+            key: issue.key.clone(),
+            summary: issue.fields.summary.clone(),
+            status: issue.fields.status.name.clone(),
+            assignee: issue.fields.assignee.as_ref().map(|u| u.display_name.clone()),
+            cached_at: Utc::now(),
+            description,
+            comments: previous.map(|p| p.comments).unwrap_or_default(),
+        };
+
+        self.issues.insert(issue.key.clone(), cached);
+    }
+
+    pub fn all(&self) -> impl Iterator<Item = &CachedIssue> {
+        self.issues.values()
+    }
+
+    // Convenience for command handlers: record fetched issues without failing the command
+    pub fn record(issues: &[&JiraIssue]) {
+        let _ = Self::edit(|cache| {
+            for issue in issues {
+                cache.update(issue);
+            }
+        });
+    }
+
+    // Comment text for an issue already in the cache, replacing what was there
+    pub fn record_comments(issue_key: &str, comments: &[Comment]) {
+        let text: Vec<String> = comments.iter()
+            .filter_map(|c| c.body.as_ref().map(crate::jira::adf::to_markdown))
+            .collect();
+        let _ = Self::edit(|cache| {
+            if let Some(issue) = cache.issues.get_mut(issue_key) {
+                issue.comments = text;
+            }
+        });
+    }
+
+    fn cache_path() -> Result<PathBuf> {
+        let cache_dir = dirs::cache_dir()
+            .context("Failed to determine cache directory")?;
+        Ok(cache_dir.join("qq").join("issues.json"))
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::{Context, Result};
+use std::io::{Read, Write};
+
+// Set on the background copy of qq that `copy_and_exit` starts; the text comes in on stdin
+const SERVE_ENV: &str = "QQ_CLIPBOARD_SERVE";
+
+// A clipboard kept open for as long as a view is. On X11 and Wayland the process that copied has
+// to stay around to hand the text out, so closing it right after copying can lose the text.
+#[derive(Default)]
+pub struct Clipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    pub fn copy(&mut self, text: &str) -> Result<()> {
+        if self.inner.is_none() {
+            self.inner = Some(open()?);
+        }
+        if let Some(clipboard) = &mut self.inner {
+            clipboard.set_text(text.to_string())
+                .context("Failed to copy to clipboard")?;
+        }
+        Ok(())
+    }
+}
+
+// Copies from a command that exits right after. On Linux the text is handed to a background copy
+// of qq, which serves it until something else is copied.
+pub fn copy_and_exit(text: &str) -> Result<()> {
+    if cfg!(target_os = "linux") {
+        // Opened here first so a missing display is reported instead of failing in the background
+        drop(open()?);
+        let exe = std::env::current_exe().context("Failed to find the qq executable")?;
+        let mut child = std::process::Command::new(exe)
+            .env(SERVE_ENV, "1")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .context("Failed to start the background clipboard copy")?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())
+                .context("Failed to copy to clipboard")?;
+        }
+        Ok(())
+    } else {
+        Clipboard::default().copy(text)
+    }
+}
+
+// Whether this process is the background copy started by `copy_and_exit`
+pub fn serving() -> bool {
+    std::env::var_os(SERVE_ENV).is_some()
+}
+
+pub fn serve() -> Result<()> {
+    let mut text = String::new();
+    std::io::stdin().read_to_string(&mut text)?;
+    let mut clipboard = open()?;
+    #[cfg(target_os = "linux")]
+    {
+        use arboard::SetExtLinux;
+        clipboard.set().wait().text(text)
+            .context("Failed to copy to clipboard")?;
+    }
+    #[cfg(not(target_os = "linux"))]
+    clipboard.set_text(text)
+        .context("Failed to copy to clipboard")?;
+    Ok(())
+}
+
+fn open() -> Result<arboard::Clipboard> {
+    arboard::Clipboard::new().context("Failed to access the system clipboard")
+}
@@ -1,42 +1,438 @@
 use anyhow::{Context, Result};
 use dirs;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::alias::Alias;
+use crate::google::{MeetingRule, WorkingHours};
+use crate::hooks::HooksConfig;
+use crate::notify::{DoNotDisturb, NotifyBackend};
+use crate::sla::SlaRule;
+use crate::store;
+use crate::verify::VerifyConfig;
+
+// Bump when new config fields need user action (e.g. re-running `qq jira fields --pin`)
+pub const CONFIG_VERSION: u32 = 1;
+
+// Stands in for a secret in config.toml once it's kept in the keyring
+const IN_KEYRING: &str = "<keyring>";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoogleAuthFlow {
+    // Browser redirect to a local port; needs a browser on the same machine
+    #[default]
+    Installed,
+    // Code entered on another device; works over SSH and on headless machines
+    Device,
+    // Sign in with a browser anywhere, then paste the redirected address back into qq
+    Paste,
+}
+
+// Where the Jira API tokens, Google client secrets, and the GitHub token are kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretStore {
+    // In config.toml as plain text
+    #[default]
+    File,
+    // In the OS keyring, with a placeholder in config.toml; set by `qq config migrate-secrets`
+    Keyring,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+}
+
+// Extra styling for the selected row; the ➤ arrow is always shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectionStyle {
+    #[default]
+    Arrow,
+    Bold,
+    Reverse,
+    Underline,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub preset: ThemePreset,
+    // Titles and the selection arrow: a name ("magenta"), 256-color index ("208"), or hex ("#ff8800")
+    #[serde(default)]
+    pub accent: Option<String>,
+    #[serde(default)]
+    pub selection: SelectionStyle,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UiConfig {
+    #[serde(default)]
+    pub theme: ThemeConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoogleAccount {
+    pub client_id: String,
+    pub client_secret: String,
+    #[serde(default)]
+    pub flow: GoogleAuthFlow,
+}
+
+// Personal access token for `qq pr`. `api_url` is only set for GitHub Enterprise Server,
+// e.g. https://github.example.com/api/v3
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubConfig {
+    pub token: String,
+    #[serde(default)]
+    pub api_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraProfile {
+    pub jira_url: String,
+    pub username: String,
+    pub api_token: String,
+}
+
+pub const DEFAULT_JIRA_PROFILE: &str = "default";
+
+// Which backlog issues `qq jira grab-next` may pick; flags override these per run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GrabNextFilter {
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub max_points: Option<f64>,
+    // Status to move the issue to; defaults to In Progress
+    #[serde(default)]
+    pub transition: Option<String>,
+}
+
+// Epic fields a new child can copy with `qq jira create --epic`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InheritField {
+    Labels,
+    FixVersions,
+    Components,
+    Sprint,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateConfig {
+    // e.g. `inherit = ["labels", "fix_versions", "sprint"]`
+    #[serde(default)]
+    pub inherit: Vec<InheritField>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RemindersConfig {
+    // Mirror snoozes into Google Calendar as events at their wake time, so they reach my phone
+    #[serde(default)]
+    pub calendar: bool,
+    // Google account whose primary calendar gets the events; the default account when unset
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    #[serde(default)]
+    pub config_version: u32,
     pub jira_url: String,
     pub username: String,
     pub api_token: String,
+    // Additional named Jira instances, switched with `qq use <profile>`
+    #[serde(default)]
+    pub jira_profiles: HashMap<String, JiraProfile>,
+    #[serde(default)]
+    pub active_jira_profile: Option<String>,
     pub google_client_id: Option<String>,
     pub google_client_secret: Option<String>,
+    #[serde(default)]
+    pub google_flow: GoogleAuthFlow,
+    // Additional named Google accounts, selected with --account
+    #[serde(default)]
+    pub google_accounts: HashMap<String, GoogleAccount>,
+    #[serde(default)]
+    pub github: Option<GitHubConfig>,
+    pub story_points_field: Option<String>,
+    pub epic_link_field: Option<String>,
+    pub sprint_field: Option<String>,
+    // User picker set by `qq jira review`; found by `qq jira fields --pin` when named "Reviewer"
+    #[serde(default)]
+    pub reviewer_field: Option<String>,
+    // Jira's "Flagged" checkbox, read by the Flagged quick filter in `qq jira mine`
+    #[serde(default)]
+    pub flagged_field: Option<String>,
+    // Agile board for the sprint picker; found from the ticket's project when unset
+    pub board_id: Option<u64>,
+    // Short names for workflow statuses, e.g. `review = "In Code Review"`
+    #[serde(default)]
+    pub transitions: HashMap<String, String>,
+    // Where reminders and alerts are delivered; see the `[[notify]]` section in the README
+    #[serde(default)]
+    pub notify: Vec<NotifyBackend>,
+    // Holds non-urgent notifications while a meeting is in progress
+    #[serde(default)]
+    pub do_not_disturb: DoNotDisturb,
+    // Importance overrides for calendar events, e.g. "Office hours" is low priority
+    #[serde(default)]
+    pub meeting_rules: Vec<MeetingRule>,
+    #[serde(default)]
+    pub grab_next: GrabNextFilter,
+    // Resolution targets highlighted in lists and reported by `qq jira sla`
+    #[serde(default)]
+    pub sla: Vec<SlaRule>,
+    // Canned comments with {placeholders}, e.g. `deployed = "Deployed to staging: {url}"`
+    #[serde(default)]
+    pub comment_templates: HashMap<String, String>,
+    #[serde(default)]
+    pub working_hours: WorkingHours,
+    // Named --format templates for list commands, e.g. `short = "{key}\t{summary}"`
+    #[serde(default)]
+    pub formats: HashMap<String, String>,
+    // Saved JQL for `qq jira search --saved <name>`, usually written by `qq jira query-builder`
+    #[serde(default)]
+    pub searches: HashMap<String, String>,
+    // Shell commands run before or after start, close, transition, and comment
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    // Checks run by `qq jira verify` and before closing a ticket
+    #[serde(default)]
+    pub verify: VerifyConfig,
+    // Shortcuts run as `qq <name>`, e.g. `standup = "jira search --saved standup"`
+    #[serde(default)]
+    pub alias: HashMap<String, Alias>,
+    #[serde(default)]
+    pub ui: UiConfig,
+    // Defaults for `qq jira create`
+    #[serde(default)]
+    pub create: CreateConfig,
+    #[serde(default)]
+    pub reminders: RemindersConfig,
+    #[serde(default)]
+    pub secret_store: SecretStore,
 }
 
 impl Config {
     pub fn new(jira_url: String, username: String, api_token: String) -> Self {
         Self {
+            config_version: CONFIG_VERSION,
             jira_url,
             username,
             api_token,
+            jira_profiles: HashMap::new(),
+            active_jira_profile: None,
             google_client_id: None,
             google_client_secret: None,
+            google_flow: GoogleAuthFlow::default(),
+            google_accounts: HashMap::new(),
+            github: None,
+            story_points_field: None,
+            epic_link_field: None,
+            sprint_field: None,
+            reviewer_field: None,
+            flagged_field: None,
+            board_id: None,
+            transitions: HashMap::new(),
+            notify: Vec::new(),
+            do_not_disturb: DoNotDisturb::default(),
+            meeting_rules: Vec::new(),
+            grab_next: GrabNextFilter::default(),
+            sla: Vec::new(),
+            comment_templates: HashMap::new(),
+            working_hours: WorkingHours::default(),
+            formats: HashMap::new(),
+            searches: HashMap::new(),
+            hooks: HooksConfig::default(),
+            verify: VerifyConfig::default(),
+            alias: HashMap::new(),
+            ui: UiConfig::default(),
+            create: CreateConfig::default(),
+            reminders: RemindersConfig::default(),
+            secret_store: SecretStore::default(),
+        }
+    }
+    
+    // The default profile comes from the top-level jira_* settings
+    pub fn set_jira_profile(&mut self, name: Option<String>, profile: JiraProfile) {
+        match name.filter(|n| n != DEFAULT_JIRA_PROFILE) {
+            Some(name) => {
+                self.jira_profiles.insert(name, profile);
+            }
+            None => {
+                self.jira_url = profile.jira_url;
+                self.username = profile.username;
+                self.api_token = profile.api_token;
+            }
+        }
+    }
+    
+    pub fn active_jira_profile_name(&self) -> &str {
+        self.active_jira_profile.as_deref().unwrap_or(DEFAULT_JIRA_PROFILE)
+    }
+    
+    pub fn active_jira(&self) -> JiraProfile {
+        self.active_jira_profile.as_ref()
+            .and_then(|name| self.jira_profiles.get(name))
+            .cloned()
+            .unwrap_or_else(|| JiraProfile {
+                jira_url: self.jira_url.clone(),
+                username: self.username.clone(),
+                api_token: self.api_token.clone(),
+            })
+    }
+    
+    // Profile name and Jira host, shown in TUI headers so it's clear which instance is in use
+    pub fn jira_label(&self) -> String {
+        let url = self.active_jira().jira_url;
+        let host = url.trim_start_matches("https://").trim_start_matches("http://").trim_end_matches('/');
+        format!("{} · {}", self.active_jira_profile_name(), host)
+    }
+    
+    pub fn use_jira_profile(&mut self, name: &str) -> Result<()> {
+        if name == DEFAULT_JIRA_PROFILE {
+            self.active_jira_profile = None;
+            return Ok(());
+        }
+        if !self.jira_profiles.contains_key(name) {
+            anyhow::bail!("Jira profile '{}' not configured. Run 'qq config jira --profile {} ...' first.", name, name);
+        }
+        self.active_jira_profile = Some(name.to_string());
+        Ok(())
+    }
+    
+    pub fn set_google_account(&mut self, name: Option<String>, account: GoogleAccount) -> Result<()> {
+        match name {
+            Some(name) => {
+                check_account_name(&name)?;
+                self.google_accounts.insert(name, account);
+            }
+            None => {
+                self.google_client_id = Some(account.client_id);
+                self.google_client_secret = Some(account.client_secret);
+                self.google_flow = account.flow;
+            }
+        }
+        Ok(())
+    }
+    
+    // The unnamed account comes from the top-level google_* settings
+    pub fn google_account(&self, name: Option<&str>) -> Result<GoogleAccount> {
+        match name {
+            Some(name) => self.google_accounts.get(name)
+                .cloned()
+                .context(format!("Google account '{}' not configured. Run 'qq config google --account {}' first.", name, name)),
+            None => Ok(GoogleAccount {
+                client_id: self.google_client_id.clone()
+                    .context("Google client ID not configured. Run 'qq config google' first.")?,
+                client_secret: self.google_client_secret.clone()
+                    .context("Google client secret not configured. Run 'qq config google' first.")?,
+                flow: self.google_flow,
+            }),
         }
     }
     
-    pub fn set_google_credentials(&mut self, client_id: String, client_secret: String) {
-        self.google_client_id = Some(client_id);
-        self.google_client_secret = Some(client_secret);
+    pub fn comment_template(&self, name: &str) -> Result<&str> {
+        if let Some(template) = self.comment_templates.get(name) {
+            return Ok(template);
+        }
+        
+        let mut names: Vec<&str> = self.comment_templates.keys().map(|n| n.as_str()).collect();
+        names.sort();
+        if names.is_empty() {
+            anyhow::bail!("No comment templates configured. Add a [comment_templates] section to your config.")
+        }
+        anyhow::bail!("Unknown comment template '{}'. Available: {}", name, names.join(", "))
+    }
+    
+    // Every secret with the keyring entry it's kept under, e.g. "jira:default" or "google:work"
+    fn secrets_mut(&mut self) -> Vec<(String, &mut String)> {
+        let mut secrets = vec![(format!("jira:{}", DEFAULT_JIRA_PROFILE), &mut self.api_token)];
+        secrets.extend(self.jira_profiles.iter_mut()
+            .map(|(name, profile)| (format!("jira:{}", name), &mut profile.api_token)));
+        secrets.extend(self.google_client_secret.as_mut()
+            .map(|secret| ("google:default".to_string(), secret)));
+        secrets.extend(self.google_accounts.iter_mut()
+            .map(|(name, account)| (format!("google:{}", name), &mut account.client_secret)));
+        secrets.extend(self.github.as_mut()
+            .map(|github| ("github".to_string(), &mut github.token)));
+        // Unset secrets, like the default profile's token when only named profiles are used
+        secrets.retain(|(_, secret)| !secret.is_empty());
+        secrets
+    }
+    
+    // Swaps the keyring placeholders for the secrets they stand for
+    fn resolve_secrets(&mut self) -> Result<()> {
+        for (name, secret) in self.secrets_mut() {
+            if secret == IN_KEYRING {
+                *secret = keyring::read(&name)?;
+            }
+        }
+        Ok(())
+    }
+    
+    // Writes each secret to the keyring and leaves a placeholder in its place. Returns how
+    // many were stored.
+    fn store_secrets(&mut self) -> Result<usize> {
+        let mut stored = 0;
+        for (name, secret) in self.secrets_mut() {
+            if secret != IN_KEYRING {
+                keyring::write(&name, secret)?;
+                *secret = IN_KEYRING.to_string();
+                stored += 1;
+            }
+        }
+        Ok(stored)
+    }
+    
+    // Moves the secrets from config.toml into the keyring, or back with `to_file`. Returns how
+    // many secrets were moved.
+    pub fn migrate_secrets(&mut self, to_file: bool) -> Result<usize> {
+        if to_file {
+            // Loading already read them out of the keyring, so saving writes them in plain text
+            self.secret_store = SecretStore::File;
+            self.save()?;
+            let names: Vec<String> = self.secrets_mut().into_iter().map(|(name, _)| name).collect();
+            for name in &names {
+                keyring::delete(name)?;
+            }
+            return Ok(names.len());
+        }
+        
+        self.secret_store = SecretStore::Keyring;
+        let moved = self.secrets_mut().len();
+        self.save()?;
+        // Read them back, so a keyring that accepts writes but can't return them is caught
+        // while the values are still at hand
+        Self::load().context("Secrets were written to the keyring but can't be read back")?;
+        Ok(moved)
     }
     
     pub fn save(&self) -> Result<()> {
         let config_path = Self::config_path()?;
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent).context("Failed to create config directory")?;
+        
+        let mut current = self.clone();
+        current.config_version = CONFIG_VERSION;
+        if current.secret_store == SecretStore::Keyring {
+            current.store_secrets()?;
         }
         
-        let toml_string = toml::to_string_pretty(&self)?;
-        fs::write(&config_path, toml_string).context("Failed to write config file")?;
+        let toml_string = toml::to_string_pretty(&current)?;
+        let _lock = store::lock(&config_path)?;
+        store::write_atomic(&config_path, toml_string.as_bytes())
+            .context("Failed to write config file")?;
         
         Ok(())
     }
@@ -46,19 +442,99 @@ impl Config {
         let contents = fs::read_to_string(&config_path)
             .context("Failed to read config file. Please run 'jira-git-cli config' first.")?;
         
-        let config: Config = toml::from_str(&contents)?;
+        let mut config: Config = toml::from_str(&contents)?;
+        if config.secret_store == SecretStore::Keyring {
+            config.resolve_secrets()?;
+        }
+        // Falling back to another instance here could mutate the wrong Jira
+        if let Some(name) = &config.active_jira_profile
+            && !config.jira_profiles.contains_key(name)
+        {
+            anyhow::bail!("Active Jira profile '{}' is no longer configured. Run 'qq use default' or 'qq use <profile>'.", name);
+        }
         Ok(config)
     }
     
-    fn config_path() -> Result<PathBuf> {
+    pub fn is_outdated(&self) -> bool {
+        self.config_version < CONFIG_VERSION
+    }
+    
+    pub fn config_path() -> Result<PathBuf> {
         let home_dir = dirs::config_dir()
             .context("Failed to determine config directory")?;
         Ok(home_dir.join("qq").join("config.toml"))
     }
     
-    pub fn google_token_path() -> Result<PathBuf> {
+    pub fn google_token_path(account: Option<&str>) -> Result<PathBuf> {
         let home_dir = dirs::config_dir()
             .context("Failed to determine config directory")?;
-        Ok(home_dir.join("qq").join("google_tokens.json"))
+        let file_name = match account {
+            Some(name) => {
+                check_account_name(name)?;
+                format!("google_tokens_{}.json", name)
+            }
+            None => "google_tokens.json".to_string(),
+        };
+        Ok(home_dir.join("qq").join(file_name))
     }
-}
\ No newline at end of file
+}
+
+// Account names become part of the token file name, so they're kept to a safe set of characters
+fn check_account_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        anyhow::bail!("Invalid Google account name '{}': use only letters, digits, '-' and '_'", name);
+    }
+    Ok(())
+}
+
+// Secrets in the OS keyring (macOS Keychain, Windows Credential Manager, or the Secret Service
+// on Linux), as entries of the "qq" service
+#[cfg(feature = "keyring")]
+mod keyring {
+    use anyhow::{Context, Result};
+    
+    const SERVICE: &str = "qq";
+    
+    fn entry(name: &str) -> Result<::keyring::Entry> {
+        ::keyring::Entry::new(SERVICE, name)
+            .context(format!("Failed to open keyring entry '{}'", name))
+    }
+    
+    pub fn read(name: &str) -> Result<String> {
+        entry(name)?.get_password()
+            .context(format!("Failed to read '{}' from the keyring. If it's gone, put the secret in config.toml in place of \"<keyring>\" and qq will store it again.", name))
+    }
+    
+    pub fn write(name: &str, secret: &str) -> Result<()> {
+        entry(name)?.set_password(secret)
+            .context(format!("Failed to write '{}' to the keyring", name))
+    }
+    
+    // Already gone counts as deleted
+    pub fn delete(name: &str) -> Result<()> {
+        match entry(name)?.delete_credential() {
+            Ok(()) | Err(::keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(e).context(format!("Failed to delete '{}' from the keyring", name)),
+        }
+    }
+}
+
+// Without the keyring, a config whose secrets were moved there can't be used by this build
+#[cfg(not(feature = "keyring"))]
+mod keyring {
+    use anyhow::Result;
+    
+    const NOT_COMPILED_IN: &str = "Keyring support is not compiled into this build of qq. Rebuild with the default features (or --features keyring), or run 'qq config migrate-secrets --to-file' with a build that has it.";
+    
+    pub fn read(_name: &str) -> Result<String> {
+        anyhow::bail!(NOT_COMPILED_IN)
+    }
+    
+    pub fn write(_name: &str, _secret: &str) -> Result<()> {
+        anyhow::bail!(NOT_COMPILED_IN)
+    }
+    
+    pub fn delete(_name: &str) -> Result<()> {
+        anyhow::bail!(NOT_COMPILED_IN)
+    }
+}
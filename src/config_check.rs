@@ -0,0 +1,162 @@
+use toml::Value;
+
+use crate::config::{Config, CONFIG_VERSION};
+use crate::style;
+
+// Keys renamed since they were introduced, as (old, new) dotted paths. Renamed fields keep
+// loading the old name through `#[serde(alias = "...")]`; this list only drives the warning.
+// Nothing has been renamed yet.
+const RENAMED_KEYS: &[(&str, &str)] = &[];
+
+// Unknown keys are only suggested a replacement when they look this much like a real one
+const MIN_SUGGESTION_SCORE: f64 = 0.4;
+
+#[derive(Debug, Default)]
+pub struct Report {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+// Everything `qq config validate` reports: TOML and type errors, unknown and deprecated keys,
+// and settings that can't work together (a Google client ID without its secret)
+pub fn check(contents: &str) -> Report {
+    let mut report = Report::default();
+
+    let raw: Value = match contents.parse::<toml::Table>() {
+        Ok(table) => Value::Table(table),
+        Err(e) => {
+            report.errors.push(format!("Not valid TOML: {}", e.to_string().trim_end()));
+            return report;
+        }
+    };
+    // toml's message names the key, the line, and what type was expected
+    let config: Config = match toml::from_str(contents) {
+        Ok(config) => config,
+        Err(e) => {
+            report.errors.push(e.to_string().trim_end().to_string());
+            return report;
+        }
+    };
+
+    report.warnings = key_warnings(&raw, &config);
+    if config.is_outdated() {
+        report.warnings.push(format!(
+            "config_version is {} (current is {}). Re-run 'qq config jira' or 'qq jira fields --pin' to pick up new settings.",
+            config.config_version, CONFIG_VERSION
+        ));
+    }
+    report.errors.extend(feature_errors(&config));
+    report
+}
+
+// Unknown and renamed keys. Found by writing the parsed config back out: whatever serde
+// dropped on the way in is a key qq doesn't read.
+pub fn key_warnings(raw: &Value, config: &Config) -> Vec<String> {
+    let Ok(known) = Value::try_from(config) else {
+        return Vec::new();
+    };
+    let mut unknown = Vec::new();
+    unknown_keys(raw, &known, "", &mut unknown);
+
+    unknown.into_iter()
+        .map(|(path, suggestion)| {
+            if let Some((_, new)) = RENAMED_KEYS.iter().find(|(old, _)| *old == path) {
+                format!("{} is deprecated; rename it to {}", path, new)
+            } else if let Some(suggestion) = suggestion {
+                format!("Unknown key {} (did you mean {}?)", path, suggestion)
+            } else {
+                format!("Unknown key {} is ignored", path)
+            }
+        })
+        .collect()
+}
+
+// Run on startup so a typo'd key doesn't go unnoticed; errors are left to the command that loads the config
+pub fn warn_on_load(config: &Config) {
+    let Some(raw) = Config::config_path().ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| contents.parse::<toml::Table>().ok())
+    else {
+        return;
+    };
+    let warnings = key_warnings(&Value::Table(raw), config);
+    for warning in &warnings {
+        style::warning(warning);
+    }
+    if !warnings.is_empty() {
+        eprintln!("Run 'qq config validate' to check the rest of the config.");
+    }
+}
+
+fn unknown_keys(raw: &Value, known: &Value, path: &str, found: &mut Vec<(String, Option<String>)>) {
+    match (raw, known) {
+        (Value::Table(raw), Value::Table(known)) => {
+            for (key, value) in raw {
+                let full = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                match known.get(key) {
+                    Some(known_value) => unknown_keys(value, known_value, &full, found),
+                    None => found.push((full, suggest(key, known.keys()))),
+                }
+            }
+        }
+        (Value::Array(raw), Value::Array(known)) => {
+            for (idx, (value, known_value)) in raw.iter().zip(known).enumerate() {
+                unknown_keys(value, known_value, &format!("{}[{}]", path, idx), found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn suggest<'a>(key: &str, candidates: impl Iterator<Item = &'a String>) -> Option<String> {
+    candidates
+        .map(|candidate| (crate::duplicate::similarity(key, candidate), candidate))
+        .filter(|(score, _)| *score >= MIN_SUGGESTION_SCORE)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, candidate)| candidate.clone())
+}
+
+// Settings that parse but can't be used as written
+fn feature_errors(config: &Config) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let urls = std::iter::once(("jira_url".to_string(), &config.jira_url))
+        .chain(config.jira_profiles.iter().map(|(name, p)| (format!("jira_profiles.{}.jira_url", name), &p.jira_url)));
+    for (key, url) in urls {
+        // An empty default profile just means only named profiles or Google are set up
+        if !url.is_empty() && !url.starts_with("https://") && !url.starts_with("http://") {
+            errors.push(format!("{} must start with https:// (got '{}')", key, url));
+        }
+    }
+    if let Some(name) = &config.active_jira_profile
+        && !config.jira_profiles.contains_key(name)
+    {
+        errors.push(format!("active_jira_profile is '{}', but there's no [jira_profiles.{}]", name, name));
+    }
+
+    match (&config.google_client_id, &config.google_client_secret) {
+        (Some(_), None) => errors.push("google_client_secret is missing; it's needed with google_client_id".to_string()),
+        (None, Some(_)) => errors.push("google_client_id is missing; it's needed with google_client_secret".to_string()),
+        _ => {}
+    }
+
+    for rule in &config.sla {
+        if let Err(e) = crate::sla::parse_window(&rule.resolve_within) {
+            errors.push(format!("[[sla]] rule '{}': {}", rule.name, e));
+        }
+    }
+
+    let hours = &config.working_hours;
+    if hours.start >= hours.end {
+        errors.push(format!("working_hours.start ({}) must be before working_hours.end ({})", hours.start, hours.end));
+    }
+    if hours.days.is_empty() {
+        errors.push("working_hours.days is empty, so no time would ever count as working hours".to_string());
+    }
+
+    if let Err(e) = crate::ui::Theme::new(&config.ui.theme) {
+        errors.push(e.to_string());
+    }
+
+    errors
+}
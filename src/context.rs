@@ -0,0 +1,121 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset};
+use serde_json::Value;
+
+use crate::google::{BlockingCalendarClient, Meeting};
+use crate::jira::{parse_jira_datetime, Comment, JiraClient, JiraIssue, PullRequest};
+
+#[derive(Debug)]
+pub struct ChangeEntry {
+    pub author: String,
+    pub created: Option<DateTime<FixedOffset>>,
+    pub field: String,
+    pub from: String,
+    pub to: String,
+}
+
+// Everything qq knows about one ticket, gathered from Jira, git, and the calendar
+pub struct TicketContext {
+    pub issue: JiraIssue,
+    pub comments: Vec<Comment>,
+    pub changes: Vec<ChangeEntry>,
+    pub pull_requests: Vec<PullRequest>,
+    pub branches: Vec<String>,
+    // None when Google Calendar isn't configured
+    pub meetings: Option<Vec<Meeting>>,
+}
+
+impl TicketContext {
+    pub fn gather(client: &JiraClient, calendar: Option<&BlockingCalendarClient>, issue_key: &str) -> Result<Self> {
+        let raw = client.get_issue_raw(issue_key, Some("changelog"))?;
+        let issue: JiraIssue = serde_json::from_value(raw.clone())
+            .context("Failed to parse JIRA issue")?;
+        let issue_id = raw.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+
+        // Comments and PRs are independent requests, so fetch them while the calendar loads
+        let (comments, pull_requests, meetings) = std::thread::scope(|scope| {
+            let comments = scope.spawn(|| client.get_comments(issue_key));
+            let pull_requests = scope.spawn(|| client.get_pull_requests(&issue_id).unwrap_or_default());
+            let meetings = calendar.and_then(|c| c.list_meetings().ok());
+            (comments.join(), pull_requests.join(), meetings)
+        });
+        let comments = comments.map_err(|_| anyhow::anyhow!("Comment fetch thread panicked"))??;
+        let pull_requests = pull_requests.map_err(|_| anyhow::anyhow!("Pull request fetch thread panicked"))?;
+
+        let key_lower = issue_key.to_lowercase();
+        let meetings = meetings.map(|meetings| {
+            meetings.into_iter()
+                .filter(|m| {
+                    m.ticket.as_deref() == Some(issue_key)
+                        || m.summary.to_lowercase().contains(&key_lower)
+                        || m.description.as_deref().is_some_and(|d| d.to_lowercase().contains(&key_lower))
+                })
+                .collect()
+        });
+
+        Ok(Self {
+            changes: parse_changelog(&raw),
+            branches: local_branches_matching(issue_key),
+            issue,
+            comments,
+            pull_requests,
+            meetings,
+        })
+    }
+}
+
+fn parse_changelog(raw: &Value) -> Vec<ChangeEntry> {
+    let histories = raw.get("changelog")
+        .and_then(|c| c.get("histories"))
+        .and_then(|h| h.as_array());
+
+    let mut changes = Vec::new();
+    for history in histories.into_iter().flatten() {
+        let author = history.get("author")
+            .and_then(|a| a.get("displayName"))
+            .and_then(|n| n.as_str())
+            .unwrap_or("Unknown")
+            .to_string();
+        let created = history.get("created")
+            .and_then(|c| c.as_str())
+            .and_then(parse_jira_datetime);
+
+        let items = history.get("items").and_then(|i| i.as_array());
+        for item in items.into_iter().flatten() {
+            let text = |side: &str| {
+                item.get(side)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string()
+            };
+            changes.push(ChangeEntry {
+                author: author.clone(),
+                created,
+                field: text("field"),
+                from: text("fromString"),
+                to: text("toString"),
+            });
+        }
+    }
+
+    // Newest first reads better in a history tab
+    changes.sort_by_key(|c| std::cmp::Reverse(c.created));
+    changes
+}
+
+// Local branches whose name mentions the key; empty outside a git repository
+fn local_branches_matching(issue_key: &str) -> Vec<String> {
+    let Ok(repo) = git2::Repository::discover(".") else {
+        return Vec::new();
+    };
+    let Ok(branches) = repo.branches(Some(git2::BranchType::Local)) else {
+        return Vec::new();
+    };
+
+    let key_lower = issue_key.to_lowercase();
+    branches
+        .filter_map(|branch| branch.ok())
+        .filter_map(|(branch, _)| branch.name().ok().flatten().map(|n| n.to_string()))
+        .filter(|name| name.to_lowercase().contains(&key_lower))
+        .collect()
+}
@@ -0,0 +1,230 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::jira::{JiraClient, JiraIssue, LinkedIssue};
+use crate::store;
+
+// When `qq jira dependencies ready` last ran, so each run shows what's been unblocked since
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DependencyStore {
+    #[serde(default)]
+    last_checked: Option<DateTime<Utc>>,
+}
+
+impl DependencyStore {
+    pub fn last_checked() -> Option<DateTime<Utc>> {
+        Self::store_path().ok()
+            .map(|path| store::read_json::<Self>(&path))
+            .and_then(|store| store.last_checked)
+    }
+
+    pub fn mark_checked(at: DateTime<Utc>) -> Result<()> {
+        store::edit_json(&Self::store_path()?, |store: &mut Self| store.last_checked = Some(at))
+    }
+
+    fn store_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .context("Failed to determine data directory")?;
+        Ok(data_dir.join("qq").join("dependencies.json"))
+    }
+}
+
+pub struct Blocker {
+    pub key: String,
+    pub summary: String,
+    // When it last changed status, i.e. when it was resolved; None when Jira has no record
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+pub struct ReadyIssue {
+    pub issue: JiraIssue,
+    pub blockers: Vec<Blocker>,
+    // When the last of its blockers was resolved
+    pub unblocked_at: Option<DateTime<Utc>>,
+}
+
+// "yesterday" for the start of yesterday, or a duration back from now like 12h or 3d
+pub fn parse_since(input: &str) -> Result<DateTime<Utc>> {
+    if input.trim().eq_ignore_ascii_case("yesterday") {
+        let yesterday = Local::now().date_naive() - Duration::days(1);
+        return Ok(crate::meeting_stats::start_of(yesterday).with_timezone(&Utc));
+    }
+    Ok(Utc::now() - crate::sla::parse_window(input)?)
+}
+
+fn blockers_of(issue: &JiraIssue) -> Vec<&LinkedIssue> {
+    issue.fields.issuelinks.iter()
+        .filter(|link| link.link_type.inward.to_lowercase().contains("blocked by"))
+        .filter_map(|link| link.inward_issue.as_ref())
+        .collect()
+}
+
+// My open tickets with at least one "is blocked by" link, all of them resolved. With `since`,
+// only those whose last blocker was resolved after it.
+pub fn ready(client: &JiraClient, since: Option<DateTime<Utc>>) -> Result<Vec<ReadyIssue>> {
+    let candidates: Vec<JiraIssue> = client.get_my_issues()?
+        .into_iter()
+        .filter(|issue| {
+            let blockers = blockers_of(issue);
+            !blockers.is_empty() && blockers.iter().all(|blocker| blocker.fields.is_done())
+        })
+        .collect();
+    if candidates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut keys: Vec<String> = candidates.iter()
+        .flat_map(|issue| blockers_of(issue).into_iter().map(|blocker| blocker.key.clone()))
+        .collect();
+    keys.sort();
+    keys.dedup();
+    let resolved = resolved_times(client, &keys)?;
+
+    let mut ready: Vec<ReadyIssue> = candidates.into_iter()
+        .map(|issue| {
+            let blockers: Vec<Blocker> = blockers_of(&issue).into_iter()
+                .map(|blocker| Blocker {
+                    key: blocker.key.clone(),
+                    summary: blocker.fields.summary.clone(),
+                    resolved_at: resolved.get(&blocker.key).copied(),
+                })
+                .collect();
+            let unblocked_at = blockers.iter().filter_map(|b| b.resolved_at).max();
+            ReadyIssue { issue, blockers, unblocked_at }
+        })
+        .filter(|ready| match since {
+            // Without a resolution time there's no telling when it happened, so it isn't "new"
+            Some(since) => ready.unblocked_at.is_some_and(|at| at > since),
+            None => true,
+        })
+        .collect();
+    ready.sort_by_key(|ready| std::cmp::Reverse(ready.unblocked_at));
+    Ok(ready)
+}
+
+// When each issue last changed status, from its changelog. The issues are all done, so that's
+// when they were resolved. Falls back to the resolution date when the changelog has no status change.
+fn resolved_times(client: &JiraClient, keys: &[String]) -> Result<HashMap<String, DateTime<Utc>>> {
+    let jql = format!("key in ({})", keys.join(","));
+    let issues = client.search_raw(&jql, "resolutiondate", Some("changelog"))?;
+
+    Ok(issues.iter()
+        .filter_map(|issue| {
+            let key = issue.get("key")?.as_str()?.to_string();
+            let histories = issue.pointer("/changelog/histories").and_then(Value::as_array);
+            let status_changed = histories.into_iter().flatten()
+                .filter(|history| {
+                    history.get("items").and_then(Value::as_array).into_iter().flatten()
+                        .any(|item| item.get("field").and_then(Value::as_str) == Some("status"))
+                })
+                .filter_map(|history| history.get("created")?.as_str().and_then(crate::jira::parse_jira_datetime))
+                .max();
+            let resolved = status_changed.or_else(|| {
+                issue.pointer("/fields/resolutiondate")?.as_str().and_then(crate::jira::parse_jira_datetime)
+            })?;
+            Some((key, resolved.with_timezone(&Utc)))
+        })
+        .collect())
+}
+
+// One of my open tickets that holds up someone else's
+#[derive(Debug, Serialize)]
+pub struct MyBlocker {
+    pub key: String,
+    pub summary: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockedIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub assignee: String,
+    pub blockers: Vec<MyBlocker>,
+    // When the first of my tickets was linked as its blocker; None when Jira has no record
+    pub blocked_since: Option<DateTime<Utc>>,
+}
+
+// Open tickets assigned to someone else with an "is blocked by" link to one of my open tickets,
+// blocked the longest first
+pub fn blocked_by_me(client: &JiraClient) -> Result<Vec<BlockedIssue>> {
+    let mine = client.get_my_issues()?;
+    let mut blockers: HashMap<String, Vec<MyBlocker>> = HashMap::new();
+    for issue in &mine {
+        let blocked = issue.fields.issuelinks.iter()
+            .filter(|link| link.link_type.outward.to_lowercase().contains("blocks"))
+            .filter_map(|link| link.outward_issue.as_ref())
+            .filter(|blocked| !blocked.fields.is_done());
+        for blocked in blocked {
+            blockers.entry(blocked.key.clone()).or_default().push(MyBlocker {
+                key: issue.key.clone(),
+                summary: issue.fields.summary.clone(),
+            });
+        }
+    }
+    if blockers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut keys: Vec<&String> = blockers.keys().collect();
+    keys.sort();
+    // Unassigned tickets don't match, since nobody is waiting on them yet
+    let jql = format!(
+        "key in ({}) AND assignee != currentUser()",
+        keys.iter().map(|key| key.as_str()).collect::<Vec<_>>().join(","),
+    );
+    let issues = client.search_raw(&jql, "summary,status,assignee", Some("changelog"))?;
+
+    let text = |issue: &Value, pointer: &str| issue.pointer(pointer).and_then(Value::as_str).unwrap_or_default().to_string();
+    let mut blocked: Vec<BlockedIssue> = issues.iter()
+        .filter_map(|issue| {
+            let key = text(issue, "/key");
+            let mine = blockers.remove(&key)?;
+            let blocked_since = mine.iter()
+                .filter_map(|blocker| linked_at(issue, &blocker.key))
+                .min();
+            Some(BlockedIssue {
+                summary: text(issue, "/fields/summary"),
+                status: text(issue, "/fields/status/name"),
+                assignee: text(issue, "/fields/assignee/displayName"),
+                key,
+                blockers: mine,
+                blocked_since,
+            })
+        })
+        .collect();
+    // Tickets with no record of when they were linked go last
+    blocked.sort_by_key(|issue| (issue.blocked_since.is_none(), issue.blocked_since));
+    Ok(blocked)
+}
+
+// When `other` was last linked to the issue, from its changelog
+fn linked_at(issue: &Value, other: &str) -> Option<DateTime<Utc>> {
+    let histories = issue.pointer("/changelog/histories").and_then(Value::as_array);
+    histories.into_iter().flatten()
+        .filter(|history| {
+            history.get("items").and_then(Value::as_array).into_iter().flatten()
+                .any(|item| {
+                    item.get("field").and_then(Value::as_str) == Some("Link")
+                        && item.get("to").and_then(Value::as_str) == Some(other)
+                })
+        })
+        .filter_map(|history| history.get("created")?.as_str().and_then(crate::jira::parse_jira_datetime))
+        .max()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+// "3h ago", for when a ticket was unblocked
+pub fn ago(at: DateTime<Utc>) -> String {
+    let elapsed = Utc::now() - at;
+    match elapsed.num_minutes() {
+        m if m < 1 => "just now".to_string(),
+        m if m < 60 => format!("{}m ago", m),
+        m if m < 48 * 60 => format!("{}h ago", m / 60),
+        _ => format!("{}d ago", elapsed.num_days()),
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+use crate::jira::{JiraClient, JiraIssue};
+
+// Below this score a match is usually just a shared word
+const MIN_SCORE: f64 = 0.35;
+const MAX_CANDIDATES: usize = 10;
+// Jira's text search matches any word, so ask for plenty and rank them here
+const SEARCH_LIMIT: usize = 50;
+// Longer summaries are searched by their most distinctive (longest) words
+const MAX_SEARCH_WORDS: usize = 8;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "into", "is", "it",
+    "of", "on", "or", "should", "so", "that", "the", "this", "to", "when", "with",
+];
+
+pub struct Candidate {
+    pub issue: JiraIssue,
+    pub score: f64,
+}
+
+pub fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 1 && !STOPWORDS.contains(w))
+        .map(|w| w.to_string())
+        .collect()
+}
+
+fn bigrams(text: &str) -> HashSet<(char, char)> {
+    let chars: Vec<char> = text.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+fn dice<T: Eq + std::hash::Hash>(a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    2.0 * a.intersection(b).count() as f64 / (a.len() + b.len()) as f64
+}
+
+// 0.0-1.0. Shared words count most; character pairs catch "log-in" vs "login" and plurals.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let words_a: HashSet<String> = words(a).into_iter().collect();
+    let words_b: HashSet<String> = words(b).into_iter().collect();
+    0.6 * dice(&words_a, &words_b) + 0.4 * dice(&bigrams(a), &bigrams(b))
+}
+
+// Existing issues that look like the same work as `summary`, best match first
+pub fn find(client: &JiraClient, summary: &str, project: Option<&str>, exclude: Option<&str>) -> Result<Vec<Candidate>> {
+    let mut search_words = words(summary);
+    search_words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+    search_words.dedup();
+    search_words.truncate(MAX_SEARCH_WORDS);
+
+    let issues = client.search_summary(&search_words, project, SEARCH_LIMIT)?;
+    let mut candidates: Vec<Candidate> = issues.into_iter()
+        .filter(|issue| Some(issue.key.as_str()) != exclude)
+        .map(|issue| Candidate {
+            score: similarity(summary, &issue.fields.summary),
+            issue,
+        })
+        .filter(|c| c.score >= MIN_SCORE)
+        .collect();
+
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates.truncate(MAX_CANDIDATES);
+    Ok(candidates)
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, FixedOffset};
+
+use crate::jira::JiraIssue;
+use crate::output::csv_field;
+
+// One epic with its children rolled up, a row of `qq jira epics export`
+#[derive(Debug)]
+pub struct EpicRollup {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub assignee: String,
+    pub children: usize,
+    pub done: usize,
+    pub points_total: f64,
+    pub points_remaining: f64,
+    // Latest update to the epic or any of its children
+    pub updated: Option<DateTime<FixedOffset>>,
+}
+
+impl EpicRollup {
+    pub fn build(epic: &JiraIssue, children: &[JiraIssue]) -> Self {
+        let points = |issue: &JiraIssue| issue.fields.story_points.unwrap_or(0.0);
+        let updated = children.iter()
+            .chain(std::iter::once(epic))
+            .filter_map(|issue| issue.fields.updated_at())
+            .max();
+
+        Self {
+            key: epic.key.clone(),
+            summary: epic.fields.summary.clone(),
+            status: epic.fields.status.name.clone(),
+            assignee: epic.fields.assignee.as_ref().map(|a| a.display_name.clone()).unwrap_or_default(),
+            children: children.len(),
+            done: children.iter().filter(|c| c.fields.is_done()).count(),
+            points_total: children.iter().map(points).sum(),
+            points_remaining: children.iter().filter(|c| !c.fields.is_done()).map(points).sum(),
+            updated,
+        }
+    }
+
+    // Share of children done; an epic without children counts as 0%
+    pub fn done_percent(&self) -> f64 {
+        if self.children == 0 {
+            0.0
+        } else {
+            self.done as f64 / self.children as f64 * 100.0
+        }
+    }
+
+    fn updated_date(&self) -> String {
+        self.updated.map(|u| u.format("%Y-%m-%d").to_string()).unwrap_or_default()
+    }
+}
+
+pub fn to_csv(rows: &[EpicRollup]) -> String {
+    let mut csv = String::from("key,summary,status,assignee,children,done,done_percent,points_total,points_remaining,updated\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{:.0},{},{},{}\n",
+            csv_field(&row.key),
+            csv_field(&row.summary),
+            csv_field(&row.status),
+            csv_field(&row.assignee),
+            row.children,
+            row.done,
+            row.done_percent(),
+            row.points_total,
+            row.points_remaining,
+            row.updated_date()
+        ));
+    }
+    csv
+}
+
+// Aligned columns for reading in the terminal
+pub fn to_table(rows: &[EpicRollup]) -> String {
+    let key_width = rows.iter().map(|r| r.key.len()).max().unwrap_or(0).max(3);
+    let mut table = format!(
+        "{:key_width$}  {:>9}  {:>5}  {:>9}  {:10}  {}\n",
+        "KEY", "CHILDREN", "DONE", "PTS LEFT", "UPDATED", "SUMMARY",
+        key_width = key_width
+    );
+    for row in rows {
+        table.push_str(&format!(
+            "{:key_width$}  {:>9}  {:>4.0}%  {:>9}  {:10}  {}\n",
+            row.key,
+            format!("{}/{}", row.done, row.children),
+            row.done_percent(),
+            row.points_remaining,
+            row.updated_date(),
+            row.summary,
+            key_width = key_width
+        ));
+    }
+    table
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::cache::{CachedIssue, IssueCache};
+
+// Local full-text search over the issue cache for `qq jira find`. The inverted index is built
+// from the cache on each run: a few thousand cached issues index in milliseconds, and there's
+// nothing on disk to keep in sync with the cache.
+
+// A word in the summary says more about an issue than one in a long comment thread
+const SUMMARY_WEIGHT: f64 = 3.0;
+const DESCRIPTION_WEIGHT: f64 = 1.0;
+const COMMENT_WEIGHT: f64 = 0.5;
+// Characters of context shown either side of the first match
+const SNIPPET_CONTEXT: usize = 40;
+
+pub struct Hit<'a> {
+    pub issue: &'a CachedIssue,
+    pub score: f64,
+    // Where a query word appears, when it isn't in the summary
+    pub snippet: Option<String>,
+}
+
+pub struct Index<'a> {
+    issues: Vec<&'a CachedIssue>,
+    // Stemmed word -> issue index -> weighted occurrences
+    postings: HashMap<String, HashMap<usize, f64>>,
+}
+
+impl<'a> Index<'a> {
+    pub fn build(cache: &'a IssueCache) -> Self {
+        let issues: Vec<&CachedIssue> = cache.all().collect();
+        let mut postings: HashMap<String, HashMap<usize, f64>> = HashMap::new();
+
+        for (idx, issue) in issues.iter().enumerate() {
+            let mut add = |text: &str, weight: f64| {
+                for term in terms(text) {
+                    *postings.entry(term).or_default().entry(idx).or_default() += weight;
+                }
+            };
+            add(&issue.key, SUMMARY_WEIGHT);
+            add(&issue.summary, SUMMARY_WEIGHT);
+            if let Some(description) = &issue.description {
+                add(description, DESCRIPTION_WEIGHT);
+            }
+            for comment in &issue.comments {
+                add(comment, COMMENT_WEIGHT);
+            }
+        }
+
+        Self { issues, postings }
+    }
+
+    // Issues containing every word of the query, best first. Rarer words count for more.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<Hit<'a>> {
+        let mut query_terms = terms(query);
+        query_terms.sort();
+        query_terms.dedup();
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: Option<HashMap<usize, f64>> = None;
+        for term in &query_terms {
+            let Some(docs) = self.postings.get(term) else {
+                return Vec::new();
+            };
+            let idf = (1.0 + self.issues.len() as f64 / docs.len() as f64).ln();
+            scores = Some(match scores {
+                None => docs.iter().map(|(idx, weight)| (*idx, weight * idf)).collect(),
+                Some(scores) => scores.into_iter()
+                    .filter_map(|(idx, score)| docs.get(&idx).map(|weight| (idx, score + weight * idf)))
+                    .collect(),
+            });
+        }
+
+        let mut hits: Vec<Hit> = scores.unwrap_or_default()
+            .into_iter()
+            .map(|(idx, score)| {
+                let issue = self.issues[idx];
+                Hit { issue, score, snippet: snippet(issue, &query_terms) }
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.issue.key.cmp(&b.issue.key)));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+// Lowercased, stemmed words without stopwords, so "Payment retries" matches "retrying payments"
+fn terms(text: &str) -> Vec<String> {
+    crate::duplicate::words(text).iter().map(|word| stem(word)).collect()
+}
+
+fn stem(word: &str) -> String {
+    const SUFFIXES: &[(&str, &str)] = &[
+        ("sses", "ss"), ("ies", "y"), ("ches", "ch"), ("shes", "sh"), ("xes", "x"),
+        ("ing", ""), ("ed", ""), ("ss", "ss"), ("s", ""),
+    ];
+    for (suffix, replacement) in SUFFIXES {
+        if let Some(base) = word.strip_suffix(suffix)
+            && base.len() >= 3
+        {
+            return format!("{}{}", base, replacement);
+        }
+    }
+    word.to_string()
+}
+
+// The description or comment line where a query word first appears, trimmed around it
+fn snippet(issue: &CachedIssue, query_terms: &[String]) -> Option<String> {
+    if query_terms.iter().any(|term| terms(&issue.summary).contains(term)) {
+        return None;
+    }
+    let lines = issue.description.iter().chain(&issue.comments).flat_map(|text| text.lines());
+    for line in lines {
+        let words = crate::duplicate::words(line);
+        let Some(word) = words.iter().find(|word| query_terms.contains(&stem(word))) else {
+            continue;
+        };
+        let lower = line.to_lowercase();
+        let at = lower.find(word.as_str()).unwrap_or(0);
+        let start = line.floor_char_boundary(at.saturating_sub(SNIPPET_CONTEXT));
+        let end = line.ceil_char_boundary((at + word.len() + SNIPPET_CONTEXT).min(line.len()));
+        let mut snippet = line[start..end].trim().to_string();
+        if start > 0 {
+            snippet.insert(0, '…');
+        }
+        if end < line.len() {
+            snippet.push('…');
+        }
+        return Some(snippet);
+    }
+    None
+}
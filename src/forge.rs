@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use git2::Repository;
+use std::collections::HashMap;
+use std::process::Command;
+
+use crate::config::GitHubConfig;
+use crate::github::{GitHubClient, RepoId};
+use crate::jira::{JiraClient, JiraIssue, PullRequest};
+
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrState {
+    Open,
+    Approved,
+    ChangesRequested,
+    Merged,
+    Declined,
+}
+
+impl PrState {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Approved => "approved",
+            Self::ChangesRequested => "changes requested",
+            Self::Merged => "merged",
+            Self::Declined => "declined",
+        }
+    }
+
+    fn of(pr: &PullRequest) -> Self {
+        match pr.status.to_uppercase().as_str() {
+            "MERGED" => Self::Merged,
+            "DECLINED" => Self::Declined,
+            _ if pr.reviewers.iter().any(|r| r.approval_status.as_deref() == Some("NEEDSWORK")) => Self::ChangesRequested,
+            _ if pr.reviewers.iter().any(|r| r.approved) => Self::Approved,
+            _ => Self::Open,
+        }
+    }
+
+    // Open work first, then merged, then abandoned
+    fn rank(self) -> u8 {
+        match self {
+            Self::ChangesRequested | Self::Approved | Self::Open => 0,
+            Self::Merged => 1,
+            Self::Declined => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PrStatus {
+    pub state: PrState,
+    pub url: String,
+    // Some(true) when CI is green, Some(false) when any build failed, None when no builds are known
+    pub ci_passing: Option<bool>,
+}
+
+// The pull request for an issue as seen by the Jira development panel (GitHub, GitLab,
+// Bitbucket). PRs from a branch that mentions the key win over ones only linked by title.
+pub fn status_for(client: &JiraClient, issue: &JiraIssue) -> Option<PrStatus> {
+    if issue.id.is_empty() {
+        return None;
+    }
+    let pull_requests = client.get_pull_requests(&issue.id).unwrap_or_default();
+    let from_branch = |pr: &PullRequest| pr.source.as_ref()
+        .is_some_and(|source| crate::git::mentions_ticket(&source.branch, &issue.key));
+    let pr = pull_requests.iter()
+        .min_by_key(|pr| (!from_branch(pr), PrState::of(pr).rank()))?;
+
+    let ci_passing = client.get_build_summary(&issue.id).ok().flatten()
+        .and_then(|builds| match (builds.failed, builds.successful) {
+            (0, 0) => None,
+            (0, _) => Some(true),
+            _ => Some(false),
+        });
+
+    Some(PrStatus {
+        state: PrState::of(pr),
+        url: pr.url.clone(),
+        ci_passing,
+    })
+}
+
+// Issue key to PR status for every issue that has one
+pub fn statuses_for(client: &JiraClient, issues: Vec<JiraIssue>) -> HashMap<String, PrStatus> {
+    crate::pool::run_parallel(issues, MAX_CONCURRENT_REQUESTS, |issue| {
+        status_for(client, &issue).map(|status| (issue.key, status))
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+// A pull request to open from `branch`, into `base` or the repository's default branch
+pub struct PrRequest<'a> {
+    pub branch: &'a str,
+    pub base: Option<&'a str>,
+    pub title: &'a str,
+    pub body: &'a str,
+    pub draft: bool,
+}
+
+pub struct OpenedPr {
+    pub url: String,
+    // False when the branch already had an open pull request, which is returned instead
+    pub created: bool,
+}
+
+// Opens a pull request for a branch already pushed to origin. With a token from
+// `qq config github`, GitHub remotes go through the REST API. Otherwise it goes through the
+// host's own CLI (gh or glab), which already knows how to sign in.
+pub fn open_pr(repo: &Repository, github: Option<&GitHubConfig>, request: &PrRequest) -> Result<OpenedPr> {
+    let remote_url = crate::git::remote_url(repo)
+        .context("No 'origin' remote to open a pull request on")?;
+
+    if let Some(config) = github
+        && (remote_url.contains("github") || config.api_url.is_some())
+    {
+        let repo_id = RepoId::from_remote(&remote_url)
+            .context(format!("Couldn't tell the GitHub repository from origin ({})", remote_url))?;
+        return open_with_api(&GitHubClient::new(config), &repo_id, request);
+    }
+    open_with_cli(repo, &remote_url, request)
+}
+
+fn open_with_api(github: &GitHubClient, repo_id: &RepoId, request: &PrRequest) -> Result<OpenedPr> {
+    let base = match request.base {
+        Some(base) => base.to_string(),
+        None => github.default_branch(repo_id)?,
+    };
+    if request.branch == base {
+        anyhow::bail!("'{}' is the branch the pull request would merge into; check out the ticket's branch first", base);
+    }
+
+    // Pushing again to a branch with an open pull request updates it; there's nothing to create
+    if let Some(existing) = github.find_open_pr(repo_id, request.branch)? {
+        return Ok(OpenedPr { url: existing.html_url, created: false });
+    }
+    let pr = github.create_pr(repo_id, request.branch, &base, request.title, request.body, request.draft)?;
+    Ok(OpenedPr { url: pr.html_url, created: true })
+}
+
+fn open_with_cli(repo: &Repository, remote_url: &str, request: &PrRequest) -> Result<OpenedPr> {
+    let PrRequest { branch, base, title, body, draft } = *request;
+    let (tool, mut args) = if remote_url.contains("github") {
+        let existing = run_cli(repo, "gh", &["pr", "list", "--head", branch, "--state", "open", "--json", "url", "--jq", ".[0].url"])?;
+        if !existing.trim().is_empty() {
+            return Ok(OpenedPr { url: existing.trim().to_string(), created: false });
+        }
+        ("gh", vec!["pr", "create", "--head", branch, "--title", title, "--body", body])
+    } else if remote_url.contains("gitlab") {
+        // glab refuses to open a second merge request for a branch on its own
+        ("glab", vec!["mr", "create", "--yes", "--source-branch", branch, "--title", title, "--description", body])
+    } else {
+        anyhow::bail!("Pull requests are supported for GitHub and GitLab remotes (origin is {})", remote_url);
+    };
+    if draft {
+        args.push("--draft");
+    }
+    if let Some(base) = base {
+        args.extend([if tool == "gh" { "--base" } else { "--target-branch" }, base]);
+    }
+
+    // Both print the new pull request's address, after any progress lines
+    run_cli(repo, tool, &args)?
+        .lines()
+        .rev()
+        .find_map(|line| line.split_whitespace().find(|word| word.starts_with("https://")))
+        .map(|url| OpenedPr { url: url.to_string(), created: true })
+        .context(format!("{} opened the pull request but didn't print its address", tool))
+}
+
+// Standard output of a gh or glab command run in the repository
+fn run_cli(repo: &Repository, tool: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(tool)
+        .args(args)
+        .current_dir(repo.workdir().unwrap_or(repo.path()))
+        .output()
+        .context(format!("Failed to run '{}'. Install it and sign in with '{} auth login'.", tool, tool))?;
+    if !output.status.success() {
+        anyhow::bail!("'{} {}' failed: {}", tool, args[..2].join(" "), String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
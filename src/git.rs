@@ -0,0 +1,154 @@
+use anyhow::{Context, Result};
+use git2::{BranchType, Cred, CredentialType, Direction, FetchOptions, PushOptions, RemoteCallbacks, Repository};
+
+const REMOTE: &str = "origin";
+// libgit2 keeps asking for credentials after a rejected attempt; give up instead of looping
+const MAX_CREDENTIAL_ATTEMPTS: usize = 3;
+
+// True when the branch name mentions the ticket key as a whole key, so PROJ-1 doesn't match PROJ-12
+pub fn mentions_ticket(branch_name: &str, ticket: &str) -> bool {
+    let name = branch_name.to_lowercase();
+    let ticket = ticket.to_lowercase();
+    name.match_indices(&ticket).any(|(idx, _)| {
+        let before = name[..idx].chars().next_back();
+        let after = name[idx + ticket.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric()) && !after.is_some_and(|c| c.is_ascii_digit())
+    })
+}
+
+// Credentials the way the git CLI finds them: the SSH agent, then credential helpers
+fn callbacks(repo: &Repository) -> RemoteCallbacks<'static> {
+    let git_config = repo.config().ok();
+    let mut attempts = 0;
+
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(move |url, username, allowed| {
+        attempts += 1;
+        if attempts > MAX_CREDENTIAL_ATTEMPTS {
+            return Err(git2::Error::from_str("Authentication failed"));
+        }
+        if allowed.contains(CredentialType::SSH_KEY) {
+            Cred::ssh_key_from_agent(username.unwrap_or("git"))
+        } else if allowed.contains(CredentialType::USER_PASS_PLAINTEXT)
+            && let Some(config) = &git_config
+        {
+            Cred::credential_helper(config, url, username)
+        } else {
+            Cred::default()
+        }
+    });
+    callbacks
+}
+
+// Local branch whose name mentions the ticket key, e.g. feature/PROJ-12 or proj-12-fix-login
+pub fn find_local_branch(repo: &Repository, ticket: &str) -> Option<String> {
+    repo.branches(Some(BranchType::Local)).ok()?
+        .filter_map(|branch| branch.ok())
+        .filter_map(|(branch, _)| branch.name().ok().flatten().map(|n| n.to_string()))
+        .find(|name| mentions_ticket(name, ticket))
+}
+
+// Branch on origin whose name mentions the ticket key. Asks the remote directly so branches
+// pushed by teammates since the last fetch are found; falls back to the remote-tracking
+// branches already fetched when the remote can't be reached.
+pub fn find_remote_branch(repo: &Repository, ticket: &str) -> Option<String> {
+    let mut remote = repo.find_remote(REMOTE).ok()?;
+    if let Ok(connection) = remote.connect_auth(Direction::Fetch, Some(callbacks(repo)), None)
+        && let Ok(heads) = connection.list()
+    {
+        return heads.iter()
+            .filter_map(|head| head.name().strip_prefix("refs/heads/"))
+            .find(|name| mentions_ticket(name, ticket))
+            .map(|name| name.to_string());
+    }
+
+    let prefix = format!("{}/", REMOTE);
+    repo.branches(Some(BranchType::Remote)).ok()?
+        .filter_map(|branch| branch.ok())
+        .filter_map(|(branch, _)| branch.name().ok().flatten().map(|n| n.to_string()))
+        .filter_map(|name| name.strip_prefix(&prefix).map(|n| n.to_string()))
+        .find(|name| name != "HEAD" && mentions_ticket(name, ticket))
+}
+
+pub fn checkout_local(repo: &Repository, branch_name: &str) -> Result<()> {
+    let refname = format!("refs/heads/{}", branch_name);
+    let obj = repo.revparse_single(&refname)
+        .context(format!("Failed to find branch '{}'", branch_name))?;
+    repo.checkout_tree(&obj, None)
+        .context(format!("Failed to checkout branch '{}'", branch_name))?;
+    repo.set_head(&refname)
+        .context(format!("Failed to set HEAD to branch '{}'", branch_name))?;
+    Ok(())
+}
+
+// Fetches a branch from origin and checks it out as a local branch tracking it
+pub fn checkout_tracking(repo: &Repository, branch_name: &str) -> Result<()> {
+    let mut remote = repo.find_remote(REMOTE).context("No 'origin' remote")?;
+    let refspec = format!("refs/heads/{0}:refs/remotes/{1}/{0}", branch_name, REMOTE);
+    let mut options = FetchOptions::new();
+    options.remote_callbacks(callbacks(repo));
+    remote.fetch(&[refspec.as_str()], Some(&mut options), None)
+        .context(format!("Failed to fetch '{}' from {}", branch_name, REMOTE))?;
+
+    let upstream = format!("{}/{}", REMOTE, branch_name);
+    let commit = repo.find_branch(&upstream, BranchType::Remote)
+        .and_then(|b| b.get().peel_to_commit())
+        .context(format!("Failed to find '{}' after fetching", upstream))?;
+
+    let mut branch = repo.branch(branch_name, &commit, false)
+        .context(format!("Failed to create branch '{}'", branch_name))?;
+    branch.set_upstream(Some(&upstream))
+        .context(format!("Failed to track '{}'", upstream))?;
+
+    checkout_local(repo, branch_name)
+}
+
+// Pushes a local branch to origin and sets it as the branch's upstream
+pub fn push_with_upstream(repo: &Repository, branch_name: &str) -> Result<()> {
+    let mut remote = repo.find_remote(REMOTE).context("No 'origin' remote")?;
+    let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch_name);
+    let mut options = PushOptions::new();
+    options.remote_callbacks(callbacks(repo));
+    remote.push(&[refspec.as_str()], Some(&mut options))
+        .context(format!("Failed to push '{}' to {}", branch_name, REMOTE))?;
+
+    // A successful push updates refs/remotes/origin/<branch>, which tracking needs
+    let upstream = format!("{}/{}", REMOTE, branch_name);
+    let mut branch = repo.find_branch(branch_name, BranchType::Local)?;
+    branch.set_upstream(Some(&upstream))
+        .context(format!("Failed to track '{}'", upstream))?;
+    Ok(())
+}
+
+pub fn remote_url(repo: &Repository) -> Option<String> {
+    repo.find_remote(REMOTE).ok()?.url().map(|url| url.to_string())
+}
+
+// True when the branch has commits origin's default branch doesn't, so a pull request would
+// have something to show. Assumed true when origin/HEAD isn't known locally.
+pub fn has_commits_ahead_of_default(repo: &Repository, branch_name: &str) -> Result<bool> {
+    let tip = repo.find_branch(branch_name, BranchType::Local)
+        .and_then(|b| b.get().peel_to_commit())
+        .context(format!("Failed to find branch '{}'", branch_name))?
+        .id();
+    let Ok(base) = repo.find_reference(&format!("refs/remotes/{}/HEAD", REMOTE))
+        .and_then(|r| r.resolve())
+        .and_then(|r| r.peel_to_commit())
+    else {
+        return Ok(true);
+    };
+    Ok(tip != base.id() && !repo.graph_descendant_of(base.id(), tip)?)
+}
+
+// Commits the current tree again on HEAD, giving a fresh branch something to open a pull request for
+pub fn commit_empty(repo: &Repository, message: &str) -> Result<()> {
+    let signature = repo.signature()
+        .context("Set user.name and user.email in your git config to commit")?;
+    let parent = repo.head()
+        .and_then(|head| head.peel_to_commit())
+        .context("Failed to find HEAD commit")?;
+    let tree = parent.tree()?;
+    repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[&parent])
+        .context("Failed to create commit")?;
+    Ok(())
+}
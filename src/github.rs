@@ -0,0 +1,147 @@
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use serde::{Deserialize, Serialize};
+
+use crate::config::GitHubConfig;
+
+// Pull requests through GitHub's REST API, signed in with the token from `qq config github`.
+// Without a token, forge.rs opens them with the gh CLI instead. Classic tokens need the "repo"
+// scope; fine-grained ones need read and write access to pull requests.
+
+const DEFAULT_API_URL: &str = "https://api.github.com";
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequest {
+    pub html_url: String,
+}
+
+// owner/name of a repository on GitHub
+#[derive(Debug, Clone)]
+pub struct RepoId {
+    pub owner: String,
+    pub name: String,
+}
+
+impl RepoId {
+    // From a remote URL: git@github.com:owner/name.git, https://github.com/owner/name, or
+    // ssh://git@github.example.com/owner/name.git
+    pub fn from_remote(url: &str) -> Option<Self> {
+        let path = match url.split_once("://") {
+            Some((_, rest)) => rest.split_once('/')?.1,
+            None => url.split_once(':')?.1,
+        };
+        let mut parts = path.trim_end_matches('/').trim_end_matches(".git").rsplitn(2, '/');
+        let name = parts.next()?.to_string();
+        let owner = parts.next()?.rsplit('/').next()?.to_string();
+        (!owner.is_empty() && !name.is_empty()).then_some(Self { owner, name })
+    }
+}
+
+impl std::fmt::Display for RepoId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.owner, self.name)
+    }
+}
+
+pub struct GitHubClient {
+    client: Client,
+    api_url: String,
+    token: String,
+}
+
+impl GitHubClient {
+    pub fn new(config: &GitHubConfig) -> Self {
+        Self {
+            client: Client::new(),
+            api_url: config.api_url.as_deref()
+                .unwrap_or(DEFAULT_API_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            token: config.token.clone(),
+        }
+    }
+
+    pub fn default_branch(&self, repo: &RepoId) -> Result<String> {
+        #[derive(Deserialize)]
+        struct Repository {
+            default_branch: String,
+        }
+
+        let repository: Repository = self.get_json(&format!("/repos/{}", repo))
+            .context(format!("Failed to look up {} on GitHub", repo))?;
+        Ok(repository.default_branch)
+    }
+
+    // The open pull request from `branch`, if there is one
+    pub fn find_open_pr(&self, repo: &RepoId, branch: &str) -> Result<Option<PullRequest>> {
+        let path = format!("/repos/{}/pulls?state=open&head={}:{}", repo, repo.owner, branch);
+        let pull_requests: Vec<PullRequest> = self.get_json(&path)
+            .context(format!("Failed to list pull requests on {}", repo))?;
+        Ok(pull_requests.into_iter().next())
+    }
+
+    pub fn create_pr(&self, repo: &RepoId, head: &str, base: &str, title: &str, body: &str, draft: bool) -> Result<PullRequest> {
+        #[derive(Serialize)]
+        struct CreateRequest<'a> {
+            title: &'a str,
+            head: &'a str,
+            base: &'a str,
+            body: &'a str,
+            draft: bool,
+        }
+
+        let response = self.request(self.client.post(format!("{}/repos/{}/pulls", self.api_url, repo)))
+            .json(&CreateRequest { title, head, base, body, draft })
+            .send()
+            .context("Failed to send request to GitHub")?;
+
+        let status = response.status();
+        let response_text = response.text()?;
+        if !status.is_success() {
+            anyhow::bail!("GitHub couldn't open the pull request: {} {}", status, error_message(&response_text));
+        }
+        serde_json::from_str(&response_text).context("Failed to parse GitHub response")
+    }
+
+    fn get_json<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<T> {
+        let response = self.request(self.client.get(format!("{}{}", self.api_url, path)))
+            .send()
+            .context("Failed to send request to GitHub")?;
+
+        let status = response.status();
+        let response_text = response.text()?;
+        if !status.is_success() {
+            anyhow::bail!("GitHub API error: {} {}", status, error_message(&response_text));
+        }
+        serde_json::from_str(&response_text).context("Failed to parse GitHub response")
+    }
+
+    fn request(&self, builder: reqwest::blocking::RequestBuilder) -> reqwest::blocking::RequestBuilder {
+        builder
+            .header(AUTHORIZATION, format!("Bearer {}", self.token))
+            .header(USER_AGENT, format!("qq/{}", crate::release::current_version()))
+            .header(ACCEPT, "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+    }
+}
+
+// GitHub's explanation from an error response, e.g. "Validation Failed: No commits between
+// main and feature/PROJ-1"
+fn error_message(response_text: &str) -> String {
+    let Ok(error) = serde_json::from_str::<serde_json::Value>(response_text) else {
+        return response_text.trim().to_string();
+    };
+    let message = error.get("message").and_then(|m| m.as_str()).unwrap_or_default();
+    let details: Vec<&str> = error.get("errors")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+        .collect();
+    if details.is_empty() {
+        message.to_string()
+    } else {
+        format!("{}: {}", message, details.join("; "))
+    }
+}
@@ -0,0 +1,1048 @@
+use anyhow::{Context, Result};
+use google_calendar3::{api, CalendarHub, hyper_rustls};
+use yup_oauth2::{DeviceFlowAuthenticator, InstalledFlowAuthenticator, InstalledFlowReturnMethod};
+use yup_oauth2::authenticator_delegate::InstalledFlowDelegate;
+use yup_oauth2::storage::{TokenInfo, TokenStorage};
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use chrono_tz::America::New_York;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::Duration;
+
+use crate::config::{GoogleAccount, GoogleAuthFlow};
+use crate::store;
+use super::{
+    Attachment, Attendee, CalendarInfo, DialIn, HoursConflict, Importance, Meeting, MeetingRule, ReminderEvent,
+    SavedToken, WorkingHours,
+};
+
+// Extended property holding the Jira ticket a meeting is about, set by `qq meetings book --ticket`.
+// Private properties are only visible on my copy of the event; shared ones are read too so
+// tickets linked by other tools show up.
+const TICKET_PROPERTY: &str = "qqTicket";
+// Marks events qq writes for its own reminders (currently only "snooze"); they're left out of
+// meeting lists and found again by this property when reconciling
+const REMINDER_PROPERTY: &str = "qqReminder";
+// Reminder events are short so they don't cover the day view
+const REMINDER_EVENT_MINUTES: i64 = 15;
+
+fn is_reminder(event: &api::Event) -> bool {
+    event.extended_properties.as_ref()
+        .and_then(|p| p.private.as_ref())
+        .is_some_and(|values| values.contains_key(REMINDER_PROPERTY))
+}
+
+fn linked_ticket(event: &api::Event) -> Option<String> {
+    let properties = event.extended_properties.as_ref();
+    let linked = [properties.and_then(|p| p.private.as_ref()), properties.and_then(|p| p.shared.as_ref())]
+        .into_iter()
+        .flatten()
+        .find_map(|values| values.get(TICKET_PROPERTY))
+        .map(|key| key.trim().to_uppercase())
+        .filter(|key| !key.is_empty());
+    if linked.is_some() {
+        return linked;
+    }
+
+    [event.summary.as_deref(), event.description.as_deref()]
+        .into_iter()
+        .flatten()
+        .find_map(|text| crate::jira::issue_keys(text).into_iter().next())
+}
+
+impl MeetingRule {
+    fn matches(&self, meeting: &Meeting) -> bool {
+        let title_matches = self.title_contains.as_ref()
+            .is_none_or(|t| meeting.summary.to_lowercase().contains(&t.to_lowercase()));
+        let color_matches = self.color.as_ref().is_none_or(|c| {
+            meeting.color_id.as_deref() == Some(c.as_str())
+                || meeting.color_name().is_some_and(|name| name.eq_ignore_ascii_case(c))
+        });
+        let optional_matches = self.optional.is_none_or(|o| o == meeting.is_optional);
+
+        title_matches && color_matches && optional_matches
+    }
+}
+
+pub struct GoogleCalendarClient {
+    hub: CalendarHub<hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>>,
+    auth: Authenticator,
+    flow: GoogleAuthFlow,
+    token_path: PathBuf,
+    rules: Vec<MeetingRule>,
+    working_hours: WorkingHours,
+}
+
+type Authenticator = yup_oauth2::authenticator::Authenticator<
+    hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>,
+>;
+
+const SIGN_IN_NEEDED: &str = "Google sign-in is missing, expired, or revoked. Run 'qq auth google login' in a terminal to sign in again (add --account NAME for a named account).";
+
+// A refresh takes a moment; anything longer means the user is being asked to sign in
+const UNATTENDED_AUTH_TIMEOUT: Duration = Duration::from_secs(20);
+
+// How long the browser sign-in may sit unfinished before qq gives up and closes the listener
+const BROWSER_AUTH_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+// The browser flow's local listener. Google only accepts the redirect URI registered for the
+// client, so the paste flow sends the browser to the same address even though nothing listens.
+const REDIRECT_PORT: u16 = 8080;
+const REDIRECT_URI: &str = "http://localhost:8080";
+
+// Signing in only asks to read the calendar. Changing events (declining, cancelling, booking)
+// needs the events scope, which is asked for the first time one of those is used.
+const READ_SCOPE: &str = "https://www.googleapis.com/auth/calendar.readonly";
+const WRITE_SCOPE: &str = "https://www.googleapis.com/auth/calendar.events";
+
+// Token storage in the same format yup-oauth2 writes to disk, but updated under a lock with
+// an atomic rename so two qq processes refreshing at once can't corrupt the file
+struct TokenFile {
+    path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredToken {
+    // What the token was requested for, which is how yup-oauth2 looks it up
+    scopes: Vec<String>,
+    // What Google actually granted: more than requested with incremental consent, or less
+    // when a box was left unticked. Empty in token files written before this was recorded.
+    #[serde(default)]
+    granted: Vec<String>,
+    token: TokenInfo,
+}
+
+impl StoredToken {
+    fn granted(&self) -> &[String] {
+        if self.granted.is_empty() { &self.scopes } else { &self.granted }
+    }
+
+    fn covers(&self, scopes: &[&str]) -> bool {
+        scopes.iter().all(|s| self.granted().iter().any(|t| t == s))
+    }
+
+    fn is_exactly(&self, scopes: &[&str]) -> bool {
+        self.scopes.len() == scopes.len() && scopes.iter().all(|s| self.scopes.iter().any(|t| t == s))
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStorage for TokenFile {
+    async fn set(&self, scopes: &[&str], token: TokenInfo) -> anyhow::Result<()> {
+        store::edit_json(&self.path, |tokens: &mut Vec<StoredToken>| {
+            // A refreshed token can do what the one it replaces could
+            let granted = tokens.iter()
+                .find(|t| t.is_exactly(scopes))
+                .map(|t| t.granted.clone())
+                .unwrap_or_default();
+            tokens.retain(|t| !t.is_exactly(scopes));
+            tokens.push(StoredToken {
+                scopes: scopes.iter().map(|s| s.to_string()).collect(),
+                granted,
+                token,
+            });
+        })
+    }
+
+    // Re-reads the file each time so a token refreshed by another process is picked up
+    async fn get(&self, scopes: &[&str]) -> Option<TokenInfo> {
+        let tokens: Vec<StoredToken> = store::read_json(&self.path);
+        tokens.iter()
+            .find(|t| t.is_exactly(scopes) && t.covers(scopes))
+            .or_else(|| tokens.iter().find(|t| t.covers(scopes)))
+            .map(|t| t.token.clone())
+    }
+}
+
+pub fn saved_tokens(token_path: &Path) -> Vec<SavedToken> {
+    let tokens: Vec<StoredToken> = store::read_json(token_path);
+    tokens.into_iter()
+        .map(|t| SavedToken {
+            expires_at: t.token.expires_at.and_then(|at| DateTime::from_timestamp(at.unix_timestamp(), 0)),
+            refreshable: t.token.refresh_token.is_some(),
+            scopes: t.granted().to_vec(),
+        })
+        .collect()
+}
+
+// Deletes the saved tokens so the next calendar request signs in again. Google is asked to
+// revoke them too, but the local copy goes away even if that request fails.
+// Returns false when there was nothing to delete.
+pub fn logout(token_path: &Path) -> Result<bool> {
+    let _lock = store::lock(token_path)?;
+    if !token_path.exists() {
+        return Ok(false);
+    }
+
+    let tokens: Vec<StoredToken> = store::read_json(token_path);
+    let client = reqwest::blocking::Client::new();
+    for stored in &tokens {
+        // Revoking the refresh token revokes the access tokens issued from it
+        if let Some(token) = stored.token.refresh_token.as_ref().or(stored.token.access_token.as_ref()) {
+            let _ = client.post("https://oauth2.googleapis.com/revoke")
+                .form(&[("token", token)])
+                .send();
+        }
+    }
+
+    std::fs::remove_file(token_path)
+        .context(format!("Failed to delete {}", token_path.display()))?;
+    Ok(true)
+}
+
+fn confirm_retry() -> bool {
+    use std::io::Write;
+    eprint!("Try again? [y/N] ");
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer).is_ok() && answer.trim().eq_ignore_ascii_case("y")
+}
+
+// Opens the consent page itself, with the URL printed in case no browser comes up
+struct BrowserSignIn;
+
+impl InstalledFlowDelegate for BrowserSignIn {
+    fn present_user_url<'a>(
+        &'a self,
+        url: &'a str,
+        _need_code: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>> {
+        Box::pin(async move {
+            let url = with_granted_scopes(url);
+            eprintln!("Opening your browser to sign in. If it doesn't open, visit:\n\n  {}\n", url);
+            eprintln!(
+                "Waiting up to {} minutes. Without a browser on this machine, use 'qq auth google login --no-browser'.",
+                BROWSER_AUTH_TIMEOUT.as_secs() / 60
+            );
+            let _ = webbrowser::open(&url);
+            Ok(String::new())
+        })
+    }
+}
+
+// The user signs in on any machine and pastes back the address the browser was sent to,
+// which fails to load but carries the authorization code
+struct PastedCodeSignIn;
+
+impl InstalledFlowDelegate for PastedCodeSignIn {
+    fn redirect_uri(&self) -> Option<&str> {
+        Some(REDIRECT_URI)
+    }
+
+    fn present_user_url<'a>(
+        &'a self,
+        url: &'a str,
+        _need_code: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>> {
+        Box::pin(async move {
+            use tokio::io::AsyncBufReadExt;
+
+            eprintln!("Open this URL in a browser on any machine and sign in:\n\n  {}\n", with_granted_scopes(url));
+            eprintln!("The browser then shows a page that can't be reached at {}.", REDIRECT_URI);
+            eprintln!("Copy that page's full address from the address bar and paste it here:");
+            let mut input = String::new();
+            tokio::io::BufReader::new(tokio::io::stdin())
+                .read_line(&mut input)
+                .await
+                .map_err(|e| format!("Couldn't read the pasted address: {}", e))?;
+            auth_code_from(&input).ok_or_else(|| "No authorization code found in what was pasted".to_string())
+        })
+    }
+}
+
+// Incremental consent: the new token also covers everything granted before, so asking for
+// write access later doesn't lose read access or show the read permission again
+fn with_granted_scopes(url: &str) -> String {
+    if url.contains("include_granted_scopes=") {
+        url.to_string()
+    } else {
+        format!("{}&include_granted_scopes=true", url)
+    }
+}
+
+// Takes the redirected address (or just its code= value) and returns the decoded code
+fn auth_code_from(input: &str) -> Option<String> {
+    let input = input.trim();
+    let code = match input.split_once('?') {
+        Some((_, query)) => query.split('&').find_map(|pair| pair.strip_prefix("code="))?,
+        None => input.strip_prefix("code=").unwrap_or(input),
+    };
+    let code = percent_decode(code.split('&').next().unwrap_or(code));
+    (!code.is_empty()).then_some(code)
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// A sign-in that was abandoned or timed out may still be closing its listener, so give the
+// port a few seconds to free up before failing with something clearer than "address in use"
+async fn wait_for_redirect_port() -> Result<()> {
+    for _ in 0..15 {
+        if std::net::TcpListener::bind(("127.0.0.1", REDIRECT_PORT)).is_ok() {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    anyhow::bail!(
+        "Port {} is in use, so the browser sign-in can't receive Google's redirect. Close the program using it (possibly another qq waiting on a sign-in), or run 'qq auth google login --no-browser'.",
+        REDIRECT_PORT
+    )
+}
+
+async fn build_authenticator(account: &GoogleAccount, token_path: PathBuf) -> Result<Authenticator> {
+    let secret = yup_oauth2::ApplicationSecret {
+        client_id: account.client_id.clone(),
+        client_secret: account.client_secret.clone(),
+        auth_uri: "https://accounts.google.com/o/oauth2/auth".to_string(),
+        token_uri: "https://oauth2.googleapis.com/token".to_string(),
+        ..Default::default()
+    };
+
+    let auth = match account.flow {
+        GoogleAuthFlow::Installed => {
+            InstalledFlowAuthenticator::builder(
+                secret,
+                InstalledFlowReturnMethod::HTTPPortRedirect(REDIRECT_PORT),
+            )
+            .flow_delegate(Box::new(BrowserSignIn))
+            .with_storage(Box::new(TokenFile { path: token_path }))
+            .build()
+            .await
+        }
+        GoogleAuthFlow::Paste => {
+            InstalledFlowAuthenticator::builder(secret, InstalledFlowReturnMethod::Interactive)
+                .flow_delegate(Box::new(PastedCodeSignIn))
+                .with_storage(Box::new(TokenFile { path: token_path }))
+                .build()
+                .await
+        }
+        GoogleAuthFlow::Device => {
+            DeviceFlowAuthenticator::builder(secret)
+                .with_storage(Box::new(TokenFile { path: token_path }))
+                .build()
+                .await
+        }
+    };
+
+    auth.context("Failed to build authenticator")
+}
+
+impl GoogleCalendarClient {
+    pub async fn new(account: &GoogleAccount, token_path: PathBuf) -> Result<Self> {
+        let auth = build_authenticator(account, token_path.clone()).await?;
+
+        let client = hyper_util::client::legacy::Client::builder(
+            hyper_util::rt::TokioExecutor::new()
+        ).build(
+            hyper_rustls::HttpsConnectorBuilder::new()
+                .with_native_roots()
+                .context("Failed to load native roots")?
+                .https_only()
+                .enable_http1()
+                .build()
+        );
+
+        let hub = CalendarHub::new(client, auth.clone());
+        
+        Ok(Self {
+            hub,
+            auth,
+            flow: account.flow,
+            token_path,
+            rules: Vec::new(),
+            working_hours: WorkingHours::default(),
+        })
+    }
+
+    // Gets a token before any view starts, so an expired or revoked sign-in is dealt with up
+    // front rather than halfway through a request. Without a terminal, a sign-in that needs
+    // the user fails fast instead of waiting on a browser or device code.
+    pub async fn ensure_authorized(&self, interactive: bool) -> Result<()> {
+        self.authorize(READ_SCOPE, interactive, "Google Calendar needs you to sign in before continuing.").await
+    }
+
+    // Asks for the events scope the first time `action` needs it, on top of the read access
+    // from signing in. Without a terminal it only checks, so views and cron jobs never start a sign-in.
+    pub async fn ensure_write_access(&self, interactive: bool, action: &str) -> Result<()> {
+        let needed = format!(
+            "{} needs permission to change your calendar events. Run 'qq auth google login --write' in a terminal to grant it.",
+            action
+        );
+        if self.has_write_access().await {
+            return Ok(());
+        }
+        if !interactive {
+            anyhow::bail!("{}", needed);
+        }
+
+        let prompt = format!(
+            "{} needs permission to change your calendar events, which qq hasn't asked for yet. Google will ask you to allow it; your read access stays as it is.",
+            action
+        );
+        self.authorize(WRITE_SCOPE, true, &prompt).await?;
+        if !self.has_write_access().await {
+            anyhow::bail!("Google didn't grant permission to change calendar events (the box may have been left unticked). {}", needed);
+        }
+        Ok(())
+    }
+
+    async fn has_write_access(&self) -> bool {
+        TokenFile { path: self.token_path.clone() }.get(&[WRITE_SCOPE]).await.is_some()
+    }
+
+    async fn authorize(&self, scope: &str, interactive: bool, prompt: &str) -> Result<()> {
+        let scopes = [scope];
+        let stored = TokenFile { path: self.token_path.clone() }.get(&scopes).await;
+
+        if stored.is_none() {
+            if !interactive {
+                anyhow::bail!("{}", SIGN_IN_NEEDED);
+            }
+            eprintln!("{}", prompt);
+        }
+
+        if !interactive {
+            return tokio::time::timeout(UNATTENDED_AUTH_TIMEOUT, self.auth.token(&scopes))
+                .await
+                .map_err(|_| anyhow::anyhow!("{}", SIGN_IN_NEEDED))?
+                .map(|_| ())
+                .context(SIGN_IN_NEEDED);
+        }
+        if self.flow != GoogleAuthFlow::Installed {
+            self.auth.token(&scopes).await.context(SIGN_IN_NEEDED)?;
+        } else {
+            // An abandoned browser sign-in would otherwise hold the port until qq is killed.
+            // Timing out drops the flow, which shuts its listener down for the next attempt.
+            loop {
+                if stored.is_none() {
+                    wait_for_redirect_port().await?;
+                }
+                match tokio::time::timeout(BROWSER_AUTH_TIMEOUT, self.auth.token(&scopes)).await {
+                    Ok(result) => {
+                        result.context(SIGN_IN_NEEDED)?;
+                        break;
+                    }
+                    Err(_) => {
+                        eprintln!("Google sign-in wasn't finished within {} minutes.", BROWSER_AUTH_TIMEOUT.as_secs() / 60);
+                        if !confirm_retry() {
+                            anyhow::bail!("Google sign-in timed out. Run 'qq auth google login' to try again, or add --no-browser to paste the code from another machine.");
+                        }
+                    }
+                }
+            }
+        }
+
+        if stored.is_none() {
+            self.record_granted_scopes(scope).await;
+        }
+        Ok(())
+    }
+
+    // Asks Google what a freshly issued token can do and saves that next to it. Best-effort:
+    // without it the token is assumed to have exactly what was requested.
+    async fn record_granted_scopes(&self, scope: &str) {
+        let Ok(token) = self.auth.token(&[scope]).await else {
+            return;
+        };
+        let Some(access_token) = token.token() else {
+            return;
+        };
+        let info: Option<serde_json::Value> = match reqwest::Client::new()
+            .get("https://oauth2.googleapis.com/tokeninfo")
+            .query(&[("access_token", access_token)])
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => response.json().await.ok(),
+            _ => None,
+        };
+        let Some(granted) = info.as_ref().and_then(|i| i.get("scope")).and_then(|s| s.as_str()) else {
+            return;
+        };
+        let granted: Vec<String> = granted.split_whitespace().map(|s| s.to_string()).collect();
+        let _ = store::edit_json(&self.token_path, |tokens: &mut Vec<StoredToken>| {
+            for stored in tokens.iter_mut().filter(|t| t.is_exactly(&[scope])) {
+                stored.granted = granted.clone();
+            }
+        });
+    }
+
+    pub async fn list_calendars(&self) -> Result<Vec<CalendarInfo>> {
+        let (_, list) = self.hub
+            .calendar_list()
+            .list()
+            .add_scope(api::Scope::Readonly)
+            .doit()
+            .await
+            .context("Failed to list calendars")?;
+
+        Ok(list.items.unwrap_or_default()
+            .into_iter()
+            .map(|entry| CalendarInfo {
+                name: entry.summary.clone().or(entry.id.clone()).unwrap_or_default(),
+                id: entry.id.unwrap_or_default(),
+                primary: entry.primary == Some(true),
+            })
+            .collect())
+    }
+
+    pub async fn list_meetings(&self) -> Result<Vec<Meeting>> {
+        let now = Utc::now();
+        self.list_meetings_between(now, now + chrono::Duration::days(7)).await
+    }
+
+    pub async fn list_meetings_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<Meeting>> {
+        let mut meetings = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        // Longer windows can span several pages of events
+        loop {
+            let mut call = self.hub
+                .events()
+                .list("primary")
+                .time_min(start)
+                .time_max(end)
+                .single_events(true)
+                .order_by("startTime")
+                .add_scope(api::Scope::Readonly);
+            if let Some(token) = &page_token {
+                call = call.page_token(token);
+            }
+
+            let (_, events_list) = call.doit()
+                .await
+                .context("Failed to fetch calendar events")?;
+
+            for event in events_list.items.unwrap_or_default().into_iter().filter(|e| !is_reminder(e)) {
+                meetings.push(self.parse_event_to_meeting(event)?);
+            }
+
+            match events_list.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        self.flag_hours_conflicts(&mut meetings);
+
+        // Meetings that start together list the more important one first
+        meetings.sort_by_key(|m| (m.start_time, std::cmp::Reverse(m.importance)));
+
+        Ok(meetings)
+    }
+
+    pub async fn decline_meeting(&self, event_id: &str, note: Option<&str>) -> Result<()> {
+        let (_, event) = self.hub
+            .events()
+            .get("primary", event_id)
+            .add_scope(api::Scope::Event)
+            .doit()
+            .await
+            .context("Failed to fetch calendar event")?;
+
+        let mut attendees = event.attendees.unwrap_or_default();
+        let attendee = attendees.iter_mut()
+            .find(|a| a.self_ == Some(true))
+            .context("You are not an attendee of this meeting")?;
+
+        attendee.response_status = Some("declined".to_string());
+        attendee.comment = note.map(|n| n.to_string());
+
+        let patch = api::Event {
+            attendees: Some(attendees),
+            ..Default::default()
+        };
+
+        self.hub
+            .events()
+            .patch(patch, "primary", event_id)
+            .send_updates("all")
+            .add_scope(api::Scope::Event)
+            .doit()
+            .await
+            .context("Failed to decline meeting")?;
+
+        Ok(())
+    }
+
+    // Busy periods of all the given calendars merged together. Another person's calendar is
+    // named by their email and only readable when they share free/busy with you.
+    pub async fn busy_times(&self, calendars: &[&str], start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+        let request = api::FreeBusyRequest {
+            time_min: Some(start),
+            time_max: Some(end),
+            items: Some(calendars.iter().map(|id| api::FreeBusyRequestItem { id: Some(id.to_string()) }).collect()),
+            ..Default::default()
+        };
+        let (_, response) = self.hub
+            .freebusy()
+            .query(request)
+            .add_scope(api::Scope::Readonly)
+            .doit()
+            .await
+            .context("Failed to query free/busy")?;
+
+        let mut busy = Vec::new();
+        for (id, calendar) in response.calendars.unwrap_or_default() {
+            if let Some(error) = calendar.errors.unwrap_or_default().first() {
+                anyhow::bail!(
+                    "Can't see when {} is free ({}). Their calendar may not be shared with you.",
+                    id,
+                    error.reason.as_deref().unwrap_or("unknown error")
+                );
+            }
+            busy.extend(calendar.busy.unwrap_or_default()
+                .into_iter()
+                .filter_map(|period| Some((period.start?, period.end?))));
+        }
+        Ok(busy)
+    }
+
+    // Creates an event on my calendar with a Google Meet link and invites the attendee
+    pub async fn book_meeting(&self, summary: &str, attendee: &str, start: DateTime<Utc>, end: DateTime<Utc>, ticket: Option<&str>) -> Result<Meeting> {
+        let extended_properties = ticket.map(|key| api::EventExtendedProperties {
+            private: Some([(TICKET_PROPERTY.to_string(), key.to_string())].into()),
+            ..Default::default()
+        });
+        let event = api::Event {
+            summary: Some(summary.to_string()),
+            start: Some(api::EventDateTime { date_time: Some(start), ..Default::default() }),
+            end: Some(api::EventDateTime { date_time: Some(end), ..Default::default() }),
+            attendees: Some(vec![api::EventAttendee { email: Some(attendee.to_string()), ..Default::default() }]),
+            conference_data: Some(api::ConferenceData {
+                create_request: Some(api::CreateConferenceRequest {
+                    // Google uses this to de-duplicate retries of the same request
+                    request_id: Some(format!("qq-{}", Utc::now().timestamp_millis())),
+                    conference_solution_key: Some(api::ConferenceSolutionKey { type_: Some("hangoutsMeet".to_string()) }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            extended_properties,
+            ..Default::default()
+        };
+
+        let (_, created) = self.hub
+            .events()
+            .insert(event, "primary")
+            .conference_data_version(1)
+            .send_updates("all")
+            .add_scope(api::Scope::Event)
+            .doit()
+            .await
+            .context("Failed to create meeting")?;
+
+        self.parse_event_to_meeting(created)
+    }
+
+    // Upcoming reminder events of the given kind
+    pub async fn list_reminder_events(&self, kind: &str) -> Result<Vec<ReminderEvent>> {
+        let mut reminders = Vec::new();
+        let mut page_token: Option<String> = None;
+        let filter = format!("{}={}", REMINDER_PROPERTY, kind);
+
+        loop {
+            let mut call = self.hub
+                .events()
+                .list("primary")
+                .time_min(Utc::now())
+                .single_events(true)
+                .private_extended_property(&filter)
+                .add_scope(api::Scope::Readonly);
+            if let Some(token) = &page_token {
+                call = call.page_token(token);
+            }
+
+            let (_, events_list) = call.doit()
+                .await
+                .context("Failed to fetch reminder events")?;
+
+            for event in events_list.items.unwrap_or_default() {
+                let ticket = event.extended_properties.as_ref()
+                    .and_then(|p| p.private.as_ref())
+                    .and_then(|values| values.get(TICKET_PROPERTY))
+                    .cloned();
+                let at = event.start.as_ref().and_then(|start| start.date_time);
+                if let (Some(id), Some(ticket), Some(at)) = (event.id, ticket, at) {
+                    reminders.push(ReminderEvent { id, ticket, at });
+                }
+            }
+
+            match events_list.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+        Ok(reminders)
+    }
+
+    // A private, free (non-blocking) event at `at` that pops the calendar's default notification
+    pub async fn create_reminder_event(&self, kind: &str, ticket: &str, summary: &str, description: &str, at: DateTime<Utc>) -> Result<()> {
+        let event = api::Event {
+            summary: Some(summary.to_string()),
+            description: Some(description.to_string()),
+            start: Some(api::EventDateTime { date_time: Some(at), ..Default::default() }),
+            end: Some(api::EventDateTime { date_time: Some(at + chrono::Duration::minutes(REMINDER_EVENT_MINUTES)), ..Default::default() }),
+            transparency: Some("transparent".to_string()),
+            visibility: Some("private".to_string()),
+            reminders: Some(api::EventReminders { use_default: Some(true) }),
+            extended_properties: Some(api::EventExtendedProperties {
+                private: Some([
+                    (REMINDER_PROPERTY.to_string(), kind.to_string()),
+                    (TICKET_PROPERTY.to_string(), ticket.to_string()),
+                ].into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        self.hub
+            .events()
+            .insert(event, "primary")
+            .add_scope(api::Scope::Event)
+            .doit()
+            .await
+            .context("Failed to create reminder event")?;
+        Ok(())
+    }
+
+    pub async fn delete_reminder_event(&self, event_id: &str) -> Result<()> {
+        self.hub
+            .events()
+            .delete("primary", event_id)
+            .add_scope(api::Scope::Event)
+            .doit()
+            .await
+            .context("Failed to delete reminder event")?;
+        Ok(())
+    }
+
+    pub async fn cancel_meeting(&self, event_id: &str) -> Result<()> {
+        self.hub
+            .events()
+            .delete("primary", event_id)
+            .send_updates("all")
+            .add_scope(api::Scope::Event)
+            .doit()
+            .await
+            .context("Failed to cancel meeting")?;
+
+        Ok(())
+    }
+
+    fn flag_hours_conflicts(&self, meetings: &mut [Meeting]) {
+        let time_off: Vec<(DateTime<FixedOffset>, DateTime<FixedOffset>)> = meetings.iter()
+            .filter(|m| m.is_out_of_office)
+            .map(|m| (m.start_time, m.end_time))
+            .collect();
+
+        for meeting in meetings.iter_mut().filter(|m| !m.is_out_of_office && !m.is_all_day) {
+            let during_time_off = time_off.iter()
+                .any(|(start, end)| meeting.start_time < *end && meeting.end_time > *start);
+            meeting.hours_conflict = if during_time_off {
+                Some(HoursConflict::DuringOutOfOffice)
+            } else if !self.working_hours.contains(
+                meeting.start_time.with_timezone(&Local),
+                meeting.end_time.with_timezone(&Local),
+            ) {
+                Some(HoursConflict::OutsideWorkingHours)
+            } else {
+                None
+            };
+        }
+    }
+
+    fn parse_event_to_meeting(&self, event: api::Event) -> Result<Meeting> {
+        let id = event.id.clone().unwrap_or_default();
+        let summary = event.summary.clone().unwrap_or_else(|| "(No title)".to_string());
+        let status = event.status.clone().unwrap_or_else(|| "confirmed".to_string());
+        
+        let (start_time, end_time) = self.extract_times(&event)?;
+        
+        let meeting_url = self.extract_meeting_url(&event);
+        let dial_ins = self.extract_dial_ins(&event);
+        let location = event.location.clone();
+        let description = event.description.clone();
+        let is_organizer = event.organizer.as_ref()
+            .and_then(|o| o.self_)
+            .unwrap_or(false);
+        let me = event.attendees.iter()
+            .flatten()
+            .find(|a| a.self_ == Some(true));
+        let is_optional = me.and_then(|a| a.optional).unwrap_or(false);
+        let is_declined = me.and_then(|a| a.response_status.as_deref()) == Some("declined");
+        let ticket = linked_ticket(&event);
+        let attendees = event.attendees.iter()
+            .flatten()
+            .filter(|a| a.self_ != Some(true) && a.resource != Some(true))
+            .filter_map(|a| Some(Attendee { email: a.email.clone()?, name: a.display_name.clone() }))
+            .collect();
+        let attachments = event.attachments.iter()
+            .flatten()
+            .filter_map(|a| {
+                let url = a.file_url.clone()?;
+                Some(Attachment {
+                    title: a.title.clone().unwrap_or_else(|| url.clone()),
+                    url,
+                    mime_type: a.mime_type.clone(),
+                })
+            })
+            .collect();
+
+        let mut meeting = Meeting {
+            id,
+            summary,
+            start_time,
+            end_time,
+            meeting_url,
+            location,
+            description,
+            status,
+            is_organizer,
+            dial_ins,
+            color_id: event.color_id.clone(),
+            is_optional,
+            importance: Importance::Normal,
+            is_all_day: event.start.as_ref().is_some_and(|s| s.date_time.is_none()),
+            is_out_of_office: event.event_type.as_deref() == Some("outOfOffice"),
+            hours_conflict: None,
+            recurring_event_id: event.recurring_event_id.clone(),
+            is_declined,
+            ticket,
+            attendees,
+            attachments,
+        };
+        if let Some(rule) = self.rules.iter().find(|r| r.matches(&meeting)) {
+            meeting.importance = rule.importance;
+        }
+
+        Ok(meeting)
+    }
+
+    fn extract_times(&self, event: &api::Event) -> Result<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        let start = event.start.as_ref()
+            .context("Event has no start time")?;
+        let end = event.end.as_ref()
+            .context("Event has no end time")?;
+
+        let start_time = if let Some(date_time) = &start.date_time {
+            date_time.with_timezone(&New_York)
+        } else if let Some(date) = &start.date {
+            let date_time_str = format!("{}T09:00:00+00:00", date);
+            DateTime::parse_from_rfc3339(&date_time_str)
+                .context("Failed to parse all-day start time")?
+                .with_timezone(&New_York)
+        } else {
+            anyhow::bail!("Event has neither date_time nor date for start")
+        };
+
+        let end_time = if let Some(date_time) = &end.date_time {
+            date_time.with_timezone(&New_York)
+        } else if let Some(date) = &end.date {
+            let date_time_str = format!("{}T17:00:00+00:00", date);
+            DateTime::parse_from_rfc3339(&date_time_str)
+                .context("Failed to parse all-day end time")?
+                .with_timezone(&New_York)
+        } else {
+            anyhow::bail!("Event has neither date_time nor date for end")
+        };
+
+        Ok((start_time.fixed_offset(), end_time.fixed_offset()))
+    }
+
+    fn extract_dial_ins(&self, event: &api::Event) -> Vec<DialIn> {
+        let entry_points = event.conference_data.as_ref()
+            .and_then(|c| c.entry_points.as_ref());
+        
+        entry_points.into_iter()
+            .flatten()
+            .filter(|e| e.entry_point_type.as_deref() == Some("phone"))
+            .filter_map(|e| {
+                let number = e.label.clone()
+                    .or_else(|| e.uri.as_ref().map(|u| u.trim_start_matches("tel:").to_string()))?;
+                let pin = e.pin.clone()
+                    .or_else(|| e.access_code.clone())
+                    .or_else(|| e.passcode.clone());
+                Some(DialIn { number, pin })
+            })
+            .collect()
+    }
+
+    fn extract_meeting_url(&self, event: &api::Event) -> Option<String> {
+        if let Some(hangout_link) = &event.hangout_link {
+            return Some(hangout_link.clone());
+        }
+
+        if let Some(conference_data) = &event.conference_data {
+            if let Some(entry_points) = &conference_data.entry_points {
+                for entry_point in entry_points {
+                    if entry_point.entry_point_type == Some("video".to_string()) {
+                        if let Some(uri) = &entry_point.uri {
+                            return Some(uri.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(location) = &event.location {
+            if location.starts_with("http://") || location.starts_with("https://") {
+                return Some(location.clone());
+            }
+        }
+
+        if let Some(description) = &event.description {
+            let url_regex = regex::Regex::new(r"https?://[^\s<>]+(?:zoom\.us|meet\.google\.com|teams\.microsoft\.com)[^\s<>]*").ok()?;
+            if let Some(mat) = url_regex.find(description) {
+                return Some(mat.as_str().to_string());
+            }
+        }
+
+        None
+    }
+}
+
+pub struct BlockingCalendarClient {
+    runtime: tokio::runtime::Runtime,
+    client: GoogleCalendarClient,
+    // Identifies this account's meetings in the shared presence file
+    presence_key: String,
+}
+
+impl BlockingCalendarClient {
+    pub fn new(account: &GoogleAccount, token_path: PathBuf) -> Result<Self> {
+        // Initialize the crypto provider for rustls
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let runtime = tokio::runtime::Runtime::new()
+            .context("Failed to create Tokio runtime")?;
+
+        let presence_key = token_path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let client = runtime.block_on(GoogleCalendarClient::new(account, token_path))?;
+
+        Ok(Self { runtime, client, presence_key })
+    }
+
+    pub fn with_rules(mut self, rules: Vec<MeetingRule>) -> Self {
+        self.client.rules = rules;
+        self
+    }
+
+    pub fn with_working_hours(mut self, working_hours: WorkingHours) -> Self {
+        self.client.working_hours = working_hours;
+        self
+    }
+
+    pub fn ensure_authorized(&self, interactive: bool) -> Result<()> {
+        self.runtime.block_on(self.client.ensure_authorized(interactive))
+    }
+
+    pub fn ensure_write_access(&self, interactive: bool, action: &str) -> Result<()> {
+        self.runtime.block_on(self.client.ensure_write_access(interactive, action))
+    }
+
+    pub fn list_calendars(&self) -> Result<Vec<CalendarInfo>> {
+        self.runtime.block_on(self.client.list_calendars())
+    }
+
+    // Also refreshes the busy times that do-not-disturb checks; that part is best-effort
+    pub fn list_meetings(&self) -> Result<Vec<Meeting>> {
+        let meetings = self.runtime.block_on(self.client.list_meetings())?;
+        let _ = crate::presence::record(&self.presence_key, &meetings);
+        Ok(meetings)
+    }
+
+    pub fn list_meetings_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<Meeting>> {
+        self.runtime.block_on(self.client.list_meetings_between(start, end))
+    }
+
+    // Write calls never start a sign-in themselves, since they also run inside views;
+    // commands ask for write access up front with ensure_write_access
+    pub fn decline_meeting(&self, event_id: &str, note: Option<&str>) -> Result<()> {
+        self.ensure_write_access(false, "Declining a meeting")?;
+        self.runtime.block_on(self.client.decline_meeting(event_id, note))
+    }
+
+    pub fn cancel_meeting(&self, event_id: &str) -> Result<()> {
+        self.ensure_write_access(false, "Cancelling a meeting")?;
+        self.runtime.block_on(self.client.cancel_meeting(event_id))
+    }
+
+    pub fn busy_times(&self, calendars: &[&str], start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+        self.runtime.block_on(self.client.busy_times(calendars, start, end))
+    }
+
+    pub fn book_meeting(&self, summary: &str, attendee: &str, start: DateTime<Utc>, end: DateTime<Utc>, ticket: Option<&str>) -> Result<Meeting> {
+        self.ensure_write_access(false, "Booking a meeting")?;
+        self.runtime.block_on(self.client.book_meeting(summary, attendee, start, end, ticket))
+    }
+
+    pub fn list_reminder_events(&self, kind: &str) -> Result<Vec<ReminderEvent>> {
+        self.runtime.block_on(self.client.list_reminder_events(kind))
+    }
+
+    pub fn create_reminder_event(&self, kind: &str, ticket: &str, summary: &str, description: &str, at: DateTime<Utc>) -> Result<()> {
+        self.ensure_write_access(false, "Adding reminders to the calendar")?;
+        self.runtime.block_on(self.client.create_reminder_event(kind, ticket, summary, description, at))
+    }
+
+    pub fn delete_reminder_event(&self, event_id: &str) -> Result<()> {
+        self.ensure_write_access(false, "Removing reminders from the calendar")?;
+        self.runtime.block_on(self.client.delete_reminder_event(event_id))
+    }
+
+    pub fn working_hours(&self) -> &WorkingHours {
+        &self.client.working_hours
+    }
+}
+
+// Forces a refresh of every saved token, or signs in for read access when there are none.
+// With a saved refresh token this needs no browser; otherwise the account's configured flow
+// runs to obtain one. Write access is only refreshed once it has been granted.
+pub fn refresh_tokens(account: &GoogleAccount, token_path: PathBuf) -> Result<()> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let runtime = tokio::runtime::Runtime::new()
+        .context("Failed to create Tokio runtime")?;
+
+    let saved: Vec<StoredToken> = store::read_json(&token_path);
+    let mut requested: Vec<Vec<String>> = saved.into_iter().map(|t| t.scopes).collect();
+    if requested.is_empty() {
+        requested.push(vec![READ_SCOPE.to_string()]);
+    }
+
+    runtime.block_on(async {
+        let auth = build_authenticator(account, token_path).await?;
+        for scopes in &requested {
+            let scopes: Vec<&str> = scopes.iter().map(|s| s.as_str()).collect();
+            auth.force_refreshed_token(&scopes)
+                .await
+                .context(format!("Failed to refresh token for {}", scopes.join(" ")))?;
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,349 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+// The calendar API client is only built with the "calendar" feature. Without it the stand-in
+// client fails to start with a "not compiled in" error, so commands that read meetings report
+// that and the views that show them optionally (today, context, prep) leave them out.
+#[cfg(feature = "calendar")]
+mod client;
+#[cfg(not(feature = "calendar"))]
+mod unavailable;
+
+#[cfg(feature = "calendar")]
+pub use client::{BlockingCalendarClient, logout, refresh_tokens, saved_tokens};
+#[cfg(not(feature = "calendar"))]
+pub use unavailable::{BlockingCalendarClient, logout, refresh_tokens, saved_tokens};
+
+const NOT_COMPILED_IN: &str = "Google Calendar support is not compiled into this build of qq. Rebuild with the default features (or --features calendar) to use it.";
+
+// For commands that only work with the calendar, so they fail before asking for anything
+pub fn require() -> Result<()> {
+    if !cfg!(feature = "calendar") {
+        anyhow::bail!(NOT_COMPILED_IN);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Meeting {
+    pub id: String,
+    pub summary: String,
+    pub start_time: DateTime<FixedOffset>,
+    pub end_time: DateTime<FixedOffset>,
+    pub meeting_url: Option<String>,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub status: String,
+    #[serde(default)]
+    pub is_organizer: bool,
+    #[serde(default)]
+    pub dial_ins: Vec<DialIn>,
+    // Google's event color ("1".."11"), unset when the event uses the calendar color
+    #[serde(default)]
+    pub color_id: Option<String>,
+    // True when I was invited as an optional attendee
+    #[serde(default)]
+    pub is_optional: bool,
+    #[serde(default)]
+    pub importance: Importance,
+    #[serde(default)]
+    pub is_all_day: bool,
+    #[serde(default)]
+    pub is_out_of_office: bool,
+    // Set when the meeting falls outside working hours or during time off
+    #[serde(default)]
+    pub hours_conflict: Option<HoursConflict>,
+    // Shared by every occurrence of a recurring meeting
+    #[serde(default)]
+    pub recurring_event_id: Option<String>,
+    #[serde(default)]
+    pub is_declined: bool,
+    // Jira ticket the meeting is about, from the qqTicket extended property or the first key
+    // mentioned in the title or description
+    #[serde(default)]
+    pub ticket: Option<String>,
+    // Everyone else invited, leaving out rooms and other resources
+    #[serde(default)]
+    pub attendees: Vec<Attendee>,
+    // Files attached to the event, such as the notes doc Meet attaches after a call
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attendee {
+    pub email: String,
+    pub name: Option<String>,
+}
+
+impl Attendee {
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.email)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub title: String,
+    pub url: String,
+    // e.g. "video/mp4" for a Meet recording
+    #[serde(default)]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentKind {
+    Notes,
+    Recording,
+    Transcript,
+    File,
+}
+
+impl AttachmentKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AttachmentKind::Notes => "Notes",
+            AttachmentKind::Recording => "Recording",
+            AttachmentKind::Transcript => "Transcript",
+            AttachmentKind::File => "File",
+        }
+    }
+}
+
+impl Attachment {
+    // Meet attaches a call's recording as a video, and its notes and transcript as Docs
+    pub fn kind(&self) -> AttachmentKind {
+        let mime = self.mime_type.as_deref().unwrap_or_default();
+        if mime.starts_with("video/") {
+            AttachmentKind::Recording
+        } else if mime == "application/vnd.google-apps.document" {
+            if self.title.to_lowercase().contains("transcript") {
+                AttachmentKind::Transcript
+            } else {
+                AttachmentKind::Notes
+            }
+        } else {
+            AttachmentKind::File
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HoursConflict {
+    OutsideWorkingHours,
+    DuringOutOfOffice,
+}
+
+impl HoursConflict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HoursConflict::OutsideWorkingHours => "outside working hours",
+            HoursConflict::DuringOutOfOffice => "during out of office",
+        }
+    }
+}
+
+// `[working_hours]` in config.toml, in local time. Google doesn't expose the calendar's
+// working hours setting through the API, so they're configured here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkingHours {
+    #[serde(default = "WorkingHours::default_start")]
+    pub start: NaiveTime,
+    #[serde(default = "WorkingHours::default_end")]
+    pub end: NaiveTime,
+    #[serde(default = "WorkingHours::default_days")]
+    pub days: Vec<Weekday>,
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self {
+            start: Self::default_start(),
+            end: Self::default_end(),
+            days: Self::default_days(),
+        }
+    }
+}
+
+impl WorkingHours {
+    fn default_start() -> NaiveTime {
+        NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default()
+    }
+
+    fn default_end() -> NaiveTime {
+        NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default()
+    }
+
+    fn default_days() -> Vec<Weekday> {
+        vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+    }
+
+    fn contains(&self, start: DateTime<Local>, end: DateTime<Local>) -> bool {
+        self.days.contains(&start.weekday())
+            && start.date_naive() == end.date_naive()
+            && start.time() >= self.start
+            && end.time() <= self.end
+    }
+
+    // Up to `limit` start times on the half hour, from `from` to `until`, where a meeting of
+    // `length` fits in working hours without overlapping any busy period
+    pub fn free_slots(
+        &self,
+        busy: &[(DateTime<Utc>, DateTime<Utc>)],
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        length: chrono::Duration,
+        limit: usize,
+    ) -> Vec<DateTime<Local>> {
+        let step = chrono::Duration::minutes(SLOT_STEP_MINUTES);
+        let offset = from.timestamp().rem_euclid(step.num_seconds());
+        let mut start = if offset == 0 { from } else { from + chrono::Duration::seconds(step.num_seconds() - offset) };
+
+        let mut slots = Vec::new();
+        while start + length <= until && slots.len() < limit {
+            let end = start + length;
+            let local = (start.with_timezone(&Local), end.with_timezone(&Local));
+            if self.contains(local.0, local.1) && !busy.iter().any(|(b_start, b_end)| start < *b_end && end > *b_start) {
+                slots.push(local.0);
+            }
+            start += step;
+        }
+        slots
+    }
+}
+
+// Proposed meeting times start on the hour or half hour
+const SLOT_STEP_MINUTES: i64 = 30;
+
+// A reminder event qq wrote to the calendar
+pub struct ReminderEvent {
+    pub id: String,
+    pub ticket: String,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Importance {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+// One entry per `[[meeting_rules]]` table in config.toml. Every condition that is set must
+// match; the first matching rule decides the meeting's importance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingRule {
+    #[serde(default)]
+    pub title_contains: Option<String>,
+    // Color ID or name, e.g. "11" or "tomato"
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub optional: Option<bool>,
+    pub importance: Importance,
+}
+
+// Google Calendar's fixed event palette, indexed by colorId
+const EVENT_COLORS: [(&str, (u8, u8, u8)); 11] = [
+    ("Lavender", (0x79, 0x86, 0xcb)),
+    ("Sage", (0x33, 0xb6, 0x79)),
+    ("Grape", (0x8e, 0x24, 0xaa)),
+    ("Flamingo", (0xe6, 0x7c, 0x73)),
+    ("Banana", (0xf6, 0xbf, 0x26)),
+    ("Tangerine", (0xf4, 0x51, 0x1e)),
+    ("Peacock", (0x03, 0x9b, 0xe5)),
+    ("Graphite", (0x61, 0x61, 0x61)),
+    ("Blueberry", (0x3f, 0x51, 0xb5)),
+    ("Basil", (0x0b, 0x80, 0x43)),
+    ("Tomato", (0xd5, 0x00, 0x00)),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialIn {
+    pub number: String,
+    pub pin: Option<String>,
+}
+
+impl Meeting {
+    fn event_color(&self) -> Option<(&'static str, (u8, u8, u8))> {
+        let index = self.color_id.as_ref()?.parse::<usize>().ok()?;
+        EVENT_COLORS.get(index.checked_sub(1)?).copied()
+    }
+
+    pub fn color_name(&self) -> Option<&'static str> {
+        self.event_color().map(|(name, _)| name)
+    }
+
+    pub fn color_rgb(&self) -> Option<(u8, u8, u8)> {
+        self.event_color().map(|(_, rgb)| rgb)
+    }
+
+    // Low-priority meetings are left out of reminders and the today dashboard.
+    // Add a rule with `optional = true` to treat optional invites the same way.
+    pub fn is_low_priority(&self) -> bool {
+        self.importance == Importance::Low
+    }
+
+    pub fn is_dimmed(&self) -> bool {
+        self.is_optional || self.is_out_of_office || self.is_low_priority()
+    }
+
+    pub fn time_range(&self) -> String {
+        format!(
+            "{} - {}",
+            self.start_time.format("%a %b %d, %l:%M %p"),
+            self.end_time.format("%l:%M %p")
+        )
+    }
+    
+    pub fn attachment(&self, kind: AttachmentKind) -> Option<&Attachment> {
+        self.attachments.iter().find(|a| a.kind() == kind)
+    }
+
+    // "Notes and recording available", or None when the call left neither behind
+    pub fn follow_up_label(&self) -> Option<String> {
+        let kinds: Vec<&str> = [AttachmentKind::Notes, AttachmentKind::Recording]
+            .into_iter()
+            .filter(|kind| self.attachment(*kind).is_some())
+            .map(|kind| kind.label())
+            .collect();
+        if kinds.is_empty() {
+            return None;
+        }
+        let label = kinds.join(" and ").to_lowercase();
+        Some(format!("{}{} available", label[..1].to_uppercase(), &label[1..]))
+    }
+
+    // Plain-text invite suitable for pasting into chat
+    pub fn invite_text(&self) -> String {
+        let mut lines = vec![self.summary.clone(), self.time_range()];
+        
+        if let Some(url) = &self.meeting_url {
+            lines.push(format!("Join: {}", url));
+        }
+        for dial_in in &self.dial_ins {
+            match &dial_in.pin {
+                Some(pin) => lines.push(format!("Dial-in: {} (PIN: {})", dial_in.number, pin)),
+                None => lines.push(format!("Dial-in: {}", dial_in.number)),
+            }
+        }
+        
+        lines.join("\n")
+    }
+}
+
+// A saved token as `qq meetings whoami` reports it
+pub struct SavedToken {
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub refreshable: bool,
+}
+
+pub struct CalendarInfo {
+    pub id: String,
+    pub name: String,
+    pub primary: bool,
+}
@@ -0,0 +1,95 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+
+use crate::config::GoogleAccount;
+use super::{NOT_COMPILED_IN, CalendarInfo, Meeting, MeetingRule, ReminderEvent, SavedToken, WorkingHours};
+
+// Stand-in for the calendar client in builds without the "calendar" feature, with the same
+// methods so callers don't need their own cfg checks. It can never be created, so the methods
+// past `new` are unreachable.
+
+pub struct BlockingCalendarClient {
+    never: Infallible,
+}
+
+impl BlockingCalendarClient {
+    pub fn new(_account: &GoogleAccount, _token_path: PathBuf) -> Result<Self> {
+        anyhow::bail!(NOT_COMPILED_IN)
+    }
+
+    pub fn with_rules(self, _rules: Vec<MeetingRule>) -> Self {
+        match self.never {}
+    }
+
+    pub fn with_working_hours(self, _working_hours: WorkingHours) -> Self {
+        match self.never {}
+    }
+
+    pub fn ensure_authorized(&self, _interactive: bool) -> Result<()> {
+        match self.never {}
+    }
+
+    pub fn ensure_write_access(&self, _interactive: bool, _action: &str) -> Result<()> {
+        match self.never {}
+    }
+
+    pub fn list_calendars(&self) -> Result<Vec<CalendarInfo>> {
+        match self.never {}
+    }
+
+    pub fn list_meetings(&self) -> Result<Vec<Meeting>> {
+        match self.never {}
+    }
+
+    pub fn list_meetings_between(&self, _start: DateTime<Utc>, _end: DateTime<Utc>) -> Result<Vec<Meeting>> {
+        match self.never {}
+    }
+
+    pub fn decline_meeting(&self, _event_id: &str, _note: Option<&str>) -> Result<()> {
+        match self.never {}
+    }
+
+    pub fn cancel_meeting(&self, _event_id: &str) -> Result<()> {
+        match self.never {}
+    }
+
+    pub fn busy_times(&self, _calendars: &[&str], _start: DateTime<Utc>, _end: DateTime<Utc>) -> Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+        match self.never {}
+    }
+
+    pub fn book_meeting(&self, _summary: &str, _attendee: &str, _start: DateTime<Utc>, _end: DateTime<Utc>, _ticket: Option<&str>) -> Result<Meeting> {
+        match self.never {}
+    }
+
+    pub fn list_reminder_events(&self, _kind: &str) -> Result<Vec<ReminderEvent>> {
+        match self.never {}
+    }
+
+    pub fn create_reminder_event(&self, _kind: &str, _ticket: &str, _summary: &str, _description: &str, _at: DateTime<Utc>) -> Result<()> {
+        match self.never {}
+    }
+
+    pub fn delete_reminder_event(&self, _event_id: &str) -> Result<()> {
+        match self.never {}
+    }
+
+    pub fn working_hours(&self) -> &WorkingHours {
+        match self.never {}
+    }
+}
+
+// Tokens can only have been saved by a build with the calendar client, and reading them back
+// needs its token format, so none are reported
+pub fn saved_tokens(_token_path: &Path) -> Vec<SavedToken> {
+    Vec::new()
+}
+
+pub fn logout(_token_path: &Path) -> Result<bool> {
+    anyhow::bail!(NOT_COMPILED_IN)
+}
+
+pub fn refresh_tokens(_account: &GoogleAccount, _token_path: PathBuf) -> Result<()> {
+    anyhow::bail!(NOT_COMPILED_IN)
+}
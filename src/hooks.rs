@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+
+use crate::jira::JiraClient;
+use crate::template::TemplateValues;
+
+// Shell commands to run around an action, keyed by action name, e.g.
+//   [hooks.after]
+//   close = ["./scripts/notify.sh {key}"]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HooksConfig {
+    #[serde(default)]
+    pub before: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub after: HashMap<String, Vec<String>>,
+}
+
+// Actions that run hooks; anything else in [hooks] is reported as a typo
+pub const ACTIONS: &[&str] = &["start", "close", "transition", "comment"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stage {
+    Before,
+    After,
+}
+
+pub struct HookContext {
+    pub action: &'static str,
+    pub key: String,
+    pub summary: String,
+    pub branch: Option<String>,
+}
+
+impl HooksConfig {
+    pub fn commands(&self, stage: Stage, action: &str) -> &[String] {
+        let hooks = match stage {
+            Stage::Before => &self.before,
+            Stage::After => &self.after,
+        };
+        hooks.get(action).map(|commands| commands.as_slice()).unwrap_or_default()
+    }
+
+    pub fn unknown_actions(&self) -> Vec<&str> {
+        self.before.keys()
+            .chain(self.after.keys())
+            .map(|action| action.as_str())
+            .filter(|action| !ACTIONS.contains(action))
+            .collect()
+    }
+}
+
+// Runs the hooks configured for `action` on `key`, for both the CLI commands and the TUI's
+// background jobs. The summary is only fetched when a hook is configured. `quiet` captures
+// hook output so it can't draw over the TUI. Returns a warning for each failed after-hook.
+pub fn run_for(client: &JiraClient, stage: Stage, action: &'static str, key: &str, quiet: bool) -> Result<Vec<String>> {
+    let commands = client.hooks().commands(stage, action);
+    if commands.is_empty() {
+        return Ok(Vec::new());
+    }
+    let summary = client.get_issue(key)
+        .map(|issue| issue.fields.summary)
+        .unwrap_or_default();
+    let branch = git2::Repository::discover(".")
+        .ok()
+        .and_then(|repo| repo.head().ok().and_then(|head| head.shorthand().map(|name| name.to_string())));
+    let context = HookContext {
+        action,
+        key: key.to_string(),
+        summary,
+        branch,
+    };
+    run(commands, stage, &context, quiet)
+}
+
+// Runs each command with `sh -c` in order. {key}, {summary}, and {branch} are filled in
+// shell-quoted, and the same values are exported as QQ_TICKET_KEY, QQ_TICKET_SUMMARY, and
+// QQ_BRANCH. A failing before-hook stops the action; after-hooks only warn, since the
+// action has already happened.
+pub fn run(commands: &[String], stage: Stage, context: &HookContext, quiet: bool) -> Result<Vec<String>> {
+    let branch = context.branch.clone().unwrap_or_default();
+    let mut values = TemplateValues::new();
+    values.set("key", shell_quote(&context.key));
+    values.set("summary", shell_quote(&context.summary));
+    values.set("branch", shell_quote(&branch));
+
+    let mut warnings = Vec::new();
+    for command in commands {
+        let (line, _) = values.fill(command);
+        let mut child = Command::new("sh");
+        child.args(["-c", &line])
+            .env("QQ_ACTION", context.action)
+            .env("QQ_TICKET_KEY", &context.key)
+            .env("QQ_TICKET_SUMMARY", &context.summary)
+            .env("QQ_BRANCH", &branch);
+        let status = if quiet {
+            child.stdin(Stdio::null()).output().map(|output| output.status)
+        } else {
+            child.status()
+        }
+        .context(format!("Failed to run hook '{}'", command))?;
+
+        if !status.success() {
+            match stage {
+                Stage::Before => anyhow::bail!("Hook '{}' exited with {}, not running {}", command, status, context.action),
+                Stage::After => warnings.push(format!("hook '{}' exited with {}", command, status)),
+            }
+        }
+    }
+    Ok(warnings)
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
@@ -0,0 +1,193 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+// Ticket drafts read from a file for `qq jira import`: a Markdown outline, a CSV with a
+// summary column, or a YAML list
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Draft {
+    pub summary: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    // Overrides --type for this ticket
+    #[serde(default, rename = "type")]
+    pub issue_type: Option<String>,
+}
+
+pub fn read(path: &Path) -> Result<Vec<Draft>> {
+    let text = std::fs::read_to_string(path)
+        .context(format!("Failed to read {}", path.display()))?;
+    let extension = path.extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let drafts = match extension.as_str() {
+        "csv" => parse_csv(&text)?,
+        "yaml" | "yml" => parse_yaml(&text)?,
+        _ => parse_markdown(&text),
+    };
+    let drafts: Vec<Draft> = drafts.into_iter()
+        .map(|draft| Draft {
+            summary: draft.summary.trim().to_string(),
+            description: draft.description.map(|d| d.trim().to_string()).filter(|d| !d.is_empty()),
+            issue_type: draft.issue_type.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()),
+        })
+        .filter(|draft| !draft.summary.is_empty())
+        .collect();
+    if drafts.is_empty() {
+        anyhow::bail!("No tickets found in {}", path.display());
+    }
+    Ok(drafts)
+}
+
+// Each top-level list item is a ticket, and the lines under it until the next item are its
+// description. Files without a top-level list use headings the same way, so a design doc's
+// sections each become a ticket; a title heading used only once above them is skipped.
+fn parse_markdown(text: &str) -> Vec<Draft> {
+    let has_items = text.lines().any(|line| list_item(line).is_some());
+    let levels: Vec<usize> = text.lines().filter_map(heading).map(|(level, _)| level).collect();
+    let section_level = levels.iter()
+        .copied()
+        .filter(|level| levels.iter().filter(|l| *l == level).count() > 1)
+        .min()
+        .or_else(|| levels.iter().copied().min());
+    let starts_ticket = |line| if has_items {
+        list_item(line)
+    } else {
+        heading(line).filter(|(level, _)| Some(*level) == section_level).map(|(_, title)| title)
+    };
+
+    let mut drafts: Vec<Draft> = Vec::new();
+    let mut description: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if let Some(summary) = starts_ticket(line) {
+            if let Some(last) = drafts.last_mut() {
+                last.description = Some(dedent(&description));
+            }
+            description.clear();
+            drafts.push(Draft { summary: summary.to_string(), description: None, issue_type: None });
+        } else if !drafts.is_empty() && heading(line).is_none_or(|(level, _)| Some(level) >= section_level) {
+            description.push(line);
+        }
+    }
+    if let Some(last) = drafts.last_mut() {
+        last.description = Some(dedent(&description));
+    }
+    drafts
+}
+
+// "- Summary", "* [ ] Summary", or "1. Summary", only when not indented
+fn list_item(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+        .or_else(|| {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            (digits > 0).then(|| line[digits..].strip_prefix(". ")).flatten()
+        })?;
+    let rest = ["[ ] ", "[x] ", "[X] "].iter()
+        .find_map(|checkbox| rest.strip_prefix(checkbox))
+        .unwrap_or(rest);
+    Some(rest)
+}
+
+// "## Title" as (2, "Title")
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let rest = line.trim_start_matches('#');
+    let level = line.len() - rest.len();
+    (level > 0).then(|| rest.strip_prefix(' ')).flatten().map(|title| (level, title))
+}
+
+// Strips the indentation shared by every non-blank line, so nested bullets keep their shape
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines.iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines.iter()
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// The first row names the columns; "summary" is required, "description" and "type" are optional
+fn parse_csv(text: &str) -> Result<Vec<Draft>> {
+    let mut rows = csv_rows(text).into_iter();
+    let header: Vec<String> = rows.next()
+        .context("The CSV file is empty")?
+        .iter()
+        .map(|name| name.trim().to_lowercase())
+        .collect();
+    let column = |name: &str| header.iter().position(|h| h == name);
+    let summary = column("summary").context("The CSV file needs a 'summary' column")?;
+    let description = column("description");
+    let issue_type = column("type");
+
+    Ok(rows
+        .map(|row| {
+            let cell = |index: Option<usize>| index.and_then(|i| row.get(i)).cloned();
+            Draft {
+                summary: cell(Some(summary)).unwrap_or_default(),
+                description: cell(description),
+                issue_type: cell(issue_type),
+            }
+        })
+        .collect())
+}
+
+// Commas separate cells; quoted cells may hold commas, newlines, and "" for a quote
+fn csv_rows(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if cell.is_empty() => quoted = true,
+            (',', false) => row.push(std::mem::take(&mut cell)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut cell));
+                // Blank lines are skipped
+                if row.iter().any(|cell| !cell.trim().is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            _ => cell.push(c),
+        }
+    }
+    row.push(cell);
+    if row.iter().any(|cell| !cell.trim().is_empty()) {
+        rows.push(row);
+    }
+    rows
+}
+
+// A list whose entries are either a summary or a mapping with summary, description, and type
+fn parse_yaml(text: &str) -> Result<Vec<Draft>> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Summary(String),
+        Full(Draft),
+    }
+
+    let entries: Vec<Entry> = serde_yaml::from_str(text)
+        .context("Failed to parse YAML; expected a list of summaries or of {summary, description, type}")?;
+    Ok(entries.into_iter()
+        .map(|entry| match entry {
+            Entry::Summary(summary) => Draft { summary, description: None, issue_type: None },
+            Entry::Full(draft) => draft,
+        })
+        .collect())
+}
@@ -0,0 +1,896 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+use serde_json::Value;
+use std::sync::OnceLock;
+
+use crate::cache::IssueCache;
+use crate::ui::Theme;
+
+// Atlassian Document Format is the JSON tree Jira uses for descriptions and comments.
+// It renders two ways: styled text for the TUI, and Markdown for plain-text output.
+// Plain strings (Jira Server, older API versions) pass through unchanged.
+
+// Table cells wider than this wrap onto several lines in the terminal
+const MAX_CELL_WIDTH: usize = 40;
+
+pub fn to_text(value: &Value, theme: &Theme) -> Text<'static> {
+    if let Some(text) = value.as_str() {
+        return Text::from(text.lines().map(|l| Line::from(l.to_string())).collect::<Vec<_>>());
+    }
+
+    let mut renderer = TextRenderer { lines: Vec::new(), theme };
+    renderer.blocks(children(value), "");
+    while renderer.lines.last().is_some_and(|l| l.width() == 0) {
+        renderer.lines.pop();
+    }
+    Text::from(renderer.lines)
+}
+
+pub fn to_markdown(value: &Value) -> String {
+    if let Some(text) = value.as_str() {
+        return text.to_string();
+    }
+
+    let mut renderer = MarkdownRenderer { lines: Vec::new() };
+    renderer.blocks(children(value), "");
+    while renderer.lines.last().is_some_and(|l| l.trim().is_empty()) {
+        renderer.lines.pop();
+    }
+    renderer.lines.join("\n")
+}
+
+// Document from plain text: blank lines separate paragraphs, and single newlines become line breaks
+pub fn from_text(text: &str) -> Value {
+    let paragraphs: Vec<Value> = text.split("\n\n")
+        .map(|paragraph| paragraph.trim_matches('\n'))
+        .filter(|paragraph| !paragraph.trim().is_empty())
+        .map(|paragraph| {
+            let mut content = Vec::new();
+            for (i, line) in paragraph.lines().enumerate() {
+                if i > 0 {
+                    content.push(serde_json::json!({ "type": "hardBreak" }));
+                }
+                if !line.is_empty() {
+                    content.push(serde_json::json!({ "type": "text", "text": line }));
+                }
+            }
+            serde_json::json!({ "type": "paragraph", "content": content })
+        })
+        .collect();
+    serde_json::json!({ "type": "doc", "version": 1, "content": paragraphs })
+}
+
+// One-paragraph document from plain text, with each `placeholder` replaced by a mention of
+// the user so Jira notifies them
+pub fn with_mention(text: &str, placeholder: &str, account_id: &str, name: &str) -> Value {
+    let mut content = Vec::new();
+    for (i, part) in text.split(placeholder).enumerate() {
+        if i > 0 {
+            content.push(serde_json::json!({
+                "type": "mention",
+                "attrs": { "id": account_id, "text": format!("@{}", name) },
+            }));
+        }
+        if !part.is_empty() {
+            content.push(serde_json::json!({ "type": "text", "text": part }));
+        }
+    }
+    serde_json::json!({
+        "type": "doc",
+        "version": 1,
+        "content": [{ "type": "paragraph", "content": content }],
+    })
+}
+
+// One-paragraph document of `text` followed by `url` as a link, e.g. "Pull request: <url>"
+pub fn with_link(text: &str, url: &str) -> Value {
+    serde_json::json!({
+        "type": "doc",
+        "version": 1,
+        "content": [{
+            "type": "paragraph",
+            "content": [
+                { "type": "text", "text": text },
+                { "type": "text", "text": url, "marks": [{ "type": "link", "attrs": { "href": url } }] },
+            ],
+        }],
+    })
+}
+
+// Every web address in the document, in order and without repeats: link targets, smart link
+// URLs, and bare URLs typed into the text
+pub fn links(value: &Value) -> Vec<String> {
+    let mut found = Vec::new();
+    if let Some(text) = value.as_str() {
+        bare_urls(text, &mut found);
+    } else {
+        collect_links(value, &mut found);
+    }
+    let mut seen = std::collections::HashSet::new();
+    found.retain(|url| seen.insert(url.clone()));
+    found
+}
+
+fn collect_links(node: &Value, found: &mut Vec<String>) {
+    if let Some(href) = link_href(node).or_else(|| card_url(node)) {
+        found.push(href.to_string());
+    }
+    if let Some(text) = node.get("text").and_then(|t| t.as_str()) {
+        bare_urls(text, found);
+    }
+    for child in children(node) {
+        collect_links(child, found);
+    }
+}
+
+fn bare_urls(text: &str, found: &mut Vec<String>) {
+    static URL: OnceLock<regex::Regex> = OnceLock::new();
+    let re = URL.get_or_init(|| regex::Regex::new(r#"https?://[^\s<>"'`]+"#).expect("valid URL pattern"));
+    // Punctuation ending a sentence or closing a bracket isn't part of the address
+    found.extend(re.find_iter(text).map(|m| m.as_str().trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']']).to_string()));
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .map(|c| c.as_slice())
+        .unwrap_or(&[])
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(|t| t.as_str()).unwrap_or("")
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|a| a.get(name))
+}
+
+fn attr_str<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    attr(node, name).and_then(|v| v.as_str())
+}
+
+fn marks(node: &Value) -> impl Iterator<Item = &Value> {
+    node.get("marks").and_then(|m| m.as_array()).into_iter().flatten()
+}
+
+fn link_href(node: &Value) -> Option<&str> {
+    marks(node)
+        .find(|m| node_type(m) == "link")
+        .and_then(|m| attr_str(m, "href"))
+}
+
+fn card_url(node: &Value) -> Option<&str> {
+    attr_str(node, "url")
+}
+
+// What a smart link shows instead of its address: the title Jira resolved when the card carries
+// one, the ticket key and cached summary for Jira links, or the page title in a Confluence URL
+fn card_title(node: &Value) -> Option<String> {
+    static ISSUES: OnceLock<IssueCache> = OnceLock::new();
+
+    if let Some(name) = attr(node, "data").and_then(|d| d.get("name")).and_then(|n| n.as_str())
+        && !name.is_empty()
+    {
+        return Some(name.to_string());
+    }
+
+    let url = card_url(node)?;
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    if let Some((_, rest)) = path.split_once("/browse/") {
+        let key = rest.split('/').next().unwrap_or(rest);
+        if !key.contains('-') {
+            return None;
+        }
+        return Some(match ISSUES.get_or_init(IssueCache::load).get(key) {
+            Some(issue) => format!("{}: {}", key, issue.summary),
+            None => key.to_string(),
+        });
+    }
+    if path.contains("/wiki/") && path.contains("/pages/") {
+        let title = path.rsplit('/').next()?.replace('+', " ").replace("%20", " ");
+        if !title.is_empty() && !title.chars().all(|c| c.is_ascii_digit()) {
+            return Some(title);
+        }
+    }
+    None
+}
+
+// Text of an inline node other than plain text, e.g. "@Jane Doe" for a mention
+fn inline_atom(node: &Value) -> Option<String> {
+    match node_type(node) {
+        "mention" => {
+            let name = attr_str(node, "text").filter(|n| !n.is_empty()).unwrap_or("someone");
+            Some(if name.starts_with('@') { name.to_string() } else { format!("@{}", name) })
+        }
+        // Custom emoji have no unicode text, only a :short_name:
+        "emoji" => attr_str(node, "text")
+            .filter(|e| !e.is_empty())
+            .or_else(|| attr_str(node, "shortName"))
+            .map(|e| e.to_string()),
+        "inlineCard" | "blockCard" | "embedCard" => card_title(node).or_else(|| card_url(node).map(|u| u.to_string())),
+        "status" => attr_str(node, "text")
+            .filter(|s| !s.is_empty())
+            .map(|s| format!("[{}]", s.to_uppercase())),
+        "date" => {
+            let millis = attr(node, "timestamp")
+                .and_then(|t| t.as_str().and_then(|s| s.parse::<i64>().ok()).or_else(|| t.as_i64()))?;
+            chrono::DateTime::from_timestamp_millis(millis).map(|d| d.format("%Y-%m-%d").to_string())
+        }
+        "media" | "mediaInline" => Some(match attr_str(node, "alt") {
+            Some(alt) => format!("(attachment: {})", alt),
+            None => "(attachment)".to_string(),
+        }),
+        _ => None,
+    }
+}
+
+// Unformatted text of a node and everything below it, used for table cells
+fn flat_text(node: &Value) -> String {
+    if let Some(text) = node.get("text").and_then(|t| t.as_str()) {
+        return text.to_string();
+    }
+    if let Some(atom) = inline_atom(node) {
+        return atom;
+    }
+    if node_type(node) == "hardBreak" {
+        return " ".to_string();
+    }
+    let parts: Vec<String> = children(node).iter()
+        .map(flat_text)
+        .filter(|t| !t.is_empty())
+        .collect();
+    parts.join(" ")
+}
+
+fn table_rows(table: &Value) -> Vec<(bool, Vec<String>)> {
+    children(table).iter()
+        .filter(|row| node_type(row) == "tableRow")
+        .map(|row| {
+            let cells = children(row);
+            let is_header = !cells.is_empty() && cells.iter().all(|c| node_type(c) == "tableHeader");
+            (is_header, cells.iter().map(flat_text).collect())
+        })
+        .collect()
+}
+
+struct TextRenderer<'a> {
+    lines: Vec<Line<'static>>,
+    theme: &'a Theme,
+}
+
+fn line_text(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+impl TextRenderer<'_> {
+    // Separates blocks with one empty line, keeping any quote bar in the prefix
+    fn blank(&mut self, prefix: &str) {
+        if self.lines.last().is_some_and(|l| line_text(l).trim_end() != prefix.trim_end()) {
+            self.lines.push(Line::from(prefix.trim_end().to_string()));
+        }
+    }
+
+    // Drops the separator nested blocks leave behind, so it doesn't double up with the parent's
+    fn trim_blanks(&mut self, prefix: &str, keep: usize) {
+        while self.lines.len() > keep
+            && self.lines.last().is_some_and(|l| line_text(l).trim_end() == prefix.trim_end())
+        {
+            self.lines.pop();
+        }
+    }
+
+    fn blocks(&mut self, nodes: &[Value], prefix: &str) {
+        for node in nodes {
+            self.block(node, prefix);
+        }
+    }
+
+    fn block(&mut self, node: &Value, prefix: &str) {
+        match node_type(node) {
+            "paragraph" => {
+                self.inline(node, prefix, prefix, Style::default());
+                self.blank(prefix);
+            }
+            "heading" => {
+                let level = attr(node, "level").and_then(|l| l.as_u64()).unwrap_or(1) as usize;
+                let first = format!("{}{} ", prefix, "#".repeat(level.clamp(1, 6)));
+                self.inline(node, &first, prefix, Style::default().fg(self.theme.highlight).add_modifier(Modifier::BOLD));
+                self.blank(prefix);
+            }
+            "bulletList" | "orderedList" | "taskList" => {
+                let start = attr(node, "order").and_then(|o| o.as_u64()).unwrap_or(1) as usize;
+                for (i, item) in children(node).iter().enumerate() {
+                    let marker = match node_type(node) {
+                        "orderedList" => format!("{}. ", start + i),
+                        "taskList" if attr_str(item, "state") == Some("DONE") => "☑ ".to_string(),
+                        "taskList" => "☐ ".to_string(),
+                        _ => "• ".to_string(),
+                    };
+                    self.list_item(item, prefix, &marker);
+                }
+                self.blank(prefix);
+            }
+            "codeBlock" => {
+                let code = children(node).iter().map(flat_text).collect::<String>();
+                for line in code.lines() {
+                    self.lines.push(Line::from(vec![
+                        Span::raw(prefix.to_string()),
+                        Span::styled(format!("  {}", line), Style::default().fg(self.theme.label)),
+                    ]));
+                }
+                self.blank(prefix);
+            }
+            "blockquote" | "panel" => {
+                let bar = if node_type(node) == "panel" { "┃ " } else { "│ " };
+                let quoted = format!("{}{}", prefix, bar);
+                let keep = self.lines.len();
+                self.blocks(children(node), &quoted);
+                self.trim_blanks(&quoted, keep);
+                self.blank(prefix);
+            }
+            "expand" | "nestedExpand" => {
+                let title = attr_str(node, "title").unwrap_or("Details");
+                self.lines.push(Line::from(vec![
+                    Span::raw(prefix.to_string()),
+                    Span::styled(format!("▸ {}", title), Style::default().add_modifier(Modifier::BOLD)),
+                ]));
+                self.blocks(children(node), &format!("{}  ", prefix));
+            }
+            "rule" => {
+                self.lines.push(Line::from(vec![
+                    Span::raw(prefix.to_string()),
+                    Span::styled("─".repeat(40), Style::default().fg(self.theme.muted)),
+                ]));
+                self.blank(prefix);
+            }
+            "table" => {
+                self.table(node, prefix);
+                self.blank(prefix);
+            }
+            "mediaSingle" | "mediaGroup" => {
+                for media in children(node) {
+                    if let Some(text) = inline_atom(media) {
+                        self.lines.push(Line::from(vec![
+                            Span::raw(prefix.to_string()),
+                            Span::styled(text, Style::default().fg(self.theme.muted)),
+                        ]));
+                    }
+                }
+                self.blank(prefix);
+            }
+            "blockCard" | "embedCard" => {
+                if let Some(title) = inline_atom(node) {
+                    let mut spans = vec![
+                        Span::raw(prefix.to_string()),
+                        Span::styled(title.clone(), Style::default().fg(self.theme.link).add_modifier(Modifier::UNDERLINED)),
+                    ];
+                    if let Some(url) = card_url(node).filter(|url| *url != title) {
+                        spans.push(Span::styled(format!(" <{}>", url), Style::default().fg(self.theme.muted)));
+                    }
+                    self.lines.push(Line::from(spans));
+                    self.blank(prefix);
+                }
+            }
+            // Unknown containers still show their content
+            _ => self.blocks(children(node), prefix),
+        }
+    }
+
+    // The item's first paragraph sits on the marker line; everything else is indented under it
+    fn list_item(&mut self, item: &Value, prefix: &str, marker: &str) {
+        let first = format!("{}{}", prefix, marker);
+        let rest = format!("{}{}", prefix, " ".repeat(marker.chars().count()));
+
+        // Task items hold inline content directly instead of paragraphs
+        if node_type(item) == "taskItem" {
+            self.inline(item, &first, &rest, Style::default());
+            return;
+        }
+
+        let keep = self.lines.len() + 1;
+        for (i, child) in children(item).iter().enumerate() {
+            match node_type(child) {
+                "paragraph" if i == 0 => self.inline(child, &first, &rest, Style::default()),
+                "paragraph" => self.inline(child, &rest, &rest, Style::default()),
+                _ if i == 0 => {
+                    self.lines.push(Line::from(first.trim_end().to_string()));
+                    self.block(child, &rest);
+                }
+                _ => self.block(child, &rest),
+            }
+        }
+        // Nested blocks add their own spacing, which would double-space the list
+        self.trim_blanks(&rest, keep);
+    }
+
+    fn inline(&mut self, node: &Value, first_prefix: &str, prefix: &str, base: Style) {
+        let mut spans = vec![Span::raw(first_prefix.to_string())];
+        for child in children(node) {
+            if node_type(child) == "hardBreak" {
+                self.lines.push(Line::from(std::mem::take(&mut spans)));
+                spans.push(Span::raw(prefix.to_string()));
+                continue;
+            }
+
+            let Some(text) = child.get("text").and_then(|t| t.as_str()).map(|t| t.to_string())
+                .or_else(|| inline_atom(child))
+            else {
+                continue;
+            };
+            let style = base.patch(inline_style(child, self.theme));
+            let href = link_href(child).or_else(|| card_url(child))
+                .filter(|href| *href != text)
+                .map(|href| href.to_string());
+            spans.push(Span::styled(text, style));
+            if let Some(href) = href {
+                spans.push(Span::styled(format!(" <{}>", href), Style::default().fg(self.theme.muted)));
+            }
+        }
+        self.lines.push(Line::from(spans));
+    }
+
+    fn table(&mut self, table: &Value, prefix: &str) {
+        let rows = table_rows(table);
+        let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut widths = vec![1; columns];
+        for (_, cells) in &rows {
+            for (i, cell) in cells.iter().enumerate() {
+                widths[i] = widths[i].max(cell.chars().count().min(MAX_CELL_WIDTH));
+            }
+        }
+
+        let border = Style::default().fg(self.theme.muted);
+        for (row_index, (is_header, cells)) in rows.iter().enumerate() {
+            let wrapped: Vec<Vec<String>> = (0..columns)
+                .map(|i| {
+                    let cell = cells.get(i).map(|c| c.as_str()).unwrap_or("");
+                    textwrap::wrap(cell, widths[i]).into_iter().map(|l| l.into_owned()).collect()
+                })
+                .collect();
+            let height = wrapped.iter().map(|lines| lines.len()).max().unwrap_or(1).max(1);
+
+            let style = if *is_header {
+                Style::default().fg(self.theme.label).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            for line_index in 0..height {
+                let mut spans = vec![Span::raw(prefix.to_string())];
+                for (i, lines) in wrapped.iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Span::styled(" │ ", border));
+                    }
+                    let text = lines.get(line_index).map(|l| l.as_str()).unwrap_or("");
+                    spans.push(Span::styled(format!("{:<width$}", text, width = widths[i]), style));
+                }
+                self.lines.push(Line::from(spans));
+            }
+
+            if *is_header || (row_index == 0 && rows.len() > 1) {
+                let rule: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+                self.lines.push(Line::from(vec![
+                    Span::raw(prefix.to_string()),
+                    Span::styled(rule.join("─┼─"), border),
+                ]));
+            }
+        }
+    }
+}
+
+fn inline_style(node: &Value, theme: &Theme) -> Style {
+    let mut style = match node_type(node) {
+        "mention" => Style::default().fg(theme.special),
+        // Lozenge colors as Jira names them
+        "status" => {
+            let style = Style::default().add_modifier(Modifier::BOLD);
+            match attr_str(node, "color") {
+                Some("green") => style.fg(theme.success),
+                Some("red") => style.fg(theme.error),
+                Some("yellow") => style.fg(theme.highlight),
+                Some("blue") => style.fg(theme.link),
+                Some("purple") => style.fg(theme.special),
+                _ => style,
+            }
+        }
+        "inlineCard" => Style::default().fg(theme.link).add_modifier(Modifier::UNDERLINED),
+        "media" | "mediaInline" => Style::default().fg(theme.muted),
+        _ => Style::default(),
+    };
+    for mark in marks(node) {
+        style = match node_type(mark) {
+            "strong" => style.add_modifier(Modifier::BOLD),
+            "em" => style.add_modifier(Modifier::ITALIC),
+            "underline" => style.add_modifier(Modifier::UNDERLINED),
+            "strike" => style.add_modifier(Modifier::CROSSED_OUT),
+            "code" => style.fg(theme.label),
+            "link" => style.fg(theme.link).add_modifier(Modifier::UNDERLINED),
+            _ => style,
+        };
+    }
+    style
+}
+
+struct MarkdownRenderer {
+    lines: Vec<String>,
+}
+
+impl MarkdownRenderer {
+    fn blank(&mut self, prefix: &str) {
+        if self.lines.last().is_some_and(|l| l.trim_end() != prefix.trim_end()) {
+            self.lines.push(prefix.trim_end().to_string());
+        }
+    }
+
+    fn trim_blanks(&mut self, prefix: &str, keep: usize) {
+        while self.lines.len() > keep && self.lines.last().is_some_and(|l| l.trim_end() == prefix.trim_end()) {
+            self.lines.pop();
+        }
+    }
+
+    fn blocks(&mut self, nodes: &[Value], prefix: &str) {
+        for node in nodes {
+            self.block(node, prefix);
+        }
+    }
+
+    fn block(&mut self, node: &Value, prefix: &str) {
+        match node_type(node) {
+            "paragraph" => {
+                self.inline(node, prefix, prefix);
+                self.blank(prefix);
+            }
+            "heading" => {
+                let level = attr(node, "level").and_then(|l| l.as_u64()).unwrap_or(1) as usize;
+                let first = format!("{}{} ", prefix, "#".repeat(level.clamp(1, 6)));
+                self.inline(node, &first, prefix);
+                self.blank(prefix);
+            }
+            "bulletList" | "orderedList" | "taskList" => {
+                let start = attr(node, "order").and_then(|o| o.as_u64()).unwrap_or(1) as usize;
+                for (i, item) in children(node).iter().enumerate() {
+                    let marker = match node_type(node) {
+                        "orderedList" => format!("{}. ", start + i),
+                        "taskList" if attr_str(item, "state") == Some("DONE") => "- [x] ".to_string(),
+                        "taskList" => "- [ ] ".to_string(),
+                        _ => "- ".to_string(),
+                    };
+                    self.list_item(item, prefix, &marker);
+                }
+                self.blank(prefix);
+            }
+            "codeBlock" => {
+                let language = attr_str(node, "language").unwrap_or("");
+                let code = children(node).iter().map(flat_text).collect::<String>();
+                self.lines.push(format!("{}```{}", prefix, language));
+                for line in code.lines() {
+                    self.lines.push(format!("{}{}", prefix, line));
+                }
+                self.lines.push(format!("{}```", prefix));
+                self.blank(prefix);
+            }
+            "blockquote" | "panel" => {
+                let quoted = format!("{}> ", prefix);
+                let keep = self.lines.len();
+                self.blocks(children(node), &quoted);
+                self.trim_blanks(&quoted, keep);
+                self.blank(prefix);
+            }
+            "expand" | "nestedExpand" => {
+                let title = attr_str(node, "title").unwrap_or("Details");
+                self.lines.push(format!("{}**{}**", prefix, escape(title)));
+                self.blank(prefix);
+                self.blocks(children(node), prefix);
+            }
+            "rule" => {
+                self.lines.push(format!("{}---", prefix));
+                self.blank(prefix);
+            }
+            "table" => {
+                self.table(node, prefix);
+                self.blank(prefix);
+            }
+            "mediaSingle" | "mediaGroup" => {
+                for media in children(node) {
+                    if let Some(text) = inline_atom(media) {
+                        self.lines.push(format!("{}{}", prefix, escape(&text)));
+                    }
+                }
+                self.blank(prefix);
+            }
+            "blockCard" | "embedCard" => {
+                let card = card_markdown(node);
+                if !card.is_empty() {
+                    self.lines.push(format!("{}{}", prefix, card));
+                    self.blank(prefix);
+                }
+            }
+            _ => self.blocks(children(node), prefix),
+        }
+    }
+
+    fn list_item(&mut self, item: &Value, prefix: &str, marker: &str) {
+        let first = format!("{}{}", prefix, marker);
+        // Nested content lines up with the text after the marker ("- [ ] " items nest under "- ")
+        let indent = if marker.starts_with("- [") { 2 } else { marker.len() };
+        let rest = format!("{}{}", prefix, " ".repeat(indent));
+
+        if node_type(item) == "taskItem" {
+            self.inline(item, &first, &rest);
+            return;
+        }
+
+        let keep = self.lines.len() + 1;
+        for (i, child) in children(item).iter().enumerate() {
+            match node_type(child) {
+                "paragraph" if i == 0 => self.inline(child, &first, &rest),
+                "paragraph" => self.inline(child, &rest, &rest),
+                _ if i == 0 => {
+                    self.lines.push(first.trim_end().to_string());
+                    self.block(child, &rest);
+                }
+                _ => self.block(child, &rest),
+            }
+        }
+        self.trim_blanks(&rest, keep);
+    }
+
+    fn inline(&mut self, node: &Value, first_prefix: &str, prefix: &str) {
+        let mut line = first_prefix.to_string();
+        for child in children(node) {
+            if node_type(child) == "hardBreak" {
+                // Two trailing spaces make a line break without starting a new paragraph
+                line.push_str("  ");
+                self.lines.push(std::mem::take(&mut line));
+                line.push_str(prefix);
+                continue;
+            }
+            line.push_str(&inline_markdown(child));
+        }
+        self.lines.push(line);
+    }
+
+    fn table(&mut self, table: &Value, prefix: &str) {
+        let rows = table_rows(table);
+        let columns = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        // Markdown tables always have a header row, so the first row serves as one
+        for (row_index, (_, cells)) in rows.iter().enumerate() {
+            let cells: Vec<String> = (0..columns)
+                .map(|i| escape(cells.get(i).map(|c| c.as_str()).unwrap_or("")).replace('|', "\\|"))
+                .collect();
+            self.lines.push(format!("{}| {} |", prefix, cells.join(" | ")));
+            if row_index == 0 {
+                self.lines.push(format!("{}|{}", prefix, " --- |".repeat(columns)));
+            }
+        }
+    }
+}
+
+fn inline_markdown(node: &Value) -> String {
+    let Some(text) = node.get("text").and_then(|t| t.as_str()) else {
+        return match node_type(node) {
+            "inlineCard" => card_markdown(node),
+            "status" => inline_atom(node).map(|s| format!("`{}`", s)).unwrap_or_default(),
+            _ => inline_atom(node).map(|t| escape(&t)).unwrap_or_default(),
+        };
+    };
+
+    // Emphasis markers must hug the text, so surrounding spaces stay outside them
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_string();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+
+    let is_code = marks(node).any(|m| node_type(m) == "code");
+    let mut out = if is_code {
+        let fence = if core.contains('`') { "``" } else { "`" };
+        format!("{}{}{}", fence, core, fence)
+    } else {
+        escape(core)
+    };
+    for mark in marks(node) {
+        out = match node_type(mark) {
+            "strong" => format!("**{}**", out),
+            "em" => format!("*{}*", out),
+            "strike" => format!("~~{}~~", out),
+            _ => out,
+        };
+    }
+    if let Some(href) = link_href(node) {
+        out = format!("[{}]({})", out, href);
+    }
+
+    format!("{}{}{}", leading, out, trailing)
+}
+
+// [title](url) for cards with a readable title, <url> otherwise
+fn card_markdown(node: &Value) -> String {
+    match (card_title(node), card_url(node)) {
+        (Some(title), Some(url)) => format!("[{}]({})", escape(&title), url),
+        (None, Some(url)) => format!("<{}>", url),
+        (Some(title), None) => escape(&title),
+        (None, None) => String::new(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn description() -> Value {
+        serde_json::from_str(include_str!("fixtures/adf_description.json")).unwrap()
+    }
+
+    fn plain_lines(value: &Value) -> Vec<String> {
+        to_text(value, &Theme::default()).lines.iter().map(line_text).collect()
+    }
+
+    // The span of `line` whose text is exactly `content`
+    fn span<'a>(text: &'a Text, line: usize, content: &str) -> &'a Span<'a> {
+        text.lines[line].spans.iter()
+            .find(|s| s.content == content)
+            .unwrap_or_else(|| panic!("no span {:?} in line {}: {:?}", content, line, text.lines[line]))
+    }
+
+    #[test]
+    fn markdown_keeps_structure_and_marks() {
+        let expected = r#"## Retry policy
+
+Owner @Sam Rivera is `[IN REVIEW]`. Webhooks are **never** dropped, only ***delayed*** via `retry_after` ⚠️
+
+See the [*runbook*](https://wiki.example.com/runbook) and [Webhook Retries](https://example.atlassian.net/wiki/spaces/PAY/pages/123456/Webhook+Retries)  
+Dashboard: https://grafana.example.com/d/webhooks.
+
+- Backoff
+  1. 1s, 2s, 4s
+  2. Capped at **`30s`**
+     - ~~with jitter~~
+- Dead letters after 24h
+
+3. Deploy
+4. Verify
+
+- [x] Write the migration
+- [ ] Load test @Alex
+
+| Attempt | Delay |
+| --- | --- |
+| 1 | 1s \| jittered |
+
+> Don't retry 4xx responses.
+
+```rust
+let delay = base * 2u32.pow(attempt);
+sleep(delay);
+```
+
+<https://github.com/example/payments/pull/88>"#;
+        assert_eq!(to_markdown(&description()), expected);
+    }
+
+    #[test]
+    fn text_nests_lists_under_their_items() {
+        let lines = plain_lines(&description());
+        let start = lines.iter().position(|l| l == "• Backoff").unwrap();
+        assert_eq!(lines[start..start + 12], [
+            "• Backoff",
+            "  1. 1s, 2s, 4s",
+            "  2. Capped at 30s",
+            "     • with jitter",
+            "• Dead letters after 24h",
+            "",
+            "3. Deploy",
+            "4. Verify",
+            "",
+            "☑ Write the migration",
+            "☐ Load test @Alex",
+            "",
+        ]);
+    }
+
+    #[test]
+    fn text_shows_tables_quotes_code_and_cards() {
+        let lines = plain_lines(&description());
+        let start = lines.iter().position(|l| l.starts_with("Attempt")).unwrap();
+        assert_eq!(lines[start..], [
+            "Attempt │ Delay        ",
+            "────────┼──────────────",
+            "1       │ 1s | jittered",
+            "",
+            "│ Don't retry 4xx responses.",
+            "",
+            "  let delay = base * 2u32.pow(attempt);",
+            "  sleep(delay);",
+            "",
+            "https://github.com/example/payments/pull/88",
+        ]);
+    }
+
+    #[test]
+    fn text_styles_mixed_marks_and_inline_nodes() {
+        let theme = Theme::default();
+        let text = to_text(&description(), &theme);
+        assert_eq!(line_text(&text.lines[2]), "Owner @Sam Rivera is [IN REVIEW]. Webhooks are never dropped, only delayed via retry_after ⚠️");
+
+        assert!(span(&text, 2, "never").style.add_modifier.contains(Modifier::BOLD));
+        let delayed = span(&text, 2, "delayed ").style.add_modifier;
+        assert!(delayed.contains(Modifier::BOLD | Modifier::ITALIC));
+        assert!(span(&text, 2, "[IN REVIEW]").style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(span(&text, 2, "retry_after").style.fg, Some(theme.label));
+        assert_eq!(span(&text, 2, "@Sam Rivera").style.fg, Some(theme.special));
+
+        // Links keep their marks and show the address after the text
+        let runbook = span(&text, 4, "runbook").style.add_modifier;
+        assert!(runbook.contains(Modifier::ITALIC | Modifier::UNDERLINED));
+        span(&text, 4, " <https://wiki.example.com/runbook>");
+        span(&text, 4, "Webhook Retries");
+        assert_eq!(line_text(&text.lines[5]), "Dashboard: https://grafana.example.com/d/webhooks.");
+
+        let capped = span(&text, 9, "30s").style;
+        assert!(capped.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(capped.fg, Some(theme.label));
+        assert!(span(&text, 10, "with jitter").style.add_modifier.contains(Modifier::CROSSED_OUT));
+    }
+
+    #[test]
+    fn links_come_in_order_without_repeats() {
+        let mut doc = description();
+        // The same address again, as a link mark on later text
+        doc["content"].as_array_mut().unwrap().push(serde_json::json!({
+            "type": "paragraph",
+            "content": [{ "type": "text", "text": "again", "marks": [{ "type": "link", "attrs": { "href": "https://wiki.example.com/runbook" } }] }],
+        }));
+        assert_eq!(links(&doc), [
+            "https://wiki.example.com/runbook",
+            "https://example.atlassian.net/wiki/spaces/PAY/pages/123456/Webhook+Retries",
+            "https://grafana.example.com/d/webhooks",
+            "https://github.com/example/payments/pull/88",
+        ]);
+    }
+
+    #[test]
+    fn plain_strings_pass_through() {
+        let value = Value::String("Fixed in (https://example.com/pr/1).\nSecond line".to_string());
+        assert_eq!(to_markdown(&value), "Fixed in (https://example.com/pr/1).\nSecond line");
+        assert_eq!(plain_lines(&value), ["Fixed in (https://example.com/pr/1).", "Second line"]);
+        assert_eq!(links(&value), ["https://example.com/pr/1"]);
+    }
+
+    #[test]
+    fn text_round_trips_through_from_text() {
+        let doc = from_text("First line\nsecond line\n\n\nNext *paragraph*");
+        assert_eq!(to_markdown(&doc), "First line  \nsecond line\n\nNext \\*paragraph\\*");
+        assert_eq!(plain_lines(&doc), ["First line", "second line", "", "Next *paragraph*"]);
+    }
+
+    #[test]
+    fn mentions_replace_each_placeholder() {
+        let doc = with_mention("{me} asked {me}", "{me}", "abc", "Sam");
+        let content = &doc["content"][0]["content"];
+        assert_eq!(content[0]["attrs"]["id"], "abc");
+        assert_eq!(content[2]["attrs"]["text"], "@Sam");
+        assert_eq!(to_markdown(&doc), "@Sam asked @Sam");
+    }
+}
@@ -0,0 +1,1742 @@
+use anyhow::{Context, Result};
+use base64::Engine;
+use reqwest::blocking::Client;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::models::*;
+use crate::config::{Config, GrabNextFilter, InheritField};
+use crate::hooks::HooksConfig;
+
+// Upper bound on simultaneous requests when a command fans out over many issues
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+const ISSUE_BATCH_SIZE: usize = 25;
+// Issues edited between progress updates in bulk label and component changes
+pub const BULK_EDIT_BATCH_SIZE: usize = MAX_CONCURRENT_REQUESTS * 2;
+// How long a successful credentials probe is trusted before checking again
+const AUTH_PROBE_TTL_MINUTES: i64 = 15;
+
+// Priority and story points keyed by issue key
+type ExtraFields = HashMap<String, (Option<Priority>, Option<f64>)>;
+// Full parent issues keyed by parent key, and each issue's transitions keyed by issue key
+pub type MineDetails = (HashMap<String, JiraIssue>, HashMap<String, Vec<TransitionOption>>);
+
+// Outcome of searching for epics under one candidate issue type name
+enum EpicProbe {
+    Found(Vec<JiraIssue>),
+    NoSuchType,
+    Forbidden,
+}
+
+// Every issue key mentioned in the text, like PROJ-123, in order of first mention
+pub fn issue_keys(text: &str) -> Vec<String> {
+    static ISSUE_KEY: OnceLock<regex::Regex> = OnceLock::new();
+    let re = ISSUE_KEY.get_or_init(|| regex::Regex::new(r"\b[A-Z][A-Z0-9]+-\d+\b").expect("valid issue key pattern"));
+
+    let mut keys: Vec<String> = Vec::new();
+    for found in re.find_iter(text) {
+        if !keys.iter().any(|key| key == found.as_str()) {
+            keys.push(found.as_str().to_string());
+        }
+    }
+    keys
+}
+
+// Rejected creates name the offending fields in `errors`, e.g. {"components": "Component is required"}
+fn create_error(response_text: &str) -> String {
+    let Ok(body) = serde_json::from_str::<serde_json::Value>(response_text) else {
+        return response_text.to_string();
+    };
+    let field_errors = body.get("errors")
+        .and_then(|e| e.as_object())
+        .into_iter()
+        .flatten()
+        .map(|(field, message)| format!("{}: {}", field, message.as_str().unwrap_or_default()));
+    let messages: Vec<String> = body.get("errorMessages")
+        .and_then(|m| m.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|m| m.as_str().map(|m| m.to_string()))
+        .chain(field_errors)
+        .collect();
+    if messages.is_empty() { response_text.to_string() } else { messages.join("; ") }
+}
+
+
+#[derive(Clone)]
+pub struct JiraClient {
+    pub(super) client: Client,
+    pub(super) base_url: String,
+    pub(super) auth_header: String,
+    story_points_field: Option<String>,
+    epic_link_field: Option<String>,
+    sprint_field: Option<String>,
+    reviewer_field: Option<String>,
+    flagged_field: Option<String>,
+    board_id: Option<u64>,
+    transition_aliases: HashMap<String, String>,
+    create_inherit: Vec<InheritField>,
+    hooks: HooksConfig,
+}
+
+impl JiraClient {
+    pub fn new(config: Config) -> Self {
+        let client = Client::new();
+        let profile = config.active_jira();
+        let auth = format!("{}:{}", profile.username, profile.api_token);
+        let auth_header = format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(auth)
+        );
+        
+        Self {
+            client,
+            base_url: profile.jira_url.trim_end_matches('/').to_string(),
+            auth_header,
+            story_points_field: config.story_points_field,
+            epic_link_field: config.epic_link_field,
+            sprint_field: config.sprint_field,
+            reviewer_field: config.reviewer_field,
+            flagged_field: config.flagged_field,
+            board_id: config.board_id,
+            transition_aliases: config.transitions.into_iter()
+                .map(|(alias, status)| (alias.to_lowercase(), status))
+                .collect(),
+            create_inherit: config.create.inherit,
+            hooks: config.hooks,
+        }
+    }
+    
+    pub fn browse_url(&self, issue_key: &str) -> String {
+        format!("{}/browse/{}", self.base_url, issue_key)
+    }
+    
+    pub fn story_points_field(&self) -> Option<&str> {
+        self.story_points_field.as_deref()
+    }
+    
+    pub fn sprint_field(&self) -> Option<&str> {
+        self.sprint_field.as_deref()
+    }
+    
+    pub fn reviewer_field(&self) -> Option<&str> {
+        self.reviewer_field.as_deref()
+    }
+    
+    pub fn flagged_field(&self) -> Option<&str> {
+        self.flagged_field.as_deref()
+    }
+    
+    // Epic fields a new child copies, from `[create] inherit`
+    pub fn create_inherit(&self) -> &[InheritField] {
+        &self.create_inherit
+    }
+    
+    pub fn hooks(&self) -> &HooksConfig {
+        &self.hooks
+    }
+    
+    pub fn get_issue(&self, issue_key: &str) -> Result<JiraIssue> {
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .send()
+            .context("Failed to send request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        let issue: JiraIssue = serde_json::from_str(&response_text)
+            .context(format!("Failed to parse JIRA response. Response: {}", response_text))?;
+        
+        Ok(issue)
+    }
+    
+    // Full issue JSON, for views that need more than JiraIssue models (changelog, time tracking)
+    pub fn get_issue_raw(&self, issue_key: &str, expand: Option<&str>) -> Result<serde_json::Value> {
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+        
+        let mut request = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json");
+        if let Some(expand) = expand {
+            request = request.query(&[("expand", expand)]);
+        }
+        
+        let response = request.send().context("Failed to send request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        serde_json::from_str(&response_text).context("Failed to parse JIRA response")
+    }
+    
+    // The dev-status endpoint isn't part of the public API, so callers should treat
+    // failures as "no pull requests known" rather than hard errors
+    pub fn get_pull_requests(&self, issue_id: &str) -> Result<Vec<PullRequest>> {
+        let url = format!("{}/rest/dev-status/latest/issue/detail", self.base_url);
+        let mut pull_requests: Vec<PullRequest> = Vec::new();
+        
+        #[derive(Debug, Deserialize)]
+        struct DevStatusResponse {
+            #[serde(default)]
+            detail: Vec<DevStatusDetail>,
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct DevStatusDetail {
+            #[serde(default, rename = "pullRequests")]
+            pull_requests: Vec<PullRequest>,
+        }
+        
+        for application in ["GitHub", "bitbucket", "GitLab"] {
+            let response = self.client
+                .get(&url)
+                .header(AUTHORIZATION, &self.auth_header)
+                .header(ACCEPT, "application/json")
+                .query(&[("issueId", issue_id), ("applicationType", application), ("dataType", "pullrequest")])
+                .send()
+                .context("Failed to fetch development info")?;
+            
+            if !response.status().is_success() {
+                continue;
+            }
+            
+            let dev_status: DevStatusResponse = response.json()
+                .context("Failed to parse development info")?;
+            for detail in dev_status.detail {
+                for pr in detail.pull_requests {
+                    if !pull_requests.iter().any(|existing| existing.url == pr.url) {
+                        pull_requests.push(pr);
+                    }
+                }
+            }
+        }
+        
+        Ok(pull_requests)
+    }
+    
+    // Same caveat as get_pull_requests: None when the dev-status endpoint has nothing to say
+    pub fn get_build_summary(&self, issue_id: &str) -> Result<Option<BuildSummary>> {
+        let url = format!("{}/rest/dev-status/latest/issue/summary", self.base_url);
+        
+        #[derive(Debug, Deserialize)]
+        struct SummaryResponse {
+            summary: Option<Summary>,
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct Summary {
+            build: Option<BuildOverall>,
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct BuildOverall {
+            overall: Option<BuildSummary>,
+        }
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[("issueId", issue_id)])
+            .send()
+            .context("Failed to fetch development summary")?;
+        
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+        
+        let summary: SummaryResponse = response.json()
+            .context("Failed to parse development summary")?;
+        Ok(summary.summary.and_then(|s| s.build).and_then(|b| b.overall))
+    }
+    
+    // Returns the issue with its parent resolved when that's unambiguous. When several
+    // custom fields point at different issues, those are returned as candidates instead.
+    pub fn get_issue_with_parent(&self, issue_key: &str) -> Result<(JiraIssue, Vec<ParentCandidate>)> {
+        // Request the issue with parent field expanded
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[("expand", "parent")])
+            .send()
+            .context("Failed to send request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        let mut issue: JiraIssue = serde_json::from_str(&response_text)
+            .context(format!("Failed to parse JIRA response. Response: {}", response_text))?;
+        
+        // Early return if parent already exists
+        if let Some(parent) = issue.fields.parent.as_mut() {
+            parent.linked_via = Some("parent".to_string());
+            return Ok((issue, Vec::new()));
+        }
+        
+        // Try to get parent from Epic Link custom field
+        let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&response_text) else {
+            return Ok((issue, Vec::new()));
+        };
+        
+        let Some(fields) = json_value.get("fields").and_then(|f| f.as_object()) else {
+            return Ok((issue, Vec::new()));
+        };
+        
+        // A pinned Epic Link field is authoritative
+        if let Some(field_id) = &self.epic_link_field {
+            if let Some(epic_key) = fields.get(field_id).and_then(|v| v.as_str()) {
+                let mut epic = self.get_issue(epic_key)?;
+                epic.linked_via = Some(field_id.clone());
+                issue.fields.parent = Some(Box::new(epic));
+            }
+            return Ok((issue, Vec::new()));
+        }
+        
+        let issue_key_re = regex::Regex::new(r"^[A-Z][A-Z0-9]+-\d+$")?;
+        let mut seen_keys = std::collections::HashSet::new();
+        let linked: Vec<(String, String)> = fields.iter()
+            .filter(|(key, _)| key.starts_with("customfield_"))
+            .filter_map(|(key, value)| value.as_str().map(|v| (key.clone(), v.to_string())))
+            .filter(|(_, value)| issue_key_re.is_match(value) && value != issue_key)
+            .filter(|(_, value)| seen_keys.insert(value.clone()))
+            .collect();
+        
+        let mut candidates: Vec<ParentCandidate> = crate::pool::run_parallel(linked, MAX_CONCURRENT_REQUESTS, |(field_id, epic_key)| {
+            self.get_issue(&epic_key).ok().map(|issue| ParentCandidate { field_id, issue })
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+        
+        if candidates.len() == 1 {
+            let mut candidate = candidates.remove(0);
+            candidate.issue.linked_via = Some(candidate.field_id);
+            issue.fields.parent = Some(Box::new(candidate.issue));
+        }
+        
+        Ok((issue, candidates))
+    }
+    
+    fn comment_request(comment: &str) -> CommentRequest {
+        CommentRequest {
+            body: CommentBody {
+                doc_type: "doc".to_string(),
+                version: 1,
+                content: vec![CommentContent {
+                    content_type: "paragraph".to_string(),
+                    content: vec![CommentText {
+                        text_type: "text".to_string(),
+                        text: comment.to_string(),
+                    }],
+                }],
+            },
+        }
+    }
+    
+    pub fn add_comment(&self, issue_key: &str, comment: &str) -> Result<()> {
+        self.add_comment_doc(issue_key, serde_json::to_value(Self::comment_request(comment))?)
+    }
+    
+    // Posts a comment whose body is already an ADF document, e.g. one with mentions
+    pub fn add_comment_adf(&self, issue_key: &str, doc: serde_json::Value) -> Result<()> {
+        self.add_comment_doc(issue_key, serde_json::json!({ "body": doc }))
+    }
+    
+    fn add_comment_doc(&self, issue_key: &str, comment_request: serde_json::Value) -> Result<()> {
+        let url = format!("{}/rest/api/3/issue/{}/comment", self.base_url, issue_key);
+        
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&comment_request)
+            .send()
+            .context("Failed to send comment to JIRA")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            eprintln!("Failed to add comment. Status: {}, Response: {}", status, error_text);
+            anyhow::bail!("Failed to add comment: {}", status);
+        }
+        
+        Ok(())
+    }
+    
+    pub fn add_attachment(&self, issue_key: &str, file_name: &str, contents: Vec<u8>) -> Result<Attachment> {
+        let url = format!("{}/rest/api/3/issue/{}/attachments", self.base_url, issue_key);
+        let part = reqwest::blocking::multipart::Part::bytes(contents).file_name(file_name.to_string());
+        let form = reqwest::blocking::multipart::Form::new().part("file", part);
+        
+        // JIRA rejects multipart uploads without this header as a CSRF precaution
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .header("X-Atlassian-Token", "no-check")
+            .multipart(form)
+            .send()
+            .context("Failed to upload attachment to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        if !status.is_success() {
+            anyhow::bail!("Failed to attach {} to {}: {} {}", file_name, issue_key, status, response_text);
+        }
+        
+        let attachments: Vec<Attachment> = serde_json::from_str(&response_text)
+            .context("Failed to parse attachment response")?;
+        attachments.into_iter().next().context("JIRA returned no attachment")
+    }
+    
+    // Every comment on an issue, oldest first
+    pub fn get_comments(&self, issue_key: &str) -> Result<Vec<Comment>> {
+        self.get_all_comments_raw(issue_key)
+            .context(format!("Failed to get comments on {}", issue_key))?
+            .into_iter()
+            .map(|comment| serde_json::from_value(comment).context("Failed to parse comments response"))
+            .collect()
+    }
+    
+    pub fn get_my_last_comment(&self, issue_key: &str) -> Result<Comment> {
+        let current_user = self.get_current_user()?;
+        
+        self.get_comments(issue_key)?
+            .into_iter()
+            .rev()
+            .find(|c| c.author.as_ref().map(|a| a.account_id.as_str()) == Some(current_user.account_id.as_str()))
+            .context(format!("You have no comments on {}", issue_key))
+    }
+    
+    pub fn update_comment(&self, issue_key: &str, comment_id: &str, comment: &str) -> Result<()> {
+        let url = format!("{}/rest/api/3/issue/{}/comment/{}", self.base_url, issue_key, comment_id);
+        
+        let comment_request = Self::comment_request(comment);
+        
+        let response = self.client
+            .put(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&comment_request)
+            .send()
+            .context("Failed to send comment update to JIRA")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            eprintln!("Failed to update comment. Status: {}, Response: {}", status, error_text);
+            anyhow::bail!("Failed to update comment: {}", status);
+        }
+        
+        Ok(())
+    }
+    
+    pub fn delete_comment(&self, issue_key: &str, comment_id: &str) -> Result<()> {
+        let url = format!("{}/rest/api/3/issue/{}/comment/{}", self.base_url, issue_key, comment_id);
+        
+        let response = self.client
+            .delete(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .send()
+            .context("Failed to delete comment")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            eprintln!("Failed to delete comment. Status: {}, Response: {}", status, error_text);
+            anyhow::bail!("Failed to delete comment: {}", status);
+        }
+        
+        Ok(())
+    }
+    
+    // A resolution comment goes in with the transition when its screen has a comment field, so
+    // it's only recorded if the close succeeds. Otherwise it's posted right after the close.
+    pub fn close_issue(&self, issue_key: &str, comment: Option<&str>) -> Result<()> {
+        let transitions = self.get_transitions(issue_key)?;
+        
+        let done_transition = transitions.iter()
+            .find(|t| t.name.to_lowercase().contains("done") || 
+                      t.name.to_lowercase().contains("close") ||
+                      t.name.to_lowercase().contains("resolved"))
+            .context("No 'Done' or 'Close' transition found for this issue")?;
+        
+        let url = format!("{}/rest/api/3/issue/{}/transitions", self.base_url, issue_key);
+        
+        let comment = comment.map(str::trim).filter(|c| !c.is_empty());
+        let comment_on_screen = done_transition.fields.contains_key("comment");
+        let mut update = serde_json::Map::new();
+        if let Some(comment) = comment.filter(|_| comment_on_screen) {
+            update.insert("comment".to_string(), serde_json::json!([
+                { "add": serde_json::to_value(Self::comment_request(comment))? }
+            ]));
+        }
+        
+        let transition_request = TransitionRequest {
+            transition: TransitionId {
+                id: done_transition.id.clone(),
+            },
+            fields: serde_json::Map::new(),
+            update,
+        };
+        
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&transition_request)
+            .send()
+            .context("Failed to transition issue")?;
+        
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to close issue: {}", response.status());
+        }
+        
+        if let Some(comment) = comment.filter(|_| !comment_on_screen) {
+            self.add_comment(issue_key, comment)
+                .context(format!("{} was closed, but the comment couldn't be added", issue_key))?;
+        }
+        
+        Ok(())
+    }
+    
+    // Transitions by target status name/id, transition name/id, or a configured alias.
+    // Returns the name of the status the issue ended up in.
+    pub fn transition_to(&self, issue_key: &str, target: &str, fields: serde_json::Map<String, serde_json::Value>) -> Result<String> {
+        let target = self.transition_aliases.get(&target.to_lowercase())
+            .map(|s| s.as_str())
+            .unwrap_or(target);
+        let transitions = self.get_transitions(issue_key)?;
+        
+        let matches = |t: &&Transition| {
+            t.id == target
+                || t.name.eq_ignore_ascii_case(target)
+                || t.to.as_ref().is_some_and(|to| to.id == target || to.name.eq_ignore_ascii_case(target))
+        };
+        
+        let Some(transition) = transitions.iter().find(matches) else {
+            let available: Vec<String> = transitions.iter()
+                .map(|t| match &t.to {
+                    Some(to) => format!("{} (id {})", to.name, to.id),
+                    None => format!("{} (transition {})", t.name, t.id),
+                })
+                .collect();
+            if available.is_empty() {
+                anyhow::bail!("No transitions are available for {}", issue_key);
+            }
+            anyhow::bail!("No transition to '{}' is available for {}. Available: {}", target, issue_key, available.join(", "));
+        };
+        
+        let mut missing: Vec<String> = transition.fields.iter()
+            .filter(|(id, f)| f.required && !f.has_default_value && !fields.contains_key(*id))
+            .map(|(id, f)| format!("{} ({})", f.name, id))
+            .collect();
+        if !missing.is_empty() {
+            missing.sort();
+            anyhow::bail!("Transition '{}' requires fields: {}. Pass them with --field <id>=<value>", transition.name, missing.join(", "));
+        }
+        
+        let url = format!("{}/rest/api/3/issue/{}/transitions", self.base_url, issue_key);
+        let transition_request = TransitionRequest {
+            transition: TransitionId {
+                id: transition.id.clone(),
+            },
+            fields,
+            update: serde_json::Map::new(),
+        };
+        
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&transition_request)
+            .send()
+            .context("Failed to transition issue")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            eprintln!("Failed to transition issue. Status: {}, Response: {}", status, error_text);
+            anyhow::bail!("Failed to transition {} via '{}': {}", issue_key, transition.name, status);
+        }
+        
+        Ok(transition.to.as_ref().map(|to| to.name.clone()).unwrap_or_else(|| transition.name.clone()))
+    }
+    
+    // What `qq jira review` moves to: the "review" alias from [transitions] when configured,
+    // otherwise the first available transition whose status mentions review
+    pub fn review_target(&self, issue_key: &str) -> Result<String> {
+        if self.transition_aliases.contains_key("review") {
+            return Ok("review".to_string());
+        }
+        let transitions = self.get_transitions(issue_key)?;
+        transitions.iter()
+            .find(|t| t.to.as_ref().map_or(&t.name, |to| &to.name).to_lowercase().contains("review"))
+            .map(|t| t.id.clone())
+            .context(format!(
+                "No review status is available for {}. Pass --to or add review = \"<status>\" under [transitions].",
+                issue_key
+            ))
+    }
+    
+    // Transitions available on the issue, in the order Jira lists them
+    pub fn transition_options(&self, issue_key: &str) -> Result<Vec<TransitionOption>> {
+        Ok(self.get_transitions(issue_key)?
+            .into_iter()
+            .map(|t| TransitionOption {
+                status: t.to.map(|to| to.name).unwrap_or_else(|| t.name.clone()),
+                id: t.id,
+                name: t.name,
+            })
+            .collect())
+    }
+    
+    fn get_transitions(&self, issue_key: &str) -> Result<Vec<Transition>> {
+        let url = format!("{}/rest/api/3/issue/{}/transitions", self.base_url, issue_key);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[("expand", "transitions.fields")])
+            .send()
+            .context("Failed to get transitions")?;
+        
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to get transitions: {}", response.status());
+        }
+        
+        let transitions_response: TransitionsResponse = response.json()
+            .context("Failed to parse transitions response")?;
+        
+        Ok(transitions_response.transitions)
+    }
+    
+    pub fn assign_issue(&self, issue_key: &str, account_id: Option<&str>) -> Result<()> {
+        let url = format!("{}/rest/api/3/issue/{}/assignee", self.base_url, issue_key);
+        
+        #[derive(Debug, Serialize)]
+        struct AssignRequest {
+            #[serde(rename = "accountId")]
+            account_id: Option<String>,
+        }
+        
+        let assign_request = AssignRequest {
+            account_id: account_id.map(|id| id.to_string()),
+        };
+        
+        let response = self.client
+            .put(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&assign_request)
+            .send()
+            .context("Failed to assign issue")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            eprintln!("Failed to assign issue. Status: {}, Response: {}", status, error_text);
+            anyhow::bail!("Failed to assign issue: {}", status);
+        }
+        
+        Ok(())
+    }
+    
+    // Generic field update; callers pass the `fields` object for the PUT body
+    pub fn update_issue_fields(&self, issue_key: &str, fields: serde_json::Value) -> Result<()> {
+        self.edit_issue(issue_key, serde_json::json!({ "fields": fields }))
+    }
+    
+    // Add/remove operations, e.g. `{"labels": [{"add": "backend"}]}`, for fields where
+    // setting the whole value would drop what's already there
+    pub fn update_issue(&self, issue_key: &str, update: serde_json::Value) -> Result<()> {
+        self.edit_issue(issue_key, serde_json::json!({ "update": update }))
+    }
+    
+    // Adds or removes a label or component on several issues in parallel, with one result
+    // per key in the same order
+    pub fn edit_list_field(&self, issue_keys: &[String], field: ListField, add: bool, value: &str) -> Vec<Result<()>> {
+        let update = field.operation(add, value);
+        crate::pool::run_parallel(issue_keys.to_vec(), MAX_CONCURRENT_REQUESTS, |key| {
+            self.update_issue(&key, update.clone())
+        })
+    }
+    
+    // Creates an issue from a `fields` object and returns its key
+    pub fn create_issue(&self, fields: serde_json::Value) -> Result<String> {
+        let url = format!("{}/rest/api/3/issue", self.base_url);
+        
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&serde_json::json!({ "fields": fields }))
+            .send()
+            .context("Failed to send create request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            anyhow::bail!("Failed to create issue ({}): {}", status, create_error(&response_text));
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct CreatedIssue {
+            key: String,
+        }
+        
+        let created: CreatedIssue = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA create response")?;
+        Ok(created.key)
+    }
+    
+    // Creates an issue from `inherited` plus the given fields, then adds it to `epic`. The parent
+    // is set afterwards so instances that still use an Epic Link field work too. Returns the new
+    // key along with how adding it to the epic went, since the issue exists either way.
+    pub fn create_under_epic(
+        &self,
+        project: &str,
+        issue_type: &str,
+        summary: &str,
+        description: Option<&str>,
+        epic: Option<&str>,
+        inherited: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(String, Result<()>)> {
+        let mut fields = inherited;
+        fields.insert("project".to_string(), serde_json::json!({ "key": project }));
+        fields.insert("issuetype".to_string(), serde_json::json!({ "name": issue_type }));
+        fields.insert("summary".to_string(), serde_json::json!(summary));
+        if let Some(description) = description {
+            fields.insert("description".to_string(), super::adf::from_text(description));
+        }
+        
+        let key = self.create_issue(serde_json::Value::Object(fields))?;
+        let parented = match epic {
+            Some(epic) => self.set_parent(&key, Some(epic))
+                .context(format!("Created {}, but couldn't add it to {}", key, epic)),
+            None => Ok(()),
+        };
+        Ok((key, parented))
+    }
+    
+    // The epic's values for `inherit`, as fields to send with a new child. Only an open sprint
+    // carries over, and fields the epic leaves empty are left out.
+    pub fn inherited_fields(&self, epic_key: &str, inherit: &[InheritField]) -> Result<serde_json::Map<String, serde_json::Value>> {
+        let epic = self.get_issue_raw(epic_key, None)?;
+        let fields = epic.get("fields").cloned().unwrap_or_default();
+        let values = |name: &str| fields.get(name).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+        let ids = |name: &str| -> Vec<serde_json::Value> {
+            values(name).iter()
+                .filter_map(|v| v.get("id"))
+                .map(|id| serde_json::json!({ "id": id }))
+                .collect()
+        };
+        
+        let mut inherited = serde_json::Map::new();
+        for field in inherit {
+            let (name, value) = match field {
+                InheritField::Labels => ("labels".to_string(), values("labels")),
+                InheritField::FixVersions => ("fixVersions".to_string(), ids("fixVersions")),
+                InheritField::Components => ("components".to_string(), ids("components")),
+                InheritField::Sprint => {
+                    let Some(sprint_field) = &self.sprint_field else {
+                        crate::style::warning("not inheriting the sprint: the sprint field isn't detected yet. Run 'qq jira fields --pin' first.");
+                        continue;
+                    };
+                    let open_sprint = values(sprint_field).iter()
+                        .filter(|sprint| sprint.get("state").and_then(|s| s.as_str()) != Some("closed"))
+                        .filter_map(|sprint| sprint.get("id").and_then(|id| id.as_u64()))
+                        .next_back();
+                    if let Some(id) = open_sprint {
+                        inherited.insert(sprint_field.clone(), serde_json::json!(id));
+                    }
+                    continue;
+                }
+            };
+            if !value.is_empty() {
+                inherited.insert(name, serde_json::Value::Array(value));
+            }
+        }
+        Ok(inherited)
+    }
+    
+    // Sets story points on several issues in parallel, with one result per estimate in the same order
+    pub fn set_story_points(&self, estimates: &[(String, f64)]) -> Result<Vec<Result<()>>> {
+        let field = self.story_points_field.clone()
+            .context("Story points field not configured. Run 'qq jira fields --pin' first.")?;
+        Ok(crate::pool::run_parallel(estimates.to_vec(), MAX_CONCURRENT_REQUESTS, |(key, points)| {
+            self.update_issue_fields(&key, serde_json::json!({ field.as_str(): points }))
+        }))
+    }
+    
+    fn edit_issue(&self, issue_key: &str, body: serde_json::Value) -> Result<()> {
+        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+        
+        let response = self.client
+            .put(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&body)
+            .send()
+            .context("Failed to update issue")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            eprintln!("Failed to update issue. Status: {}, Response: {}", status, error_text);
+            anyhow::bail!("Failed to update issue: {}", status);
+        }
+        
+        Ok(())
+    }
+    
+    // Sets a user picker field, wrapping the user in a list for multi-user fields
+    pub fn set_user_field(&self, issue_key: &str, field_id: &str, account_id: &str) -> Result<()> {
+        let multi = self.get_fields()?
+            .into_iter()
+            .find(|f| f.id == field_id)
+            .context(format!("Field {} doesn't exist on this JIRA instance", field_id))?
+            .schema
+            .is_some_and(|s| s.field_type == "array");
+        let user = serde_json::json!({ "accountId": account_id });
+        let value = if multi { serde_json::json!([user]) } else { user };
+        self.update_issue_fields(issue_key, serde_json::json!({ field_id: value }))
+    }
+    
+    // Sets or clears the parent epic. Older company-managed projects may reject the parent
+    // field for epics, so fall back to the pinned epic link field when there is one.
+    pub fn set_parent(&self, issue_key: &str, parent_key: Option<&str>) -> Result<()> {
+        let parent = parent_key.map(|key| serde_json::json!({ "key": key }));
+        
+        match self.update_issue_fields(issue_key, serde_json::json!({ "parent": parent })) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let Some(epic_link_field) = &self.epic_link_field else {
+                    return Err(e);
+                };
+                let mut fields = serde_json::Map::new();
+                fields.insert(epic_link_field.clone(), serde_json::json!(parent_key));
+                self.update_issue_fields(issue_key, serde_json::Value::Object(fields))
+            }
+        }
+    }
+    
+    pub fn rename_issue(&self, issue_key: &str, summary: &str) -> Result<()> {
+        let summary = summary.trim();
+        if summary.is_empty() {
+            anyhow::bail!("Summary cannot be empty");
+        }
+        
+        self.update_issue_fields(issue_key, serde_json::json!({ "summary": summary }))
+    }
+    
+    pub fn transition_to_in_progress(&self, issue_key: &str) -> Result<()> {
+        let transitions = self.get_transitions(issue_key)?;
+        
+        let in_progress_transition = transitions.iter()
+            .find(|t| t.name.to_lowercase().contains("in progress") ||
+                      t.name.to_lowercase().contains("start") ||
+                      t.name.to_lowercase().contains("begin"))
+            .context("No 'In Progress' transition found for this issue")?;
+        
+        let url = format!("{}/rest/api/3/issue/{}/transitions", self.base_url, issue_key);
+        
+        let transition_request = TransitionRequest {
+            transition: TransitionId {
+                id: in_progress_transition.id.clone(),
+            },
+            fields: serde_json::Map::new(),
+            update: serde_json::Map::new(),
+        };
+        
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&transition_request)
+            .send()
+            .context("Failed to transition issue to In Progress")?;
+        
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to transition issue to In Progress: {}", response.status());
+        }
+        
+        Ok(())
+    }
+    
+    pub fn pickup_issue(&self, issue_key: &str) -> Result<()> {
+        // Get current user's account ID
+        let current_user = self.get_current_user()?;
+        
+        // First assign the issue to yourself
+        self.assign_issue(issue_key, Some(&current_user.account_id))?;
+        
+        // Then transition it to In Progress
+        self.transition_to_in_progress(issue_key)?;
+        
+        Ok(())
+    }
+    
+    // Top-ranked unassigned issue that hasn't been started and matches the filter
+    pub fn next_backlog_issue(&self, filter: &GrabNextFilter) -> Result<Option<JiraIssue>> {
+        let mut clauses = vec![
+            "assignee is EMPTY".to_string(),
+            "statusCategory = \"To Do\"".to_string(),
+            "issuetype != Epic".to_string(),
+        ];
+        if let Some(project) = &filter.project {
+            clauses.push(format!("project = \"{}\"", project));
+        }
+        for label in &filter.labels {
+            clauses.push(format!("labels = \"{}\"", label));
+        }
+        if let Some(max_points) = filter.max_points {
+            let field = self.story_points_field.as_deref()
+                .context("Story points field not configured. Run 'qq jira fields --pin' first.")?;
+            let field_id = field.strip_prefix("customfield_").unwrap_or(field);
+            clauses.push(format!("cf[{}] <= {}", field_id, max_points));
+        }
+        let jql = format!("{} ORDER BY Rank ASC", clauses.join(" AND "));
+        
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", jql.as_str()),
+                ("fields", "key,summary,status,assignee,description,parent"),
+                ("maxResults", "1"),
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct SearchResponse {
+            issues: Vec<JiraIssue>,
+        }
+        
+        let search_response: SearchResponse = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA search response")?;
+        
+        let mut issues = search_response.issues;
+        self.fill_extra_fields(&mut issues);
+        Ok(issues.into_iter().next())
+    }
+    
+    // Active and future sprints of the configured board, or of the first scrum board of the
+    // issue's project when no board is configured
+    pub fn get_open_sprints(&self, issue_key: &str) -> Result<Vec<Sprint>> {
+        let board_id = match self.board_id {
+            Some(id) => id,
+            None => {
+                let project = issue_key.split('-').next().unwrap_or(issue_key);
+                self.find_board(project)?
+            }
+        };
+        
+        let response = self.client
+            .get(format!("{}/rest/agile/1.0/board/{}/sprint", self.base_url, board_id))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[("state", "active,future"), ("maxResults", "50")])
+            .send()
+            .context("Failed to fetch sprints")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("Failed to fetch sprints for board {}: {}", board_id, status);
+        }
+        
+        #[derive(Deserialize)]
+        struct SprintPage {
+            values: Vec<Sprint>,
+        }
+        
+        let page: SprintPage = response.json().context("Failed to parse sprints")?;
+        Ok(page.values)
+    }
+    
+    fn find_board(&self, project: &str) -> Result<u64> {
+        let response = self.client
+            .get(format!("{}/rest/agile/1.0/board", self.base_url))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[("projectKeyOrId", project), ("type", "scrum")])
+            .send()
+            .context("Failed to look up boards")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("Failed to look up boards for {}: {}", project, status);
+        }
+        
+        let boards: serde_json::Value = response.json().context("Failed to parse boards")?;
+        boards.get("values")
+            .and_then(|v| v.as_array())
+            .and_then(|v| v.first())
+            .and_then(|b| b.get("id"))
+            .and_then(|id| id.as_u64())
+            .context(format!("No scrum board found for project {}. Set board_id in the config file.", project))
+    }
+    
+    pub fn move_to_sprint(&self, sprint_id: u64, issue_keys: &[&str]) -> Result<()> {
+        let response = self.client
+            .post(format!("{}/rest/agile/1.0/sprint/{}/issue", self.base_url, sprint_id))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&serde_json::json!({ "issues": issue_keys }))
+            .send()
+            .context("Failed to move issue to sprint")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            anyhow::bail!("Failed to move issue to sprint: {} {}", status, error_text);
+        }
+        
+        Ok(())
+    }
+    
+    // Records that `duplicate_key` duplicates `original_key`
+    pub fn link_duplicate(&self, duplicate_key: &str, original_key: &str) -> Result<()> {
+        // The API's inwardIssue takes the outward description: "<inward> duplicates <outward>"
+        let response = self.client
+            .post(format!("{}/rest/api/3/issueLink", self.base_url))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&serde_json::json!({
+                "type": { "name": "Duplicate" },
+                "inwardIssue": { "key": duplicate_key },
+                "outwardIssue": { "key": original_key },
+            }))
+            .send()
+            .context("Failed to link issues")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().unwrap_or_else(|_| "Unable to read response".to_string());
+            anyhow::bail!("Failed to link {} as a duplicate of {}: {} {}", duplicate_key, original_key, status, error_text);
+        }
+        
+        Ok(())
+    }
+    
+    // My worklogs started between the two dates (inclusive), with the issue each was logged on.
+    // The search finds issues I logged on; each issue's worklogs are then fetched and filtered,
+    // since Jira has no endpoint for one user's worklogs.
+    pub fn get_my_worklogs(&self, from: chrono::NaiveDate, to: chrono::NaiveDate) -> Result<Vec<(JiraIssue, Worklog)>> {
+        let me = self.get_current_user()?;
+        let jql = format!(
+            "worklogAuthor = currentUser() AND worklogDate >= \"{}\" AND worklogDate <= \"{}\" ORDER BY key",
+            from, to
+        );
+        let issues = self.search_jql(&jql, 200)?;
+        
+        #[derive(Debug, Deserialize)]
+        struct WorklogResponse {
+            #[serde(default)]
+            worklogs: Vec<Worklog>,
+        }
+        
+        // startedAfter/startedBefore are epoch milliseconds; a day of slack either side covers
+        // time zones, and the exact dates are checked below
+        let after = (from - chrono::Duration::days(1)).and_hms_opt(0, 0, 0).unwrap_or_default().and_utc().timestamp_millis();
+        let before = (to + chrono::Duration::days(2)).and_hms_opt(0, 0, 0).unwrap_or_default().and_utc().timestamp_millis();
+        let fetched = crate::pool::run_parallel(issues, 4, |issue| {
+            let path = format!("/rest/api/3/issue/{}/worklog?startedAfter={}&startedBefore={}", issue.key, after, before);
+            self.get_json::<WorklogResponse>(&path).map(|response| (issue, response.worklogs))
+        });
+        
+        let mut worklogs = Vec::new();
+        for result in fetched {
+            let (issue, issue_worklogs) = result?;
+            for worklog in issue_worklogs {
+                let mine = worklog.author.as_ref().is_some_and(|a| a.account_id == me.account_id);
+                let in_range = worklog.started_at()
+                    .map(|started| started.with_timezone(&chrono::Local).date_naive())
+                    .is_some_and(|date| date >= from && date <= to);
+                if mine && in_range {
+                    worklogs.push((issue.clone(), worklog));
+                }
+            }
+        }
+        Ok(worklogs)
+    }
+    
+    // Every worklog on an issue, oldest first, by anyone
+    pub fn get_worklogs(&self, issue_key: &str) -> Result<Vec<Worklog>> {
+        self.get_all_pages(&format!("/rest/api/3/issue/{}/worklog", issue_key), "worklogs")?
+            .into_iter()
+            .map(|worklog| serde_json::from_value(worklog).context(format!("Failed to parse a worklog of {}", issue_key)))
+            .collect()
+    }
+    
+    pub fn add_worklog(&self, issue_key: &str, started: chrono::DateTime<chrono::Local>, seconds: u64, comment: Option<&str>) -> Result<()> {
+        let url = format!("{}/rest/api/3/issue/{}/worklog", self.base_url, issue_key);
+        let mut body = serde_json::json!({
+            // Jira wants milliseconds and a +hhmm offset without a colon
+            "started": started.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string(),
+            "timeSpentSeconds": seconds,
+        });
+        if let Some(comment) = comment.filter(|c| !c.trim().is_empty()) {
+            body["comment"] = super::adf::from_text(comment);
+        }
+        
+        let response = self.client
+            .post(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&body)
+            .send()
+            .context("Failed to send worklog to JIRA")?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let response_text = response.text().unwrap_or_default();
+            anyhow::bail!("Failed to log work on {}: {} {}", issue_key, status, response_text);
+        }
+        
+        Ok(())
+    }
+    
+    pub fn get_projects(&self) -> Result<Vec<Project>> {
+        let mut projects: Vec<Project> = self.get_json("/rest/api/3/project?expand=lead")?;
+        projects.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(projects)
+    }
+    
+    // Components, versions, and issue types of one project, all returned by the project endpoint
+    pub fn get_project_meta(&self, project_key: &str) -> Result<ProjectMeta> {
+        #[derive(Debug, Deserialize)]
+        struct Named {
+            name: String,
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct ProjectResponse {
+            key: String,
+            name: String,
+            #[serde(default)]
+            components: Vec<Named>,
+            #[serde(default)]
+            versions: Vec<ProjectVersion>,
+            #[serde(default, rename = "issueTypes")]
+            issue_types: Vec<Named>,
+        }
+        
+        let project: ProjectResponse = self.get_json(&format!("/rest/api/3/project/{}", project_key))?;
+        let mut components: Vec<String> = project.components.into_iter().map(|c| c.name).collect();
+        components.sort();
+        let mut issue_types: Vec<String> = project.issue_types.into_iter().map(|t| t.name).collect();
+        issue_types.sort();
+        issue_types.dedup();
+        
+        Ok(ProjectMeta {
+            key: project.key,
+            name: project.name,
+            components,
+            versions: project.versions,
+            issue_types,
+        })
+    }
+    
+    // Issue type names across all projects, without the duplicates each project adds
+    pub fn get_issue_type_names(&self) -> Result<Vec<String>> {
+        let types: Vec<IssueType> = self.get_json("/rest/api/3/issuetype")?;
+        let mut names: Vec<String> = types.into_iter().map(|t| t.name).collect();
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+    
+    // Workflow status names across all projects, without duplicates
+    pub fn get_status_names(&self) -> Result<Vec<String>> {
+        let statuses: Vec<Status> = self.get_json("/rest/api/3/status")?;
+        let mut names: Vec<String> = statuses.into_iter().map(|s| s.name).collect();
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+    
+    fn get_json<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let response = self.client
+            .get(format!("{}{}", self.base_url, path))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .send()
+            .context(format!("Failed to send request to JIRA ({})", path))?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            anyhow::bail!("JIRA API error: {} {}", status, response_text);
+        }
+        
+        serde_json::from_str(&response_text)
+            .context(format!("Failed to parse JIRA response ({})", path))
+    }
+
+    // Every item of an endpoint paged with startAt/total, e.g. "comments" from an issue's comments
+    fn get_all_pages(&self, path: &str, items: &str) -> Result<Vec<serde_json::Value>> {
+        let mut all = Vec::new();
+        loop {
+            let separator = if path.contains('?') { '&' } else { '?' };
+            let mut page: serde_json::Value = self.get_json(&format!("{}{}startAt={}&maxResults=100", path, separator, all.len()))?;
+            let page_items = page.get_mut(items)
+                .and_then(|i| i.as_array_mut())
+                .map(std::mem::take)
+                .unwrap_or_default();
+            let total = page.get("total").and_then(|t| t.as_u64()).unwrap_or(0) as usize;
+            let is_last = page.get("isLast").and_then(|l| l.as_bool()).unwrap_or(false);
+
+            let fetched = page_items.len();
+            all.extend(page_items);
+            if fetched == 0 || is_last || all.len() >= total {
+                return Ok(all);
+            }
+        }
+    }
+
+    // Every comment on an issue as raw JSON, oldest first
+    pub fn get_all_comments_raw(&self, issue_key: &str) -> Result<Vec<serde_json::Value>> {
+        self.get_all_pages(&format!("/rest/api/3/issue/{}/comment?orderBy=created", issue_key), "comments")
+    }
+
+    // Every changelog entry of an issue, oldest first. Searches expanding the changelog only
+    // include the first 100.
+    pub fn get_changelog(&self, issue_key: &str) -> Result<Vec<serde_json::Value>> {
+        self.get_all_pages(&format!("/rest/api/3/issue/{}/changelog", issue_key), "values")
+    }
+
+    pub fn get_epic_children(&self, epic_key: &str) -> Result<Vec<JiraIssue>> {
+        // Try modern approach first (parent field)
+        let modern_jql = format!("parent={}", epic_key);
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", &modern_jql),
+                ("fields", &"key,summary,status,assignee,description,subtasks,issuetype,updated,created".to_string())
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if status.is_success() {
+            #[derive(Debug, Deserialize)]
+            struct SearchResponse {
+                issues: Vec<JiraIssue>,
+            }
+            
+            let search_response: SearchResponse = serde_json::from_str(&response_text)
+                .context("Failed to parse JIRA search response")?;
+            
+            // If we got results with modern approach, return them
+            if !search_response.issues.is_empty() {
+                let mut issues = search_response.issues;
+                self.fill_extra_fields(&mut issues);
+                return Ok(issues);
+            }
+        }
+        
+        // Fallback to legacy Epic Link approach
+        let legacy_jql = format!("\"Epic Link\"={}", epic_key);
+
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", &legacy_jql),
+                ("fields", &"key,summary,status,assignee,description,subtasks,issuetype,updated,created".to_string())
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct SearchResponse {
+            issues: Vec<JiraIssue>,
+        }
+        
+        let search_response: SearchResponse = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA search response")?;
+        
+        let mut issues = search_response.issues;
+        self.fill_extra_fields(&mut issues);
+        
+        Ok(issues)
+    }
+    
+    // Children of several epics fetched in parallel, in the same order as the keys. Each epic
+    // gets its own result so one unreadable epic doesn't fail the whole batch.
+    pub fn get_children_of_epics(&self, epic_keys: &[String]) -> Vec<Result<Vec<JiraIssue>>> {
+        crate::pool::run_parallel(epic_keys.to_vec(), MAX_CONCURRENT_REQUESTS, |key| {
+            self.get_epic_children(&key)
+        })
+    }
+    
+    // Full issues for a story's subtasks, in the order they were created
+    pub fn get_subtasks(&self, parent_key: &str) -> Result<Vec<JiraIssue>> {
+        self.search_jql(&format!("parent = {} ORDER BY created ASC", parent_key), 100)
+    }
+    
+    // Fetches priority and story points for the given issues in parallel batches.
+    // Failures are reported but not fatal since these fields are supplementary.
+    pub fn fill_extra_fields(&self, issues: &mut [JiraIssue]) {
+        let keys: Vec<String> = issues.iter().map(|i| i.key.clone()).collect();
+        let batches: Vec<Vec<String>> = keys.chunks(ISSUE_BATCH_SIZE).map(|c| c.to_vec()).collect();
+        
+        let results = crate::pool::run_parallel(batches, MAX_CONCURRENT_REQUESTS, |batch| {
+            self.get_extra_fields(&batch)
+        });
+        
+        let mut extras = HashMap::new();
+        for result in results {
+            match result {
+                Ok(batch_extras) => extras.extend(batch_extras),
+                Err(e) => crate::style::warning(format!("failed to fetch extra issue fields: {}", e)),
+            }
+        }
+        
+        for issue in issues.iter_mut() {
+            if let Some((priority, story_points)) = extras.remove(&issue.key) {
+                issue.fields.priority = priority;
+                issue.fields.story_points = story_points;
+            }
+        }
+    }
+    
+    fn get_extra_fields(&self, keys: &[String]) -> Result<ExtraFields> {
+        let jql = format!("key in ({})", keys.join(","));
+        let fields = match &self.story_points_field {
+            Some(field) => format!("priority,{}", field),
+            None => "priority".to_string(),
+        };
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", jql.as_str()),
+                ("fields", fields.as_str()),
+                ("maxResults", &ISSUE_BATCH_SIZE.to_string()),
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        let json: serde_json::Value = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA search response")?;
+        
+        let mut extras = HashMap::new();
+        for issue in json.get("issues").and_then(|i| i.as_array()).into_iter().flatten() {
+            let Some(key) = issue.get("key").and_then(|k| k.as_str()) else {
+                continue;
+            };
+            let fields = issue.get("fields");
+            
+            let priority = fields
+                .and_then(|f| f.get("priority"))
+                .and_then(|p| serde_json::from_value::<Priority>(p.clone()).ok());
+            let story_points = self.story_points_field.as_ref()
+                .and_then(|field| fields.and_then(|f| f.get(field)))
+                .and_then(|v| v.as_f64());
+            
+            extras.insert(key.to_string(), (priority, story_points));
+        }
+        
+        Ok(extras)
+    }
+    
+    pub fn get_my_issues(&self) -> Result<Vec<JiraIssue>> {
+        // Use JQL to find all issues assigned to current user, excluding Done status
+        let jql = "assignee = currentUser() AND status != Done ORDER BY updated DESC";
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        let mut fields = "key,summary,status,assignee,priority,issuetype,description,parent,duedate,issuelinks,updated,created".to_string();
+        if let Some(flagged) = &self.flagged_field {
+            fields.push(',');
+            fields.push_str(flagged);
+        }
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", jql),
+                ("expand", "parent"),
+                ("fields", fields.as_str())
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("JIRA API error: {}", status);
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct SearchResponse {
+            issues: Vec<JiraIssue>,
+        }
+        
+        let search_response: SearchResponse = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA search response")?;
+        let mut issues = search_response.issues;
+        
+        // The flag is a checkbox holding "Impediment" when set
+        if let Some(flagged) = &self.flagged_field {
+            let raw: serde_json::Value = serde_json::from_str(&response_text)
+                .context("Failed to parse JIRA search response")?;
+            let raw_issues = raw.get("issues").and_then(|i| i.as_array()).into_iter().flatten();
+            for (issue, raw_issue) in issues.iter_mut().zip(raw_issues) {
+                issue.fields.flagged = raw_issue.get("fields")
+                    .and_then(|f| f.get(flagged))
+                    .and_then(|v| v.as_array())
+                    .is_some_and(|values| !values.is_empty());
+            }
+        }
+        
+        Ok(issues)
+    }
+    
+    // What `qq jira mine` loads after the search: the full parent of each issue, and the
+    // transitions each one offers so `t` opens without a round trip. Both lists are fanned
+    // out at the same time; lookups that fail are left out.
+    pub fn mine_details(&self, issues: &[JiraIssue]) -> MineDetails {
+        let mut parent_keys: Vec<String> = issues.iter()
+            .filter_map(|issue| issue.fields.parent.as_ref().map(|parent| parent.key.clone()))
+            .collect();
+        parent_keys.sort();
+        parent_keys.dedup();
+        let issue_keys: Vec<String> = issues.iter().map(|issue| issue.key.clone()).collect();
+        
+        std::thread::scope(|scope| {
+            let parents = scope.spawn(|| {
+                crate::pool::run_parallel(parent_keys, MAX_CONCURRENT_REQUESTS, |key| self.get_issue(&key).ok())
+            });
+            let transitions = crate::pool::run_parallel(issue_keys, MAX_CONCURRENT_REQUESTS, |key| {
+                self.transition_options(&key).ok().map(|options| (key, options))
+            });
+            let parents = parents.join().unwrap_or_default();
+            (
+                parents.into_iter().flatten().map(|parent| (parent.key.clone(), parent)).collect(),
+                transitions.into_iter().flatten().collect(),
+            )
+        })
+    }
+    
+    pub fn get_assignable_users(&self, issue_key: &str) -> Result<Vec<User>> {
+        let url = format!("{}/rest/api/3/user/assignable/search", self.base_url);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("issueKey", issue_key),
+                ("maxResults", "50") // Reasonable limit for UI display
+            ])
+            .send()
+            .context("Failed to get assignable users")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("Failed to get assignable users: {}", status);
+        }
+        
+        let users: Vec<User> = serde_json::from_str(&response_text)
+            .context("Failed to parse assignable users response")?;
+        
+        Ok(users)
+    }
+    
+    // People whose name or email matches; app and customer accounts are left out
+    pub fn search_users(&self, query: &str) -> Result<Vec<UserProfile>> {
+        let url = format!("{}/rest/api/3/user/search", self.base_url);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[("query", query), ("maxResults", "50")])
+            .send()
+            .context("Failed to search users")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("Failed to search users: {}", status);
+        }
+        
+        let users: Vec<UserProfile> = serde_json::from_str(&response_text)
+            .context("Failed to parse user search response")?;
+        
+        Ok(users.into_iter()
+            .filter(|u| u.account_type.as_deref().is_none_or(|t| t == "atlassian"))
+            .collect())
+    }
+    
+    pub fn profile_url(&self, account_id: &str) -> String {
+        format!("{}/jira/people/{}", self.base_url, account_id)
+    }
+    
+    // Cheap up-front check that the API token still works, so an expired or revoked token is
+    // reported clearly instead of as a 401 from whichever request happens to run first.
+    // Returns false only when Jira rejects the credentials; network errors are passed through.
+    pub fn check_auth(&self) -> Result<bool> {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+        
+        let mut hasher = DefaultHasher::new();
+        (&self.base_url, &self.auth_header).hash(&mut hasher);
+        let probe_key = format!("{:x}", hasher.finish());
+        
+        let probe_path = dirs::cache_dir()
+            .context("Failed to determine cache directory")?
+            .join("qq")
+            .join("auth_probes.json");
+        let probes: HashMap<String, chrono::DateTime<chrono::Utc>> = crate::store::read_json(&probe_path);
+        let fresh_after = chrono::Utc::now() - chrono::Duration::minutes(AUTH_PROBE_TTL_MINUTES);
+        if probes.get(&probe_key).is_some_and(|at| *at > fresh_after) {
+            return Ok(true);
+        }
+        
+        let response = self.client
+            .get(format!("{}/rest/api/3/myself", self.base_url))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .send()
+            .context("Failed to reach JIRA")?;
+        
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Ok(false);
+        }
+        if status.is_success() {
+            let _ = crate::store::edit_json(&probe_path, |probes: &mut HashMap<String, chrono::DateTime<chrono::Utc>>| {
+                probes.retain(|_, at| *at > fresh_after);
+                probes.insert(probe_key, chrono::Utc::now());
+            });
+        }
+        Ok(true)
+    }
+    
+    pub fn get_current_user(&self) -> Result<User> {
+        let url = format!("{}/rest/api/3/myself", self.base_url);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .send()
+            .context("Failed to get current user info")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("Failed to get current user: {}", status);
+        }
+        
+        let user: User = serde_json::from_str(&response_text)
+            .context("Failed to parse current user response")?;
+        
+        Ok(user)
+    }
+    
+    pub fn get_fields(&self) -> Result<Vec<JiraField>> {
+        let url = format!("{}/rest/api/3/field", self.base_url);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .send()
+            .context("Failed to get fields")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            eprintln!("JIRA API error response: {}", response_text);
+            anyhow::bail!("Failed to get fields: {}", status);
+        }
+        
+        let mut fields: Vec<JiraField> = serde_json::from_str(&response_text)
+            .context("Failed to parse fields response")?;
+        
+        fields.sort_by_key(|f| f.name.to_lowercase());
+        
+        Ok(fields)
+    }
+    
+    pub fn get_all_epics(&self) -> Result<Vec<JiraIssue>> {
+        // Try different epic type names. JQL matches type names case-insensitively, so
+        // aliases differing only in case would repeat the same query; probe each type once.
+        let mut epic_types: Vec<&str> = Vec::new();
+        for alias in ["Epic", "epic", "Epic Story", "Epic Feature"] {
+            if !epic_types.iter().any(|t| t.eq_ignore_ascii_case(alias)) {
+                epic_types.push(alias);
+            }
+        }
+        
+        // Probed together so the list takes one query's latency rather than one per type
+        let probes = crate::pool::run_parallel(epic_types, MAX_CONCURRENT_REQUESTS, |epic_type| {
+            (epic_type, self.probe_epics(epic_type))
+        });
+        
+        let mut all_epics = Vec::new();
+        let mut last_error = None;
+        for (epic_type, probe) in probes {
+            match probe {
+                Ok(EpicProbe::Found(epics)) => all_epics.extend(epics),
+                Ok(EpicProbe::NoSuchType) => {}
+                Ok(EpicProbe::Forbidden) => {
+                    last_error = Some(format!("Permission denied or '{}' issue type doesn't exist", epic_type));
+                }
+                Err(e) => {
+                    eprintln!("JIRA API error for epic type '{}': {}", epic_type, e);
+                    last_error = Some(format!("Failed to get epics: {}", e));
+                }
+            }
+        }
+        
+        if all_epics.is_empty() {
+            if let Some(error) = last_error {
+                anyhow::bail!("Could not find any active epics. {}", error);
+            } else {
+                anyhow::bail!("No active epics found. Your JIRA instance might use a different issue type name for epics or all epics are Done.");
+            }
+        }
+        
+        // Remove duplicates (in case multiple epic types returned the same issues)
+        let mut unique_epics = Vec::new();
+        let mut seen_keys = std::collections::HashSet::new();
+        for epic in all_epics {
+            if seen_keys.insert(epic.key.clone()) {
+                unique_epics.push(epic);
+            }
+        }
+        
+        Ok(unique_epics)
+    }
+    
+    fn probe_epics(&self, epic_type: &str) -> Result<EpicProbe> {
+        let jql = format!("issuetype = \"{}\" AND status != Done ORDER BY updated DESC", epic_type);
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", jql.as_str()),
+                ("fields", "key,summary,status,assignee,updated"),
+                ("maxResults", "100")
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if status.is_success() {
+            #[derive(Debug, Deserialize)]
+            struct SearchResponse {
+                issues: Vec<JiraIssue>,
+            }
+            
+            let issues = serde_json::from_str::<SearchResponse>(&response_text)
+                .map(|r| r.issues)
+                .unwrap_or_default();
+            return Ok(EpicProbe::Found(issues));
+        }
+        
+        if response_text.contains("does not exist or you do not have permission") {
+            Ok(EpicProbe::Forbidden)
+        } else if response_text.contains("does not exist for the field 'issuetype'") {
+            Ok(EpicProbe::NoSuchType)
+        } else {
+            anyhow::bail!("{} {}", status, response_text)
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// Jira Cloud REST API: `client` for reading and editing issues, `search` for JQL queries,
+// `models` for the request and response types, and `adf` for the document format
+// descriptions and comments are written in
+pub mod adf;
+mod client;
+mod models;
+mod search;
+
+pub use client::*;
+pub use models::*;
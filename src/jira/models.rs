@@ -0,0 +1,679 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Request and response bodies of the Jira REST API, shared by the client and search calls
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JiraIssue {
+    // Numeric ID, needed by endpoints that don't accept keys
+    #[serde(default)]
+    pub id: String,
+    pub key: String,
+    pub fields: IssueFields,
+    // When this issue was resolved as another issue's parent, the field that linked them
+    #[serde(skip)]
+    pub linked_via: Option<String>,
+    // Fields that were missing or malformed and fell back to defaults
+    #[serde(skip)]
+    pub problems: Vec<String>,
+}
+
+// Some instances omit fields or return nulls for certain issue types. Rather than failing
+// the whole list over one issue, each field is read on its own and problems are recorded.
+impl<'de> Deserialize<'de> for JiraIssue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let key = value.get("key")
+            .and_then(|k| k.as_str())
+            .ok_or_else(|| serde::de::Error::missing_field("key"))?
+            .to_string();
+        let id = value.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+        
+        let fields = value.get("fields").unwrap_or(&serde_json::Value::Null);
+        let mut problems = Vec::new();
+        let fields = IssueFields {
+            summary: lenient_field(fields, "summary", true, &mut problems),
+            description: lenient_field(fields, "description", false, &mut problems),
+            status: lenient_field(fields, "status", true, &mut problems),
+            assignee: lenient_field(fields, "assignee", false, &mut problems),
+            parent: lenient_field(fields, "parent", false, &mut problems),
+            priority: lenient_field(fields, "priority", false, &mut problems),
+            issuetype: lenient_field(fields, "issuetype", false, &mut problems),
+            duedate: lenient_field(fields, "duedate", false, &mut problems),
+            updated: lenient_field(fields, "updated", false, &mut problems),
+            created: lenient_field(fields, "created", false, &mut problems),
+            issuelinks: lenient_field(fields, "issuelinks", false, &mut problems),
+            subtasks: lenient_field(fields, "subtasks", false, &mut problems),
+            timetracking: lenient_field(fields, "timetracking", false, &mut problems),
+            story_points: None,
+            flagged: false,
+        };
+        
+        Ok(JiraIssue { id, key, fields, linked_via: None, problems })
+    }
+}
+
+// Missing and null values fall back to the default, noting it only for `required` fields
+fn lenient_field<T>(fields: &serde_json::Value, name: &str, required: bool, problems: &mut Vec<String>) -> T
+where
+    T: serde::de::DeserializeOwned + Default,
+{
+    match fields.get(name) {
+        None | Some(serde_json::Value::Null) => {
+            if required {
+                problems.push(format!("{} is missing", name));
+            }
+            T::default()
+        }
+        Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+            problems.push(format!("{} could not be read: {}", name, e));
+            T::default()
+        }),
+    }
+}
+
+#[derive(Debug)]
+pub struct ParentCandidate {
+    pub field_id: String,
+    pub issue: JiraIssue,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueFields {
+    pub summary: String,
+    pub description: Option<serde_json::Value>,
+    pub status: Status,
+    pub assignee: Option<User>,
+    pub parent: Option<Box<JiraIssue>>,
+    pub priority: Option<Priority>,
+    pub issuetype: Option<IssueType>,
+    pub duedate: Option<String>,
+    pub updated: Option<String>,
+    pub created: Option<String>,
+    pub issuelinks: Vec<IssueLink>,
+    pub subtasks: Vec<LinkedIssue>,
+    pub timetracking: TimeTracking,
+    // Story points live in an instance-specific custom field, so they're filled in separately.
+    // Still serialized so --json output includes them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story_points: Option<f64>,
+    // Same for the Flagged checkbox; only `get_my_issues` fills it in
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub flagged: bool,
+}
+
+impl IssueFields {
+    pub fn is_done(&self) -> bool {
+        self.status.category.as_ref().is_some_and(|c| c.key == "done")
+    }
+    
+    pub fn due_date(&self) -> Option<chrono::NaiveDate> {
+        self.duedate.as_deref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+    }
+    
+    pub fn updated_at(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        self.updated.as_deref().and_then(parse_jira_datetime)
+    }
+    
+    pub fn created_at(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        self.created.as_deref().and_then(parse_jira_datetime)
+    }
+    
+    // Blocked either by workflow status or by an unresolved "is blocked by" link
+    pub fn is_blocked(&self) -> bool {
+        self.status.name.to_lowercase().contains("block")
+            || self.issuelinks.iter().any(|link| {
+                link.link_type.inward.to_lowercase().contains("blocked by")
+                    && link.inward_issue.as_ref().is_some_and(|issue| !issue.fields.is_done())
+            })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLink {
+    #[serde(rename = "type")]
+    pub link_type: IssueLinkType,
+    #[serde(default, rename = "inwardIssue")]
+    pub inward_issue: Option<LinkedIssue>,
+    #[serde(default, rename = "outwardIssue")]
+    pub outward_issue: Option<LinkedIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLinkType {
+    pub name: String,
+    pub inward: String,
+    pub outward: String,
+}
+
+// Linked issues only come back with a handful of fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedIssue {
+    pub key: String,
+    pub fields: LinkedIssueFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedIssueFields {
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub status: Status,
+}
+
+impl LinkedIssueFields {
+    pub fn is_done(&self) -> bool {
+        self.status.category.as_ref().is_some_and(|c| c.key == "done")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct User {
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(rename = "emailAddress")]
+    pub email_address: Option<String>,
+}
+
+// A person from the user directory, with the profile details `qq who` shows. Email is
+// only present when the person's privacy settings allow it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserProfile {
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(default, rename = "emailAddress")]
+    pub email_address: Option<String>,
+    #[serde(default, rename = "timeZone")]
+    pub time_zone: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+    // "atlassian" for people; apps and customers have other types
+    #[serde(default, rename = "accountType")]
+    pub account_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Sprint {
+    pub id: u64,
+    pub name: String,
+    pub state: String,
+    #[serde(default, rename = "endDate")]
+    pub end_date: Option<String>,
+}
+
+impl Sprint {
+    pub fn label(&self) -> String {
+        match self.end_date.as_deref().and_then(parse_jira_datetime) {
+            Some(end) => format!("{} ({}, ends {})", self.name, self.state, end.format("%b %-d")),
+            None => format!("{} ({})", self.name, self.state),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Project {
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub lead: Option<User>,
+}
+
+// Multi-value fields edited in bulk from the list views and `qq jira label`/`component`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListField {
+    Label,
+    Component,
+}
+
+impl ListField {
+    pub fn name(self) -> &'static str {
+        match self {
+            ListField::Label => "label",
+            ListField::Component => "component",
+        }
+    }
+
+    pub(super) fn operation(self, add: bool, value: &str) -> serde_json::Value {
+        let verb = if add { "add" } else { "remove" };
+        match self {
+            ListField::Label => serde_json::json!({ "labels": [{ verb: value }] }),
+            ListField::Component => serde_json::json!({ "components": [{ verb: { "name": value } }] }),
+        }
+    }
+
+    // Jira rejects labels with spaces with a vague error, so catch it up front
+    pub fn validate(self, value: &str) -> Result<()> {
+        if value.trim().is_empty() {
+            anyhow::bail!("The {} name is empty", self.name());
+        }
+        if self == ListField::Label && value.contains(char::is_whitespace) {
+            anyhow::bail!("Labels can't contain spaces");
+        }
+        Ok(())
+    }
+}
+
+// Choices that are valid within one project, as cached by `meta::MetaCache`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMeta {
+    pub key: String,
+    pub name: String,
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(default)]
+    pub versions: Vec<ProjectVersion>,
+    #[serde(default)]
+    pub issue_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectVersion {
+    pub name: String,
+    #[serde(default)]
+    pub released: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Status {
+    pub name: String,
+    #[serde(default, rename = "statusCategory")]
+    pub category: Option<StatusCategory>,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Self { name: "Unknown".to_string(), category: None }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatusCategory {
+    pub key: String,
+}
+
+// JIRA timestamps use a "+0000" offset that RFC 3339 parsing rejects, while agile
+// endpoints return proper RFC 3339, so accept both.
+pub fn parse_jira_datetime(value: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .or_else(|_| chrono::DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Priority {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IssueType {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JiraField {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub custom: bool,
+    #[serde(default)]
+    pub schema: Option<FieldSchema>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FieldSchema {
+    #[serde(rename = "type")]
+    pub field_type: String,
+    #[serde(default)]
+    pub items: Option<String>,
+    #[serde(default)]
+    pub custom: Option<String>,
+}
+
+impl JiraField {
+    pub fn type_description(&self) -> String {
+        match &self.schema {
+            Some(schema) => match &schema.items {
+                Some(items) => format!("{}<{}>", schema.field_type, items),
+                None => schema.field_type.clone(),
+            },
+            None => "—".to_string(),
+        }
+    }
+    
+    // User pickers, single or multi
+    fn holds_users(&self) -> bool {
+        self.schema.as_ref().is_some_and(|s| s.field_type == "user" || s.items.as_deref() == Some("user"))
+    }
+    
+    fn custom_type(&self) -> &str {
+        self.schema.as_ref()
+            .and_then(|s| s.custom.as_deref())
+            .unwrap_or("")
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DetectedFields {
+    pub story_points: Option<String>,
+    pub epic_link: Option<String>,
+    pub sprint: Option<String>,
+    pub reviewer: Option<String>,
+    pub flagged: Option<String>,
+}
+
+pub fn detect_fields(fields: &[JiraField]) -> DetectedFields {
+    let mut detected = DetectedFields::default();
+    
+    for field in fields.iter().filter(|f| f.custom) {
+        let custom_type = field.custom_type();
+        let name = field.name.to_lowercase();
+        
+        if custom_type == "com.pyxis.greenhopper.jira:gh-epic-link" {
+            detected.epic_link.get_or_insert(field.id.clone());
+        } else if custom_type == "com.pyxis.greenhopper.jira:gh-sprint" {
+            detected.sprint.get_or_insert(field.id.clone());
+        } else if custom_type == "com.pyxis.greenhopper.jira:jsw-story-points" {
+            // Team-managed "Story point estimate" field takes precedence when present
+            detected.story_points = Some(field.id.clone());
+        } else if name.contains("story point") && detected.story_points.is_none() {
+            detected.story_points = Some(field.id.clone());
+        } else if name.contains("reviewer") && field.holds_users() && detected.reviewer.is_none() {
+            detected.reviewer = Some(field.id.clone());
+        } else if name == "flagged" && detected.flagged.is_none() {
+            detected.flagged = Some(field.id.clone());
+        }
+    }
+    
+    detected
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Attachment {
+    pub filename: String,
+    // Download URL; opens in the browser for anyone signed in to JIRA
+    pub content: String,
+    #[serde(default)]
+    pub size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Comment {
+    pub id: String,
+    #[serde(default)]
+    pub author: Option<User>,
+    #[serde(default)]
+    pub body: Option<serde_json::Value>,
+    #[serde(default)]
+    pub created: String,
+    #[serde(default)]
+    pub updated: String,
+}
+
+// Jira's own rendering of each amount, like "1d 2h"; unset when nothing was estimated or logged
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeTracking {
+    #[serde(default, rename = "originalEstimate")]
+    pub original_estimate: Option<String>,
+    #[serde(default, rename = "remainingEstimate")]
+    pub remaining_estimate: Option<String>,
+    #[serde(default, rename = "timeSpent")]
+    pub time_spent: Option<String>,
+}
+
+// One worklog entry on an issue
+#[derive(Debug, Deserialize, Clone)]
+pub struct Worklog {
+    pub author: Option<User>,
+    pub started: String,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+    // ADF document, when the worklog was logged with a description
+    #[serde(default)]
+    pub comment: Option<serde_json::Value>,
+}
+
+impl Worklog {
+    pub fn started_at(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        parse_jira_datetime(&self.started)
+    }
+}
+
+// Pull request as reported by the development panel (GitHub, Bitbucket, GitLab integrations)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PullRequest {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub status: String,
+    #[serde(default)]
+    pub author: Option<PullRequestAuthor>,
+    #[serde(default)]
+    pub source: Option<PullRequestRef>,
+    #[serde(default)]
+    pub reviewers: Vec<PullRequestReviewer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PullRequestReviewer {
+    pub name: String,
+    #[serde(default)]
+    pub approved: bool,
+    // APPROVED, NEEDSWORK, or UNAPPROVED when the integration reports it
+    #[serde(default, rename = "approvalStatus")]
+    pub approval_status: Option<String>,
+}
+
+// Build counts across every CI run the development panel knows about for an issue
+#[derive(Debug, Default, Deserialize, Clone)]
+pub struct BuildSummary {
+    #[serde(default, rename = "failedBuildCount")]
+    pub failed: u32,
+    #[serde(default, rename = "successfulBuildCount")]
+    pub successful: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PullRequestAuthor {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PullRequestRef {
+    pub branch: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct CommentRequest {
+    pub(super) body: CommentBody,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct CommentBody {
+    #[serde(rename = "type")]
+    pub(super) doc_type: String,
+    pub(super) version: i32,
+    pub(super) content: Vec<CommentContent>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct CommentContent {
+    #[serde(rename = "type")]
+    pub(super) content_type: String,
+    pub(super) content: Vec<CommentText>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct CommentText {
+    #[serde(rename = "type")]
+    pub(super) text_type: String,
+    pub(super) text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct TransitionRequest {
+    pub(super) transition: TransitionId,
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub(super) fields: serde_json::Map<String, serde_json::Value>,
+    // Edits applied with the transition, e.g. `{"comment": [{"add": {"body": ...}}]}`
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub(super) update: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct TransitionId {
+    pub(super) id: String,
+}
+
+// A transition available on an issue, for pickers; `id` can be passed to `transition_to`
+#[derive(Debug, Clone)]
+pub struct TransitionOption {
+    pub id: String,
+    pub name: String,
+    // Name of the status it leads to
+    pub status: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct TransitionsResponse {
+    pub(super) transitions: Vec<Transition>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct Transition {
+    pub(super) id: String,
+    pub(super) name: String,
+    #[serde(default)]
+    pub(super) to: Option<TransitionTarget>,
+    // Only present when requested with expand=transitions.fields
+    #[serde(default)]
+    pub(super) fields: HashMap<String, TransitionField>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct TransitionTarget {
+    pub(super) id: String,
+    pub(super) name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct TransitionField {
+    #[serde(default)]
+    pub(super) required: bool,
+    #[serde(default, rename = "hasDefaultValue")]
+    pub(super) has_default_value: bool,
+    #[serde(default)]
+    pub(super) name: String,
+}
+
+// Responses shaped like Jira Cloud's, unused fields and all, to catch model changes that would stop
+// real payloads from parsing
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issue_parses_every_field() {
+        let issue: JiraIssue = serde_json::from_str(include_str!("fixtures/issue.json")).unwrap();
+        assert_eq!(issue.key, "PAY-214");
+        assert_eq!(issue.id, "10482");
+        assert!(issue.problems.is_empty(), "{:?}", issue.problems);
+
+        let fields = &issue.fields;
+        assert_eq!(fields.status.name, "In Progress");
+        assert!(!fields.is_done());
+        assert_eq!(fields.assignee.as_ref().unwrap().display_name, "Sam Rivera");
+        assert_eq!(fields.assignee.as_ref().unwrap().email_address, None);
+        assert_eq!(fields.parent.as_ref().unwrap().key, "PAY-200");
+        assert_eq!(fields.priority.as_ref().unwrap().name, "Medium");
+        assert_eq!(fields.issuetype.as_ref().unwrap().name, "Story");
+        assert_eq!(fields.due_date(), chrono::NaiveDate::from_ymd_opt(2026, 11, 2));
+        assert!(fields.updated_at().is_some());
+        assert!(fields.created_at().is_some());
+        assert_eq!(fields.subtasks[0].key, "PAY-215");
+        assert!(fields.description.is_some());
+    }
+
+    #[test]
+    fn resolved_blocker_does_not_block() {
+        let issue: JiraIssue = serde_json::from_str(include_str!("fixtures/issue.json")).unwrap();
+        let links = &issue.fields.issuelinks;
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].inward_issue.as_ref().unwrap().key, "PAY-209");
+        assert!(links[0].inward_issue.as_ref().unwrap().fields.is_done());
+        assert_eq!(links[1].outward_issue.as_ref().unwrap().key, "OPS-88");
+        assert!(!issue.fields.is_blocked());
+    }
+
+    #[test]
+    fn partial_issue_falls_back_and_records_problems() {
+        let issue: JiraIssue = serde_json::from_str(include_str!("fixtures/issue_partial.json")).unwrap();
+        assert_eq!(issue.key, "PAY-230");
+        assert_eq!(issue.fields.summary, "");
+        assert_eq!(issue.fields.status.name, "Open");
+        assert!(issue.fields.status.category.is_none());
+        assert!(issue.fields.priority.is_none());
+        assert!(issue.fields.subtasks.is_empty());
+        assert_eq!(issue.problems.len(), 2, "{:?}", issue.problems);
+        assert!(issue.problems[0].starts_with("summary"));
+        assert!(issue.problems[1].starts_with("priority"));
+    }
+
+    #[test]
+    fn issue_without_key_is_rejected() {
+        assert!(serde_json::from_str::<JiraIssue>(r#"{"id": "1", "fields": {}}"#).is_err());
+    }
+
+    #[test]
+    fn transitions_parse_with_required_fields() {
+        let response: TransitionsResponse = serde_json::from_str(include_str!("fixtures/transitions.json")).unwrap();
+        let [start, resolve] = response.transitions.as_slice() else {
+            panic!("expected two transitions");
+        };
+        assert_eq!(start.id, "21");
+        assert!(start.fields.is_empty());
+        assert_eq!(resolve.to.as_ref().unwrap().name, "Done");
+        assert!(resolve.fields["resolution"].required);
+        assert!(!resolve.fields["resolution"].has_default_value);
+        assert!(resolve.fields["fixVersions"].has_default_value);
+        assert!(!resolve.fields["comment"].required);
+    }
+
+    #[test]
+    fn comments_parse_without_author_or_body() {
+        let response: serde_json::Value = serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+        let comments: Vec<Comment> = serde_json::from_value(response["comments"].clone()).unwrap();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].author.as_ref().unwrap().email_address.as_deref(), Some("sam@example.com"));
+        assert!(comments[0].body.is_some());
+        assert!(comments[1].author.is_none());
+        assert!(comments[1].body.is_none());
+        assert_eq!(comments[1].updated, "");
+    }
+
+    #[test]
+    fn transition_request_omits_empty_maps() {
+        let request = TransitionRequest {
+            transition: TransitionId { id: "31".to_string() },
+            fields: serde_json::Map::new(),
+            update: serde_json::Map::new(),
+        };
+        assert_eq!(serde_json::to_value(request).unwrap(), serde_json::json!({ "transition": { "id": "31" } }));
+    }
+
+    #[test]
+    fn jira_and_rfc3339_timestamps_both_parse() {
+        let jira = parse_jira_datetime("2026-10-14T16:05:31.118+0000").unwrap();
+        let agile = parse_jira_datetime("2026-10-14T16:05:31.118Z").unwrap();
+        assert_eq!(jira, agile);
+        assert!(parse_jira_datetime("2026-10-14").is_none());
+    }
+}
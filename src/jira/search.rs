@@ -0,0 +1,242 @@
+use anyhow::{Context, Result};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::Deserialize;
+
+use super::client::JiraClient;
+use super::models::JiraIssue;
+
+// JQL searches: paged issue lists, raw JSON for custom fields, summary matches, and counts
+
+// Jira explains rejected JQL in `errorMessages`; fall back to the raw body otherwise
+fn jql_error(response_text: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(response_text)
+        .ok()
+        .and_then(|body| {
+            let messages: Vec<String> = body.get("errorMessages")?
+                .as_array()?
+                .iter()
+                .filter_map(|m| m.as_str().map(|m| m.to_string()))
+                .collect();
+            (!messages.is_empty()).then(|| messages.join(" "))
+        })
+        .unwrap_or_else(|| response_text.to_string())
+}
+
+impl JiraClient {
+    // Runs a JQL search following pagination, returning raw issue JSON so callers can
+    // read instance-specific custom fields.
+    pub fn search_raw(&self, jql: &str, fields: &str, expand: Option<&str>) -> Result<Vec<serde_json::Value>> {
+        let mut issues = Vec::new();
+        self.search_raw_pages(jql, fields, expand, |mut page| {
+            issues.append(&mut page);
+            Ok(true)
+        })?;
+        Ok(issues)
+    }
+    
+    // Like `search_raw`, but hands each page to `on_page` as it arrives so large result sets
+    // never have to fit in memory. Stops early when `on_page` returns false.
+    pub fn search_raw_pages(
+        &self,
+        jql: &str,
+        fields: &str,
+        expand: Option<&str>,
+        mut on_page: impl FnMut(Vec<serde_json::Value>) -> Result<bool>,
+    ) -> Result<()> {
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        let mut next_page_token: Option<String> = None;
+        
+        loop {
+            let mut query = vec![
+                ("jql", jql.to_string()),
+                ("fields", fields.to_string()),
+                ("maxResults", "100".to_string()),
+            ];
+            if let Some(expand) = expand {
+                query.push(("expand", expand.to_string()));
+            }
+            if let Some(token) = &next_page_token {
+                query.push(("nextPageToken", token.clone()));
+            }
+            
+            let response = self.client
+                .get(&url)
+                .header(AUTHORIZATION, &self.auth_header)
+                .header(ACCEPT, "application/json")
+                .query(&query)
+                .send()
+                .context("Failed to send search request to JIRA")?;
+            
+            let status = response.status();
+            let response_text = response.text()?;
+            
+            if !status.is_success() {
+                eprintln!("JIRA API error response: {}", response_text);
+                anyhow::bail!("JIRA API error: {}", status);
+            }
+            
+            let mut page: serde_json::Value = serde_json::from_str(&response_text)
+                .context("Failed to parse JIRA search response")?;
+            
+            let issues = page.get_mut("issues")
+                .and_then(|i| i.as_array_mut())
+                .map(std::mem::take)
+                .unwrap_or_default();
+            
+            next_page_token = page.get("nextPageToken")
+                .and_then(|t| t.as_str())
+                .map(|t| t.to_string());
+            let is_last = page.get("isLast").and_then(|l| l.as_bool()).unwrap_or(true);
+            
+            if !on_page(issues)? || is_last || next_page_token.is_none() {
+                return Ok(());
+            }
+        }
+    }
+    
+    // Issues whose summary contains any of the given words
+    pub fn search_summary(&self, words: &[String], project: Option<&str>, limit: usize) -> Result<Vec<JiraIssue>> {
+        // Quotes and backslashes would end the JQL string early
+        let terms: Vec<String> = words.iter()
+            .map(|w| w.replace(['"', '\\'], ""))
+            .filter(|w| !w.is_empty())
+            .map(|w| format!("summary ~ \"{}\"", w))
+            .collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut jql = format!("({})", terms.join(" OR "));
+        if let Some(project) = project {
+            jql.push_str(&format!(" AND project = \"{}\"", project));
+        }
+        
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        let response = self.client
+            .get(&url)
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(ACCEPT, "application/json")
+            .query(&[
+                ("jql", jql.as_str()),
+                ("fields", "key,summary,status,assignee"),
+                ("maxResults", &limit.to_string()),
+            ])
+            .send()
+            .context("Failed to send search request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            anyhow::bail!("JIRA API error: {} {}", status, response_text);
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct SearchResponse {
+            issues: Vec<JiraIssue>,
+        }
+        
+        let search_response: SearchResponse = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA search response")?;
+        Ok(search_response.issues)
+    }
+    
+    // Runs an arbitrary JQL query, returning at most `limit` issues
+    pub fn search_jql(&self, jql: &str, limit: usize) -> Result<Vec<JiraIssue>> {
+        let mut issues = Vec::new();
+        self.search_jql_pages(jql, Some(limit), |page| {
+            issues.extend(page);
+            true
+        })?;
+        Ok(issues)
+    }
+    
+    // Runs a JQL query a page at a time, handing each page to `on_page` as it arrives so large
+    // result sets can be shown before the last page is in. Stops early when `on_page` returns false.
+    pub fn search_jql_pages(&self, jql: &str, limit: Option<usize>, mut on_page: impl FnMut(Vec<JiraIssue>) -> bool) -> Result<()> {
+        const PAGE_SIZE: usize = 100;
+        
+        let url = format!("{}/rest/api/3/search/jql", self.base_url);
+        let mut fetched = 0;
+        let mut next_page_token: Option<String> = None;
+        
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SearchPage {
+            issues: Vec<JiraIssue>,
+            #[serde(default)]
+            next_page_token: Option<String>,
+            #[serde(default)]
+            is_last: Option<bool>,
+        }
+        
+        loop {
+            let page_size = limit.map_or(PAGE_SIZE, |limit| limit.saturating_sub(fetched).min(PAGE_SIZE));
+            if page_size == 0 {
+                return Ok(());
+            }
+            
+            let mut query = vec![
+                ("jql", jql.to_string()),
+                ("fields", "key,summary,status,assignee,priority,issuetype,parent,duedate,updated,created".to_string()),
+                ("maxResults", page_size.to_string()),
+            ];
+            if let Some(token) = &next_page_token {
+                query.push(("nextPageToken", token.clone()));
+            }
+            
+            let response = self.client
+                .get(&url)
+                .header(AUTHORIZATION, &self.auth_header)
+                .header(ACCEPT, "application/json")
+                .query(&query)
+                .send()
+                .context("Failed to send search request to JIRA")?;
+            
+            let status = response.status();
+            let response_text = response.text()?;
+            
+            if !status.is_success() {
+                anyhow::bail!("JIRA API error: {} {}", status, jql_error(&response_text));
+            }
+            
+            let page: SearchPage = serde_json::from_str(&response_text)
+                .context("Failed to parse JIRA search response")?;
+            fetched += page.issues.len();
+            next_page_token = page.next_page_token;
+            let is_last = page.is_last.unwrap_or(true) || next_page_token.is_none();
+            
+            if !on_page(page.issues) || is_last {
+                return Ok(());
+            }
+        }
+    }
+    
+    // How many issues a JQL query matches, without fetching them. Jira only promises an
+    // estimate, which is plenty for previewing a query.
+    pub fn count_jql(&self, jql: &str) -> Result<u64> {
+        let response = self.client
+            .post(format!("{}/rest/api/3/search/approximate-count", self.base_url))
+            .header(AUTHORIZATION, &self.auth_header)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .json(&serde_json::json!({ "jql": jql }))
+            .send()
+            .context("Failed to send count request to JIRA")?;
+        
+        let status = response.status();
+        let response_text = response.text()?;
+        
+        if !status.is_success() {
+            anyhow::bail!("{}", jql_error(&response_text));
+        }
+        
+        #[derive(Debug, Deserialize)]
+        struct CountResponse {
+            count: u64,
+        }
+        
+        let count: CountResponse = serde_json::from_str(&response_text)
+            .context("Failed to parse JIRA count response")?;
+        Ok(count.count)
+    }
+}
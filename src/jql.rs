@@ -0,0 +1,133 @@
+// The query assembled step by step by `qq jira query-builder`
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssigneeFilter {
+    Anyone,
+    Me,
+    Unassigned,
+}
+
+impl AssigneeFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Anyone => Self::Me,
+            Self::Me => Self::Unassigned,
+            Self::Unassigned => Self::Anyone,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Anyone => "Anyone",
+            Self::Me => "Me",
+            Self::Unassigned => "Unassigned",
+        }
+    }
+}
+
+// Label shown in the builder and the ORDER BY clause it stands for
+pub const ORDERINGS: &[(&str, &str)] = &[
+    ("Recently updated", "updated DESC"),
+    ("Recently created", "created DESC"),
+    ("Highest priority", "priority DESC, updated DESC"),
+    ("Due soonest", "duedate ASC"),
+    ("Backlog rank", "Rank ASC"),
+];
+
+// Pseudo-status for anything not in the Done category
+pub const NOT_DONE: &str = "(not done)";
+
+#[derive(Debug, Clone)]
+pub struct QueryBuilder {
+    pub project: Option<String>,
+    pub issue_types: Vec<String>,
+    pub statuses: Vec<String>,
+    pub assignee: AssigneeFilter,
+    pub text: String,
+    pub order: usize,
+}
+
+impl Default for QueryBuilder {
+    fn default() -> Self {
+        Self {
+            project: None,
+            issue_types: Vec::new(),
+            statuses: vec![NOT_DONE.to_string()],
+            assignee: AssigneeFilter::Anyone,
+            text: String::new(),
+            order: 0,
+        }
+    }
+}
+
+impl QueryBuilder {
+    pub fn toggle_issue_type(&mut self, name: &str) {
+        toggle(&mut self.issue_types, name);
+    }
+
+    // "(not done)" and specific statuses don't combine; picking one clears the other
+    pub fn toggle_status(&mut self, name: &str) {
+        if name == NOT_DONE {
+            let was_set = self.statuses.iter().any(|s| s == NOT_DONE);
+            self.statuses.clear();
+            if !was_set {
+                self.statuses.push(NOT_DONE.to_string());
+            }
+        } else {
+            self.statuses.retain(|s| s != NOT_DONE);
+            toggle(&mut self.statuses, name);
+        }
+    }
+
+    pub fn to_jql(&self) -> String {
+        let mut clauses = Vec::new();
+
+        if let Some(project) = &self.project {
+            clauses.push(format!("project = {}", quote(project)));
+        }
+        if !self.issue_types.is_empty() {
+            clauses.push(format!("issuetype in ({})", quote_all(&self.issue_types)));
+        }
+
+        if self.statuses.iter().any(|s| s == NOT_DONE) {
+            clauses.push("statusCategory != Done".to_string());
+        } else if !self.statuses.is_empty() {
+            clauses.push(format!("status in ({})", quote_all(&self.statuses)));
+        }
+
+        match self.assignee {
+            AssigneeFilter::Anyone => {}
+            AssigneeFilter::Me => clauses.push("assignee = currentUser()".to_string()),
+            AssigneeFilter::Unassigned => clauses.push("assignee is EMPTY".to_string()),
+        }
+
+        let text = self.text.trim();
+        if !text.is_empty() {
+            clauses.push(format!("text ~ {}", quote(text)));
+        }
+
+        let order = ORDERINGS.get(self.order).map(|(_, clause)| *clause).unwrap_or("updated DESC");
+        if clauses.is_empty() {
+            format!("ORDER BY {}", order)
+        } else {
+            format!("{} ORDER BY {}", clauses.join(" AND "), order)
+        }
+    }
+}
+
+// Adds the value if it's missing and removes it otherwise
+fn toggle(values: &mut Vec<String>, value: &str) {
+    if let Some(idx) = values.iter().position(|v| v == value) {
+        values.remove(idx);
+    } else {
+        values.push(value.to_string());
+    }
+}
+
+pub fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn quote_all(values: &[String]) -> String {
+    values.iter().map(|v| quote(v)).collect::<Vec<_>>().join(", ")
+}
@@ -1,11 +1,54 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 
+mod activity;
+mod alias;
+mod archive;
+mod branch_map;
+mod cache;
+mod clipboard;
 mod config;
+mod config_check;
+mod context;
+mod dependencies;
+mod duplicate;
+mod epic_report;
+mod find;
+mod forge;
+mod git;
+mod github;
+mod hooks;
+mod import;
 mod jira;
+mod jql;
+mod meeting_stats;
+mod meta;
+mod notify;
+mod output;
+mod palette;
+mod pin;
 mod ui;
+mod verify;
 mod google;
+mod pool;
+mod prep;
+mod presence;
+mod projects;
+mod release;
+mod scratch;
+mod shell;
+mod sla;
+mod snooze;
+mod sprint;
+mod store;
+mod style;
+mod template;
+mod timesheet;
+mod today;
+mod trailers;
+mod workflow;
 
+use cache::IssueCache;
 use config::Config;
 
 #[derive(Parser)]
@@ -13,29 +56,181 @@ use config::Config;
 #[command(author, version, about = "Personal CLI for day-to-day tasks", long_about = None)]
 #[command(propagate_version = true)]
 struct Cli {
+    // Without a subcommand, qq opens the command palette
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
+    
+    // Read from the raw arguments in main() so aliases see it too; declared here for --help
+    #[arg(long, global = true, help = "Don't color output (also set by the NO_COLOR environment variable)")]
+    no_color: bool,
+    
+    #[arg(long, global = true, help = "Print results as JSON instead of opening the interactive view (jira get, mine, epic, blocked-by-me, burnrate, activity-feed, from-commit; meetings list, stats, digest)")]
+    json: bool,
+}
+
+// Where a command's results go: the interactive view, or JSON on stdout for scripts and jq
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Interactive,
+    Json,
+}
+
+impl Output {
+    fn new(json: bool) -> Self {
+        if json { Output::Json } else { Output::Interactive }
+    }
+    
+    fn is_json(self) -> bool {
+        self == Output::Json
+    }
+    
+    // Progress notes shown while fetching; left out of JSON output so stdout stays parseable
+    fn progress(self, message: impl std::fmt::Display) {
+        if !self.is_json() {
+            println!("{}", style::muted(message));
+        }
+    }
+    
+    fn print_json(value: &impl serde::Serialize) -> Result<()> {
+        println!("{}", serde_json::to_string_pretty(value)?);
+        Ok(())
+    }
+    
+    // Commands without JSON output refuse --json rather than print text a script can't parse
+    fn check_supported(self, command: &Commands) -> Result<()> {
+        let supported = match command {
+            Commands::Jira { command, .. } => matches!(command,
+                JiraCommands::Get { .. }
+                | JiraCommands::Epic { .. }
+                | JiraCommands::BlockedByMe
+                | JiraCommands::Mine { .. }
+                | JiraCommands::Burnrate { .. }
+                | JiraCommands::ActivityFeed { .. }
+                | JiraCommands::FromCommit { .. }),
+            Commands::Meetings { command, .. } => matches!(command,
+                MeetingsCommands::List { .. }
+                | MeetingsCommands::Stats { .. }
+                | MeetingsCommands::Digest { .. }),
+            _ => false,
+        };
+        if self.is_json() && !supported {
+            anyhow::bail!("--json isn't supported by this command. It works with: jira get, mine, epic, blocked-by-me, burnrate, activity-feed, from-commit; meetings list, stats, digest");
+        }
+        Ok(())
+    }
+    
+    // --format and --json both replace the view, so only one makes sense
+    fn check_format(self, format: &Option<String>) -> Result<()> {
+        if self.is_json() && format.is_some() {
+            anyhow::bail!("--json and --format can't be used together");
+        }
+        Ok(())
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
     #[command(about = "JIRA integration commands")]
     Jira {
+        #[arg(long, global = true, help = "Run as if qq was started in this git repository (like git -C)")]
+        repo: Option<std::path::PathBuf>,
+        
         #[command(subcommand)]
         command: JiraCommands,
     },
     
+    #[command(about = "GitHub pull request commands")]
+    Pr {
+        #[command(subcommand)]
+        command: PrCommands,
+    },
+    
+    #[command(about = "Dashboard of today's meetings and your open tickets")]
+    Today,
+    
+    #[command(about = "Look up a person in the Jira directory by name or email")]
+    Who {
+        #[arg(required = true, help = "Name or email to search for")]
+        name: Vec<String>,
+        
+        #[arg(long, help = "Open their Jira profile in the browser")]
+        open: bool,
+        
+        #[arg(long, help = "Copy their name and email to the clipboard")]
+        copy: bool,
+    },
+    
     #[command(about = "Google Calendar meetings commands")]
     Meetings {
+        #[arg(long, global = true, help = "Named Google account to use (see 'qq config google --account')")]
+        account: Option<String>,
+        
         #[command(subcommand)]
         command: MeetingsCommands,
     },
     
+    #[command(about = "Notification settings")]
+    Notify {
+        #[command(subcommand)]
+        command: NotifyCommands,
+    },
+    
+    #[command(about = "Manage stored credentials")]
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
+    },
+    
     #[command(about = "Configure qq settings")]
     Config {
         #[command(subcommand)]
         command: ConfigCommands,
     },
+    
+    #[command(about = "Manage command aliases from the [alias] config section")]
+    Alias {
+        #[command(subcommand)]
+        command: AliasCommands,
+    },
+    
+    #[command(about = "Switch the active Jira profile (same as 'qq config jira use')")]
+    Use {
+        #[arg(help = "Profile name, or 'default'")]
+        profile: Option<String>,
+    },
+    
+    #[command(about = "Show the installed version")]
+    Version {
+        #[arg(long, help = "Check GitHub for a newer release")]
+        check: bool,
+    },
+    
+    #[command(about = "Show release notes")]
+    Changelog,
+    
+    #[command(about = "Print a shell hook that shows ticket context when the git branch changes")]
+    ShellHook {
+        #[arg(value_enum, help = "Shell to generate the hook for")]
+        shell: shell::Shell,
+    },
+}
+
+#[derive(Subcommand)]
+enum PrCommands {
+    #[command(about = "Push the current branch, open a pull request titled with its ticket, and link it on the ticket")]
+    Create {
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(long, help = "Branch to merge into (defaults to the repository's default branch)")]
+        base: Option<String>,
+        
+        #[arg(long, help = "Open the pull request as a draft")]
+        draft: bool,
+        
+        #[arg(long, help = "Pull request description; the ticket link is added below it")]
+        body: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -47,305 +242,3712 @@ enum GetSubcommands {
     Parent,
 }
 
+#[derive(Subcommand)]
+enum ParentCommands {
+    #[command(about = "Set the parent epic (pick from a list when no epic is given)")]
+    Set {
+        #[arg(help = "Epic ticket number (e.g., EPIC-10)")]
+        epic: Option<String>,
+        
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+    },
+    
+    #[command(about = "Remove the ticket from its parent epic")]
+    Remove {
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MetaCommands {
+    #[command(about = "Re-fetch components, versions, and issue types for cached projects")]
+    Refresh {
+        #[arg(help = "Project keys to refresh (defaults to every cached project, or the current branch's project)")]
+        projects: Vec<String>,
+    },
+}
+
 #[derive(Subcommand)]
 enum JiraCommands {
     #[command(about = "Get ticket information from current git branch")]
     Get {
         #[command(subcommand)]
         subcommand: Option<GetSubcommands>,
+        
+        #[arg(long, value_name = "SECONDS", num_args = 0..=1, default_missing_value = "30", help = "Keep the details open and refresh every SECONDS (default 30), highlighting what changed")]
+        follow: Option<u64>,
+    },
+    
+    #[command(about = "Change the parent epic of the ticket from current git branch")]
+    Parent {
+        #[command(subcommand)]
+        action: ParentCommands,
+    },
+    
+    #[command(about = "Everything about one ticket: details, comments, history, PRs, branches, meetings, time")]
+    Context {
+        #[arg(help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
     },
     
     #[command(about = "Add a comment to the ticket from current git branch")]
     Comment {
-        #[arg(help = "Comment text to add")]
-        message: String,
+        #[arg(help = "Comment text to add", required_unless_present_any = ["delete_last", "template"], conflicts_with = "delete_last")]
+        message: Option<String>,
+        
+        #[arg(long, help = "Use a comment template from config instead of typing the text", conflicts_with_all = ["message", "delete_last"])]
+        template: Option<String>,
+        
+        #[arg(long = "set", value_name = "NAME=VALUE", help = "Value for a template placeholder (repeatable)", requires = "template")]
+        set_values: Vec<String>,
+        
+        #[arg(long, help = "Replace the text of your most recent comment instead of adding one", conflicts_with = "delete_last")]
+        edit_last: bool,
+        
+        #[arg(long, help = "Delete your most recent comment")]
+        delete_last: bool,
+    },
+    
+    #[command(about = "Open your private scratch notes for a ticket in $EDITOR")]
+    Scratch {
+        #[arg(help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(long, help = "List every ticket with scratch notes", conflicts_with_all = ["ticket", "comment"])]
+        list: bool,
+        
+        #[arg(long, help = "Post the notes to the ticket as a comment, then remove them")]
+        comment: bool,
+    },
+    
+    #[command(about = "Move a ticket to review, pick a reviewer, and mention them in a comment")]
+    Review {
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(long, help = "Reviewer's name or email, or part of it (picked from a list when omitted)")]
+        reviewer: Option<String>,
+        
+        #[arg(long, help = "Status to move to (defaults to the 'review' alias or a status mentioning review)")]
+        to: Option<String>,
+        
+        #[arg(long, help = "Don't post the review request comment")]
+        no_comment: bool,
+    },
+    
+    #[command(about = "Upload a file, or piped output with --stdin, as a ticket attachment")]
+    Attach {
+        #[arg(help = "File to upload, or the attachment's file name with --stdin (e.g., test-output.txt)")]
+        file: String,
+        
+        #[arg(long, help = "Read the attachment from standard input, e.g. 'make test 2>&1 | qq jira attach --stdin test-output.txt'")]
+        stdin: bool,
+        
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(long, value_name = "TEXT", num_args = 0..=1, default_missing_value = "", help = "Also post a comment linking the attachment, with optional text before the link")]
+        comment: Option<String>,
     },
     
     #[command(about = "Close the ticket from current git branch")]
-    Close,
+    Close {
+        #[arg(long, help = "Close even when pre-close checks fail")]
+        force: bool,
+        
+        #[arg(short, long, value_name = "TEXT", help = "Resolution comment, posted only if the ticket closes (e.g. \"Fixed in 1.4.2\")")]
+        message: Option<String>,
+    },
     
-    #[command(about = "Create a feature branch for a JIRA ticket, assign it to yourself, and move to In Progress")]
-    Start {
-        #[arg(help = "JIRA ticket number (e.g., PROJ-123)")]
-        ticket: String,
+    #[command(about = "Run the pre-close checklist (subtasks, blockers, PR, fix version, time logged)")]
+    Verify {
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
     },
     
-    #[command(about = "List all tickets in an epic with interactive controls")]
-    Epic {
-        #[arg(help = "Epic ticket number (e.g., EPIC-123) or 'list' to show all epics")]
-        ticket: String,
+    #[command(about = "Move a ticket to another status; without a state, pick from the likeliest next statuses")]
+    Transition {
+        #[arg(help = "Target status name or id, transition name or id, or an alias from config")]
+        state: Option<String>,
+        
+        #[arg(long, conflicts_with = "state", help = "Same as STATE, for scripts and aliases that pass it as a flag")]
+        to: Option<String>,
+        
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(long = "field", value_name = "ID=VALUE", help = "Field required by the transition; VALUE may be JSON (repeatable)")]
+        fields: Vec<String>,
     },
     
-    #[command(about = "List all tickets assigned to me")]
-    Mine,
-}
-
-#[derive(Subcommand)]
-enum MeetingsCommands {
-    #[command(about = "List today's meetings from Google Calendar")]
-    List,
-}
-
-#[derive(Subcommand)]
-enum ConfigCommands {
-    #[command(about = "Configure JIRA settings")]
-    Jira {
-        #[arg(long, help = "JIRA instance URL (e.g., https://company.atlassian.net)")]
-        url: String,
+    #[command(about = "Add a ticket to an active or future sprint")]
+    MoveToSprint {
+        #[arg(help = "Sprint name or id (omit to pick from the board's active and future sprints)")]
+        sprint: Option<String>,
+        
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+    },
+    
+    #[command(about = "Look for existing tickets that duplicate a summary or ticket")]
+    DuplicateCheck {
+        #[arg(help = "Summary of the ticket you're about to create (defaults to the summary of --ticket or the current branch's ticket)")]
+        summary: Option<String>,
         
-        #[arg(long, help = "JIRA username/email")]
-        username: String,
+        #[arg(long, help = "Check an existing ticket and offer to link it as a duplicate", conflicts_with = "summary")]
+        ticket: Option<String>,
         
-        #[arg(long, help = "JIRA API token")]
-        token: String,
+        #[arg(long, help = "Only search this project")]
+        project: Option<String>,
     },
     
-    #[command(about = "Configure Google Calendar settings")]
-    Google {
-        #[arg(long, help = "Google OAuth2 Client ID")]
-        client_id: String,
+    #[command(about = "Search cached tickets' summaries, descriptions, and comments instantly, without Jira")]
+    Find {
+        #[arg(required = true, help = "Words to look for, e.g. payment retries")]
+        words: Vec<String>,
         
-        #[arg(long, help = "Google OAuth2 Client Secret")]
-        client_secret: String,
+        #[arg(long, help = "Search Jira's full text when no cached ticket matches")]
+        remote: bool,
+        
+        #[arg(long, default_value_t = 20, help = "Maximum number of tickets to show")]
+        limit: usize,
     },
-}
-
-fn main() -> Result<()> {
-    let cli = Cli::parse();
     
-    match cli.command {
-        Commands::Jira { command } => {
-            handle_jira_command(command)?;
-        }
+    #[command(about = "Write every ticket someone was assigned, with comments and changelogs, as JSON lines")]
+    Export {
+        #[arg(long, default_value = "me", help = "Whose tickets: 'me' or a Jira account ID")]
+        assignee: String,
         
-        Commands::Meetings { command } => {
-            handle_meetings_command(command)?;
-        }
+        #[arg(long, help = "Only tickets updated on or after this date (YYYY-MM-DD)")]
+        since: Option<String>,
         
-        Commands::Config { command } => {
-            handle_config_command(command)?;
-        }
-    }
+        #[arg(long, help = "File to write (defaults to standard output)")]
+        out: Option<std::path::PathBuf>,
+    },
     
-    Ok(())
-}
-
-fn handle_config_command(command: ConfigCommands) -> Result<()> {
-    match command {
-        ConfigCommands::Jira { url, username, token } => {
-            let config = Config::new(url, username, token);
-            config.save()?;
-            println!("JIRA configuration saved successfully!");
-        }
-        ConfigCommands::Google { client_id, client_secret } => {
-            let mut config = Config::load().unwrap_or_else(|_| Config::new(
-                String::new(),
-                String::new(),
-                String::new(),
-            ));
-            config.set_google_credentials(client_id, client_secret);
-            config.save()?;
-            println!("Google Calendar configuration saved successfully!");
-        }
-    }
+    #[command(about = "Run a JQL search, or list saved searches when given no query")]
+    Search {
+        #[arg(help = "JQL query (e.g., \"project = PROJ AND status = Open\")")]
+        jql: Option<String>,
+        
+        #[arg(long, help = "Run a search saved from 'qq jira query-builder' or the [searches] config section", conflicts_with = "jql")]
+        saved: Option<String>,
+        
+        #[arg(long, help = "Maximum number of issues to show (default: 50 when printing, every match in the interactive view)")]
+        limit: Option<usize>,
+        
+        #[arg(long, help = "Print one line per item using a template like \"{key}\\t{summary}\" or a name from [formats]")]
+        format: Option<String>,
+    },
     
-    Ok(())
-}
-
-fn handle_meetings_command(command: MeetingsCommands) -> Result<()> {
-    use ui::MeetingsListDisplay;
+    #[command(about = "Build a JQL search step by step with a live preview, then run or save it")]
+    QueryBuilder,
     
-    match command {
-        MeetingsCommands::List => {
-            let config = Config::load()?;
-            
-            let client_id = config.google_client_id
-                .context("Google client ID not configured. Run 'qq config google' first.")?;
-            let client_secret = config.google_client_secret
-                .context("Google client secret not configured. Run 'qq config google' first.")?;
-            
-            let token_path = Config::google_token_path()?;
-            
-            println!("Fetching meetings from Google Calendar...");
-            let meetings = google::blocking_list_meetings(client_id, client_secret, token_path)?;
-            
-            if meetings.is_empty() {
-                println!("No meetings scheduled for the next 7 days.");
-            } else {
-                println!("Found {} meeting(s).", meetings.len());
-                MeetingsListDisplay::show(meetings)?;
-            }
-        }
-    }
+    #[command(about = "Add or remove a label on one or more tickets")]
+    Label {
+        #[command(subcommand)]
+        command: ListFieldCommands,
+    },
     
-    Ok(())
-}
-
-fn handle_jira_command(command: JiraCommands) -> Result<()> {
-    use git2::Repository;
-    use regex::Regex;
-    use jira::JiraClient;
-    use ui::JiraIssueDisplay;
+    #[command(about = "Add or remove a component on one or more tickets")]
+    Component {
+        #[command(subcommand)]
+        command: ListFieldCommands,
+    },
     
-    // Helper functions for JIRA commands
-    fn get_current_branch() -> Result<String> {
-        let repo = Repository::open(".").context("Failed to open git repository")?;
-        let head = repo.head().context("Failed to get HEAD reference")?;
-        let branch = head.shorthand().unwrap_or("HEAD");
-        Ok(branch.to_string())
-    }
+    #[command(about = "Change the summary of the ticket from current git branch")]
+    Rename {
+        #[arg(help = "New summary for the ticket")]
+        summary: String,
+    },
     
-    fn extract_ticket_id(branch_name: &str) -> Result<String> {
-        let patterns = vec![
-            r"^([A-Z]+-\d+)",
-            r"([A-Z]+-\d+)",
-            r"^feature/([A-Z]+-\d+)",
-            r"^bugfix/([A-Z]+-\d+)",
-            r"^hotfix/([A-Z]+-\d+)",
-        ];
+    #[command(about = "Create a feature branch for a JIRA ticket, assign it to yourself, and move to In Progress")]
+    Start {
+        #[arg(help = "JIRA ticket number (e.g., PROJ-123)")]
+        ticket: String,
         
-        for pattern in patterns {
-            let re = Regex::new(pattern)?;
-            if let Some(captures) = re.captures(branch_name) {
-                if let Some(ticket_id) = captures.get(1) {
-                    return Ok(ticket_id.as_str().to_string());
-                }
-            }
-        }
+        #[arg(long, help = "Push the new branch to origin and set it as the upstream")]
+        push: bool,
         
-        anyhow::bail!("No JIRA ticket ID found in branch name: {}", branch_name)
-    }
+        #[arg(long, help = "Also push the branch, open a draft pull request for it, and link the PR on the ticket")]
+        draft_pr: bool,
+    },
     
-    let config = Config::load()?;
-    let client = JiraClient::new(config);
+    #[command(about = "Pick the top-ranked unassigned backlog issue, assign it to yourself, and start it")]
+    GrabNext {
+        #[arg(long, help = "Only consider issues in this project")]
+        project: Option<String>,
+        
+        #[arg(long = "label", help = "Only consider issues with this label (repeatable)")]
+        labels: Vec<String>,
+        
+        #[arg(long, help = "Only consider issues estimated at or below this many story points")]
+        max_points: Option<f64>,
+        
+        #[arg(long, help = "Show which issue would be picked without changing anything")]
+        dry_run: bool,
+    },
     
-    match command {
-        JiraCommands::Get { subcommand } => {
-            let branch = get_current_branch()?;
-            let ticket_id = extract_ticket_id(&branch)?;
-            
-            match subcommand.as_ref().unwrap_or(&GetSubcommands::Info) {
-                GetSubcommands::Info => {
-                    println!("Fetching details for ticket: {}", ticket_id);
-                    let issue = client.get_issue(&ticket_id)?;
-                    
-                    // Use the new Ratatui UI to display the issue
-                    JiraIssueDisplay::show(&issue)?;
-                }
-                GetSubcommands::Parent => {
-                    use ui::EpicListDisplay;
-                    
-                    println!("Fetching parent epic for ticket: {}", ticket_id);
-                    let issue = client.get_issue_with_parent(&ticket_id)?;
-                    
-                    if let Some(parent) = &issue.fields.parent {
-                        println!("Found parent epic: {}", parent.key);
-                        
-                        println!("Fetching child issues...");
-                        let children = client.get_epic_children(&parent.key)?;
-                        
-                        // Display the epic and its children in interactive UI
-                        EpicListDisplay::show(parent, children, &client)?;
-                    } else {
-                        println!("This issue is not part of an epic.");
-                    }
-                }
-            }
-        }
+    #[command(about = "Show an epic's tickets in one lane per assignee with workload counts")]
+    Swimlane {
+        #[arg(help = "Epic key (e.g., PROJ-100)")]
+        epic: String,
+    },
+    
+    #[command(about = "Create a ticket, optionally under an epic")]
+    Create {
+        #[arg(help = "Summary of the new ticket")]
+        summary: String,
         
-        JiraCommands::Comment { message } => {
-            let branch = get_current_branch()?;
-            let ticket_id = extract_ticket_id(&branch)?;
-            
-            println!("Adding comment to ticket: {}", ticket_id);
-            client.add_comment(&ticket_id, &message)?;
-            println!("Comment added successfully!");
-        }
+        #[arg(long, help = "Epic to create the ticket under; fields listed in [create] inherit are copied from it")]
+        epic: Option<String>,
         
-        JiraCommands::Close => {
-            let branch = get_current_branch()?;
-            let ticket_id = extract_ticket_id(&branch)?;
-            
-            println!("Closing ticket: {}", ticket_id);
-            client.close_issue(&ticket_id)?;
-            println!("Ticket closed successfully!");
-        }
+        #[arg(long, help = "Project key (defaults to the epic's project, then the current branch's or repository's)")]
+        project: Option<String>,
         
-        JiraCommands::Start { ticket } => {
-            // Create the feature branch
-            let branch_name = format!("feature/{}", ticket);
-            
-            // Open the git repository
-            let repo = Repository::open(".").context("Failed to open git repository")?;
-            
-            // Get the current HEAD commit
-            let head = repo.head().context("Failed to get HEAD reference")?;
-            let target = head.target().context("Failed to get HEAD target")?;
-            let commit = repo.find_commit(target).context("Failed to find HEAD commit")?;
-            
-            // Create the new branch
-            repo.branch(&branch_name, &commit, false)
-                .context(format!("Failed to create branch '{}'", branch_name))?;
-            
-            // Checkout the new branch
-            let obj = repo.revparse_single(&format!("refs/heads/{}", branch_name))
-                .context("Failed to find new branch")?;
-            
-            repo.checkout_tree(&obj, None)
-                .context("Failed to checkout new branch")?;
-            
-            repo.set_head(&format!("refs/heads/{}", branch_name))
-                .context("Failed to set HEAD to new branch")?;
-            
-            println!("Created and switched to branch: {}", branch_name);
-            
-            // Now assign the ticket to yourself and move it to In Progress
-            println!("Picking up ticket: {}", ticket);
-            client.pickup_issue(&ticket)?;
-            println!("Ticket assigned to you and moved to In Progress!");
-        }
+        #[arg(long = "type", default_value = "Task", help = "Issue type")]
+        issue_type: String,
         
-        JiraCommands::Epic { ticket } => {
-            use ui::{EpicListDisplay, AllEpicsDisplay};
-            
-            if ticket == "list" {
-                // Show all epics
-                println!("Fetching all epics...");
-                
-                let epics = client.get_all_epics()?;
-                
-                if epics.is_empty() {
-                    println!("No epics found.");
-                } else {
-                    println!("Found {} epic(s).", epics.len());
-                    // Display all epics in interactive UI
-                    AllEpicsDisplay::show(epics, &client)?;
-                }
-            } else {
-                // Show specific epic
-                println!("Fetching epic details for: {}", ticket);
-                let epic = client.get_issue(&ticket)?;
-                
-                println!("Fetching child issues...");
-                let children = client.get_epic_children(&ticket)?;
-                
-                // Display the epic and its children in interactive UI
-                EpicListDisplay::show(&epic, children, &client)?;
-            }
-        }
+        #[arg(long, help = "Description; blank lines separate paragraphs")]
+        description: Option<String>,
         
-        JiraCommands::Mine => {
+        #[arg(long, requires = "epic", help = "Don't copy any fields from the epic")]
+        no_inherit: bool,
+        
+        #[arg(long, value_name = "SPRINT", num_args = 0..=1, default_missing_value = "", help = "Add the ticket to a sprint, by name or id (pick from the board's open sprints when no name is given)")]
+        sprint: Option<String>,
+    },
+    
+    #[command(about = "Create tickets in bulk from a Markdown outline, CSV, or YAML file")]
+    Import {
+        #[arg(help = "File listing the tickets (.md, .csv, .yaml)")]
+        file: std::path::PathBuf,
+        
+        #[arg(long, help = "Epic to create the tickets under; fields listed in [create] inherit are copied from it")]
+        epic: Option<String>,
+        
+        #[arg(long, help = "Project key (defaults to the epic's project, then the current branch's or repository's)")]
+        project: Option<String>,
+        
+        #[arg(long = "type", default_value = "Task", help = "Issue type for tickets that don't name their own")]
+        issue_type: String,
+        
+        #[arg(long, help = "Create without asking for confirmation")]
+        yes: bool,
+        
+        #[arg(long, conflicts_with = "yes", help = "Only show what would be created")]
+        dry_run: bool,
+    },
+    
+    #[command(about = "Estimate an epic's unestimated tickets one after another and save the points at the end")]
+    Poker {
+        #[arg(help = "Epic key (e.g., PROJ-100)")]
+        epic: String,
+    },
+    
+    #[command(about = "List all tickets in an epic with interactive controls")]
+    Epic {
+        #[arg(help = "Epic ticket number (e.g., EPIC-123) or 'list' to show all epics")]
+        ticket: String,
+        
+        #[arg(long, help = "Print one line per item using a template like \"{key}\\t{summary}\" or a name from [formats] instead of opening the interactive view")]
+        format: Option<String>,
+    },
+    
+    #[command(about = "Reports across all open epics")]
+    Epics {
+        #[command(subcommand)]
+        command: EpicsCommands,
+    },
+    
+    #[command(about = "Tickets that block or are blocked by other tickets")]
+    Dependencies {
+        #[command(subcommand)]
+        command: DependencyCommands,
+    },
+    
+    #[command(about = "List other people's tickets that are blocked by my open tickets, blocked the longest first")]
+    BlockedByMe,
+    
+    #[command(about = "List all tickets assigned to me")]
+    Mine {
+        #[arg(long, help = "Print one line per item using a template like \"{key}\\t{summary}\" or a name from [formats] instead of opening the interactive view")]
+        format: Option<String>,
+    },
+    
+    #[command(about = "Hide a ticket from 'mine' until a later time")]
+    Snooze {
+        #[arg(help = "How long to snooze (e.g., 4h, 2d, 1w), 'tomorrow', or a date (YYYY-MM-DD [HH:MM])")]
+        until: String,
+        
+        #[arg(help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+    },
+    
+    #[command(about = "Bring a snoozed ticket back before its time")]
+    Unsnooze {
+        #[arg(help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+    },
+    
+    #[command(about = "List snoozed tickets and when they reappear")]
+    Snoozed,
+    
+    #[command(about = "List pinned tickets (press P in 'mine' or an epic view to pin or unpin)")]
+    Pinned,
+    
+    #[command(about = "Browse the projects you can see, with leads and your open issue counts")]
+    Projects {
+        #[arg(long, value_name = "KEY", help = "Make this the current repository's default project and exit")]
+        set_default: Option<String>,
+    },
+    
+    #[command(about = "List a project's components and versions")]
+    Components {
+        #[arg(long, help = "Project key (defaults to the project of the ticket from current git branch)")]
+        project: Option<String>,
+    },
+    
+    #[command(about = "Cached project metadata (components, versions, issue types)")]
+    Meta {
+        #[command(subcommand)]
+        command: MetaCommands,
+    },
+    
+    #[command(about = "Explore all fields on the JIRA instance and pin detected custom fields")]
+    Fields {
+        #[arg(long, help = "Detect story points, epic link, and sprint fields and save them to config")]
+        pin: bool,
+    },
+    
+    #[command(about = "Show burn rate and load for the active sprint")]
+    Burnrate {
+        #[arg(long, help = "Limit to a project key (e.g., PROJ)")]
+        project: Option<String>,
+    },
+    
+    #[command(about = "Log time on the ticket from current git branch, e.g. 'qq jira log 2h \"worked on parser\"'")]
+    Log {
+        #[arg(help = "Time spent, like 2h, 45m, or \"1h 30m\"", required_unless_present = "list")]
+        duration: Option<String>,
+        
+        #[arg(help = "What the time went to, shown on the worklog")]
+        comment: Option<String>,
+        
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(long, conflicts_with_all = ["duration", "comment"], help = "List the ticket's worklogs instead of adding one")]
+        list: bool,
+    },
+    
+    #[command(about = "Weekly timesheet of your Jira worklogs, per day and ticket")]
+    Timesheet {
+        #[arg(long, help = "Any date in the week to report (YYYY-MM-DD); defaults to this week")]
+        week: Option<String>,
+        
+        #[arg(long, conflicts_with = "week", help = "Report last week instead of this week")]
+        last: bool,
+        
+        #[arg(long, help = "Print one line per day and ticket as CSV instead of opening the TUI")]
+        csv: bool,
+        
+        #[arg(long, conflicts_with = "csv", help = "Walk through working days with less than a full day logged and log the missing time")]
+        fill: bool,
+    },
+    
+    #[command(about = "Open issues breaching or close to their SLA from the [[sla]] config")]
+    Sla {
+        #[arg(long, help = "Limit to a project key (e.g., PROJ)")]
+        project: Option<String>,
+        
+        #[arg(long, help = "Include issues that are on track")]
+        all: bool,
+        
+        #[arg(long, help = "Print one line per item using a template like \"{key}\\t{sla}\" or a name from [formats] instead of opening the interactive view")]
+        format: Option<String>,
+    },
+    
+    #[command(about = "Recent status changes, comments, and new issues in a project or epic, newest first")]
+    ActivityFeed {
+        #[arg(long, help = "Project key (defaults to the project of the ticket from current git branch)")]
+        project: Option<String>,
+        
+        #[arg(long, conflicts_with = "project", help = "Follow an epic and the issues under it instead of a whole project")]
+        epic: Option<String>,
+        
+        #[arg(long, default_value_t = 7, help = "How many days back to go")]
+        days: i64,
+    },
+    
+    #[command(about = "Run 'git commit' with Jira and Epic trailers for the ticket from current git branch")]
+    Commit {
+        #[arg(short, long, help = "Commit message (opens your editor when left out)")]
+        message: Option<String>,
+        
+        #[arg(long, help = "JIRA ticket number (defaults to the ticket from current git branch)")]
+        ticket: Option<String>,
+        
+        #[arg(last = true, help = "Arguments passed on to git commit, after --, e.g. -- --all")]
+        git_args: Vec<String>,
+    },
+    
+    #[command(about = "Add Jira and Epic trailers to a commit message file; git runs this as the commit-msg hook")]
+    CommitMsg {
+        #[arg(required_unless_present = "install", help = "Commit message file, as git passes it to the hook")]
+        file: Option<std::path::PathBuf>,
+        
+        #[arg(long, conflicts_with = "file", help = "Install the commit-msg hook in the current repository")]
+        install: bool,
+    },
+    
+    #[command(about = "Show the ticket a commit belongs to, from its Jira trailer")]
+    FromCommit {
+        #[arg(help = "Commit hash or any revision, e.g. HEAD~2")]
+        sha: String,
+    },
+    
+    #[command(about = "Relate the current branch to a ticket when its name doesn't mention one")]
+    RelateBranch {
+        #[arg(required_unless_present = "clear", help = "Ticket key, e.g. PROJ-55")]
+        ticket: Option<String>,
+        
+        #[arg(long, help = "Branch to relate instead of the current one")]
+        branch: Option<String>,
+        
+        #[arg(long, conflicts_with = "ticket", help = "Forget the branch's related ticket")]
+        clear: bool,
+    },
+    
+    #[command(about = "Print a one-line summary of the ticket from current git branch")]
+    Status {
+        #[arg(long, help = "Only use locally cached ticket data (no network)")]
+        cached: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum MeetingsCommands {
+    #[command(about = "List today's meetings from Google Calendar")]
+    List {
+        #[arg(long, help = "Print one line per item using a template like \"{key}\\t{summary}\" or a name from [formats] instead of opening the interactive view")]
+        format: Option<String>,
+    },
+    
+    #[command(about = "Show the week's meetings in a grid, one column per day")]
+    Week,
+    
+    #[command(about = "Gather what to read before a meeting: tickets in the invite, notes from last time, and attendees' shared tickets")]
+    Prep {
+        #[arg(default_value = "next", help = "Meeting ID or 'next' for the next upcoming meeting")]
+        id: String,
+    },
+    
+    #[command(about = "Decline a meeting you were invited to")]
+    Decline {
+        #[arg(help = "Meeting ID or 'next' for the next upcoming meeting")]
+        id: String,
+        
+        #[arg(long, help = "Optional note to send to the organizer")]
+        note: Option<String>,
+    },
+    
+    #[command(about = "Send a notification for meetings starting soon (run from cron or a timer)")]
+    Remind {
+        #[arg(long, default_value_t = 10, help = "Remind about meetings starting within this many minutes")]
+        within: i64,
+    },
+    
+    #[command(about = "Show the signed-in Google account, its tokens, and the calendars it can see")]
+    Whoami,
+    
+    #[command(about = "List this week's meetings outside your working hours or during time off")]
+    AfterHours,
+    
+    #[command(about = "Report meeting load: hours in meetings, free blocks, and the biggest recurring meetings")]
+    Stats {
+        #[arg(long, default_value_t = 1, help = "Number of weeks to cover, ending with the current week")]
+        weeks: u32,
+        
+        #[arg(long, default_value_t = 5, help = "How many recurring meetings to list")]
+        top: usize,
+    },
+    
+    #[command(about = "Yesterday's meetings with the notes and recordings they left behind")]
+    Digest {
+        #[arg(long, help = "Day to cover (YYYY-MM-DD) instead of yesterday")]
+        date: Option<String>,
+    },
+    
+    #[command(about = "Cancel a meeting you organize and notify attendees")]
+    Cancel {
+        #[arg(help = "Meeting ID or 'next' for the next upcoming meeting")]
+        id: String,
+    },
+    
+    #[command(about = "Find a time you're both free and book a meeting with a Meet link")]
+    Book {
+        #[arg(help = "Attendee's email address")]
+        attendee: String,
+        
+        #[arg(default_value = "30m", help = "Meeting length, e.g. 30m, 1h, or 1h 30m")]
+        duration: String,
+        
+        #[arg(long, help = "Event title (defaults to \"1:1 with <name>\")")]
+        title: Option<String>,
+        
+        #[arg(long, default_value_t = 5, help = "How many days ahead to look for free time")]
+        days: i64,
+        
+        #[arg(long, help = "Jira ticket the meeting is about, shown with its status in 'qq meetings list'")]
+        ticket: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum NotifyCommands {
+    #[command(about = "Send a test notification to every configured backend")]
+    Test {
+        #[arg(help = "Message to send", default_value = "Notifications from qq are working")]
+        message: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthCommands {
+    #[command(about = "Google Calendar credentials")]
+    Google {
+        #[command(subcommand)]
+        command: GoogleAuthCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum GoogleAuthCommands {
+    #[command(about = "Sign in to Google now, e.g. after an interrupted sign-in")]
+    Login {
+        #[arg(long, help = "Named Google account to sign in")]
+        account: Option<String>,
+        
+        #[arg(long, help = "Paste the code back instead of waiting for a browser redirect to this machine")]
+        no_browser: bool,
+        
+        #[arg(long, help = "Also allow changing calendar events (decline, cancel, book), which is otherwise asked for on first use")]
+        write: bool,
+    },
+    
+    #[command(about = "Refresh saved Google tokens without opening a browser")]
+    Refresh {
+        #[arg(long, help = "Named Google account to refresh")]
+        account: Option<String>,
+    },
+    
+    #[command(about = "Delete saved Google tokens so the next calendar command signs in again")]
+    Logout {
+        #[arg(long, help = "Named Google account to sign out")]
+        account: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ListFieldCommands {
+    #[command(about = "Add it to the tickets")]
+    Add {
+        #[arg(help = "Label or component name")]
+        name: String,
+        
+        #[arg(help = "Tickets to change (defaults to the ticket from current git branch)")]
+        tickets: Vec<String>,
+    },
+    
+    #[command(about = "Remove it from the tickets")]
+    Remove {
+        #[arg(help = "Label or component name")]
+        name: String,
+        
+        #[arg(help = "Tickets to change (defaults to the ticket from current git branch)")]
+        tickets: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum EpicsCommands {
+    #[command(about = "Print every open epic with child counts, done percentage, points remaining, and last update")]
+    Export {
+        #[arg(long, help = "Print CSV for spreadsheets instead of an aligned table")]
+        csv: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum DependencyCommands {
+    #[command(about = "List my tickets whose blockers have all been resolved since I last checked")]
+    Ready {
+        #[arg(long, help = "Look back to 'yesterday' or a duration like 12h or 3d instead of the last check")]
+        since: Option<String>,
+        
+        #[arg(long, conflicts_with = "since", help = "List every ticket whose blockers are all resolved, however long ago")]
+        all: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    #[command(about = "Configure JIRA settings", subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
+    Jira {
+        #[command(subcommand)]
+        command: Option<JiraConfigCommands>,
+        
+        #[arg(long, required = true, help = "JIRA instance URL (e.g., https://company.atlassian.net)")]
+        url: Option<String>,
+        
+        #[arg(long, required = true, help = "JIRA username/email")]
+        username: Option<String>,
+        
+        #[arg(long, required = true, help = "JIRA API token")]
+        token: Option<String>,
+        
+        #[arg(long, help = "Save as a named profile instead of the default one")]
+        profile: Option<String>,
+    },
+    
+    #[command(about = "Configure Google Calendar settings")]
+    Google {
+        #[arg(long, help = "Google OAuth2 Client ID")]
+        client_id: String,
+        
+        #[arg(long, help = "Google OAuth2 Client Secret")]
+        client_secret: String,
+        
+        #[arg(long, help = "Use the device-code flow (for SSH sessions and headless machines)")]
+        device: bool,
+        
+        #[arg(long, conflicts_with = "device", help = "Sign in with a browser on any machine and paste the result back, instead of a local redirect")]
+        no_browser: bool,
+        
+        #[arg(long, help = "Save as a named account instead of the default one")]
+        account: Option<String>,
+    },
+    
+    #[command(about = "Configure the GitHub token used to open pull requests")]
+    Github {
+        #[arg(long, help = "Personal access token with pull request write access")]
+        token: String,
+        
+        #[arg(long, help = "API URL for GitHub Enterprise Server (e.g., https://github.example.com/api/v3)")]
+        api_url: Option<String>,
+    },
+    
+    #[command(about = "Edit the theme and working hours in an interactive settings screen")]
+    Ui,
+    
+    #[command(about = "Check config.toml for unknown keys, wrong types, and incomplete settings")]
+    Validate {
+        #[arg(long, help = "Fail on warnings (unknown or deprecated keys) too")]
+        strict: bool,
+    },
+    
+    #[command(about = "Move Jira API tokens, Google client secrets, and the GitHub token from config.toml into the OS keyring")]
+    MigrateSecrets {
+        #[arg(long, help = "Move them back from the keyring into config.toml")]
+        to_file: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum AliasCommands {
+    #[command(about = "List configured aliases")]
+    List,
+    
+    #[command(about = "Add an alias; pass several commands to run them in order")]
+    Add {
+        #[arg(help = "Alias name, run as 'qq <name>'")]
+        name: String,
+        
+        #[arg(required = true, help = "qq command line without the leading 'qq', e.g. \"jira search --saved standup\"")]
+        commands: Vec<String>,
+        
+        #[arg(long, help = "Replace an existing alias with the same name")]
+        force: bool,
+    },
+    
+    #[command(about = "Remove an alias")]
+    Remove {
+        #[arg(help = "Alias name")]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum JiraConfigCommands {
+    #[command(about = "Switch the active Jira profile (lists profiles when none is given)")]
+    Use {
+        #[arg(help = "Profile name, or 'default'")]
+        profile: Option<String>,
+    },
+}
+
+fn main() {
+    // The background half of `qq who --copy` on Linux
+    let result = if clipboard::serving() { clipboard::serve() } else { run_main() };
+    if let Err(e) = result {
+        style::print_error(&e);
+        std::process::exit(1);
+    }
+}
+
+fn run_main() -> Result<()> {
+    use clap::CommandFactory;
+    
+    let args: Vec<String> = std::env::args().collect();
+    style::init(args.iter().skip(1).take_while(|arg| *arg != "--").any(|arg| arg == "--no-color"));
+    
+    let config = Config::load().ok();
+    let theme = match &config {
+        Some(config) => ui::Theme::new(&config.ui.theme).unwrap_or_else(|e| {
+            style::warning(format!("{}. Using the default theme.", e));
+            ui::Theme::default()
+        }),
+        None => ui::Theme::default(),
+    };
+    style::set_theme(theme.clone());
+    // `qq config validate` reports these itself
+    if let Some(config) = &config
+        && !(args.get(1).is_some_and(|a| a == "config") && args.get(2).is_some_and(|a| a == "validate"))
+    {
+        config_check::warn_on_load(config);
+    }
+    
+    let (globals, rest) = alias::split_globals(&args[1..]);
+    if let Some(config) = &config
+        && let Some((name, extra)) = rest.split_first()
+        && let Some(alias) = alias::lookup(&config.alias, &Cli::command(), name)
+    {
+        return run_alias(name, alias, globals, extra, &theme);
+    }
+    
+    let cli = Cli::parse();
+    match cli.command {
+        Some(command) => run_command(command, Output::new(cli.json), &theme),
+        None => handle_palette(&theme),
+    }
+}
+
+fn run_command(command: Commands, output: Output, theme: &ui::Theme) -> Result<()> {
+    output.check_supported(&command)?;
+    match command {
+        Commands::Jira { repo, command } => {
+            if let Some(repo) = repo {
+                std::env::set_current_dir(&repo)
+                    .context(format!("Failed to switch to repository at {}", repo.display()))?;
+            }
+            handle_jira_command(command, output, theme)?;
+        }
+        
+        Commands::Meetings { account, command } => {
+            google::require()?;
+            handle_meetings_command(command, account.as_deref(), output, theme)?;
+        }
+        
+        Commands::Pr { command } => {
+            handle_pr_command(command)?;
+        }
+        
+        Commands::Today => {
+            handle_today_command(theme)?;
+        }
+        
+        Commands::Who { name, open, copy } => {
+            handle_who_command(&name.join(" "), open, copy, theme)?;
+        }
+        
+        Commands::Notify { command: NotifyCommands::Test { message } } => {
+            let config = Config::load()?;
+            // Sent like any ticket alert, so it's held during meetings too
+            let notification = notify::Notification {
+                title: "qq".to_string(),
+                body: message,
+                url: None,
+                urgent: false,
+            };
+            match notify::send(&config.notify, &config.do_not_disturb, &notification)? {
+                notify::Delivery::Sent => println!("Test notification sent."),
+                notify::Delivery::Held { until } => println!(
+                    "You're in a meeting, so the notification is held until {}.",
+                    until.with_timezone(&chrono::Local).format("%H:%M")
+                ),
+            }
+        }
+        
+        Commands::Auth { command } => {
+            google::require()?;
+            handle_auth_command(command)?;
+        }
+        
+        Commands::Config { command } => {
+            handle_config_command(command)?;
+        }
+        
+        Commands::Alias { command } => {
+            handle_alias_command(command)?;
+        }
+        
+        Commands::Use { profile } => {
+            handle_use_command(profile)?;
+        }
+        
+        Commands::Version { check } => {
+            handle_version_command(check)?;
+        }
+        
+        Commands::Changelog => {
+            use ui::ChangelogDisplay;
+            
+            warn_if_config_outdated();
+            println!("{}", style::muted("Fetching release notes..."));
+            let releases = release::fetch_releases()?;
+            
+            if releases.is_empty() {
+                println!("No releases published yet.");
+            } else {
+                ChangelogDisplay::show(&releases, theme)?;
+            }
+        }
+        
+        Commands::ShellHook { shell } => {
+            print!("{}", shell::hook_script(shell));
+        }
+    }
+    
+    Ok(())
+}
+
+// Runs each step as its own qq invocation, stopping at the first one that fails
+fn run_alias(name: &str, alias: &alias::Alias, globals: &[String], extra: &[String], theme: &ui::Theme) -> Result<()> {
+    let steps = alias::expand(alias, globals, extra);
+    let total = steps.len();
+    
+    for (i, args) in steps.into_iter().enumerate() {
+        if total > 1 {
+            eprintln!("→ qq {}", args.join(" "));
+        }
+        let cli = Cli::try_parse_from(std::iter::once("qq".to_string()).chain(args.clone()))
+            .unwrap_or_else(|e| {
+                // --help and --version come through here too
+                if e.use_stderr() {
+                    eprintln!("Alias '{}' runs 'qq {}', which isn't a valid command:", name, args.join(" "));
+                }
+                e.exit()
+            });
+        if let Some(command) = cli.command {
+            run_command(command, Output::new(cli.json), theme)
+                .context(format!("Alias '{}' stopped at step {} of {}", name, i + 1, total))?;
+        }
+    }
+    
+    Ok(())
+}
+
+fn handle_alias_command(command: AliasCommands) -> Result<()> {
+    use clap::CommandFactory;
+    
+    match command {
+        AliasCommands::List => {
+            let config = Config::load()?;
+            if config.alias.is_empty() {
+                println!("No aliases configured. Add one with 'qq alias add <name> \"<command>\"'.");
+                return Ok(());
+            }
+            
+            let mut names: Vec<&String> = config.alias.keys().collect();
+            names.sort();
+            let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+            for name in names {
+                println!("{:width$}  qq {}", name, config.alias[name].steps().join(" && qq "), width = width);
+            }
+        }
+        AliasCommands::Add { name, commands, force } => {
+            if name.is_empty() || name.starts_with('-') || name.contains(char::is_whitespace) {
+                anyhow::bail!("Alias names can't be empty, start with '-', or contain spaces");
+            }
+            if alias::is_builtin(&Cli::command(), &name) {
+                anyhow::bail!("'{}' is a built-in qq command and can't be used as an alias", name);
+            }
+            // Steps run through the normal parser, so a leading "qq" is almost always a copy-paste slip
+            let commands: Vec<String> = commands.into_iter()
+                .map(|command| command.trim().trim_start_matches("qq ").trim().to_string())
+                .collect();
+            
+            let mut config = Config::load()?;
+            if config.alias.contains_key(&name) && !force {
+                anyhow::bail!("Alias '{}' already exists. Use --force to replace it.", name);
+            }
+            let new_alias = alias::Alias::from_steps(commands);
+            println!("Added alias '{}': qq {}", name, new_alias.steps().join(" && qq "));
+            config.alias.insert(name, new_alias);
+            config.save()?;
+        }
+        AliasCommands::Remove { name } => {
+            let mut config = Config::load()?;
+            if config.alias.remove(&name).is_none() {
+                anyhow::bail!("No alias named '{}'", name);
+            }
+            config.save()?;
+            println!("Removed alias '{}'", name);
+        }
+    }
+    
+    Ok(())
+}
+
+fn handle_palette(theme: &ui::Theme) -> Result<()> {
+    use clap::CommandFactory;
+    use palette::PaletteAction;
+    use std::io::{BufRead, Write};
+    
+    let mut entries = palette::command_entries(&Cli::command());
+    if let Ok(config) = Config::load() {
+        entries.extend(palette::alias_entries(&config.alias));
+    }
+    entries.extend(palette::ticket_entries(&IssueCache::load(), 10));
+    // Meetings only show up when Google Calendar is configured, as on the today dashboard
+    if Config::load().is_ok_and(|config| config.google_account(None).is_ok())
+        && let Ok(calendar) = calendar_client(None)
+        && let Ok(meetings) = calendar.list_meetings()
+    {
+        entries.extend(palette::meeting_entries(&meetings, chrono::Utc::now()));
+    }
+    
+    let Some(idx) = ui::CommandPalette::show(&entries, theme)? else {
+        return Ok(());
+    };
+    
+    match entries[idx].action.clone() {
+        PaletteAction::OpenUrl(url) => {
+            webbrowser::open(&url).context("Failed to open browser")?;
+            Ok(())
+        }
+        PaletteAction::Run { mut args, needs_input } => {
+            if needs_input {
+                print!("qq {} ", args.join(" "));
+                std::io::stdout().flush()?;
+                let mut line = String::new();
+                std::io::stdin().lock().read_line(&mut line)?;
+                args.extend(palette::split_args(&line));
+            }
+            
+            if let Ok(config) = Config::load()
+                && let Some((name, rest)) = args.split_first()
+                && let Some(alias) = alias::lookup(&config.alias, &Cli::command(), name)
+            {
+                return run_alias(name, alias, &[], rest, theme);
+            }
+            
+            let cli = Cli::try_parse_from(std::iter::once("qq".to_string()).chain(args))
+                .unwrap_or_else(|e| e.exit());
+            match cli.command {
+                Some(command) => run_command(command, Output::new(cli.json), theme),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+fn warn_if_config_outdated() {
+    if let Ok(config) = Config::load()
+        && config.is_outdated()
+    {
+        eprintln!(
+            "Warning: your config was written by an older version of qq (schema v{}, expected v{}). \
+             Re-run 'qq config jira' or 'qq jira fields --pin' to pick up new settings.",
+            config.config_version,
+            config::CONFIG_VERSION
+        );
+    }
+}
+
+fn handle_version_command(check: bool) -> Result<()> {
+    let current = release::current_version();
+    println!("qq {}", current);
+    let features: Vec<&str> = [("jira", true), ("calendar", cfg!(feature = "calendar")), ("keyring", cfg!(feature = "keyring"))]
+        .into_iter()
+        .filter_map(|(name, built)| built.then_some(name))
+        .collect();
+    println!("{}", style::muted(format!("Features: {}", features.join(", "))));
+    
+    if !check {
+        return Ok(());
+    }
+    
+    warn_if_config_outdated();
+    
+    let latest = release::fetch_latest()?;
+    if release::is_newer(&latest.tag_name, current) {
+        println!("A newer version is available: {} ({})", latest.tag_name, latest.html_url);
+        println!("Run 'qq changelog' to see what's new.");
+    } else {
+        println!("You're on the latest version.");
+    }
+    
+    Ok(())
+}
+
+fn handle_config_command(command: ConfigCommands) -> Result<()> {
+    match command {
+        ConfigCommands::Jira { command: Some(JiraConfigCommands::Use { profile }), .. } => {
+            handle_use_command(profile)?;
+        }
+        ConfigCommands::Jira { command: None, url, username, token, profile } => {
+            use config::JiraProfile;
+            
+            // clap enforces these when no subcommand is given
+            let jira = JiraProfile {
+                jira_url: url.context("--url is required")?,
+                username: username.context("--username is required")?,
+                api_token: token.context("--token is required")?,
+            };
+            
+            // An existing config that fails to load (like a locked keyring) is reported, not replaced
+            let mut config = if Config::config_path()?.exists() {
+                Config::load()?
+            } else {
+                Config::new(String::new(), String::new(), String::new())
+            };
+            config.set_jira_profile(profile.clone(), jira);
+            config.save()?;
+            match profile {
+                Some(name) => println!("JIRA profile '{}' saved. Switch to it with 'qq use {}'.", name, name),
+                None => println!("{}", style::success("JIRA configuration saved successfully!")),
+            }
+        }
+        ConfigCommands::Google { client_id, client_secret, device, no_browser, account } => {
+            use config::{GoogleAccount, GoogleAuthFlow};
+            
+            google::require()?;
+            let mut config = if Config::config_path()?.exists() {
+                Config::load()?
+            } else {
+                Config::new(String::new(), String::new(), String::new())
+            };
+            let flow = match (device, no_browser) {
+                (true, _) => GoogleAuthFlow::Device,
+                (_, true) => GoogleAuthFlow::Paste,
+                _ => GoogleAuthFlow::Installed,
+            };
+            config.set_google_account(account, GoogleAccount { client_id, client_secret, flow })?;
+            config.save()?;
+            println!("{}", style::success("Google Calendar configuration saved successfully!"));
+            if device {
+                println!("You'll be shown a code to enter at google.com/device the first time qq accesses your calendar.");
+            }
+            if no_browser {
+                println!("The first time qq accesses your calendar, you'll be given a URL to open and asked to paste back where it ends up.");
+            }
+        }
+        ConfigCommands::Github { token, api_url } => {
+            let mut config = if Config::config_path()?.exists() {
+                Config::load()?
+            } else {
+                Config::new(String::new(), String::new(), String::new())
+            };
+            config.github = Some(config::GitHubConfig { token, api_url });
+            config.save()?;
+            println!("{}", style::success("GitHub configuration saved successfully!"));
+        }
+        ConfigCommands::Ui => {
+            let mut config = Config::load()?;
+            let Some((theme, hours)) = ui::SettingsForm::show(&config.ui.theme, &config.working_hours)? else {
+                println!("{}", style::muted("No changes saved"));
+                return Ok(());
+            };
+            config.ui.theme = theme;
+            config.working_hours = hours;
+            config.save()?;
+            println!("{}", style::success(format!("✓ Settings saved to {}", Config::config_path()?.display())));
+        }
+        ConfigCommands::Validate { strict } => {
+            let path = Config::config_path()?;
+            let contents = std::fs::read_to_string(&path)
+                .context(format!("Failed to read {}. Run 'qq config jira' to create it.", path.display()))?;
+            println!("{}", style::muted(format!("Checking {}", path.display())));
+            
+            let report = config_check::check(&contents);
+            for error in &report.errors {
+                println!("{} {}", style::failure("✗"), error);
+            }
+            for warning in &report.warnings {
+                println!("{} {}", style::warn("!"), warning);
+            }
+            
+            let failed = !report.errors.is_empty() || (strict && !report.warnings.is_empty());
+            if failed {
+                anyhow::bail!("Config has {} error(s) and {} warning(s)", report.errors.len(), report.warnings.len());
+            }
+            if report.warnings.is_empty() {
+                println!("{}", style::success("✓ Config is valid"));
+            } else {
+                println!("{}", style::success(format!("✓ Config is usable, with {} warning(s)", report.warnings.len())));
+            }
+        }
+        ConfigCommands::MigrateSecrets { to_file } => {
+            use config::SecretStore;
+            
+            let mut config = Config::load()?;
+            let target = if to_file { SecretStore::File } else { SecretStore::Keyring };
+            if config.secret_store == target {
+                match target {
+                    SecretStore::Keyring => println!("Secrets are already kept in the keyring."),
+                    SecretStore::File => println!("Secrets are already kept in config.toml."),
+                }
+                return Ok(());
+            }
+            
+            let moved = config.migrate_secrets(to_file)?;
+            if to_file {
+                println!("{}", style::success(format!("✓ Moved {} secret(s) from the keyring back into config.toml", moved)));
+            } else {
+                println!("{}", style::success(format!("✓ Moved {} secret(s) into the keyring", moved)));
+                println!("config.toml now holds \"<keyring>\" in their place. Other settings can still be edited there as usual.");
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+// Applies `qq jira label`/`component` in batches, printing each ticket as it finishes
+fn edit_list_field(
+    client: &jira::JiraClient,
+    field: jira::ListField,
+    command: ListFieldCommands,
+    current_ticket_id: impl Fn() -> Result<String>,
+) -> Result<()> {
+    let (add, name, mut tickets) = match command {
+        ListFieldCommands::Add { name, tickets } => (true, name, tickets),
+        ListFieldCommands::Remove { name, tickets } => (false, name, tickets),
+    };
+    field.validate(&name)?;
+    if tickets.is_empty() {
+        tickets.push(current_ticket_id()?);
+    }
+    
+    let total = tickets.len();
+    let mut failed = 0;
+    for (batch_idx, batch) in tickets.chunks(jira::BULK_EDIT_BATCH_SIZE).enumerate() {
+        for (offset, (ticket, result)) in batch.iter().zip(client.edit_list_field(batch, field, add, &name)).enumerate() {
+            let position = format!("[{}/{}]", batch_idx * jira::BULK_EDIT_BATCH_SIZE + offset + 1, total);
+            match result {
+                Ok(()) => println!("{} {}", style::muted(position), style::success(format!("✓ {}", ticket))),
+                Err(e) => {
+                    failed += 1;
+                    println!("{} {}", style::muted(position), style::failure(format!("✗ {}: {:#}", ticket, e)));
+                }
+            }
+        }
+    }
+    
+    let (verb, preposition) = if add { ("Added", "to") } else { ("Removed", "from") };
+    if failed > 0 {
+        anyhow::bail!("{} {} '{}' {} {} of {} ticket(s); {} failed", verb, field.name(), name, preposition, total - failed, total, failed);
+    }
+    println!("{} {} '{}' {} {} ticket(s)", verb, field.name(), name, preposition, total);
+    Ok(())
+}
+
+fn print_verify_report(report: &verify::Report) {
+    println!("{}", style::heading(format!("Pre-close checks for {}:", report.key)));
+    if report.results.is_empty() {
+        println!("  {}", style::muted("(every check is turned off in [verify])"));
+    }
+    for result in &report.results {
+        let line = result.line();
+        let line = match result.outcome {
+            verify::Outcome::Pass => style::success(line),
+            verify::Outcome::Unknown => style::muted(line),
+            verify::Outcome::Fail if result.blocks() => style::failure(line),
+            verify::Outcome::Fail => style::warn(line),
+        };
+        println!("  {}", line);
+    }
+}
+
+fn calendar_client(account: Option<&str>) -> Result<google::BlockingCalendarClient> {
+    let config = Config::load()?;
+    let google_account = config.google_account(account)?;
+    let token_path = Config::google_token_path(account)?;
+    
+    let client = google::BlockingCalendarClient::new(&google_account, token_path)?;
+    client.ensure_authorized(std::io::IsTerminal::is_terminal(&std::io::stdin()))?;
+    
+    Ok(client
+        .with_rules(config.meeting_rules)
+        .with_working_hours(config.working_hours))
+}
+
+// Mirrors snoozes into the calendar when `[reminders] calendar` is on. Failures only warn, since
+// the snooze itself is already saved and the next sync catches up.
+fn sync_reminders() {
+    let Ok(config) = Config::load() else {
+        return;
+    };
+    if !config.reminders.calendar {
+        return;
+    }
+    let synced = calendar_client(config.reminders.account.as_deref()).and_then(|calendar| {
+        calendar.ensure_write_access(std::io::IsTerminal::is_terminal(&std::io::stdin()), "Adding reminders to the calendar")?;
+        snooze::sync_calendar(&calendar)
+    });
+    if let Err(e) = synced {
+        style::warning(format!("couldn't update calendar reminders: {}", e));
+    }
+}
+
+// Asks for a new API token when Jira rejects the saved one, instead of failing later with a 401
+fn ensure_jira_auth(mut config: Config) -> Result<Config> {
+    use std::io::{BufRead, IsTerminal, Write};
+    
+    const TOKEN_URL: &str = "https://id.atlassian.com/manage-profile/security/api-tokens";
+    
+    loop {
+        // Network problems aren't credential problems; let the command report them itself
+        if jira::JiraClient::new(config.clone()).check_auth().unwrap_or(true) {
+            return Ok(config);
+        }
+        
+        let label = config.jira_label();
+        if !std::io::stdin().is_terminal() {
+            anyhow::bail!(
+                "JIRA rejected the API token for {} (expired or revoked). Create a new one at {} and run 'qq config jira' again.",
+                label, TOKEN_URL
+            );
+        }
+        
+        eprintln!("JIRA rejected the API token for {}. It may have expired or been revoked.", label);
+        eprintln!("Create a new token at {}", TOKEN_URL);
+        eprint!("Paste the new API token (leave empty to cancel): ");
+        std::io::stderr().flush()?;
+        
+        let mut input = String::new();
+        std::io::stdin().lock().read_line(&mut input)?;
+        let token = input.trim();
+        if token.is_empty() {
+            anyhow::bail!("Cancelled. JIRA credentials for {} were not updated.", label);
+        }
+        
+        let mut profile = config.active_jira();
+        profile.api_token = token.to_string();
+        config.set_jira_profile(config.active_jira_profile.clone(), profile);
+        config.save()?;
+        eprintln!("Saved the new token, checking it...");
+    }
+}
+
+fn handle_use_command(profile: Option<String>) -> Result<()> {
+    let mut config = Config::load()?;
+    
+    let Some(profile) = profile else {
+        let mut names: Vec<&str> = config.jira_profiles.keys().map(|n| n.as_str()).collect();
+        names.sort();
+        names.insert(0, config::DEFAULT_JIRA_PROFILE);
+        
+        for name in names {
+            let marker = if name == config.active_jira_profile_name() { "*" } else { " " };
+            let url = match config.jira_profiles.get(name) {
+                Some(profile) => &profile.jira_url,
+                None => &config.jira_url,
+            };
+            println!("{} {} ({})", marker, name, url);
+        }
+        return Ok(());
+    };
+    
+    config.use_jira_profile(&profile)?;
+    config.save()?;
+    println!("Now using Jira profile {}", config.jira_label());
+    
+    Ok(())
+}
+
+fn handle_auth_command(command: AuthCommands) -> Result<()> {
+    match command {
+        AuthCommands::Google { command: GoogleAuthCommands::Login { account, no_browser, write } } => {
+            let config = Config::load()?;
+            let mut google_account = config.google_account(account.as_deref())?;
+            if no_browser {
+                google_account.flow = config::GoogleAuthFlow::Paste;
+            }
+            let token_path = Config::google_token_path(account.as_deref())?;
+            
+            let client = google::BlockingCalendarClient::new(&google_account, token_path)?;
+            client.ensure_authorized(true)?;
+            if write {
+                client.ensure_write_access(true, "Declining, cancelling, and booking meetings")?;
+                println!("{}", style::success("Signed in to Google Calendar with permission to change events."));
+            } else {
+                println!("{}", style::success("Signed in to Google Calendar."));
+            }
+        }
+        
+        AuthCommands::Google { command: GoogleAuthCommands::Refresh { account } } => {
+            let config = Config::load()?;
+            let google_account = config.google_account(account.as_deref())?;
+            let token_path = Config::google_token_path(account.as_deref())?;
+            
+            println!("{}", style::muted("Refreshing Google tokens..."));
+            google::refresh_tokens(&google_account, token_path)?;
+            println!("{}", style::success("Google tokens refreshed successfully!"));
+        }
+        
+        AuthCommands::Google { command: GoogleAuthCommands::Logout { account } } => {
+            let token_path = Config::google_token_path(account.as_deref())?;
+            let name = account.as_deref().unwrap_or("default");
+            
+            if google::logout(&token_path)? {
+                println!("Signed out of the {} Google account. The next calendar command will ask you to sign in.", name);
+            } else {
+                println!("No saved Google tokens for the {} account.", name);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+fn handle_pr_command(command: PrCommands) -> Result<()> {
+    use jira::JiraClient;
+    
+    let PrCommands::Create { ticket, base, draft, body } = command;
+    
+    let config = ensure_jira_auth(Config::load()?)?;
+    let github = config.github.clone();
+    let client = JiraClient::new(config);
+    
+    let branch = get_current_branch()?;
+    if branch == "HEAD" {
+        anyhow::bail!("Check out the ticket's branch first; a detached HEAD can't be the head of a pull request");
+    }
+    let ticket_id = match ticket {
+        Some(ticket) => ticket,
+        None => current_ticket_id()?,
+    };
+    let issue = client.get_issue(&ticket_id)?;
+    open_pull_request(&client, github.as_ref(), &issue, &branch, base.as_deref(), body.as_deref(), draft)
+}
+
+// Pushes the ticket's branch, opens its pull request, and links the PR in a comment on the
+// ticket. Shared by `qq pr create` and `qq jira start --draft-pr`.
+fn open_pull_request(
+    client: &jira::JiraClient,
+    github: Option<&config::GitHubConfig>,
+    issue: &jira::JiraIssue,
+    branch: &str,
+    base: Option<&str>,
+    body: Option<&str>,
+    draft: bool,
+) -> Result<()> {
+    let repo = git2::Repository::discover(".").context("Not inside a git repository")?;
+    let title = format!("{}: {}", issue.key, issue.fields.summary);
+    
+    // GitHub and GitLab won't open a pull request for a branch with nothing new on it
+    if !git::has_commits_ahead_of_default(&repo, branch)? {
+        git::commit_empty(&repo, &format!("Start {}", title))?;
+        println!("Added an empty commit so the pull request has something to show");
+    }
+    git::push_with_upstream(&repo, branch)?;
+    println!("Pushed '{}' to origin and set it as upstream", branch);
+    
+    let jira_link = format!("Jira: {}", client.browse_url(&issue.key));
+    let body = match body.map(str::trim).filter(|b| !b.is_empty()) {
+        Some(body) => format!("{}\n\n{}", body, jira_link),
+        None => jira_link,
+    };
+    let kind = if draft { "Draft pull request" } else { "Pull request" };
+    println!("{}", style::muted(format!("Opening a {}...", kind.to_lowercase())));
+    let request = forge::PrRequest { branch, base, title: &title, body: &body, draft };
+    let pr = forge::open_pr(&repo, github, &request)?;
+    if !pr.created {
+        println!("A pull request is already open for '{}': {}", branch, pr.url);
+        return Ok(());
+    }
+    println!("{}", style::success(format!("{} opened: {}", kind, pr.url)));
+    
+    client.add_comment_adf(&issue.key, jira::adf::with_link(&format!("{}: ", kind), &pr.url))
+        .context(format!("The pull request is open, but linking it on {} failed", issue.key))?;
+    println!("Linked it in a comment on {}", style::issue(&issue.key));
+    Ok(())
+}
+
+fn handle_today_command(theme: &ui::Theme) -> Result<()> {
+    use jira::JiraClient;
+    use ui::TodayDisplay;
+    
+    let config = ensure_jira_auth(Config::load()?)?;
+    let has_calendar = config.google_account(None).is_ok();
+    ui::set_jira_label(config.jira_label());
+    style::set_jira_url(&config.active_jira().jira_url);
+    sla::init(&config.sla);
+    let client = JiraClient::new(config);
+    let calendar = if has_calendar { calendar_client(None).ok() } else { None };
+    
+    println!("{}", style::muted("Fetching today's dashboard..."));
+    let mut summary = today::TodaySummary::fetch(&client, calendar.as_ref())?;
+    IssueCache::record(&summary.issues.iter().collect::<Vec<_>>());
+    
+    let snoozes = snooze::SnoozeStore::load();
+    summary.issues.retain(|issue| !snoozes.is_snoozed(&issue.key));
+    
+    TodayDisplay::show(summary, &client, calendar.as_ref(), theme)
+}
+
+fn handle_who_command(query: &str, open: bool, copy: bool, theme: &ui::Theme) -> Result<()> {
+    use jira::JiraClient;
+    use std::io::IsTerminal;
+    
+    let config = ensure_jira_auth(Config::load()?)?;
+    let client = JiraClient::new(config);
+    
+    let mut people = client.search_users(query)?;
+    let person = match people.len() {
+        0 => anyhow::bail!("No one in the Jira directory matches '{}'", query),
+        1 => people.remove(0),
+        _ if std::io::stdin().is_terminal() => {
+            let labels: Vec<String> = people.iter()
+                .map(|p| match &p.email_address {
+                    Some(email) => format!("{} <{}>", p.display_name, email),
+                    None => p.display_name.clone(),
+                })
+                .collect();
+            match ui::ListPicker::show(&format!("People matching '{}'", query), labels, theme)? {
+                Some(idx) => people.swap_remove(idx),
+                None => return Ok(()),
+            }
+        }
+        n => {
+            for p in &people {
+                println!("{}\t{}", p.display_name, p.email_address.as_deref().unwrap_or(""));
+            }
+            anyhow::bail!("{} people match '{}'; narrow the search", n, query);
+        }
+    };
+    
+    println!("{}", style::heading(&person.display_name));
+    if !person.active {
+        println!("{}", style::warn("Deactivated account"));
+    }
+    println!("Account ID: {}", person.account_id);
+    println!("Email:      {}", person.email_address.as_deref().unwrap_or("(hidden by their privacy settings)"));
+    match person.time_zone.as_deref() {
+        Some(zone) => match zone.parse::<chrono_tz::Tz>() {
+            Ok(tz) => println!("Time zone:  {} (their time now: {})", zone, chrono::Utc::now().with_timezone(&tz).format("%a %H:%M")),
+            Err(_) => println!("Time zone:  {}", zone),
+        },
+        None => println!("Time zone:  (unknown)"),
+    }
+    let jql = format!("assignee = \"{}\" AND statusCategory != Done", person.account_id);
+    match client.count_jql(&jql) {
+        Ok(count) => println!("Assigned:   {} open ticket(s)", count),
+        Err(e) => println!("Assigned:   {}", style::muted(format!("couldn't count ({})", e))),
+    }
+    let profile_url = client.profile_url(&person.account_id);
+    println!("Profile:    {}", profile_url);
+    
+    if copy {
+        let contact = match &person.email_address {
+            Some(email) => format!("{} <{}>", person.display_name, email),
+            None => person.display_name.clone(),
+        };
+        clipboard::copy_and_exit(&contact)?;
+        println!("{}", style::success(format!("✓ Copied {}", contact)));
+    }
+    if open {
+        webbrowser::open(&profile_url).context("Failed to open browser")?;
+    }
+    
+    Ok(())
+}
+
+fn handle_meetings_command(command: MeetingsCommands, account: Option<&str>, output: Output, theme: &ui::Theme) -> Result<()> {
+    use google::{BlockingCalendarClient, Meeting};
+    use ui::{MeetingsListDisplay, MeetingsWeekDisplay};
+    
+    // Helper functions for meetings commands
+    fn find_meeting(client: &BlockingCalendarClient, id: &str) -> Result<Meeting> {
+        let meetings = client.list_meetings()?;
+        
+        if id == "next" {
+            let now = chrono::Utc::now();
+            meetings.into_iter()
+                .find(|m| m.start_time > now)
+                .context("No upcoming meetings found")
+        } else {
+            meetings.into_iter()
+                .find(|m| m.id == id)
+                .context(format!("No meeting found with ID: {}", id))
+        }
+    }
+    
+    match command {
+        MeetingsCommands::List { format } => {
+            output.check_format(&format)?;
+            let client = calendar_client(account)?;
+            
+            if output.is_json() {
+                return Output::print_json(&client.list_meetings()?);
+            }
+            if let Some(format) = format {
+                let template = output::resolve_format(&format, &Config::load()?.formats);
+                let rows = client.list_meetings()?.iter().map(output::meeting_values).collect();
+                return output::print_rows(&template, rows, output::MEETING_PLACEHOLDERS);
+            }
+            
+            println!("{}", style::muted("Fetching meetings from Google Calendar..."));
+            let meetings = client.list_meetings()?;
+            
+            if meetings.is_empty() {
+                println!("No meetings scheduled for the next 7 days.");
+            } else {
+                println!("Found {} meeting(s).", meetings.len());
+                // Linked ticket statuses are a bonus; the list works without Jira configured
+                let jira = Config::load().ok().map(jira::JiraClient::new);
+                MeetingsListDisplay::show(meetings, &client, jira.as_ref(), theme)?;
+            }
+        }
+        
+        MeetingsCommands::Week => {
+            let client = calendar_client(account)?;
+            println!("{}", style::muted("Fetching this week's meetings from Google Calendar..."));
+            MeetingsWeekDisplay::show(&client, theme)?;
+        }
+        
+        MeetingsCommands::Prep { id } => {
+            let client = calendar_client(account)?;
+            let meeting = find_meeting(&client, &id)?;
+            
+            // Without Jira the prep screen still shows last time's notes
+            let config = Config::load()?;
+            let jira = if config.active_jira().jira_url.is_empty() {
+                None
+            } else {
+                Some(jira::JiraClient::new(ensure_jira_auth(config)?))
+            };
+            
+            println!("{}", style::muted(format!("Gathering prep for {}...", meeting.summary)));
+            let prep = prep::MeetingPrep::gather(&client, jira.as_ref(), meeting)?;
+            IssueCache::record(&prep.tickets.iter().collect::<Vec<_>>());
+            ui::MeetingPrepDisplay::show(&prep, theme)?;
+        }
+        
+        MeetingsCommands::Stats { weeks, top } => {
+            if weeks == 0 {
+                anyhow::bail!("--weeks must be at least 1");
+            }
+            let client = calendar_client(account)?;
+            let working_hours = Config::load()?.working_hours;
+            
+            let (from, to) = meeting_stats::window(weeks);
+            output.progress(format!("Fetching meetings from {} to {}...", from, to));
+            let meetings = client.list_meetings_between(
+                meeting_stats::start_of(from).with_timezone(&chrono::Utc),
+                meeting_stats::start_of(to + chrono::Duration::days(1)).with_timezone(&chrono::Utc),
+            )?;
+            let stats = meeting_stats::compute(&meetings, &working_hours, from, to, top);
+            
+            if output.is_json() {
+                Output::print_json(&stats)?;
+            } else {
+                ui::MeetingStatsDisplay::show(&stats, theme)?;
+            }
+        }
+        
+        MeetingsCommands::Digest { date } => {
+            let day = match date {
+                Some(date) => chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                    .context(format!("Invalid date '{}'; use YYYY-MM-DD", date))?,
+                None => chrono::Local::now().date_naive() - chrono::Duration::days(1),
+            };
+            let client = calendar_client(account)?;
+            output.progress(format!("Fetching meetings on {}...", day));
+            let meetings: Vec<Meeting> = client.list_meetings_between(
+                meeting_stats::start_of(day).with_timezone(&chrono::Utc),
+                meeting_stats::start_of(day + chrono::Duration::days(1)).with_timezone(&chrono::Utc),
+            )?
+            .into_iter()
+            .filter(|m| !m.is_declined && !m.is_all_day && !m.is_out_of_office && m.status != "cancelled")
+            .collect();
+            
+            if output.is_json() {
+                return Output::print_json(&meetings);
+            }
+            if meetings.is_empty() {
+                println!("No meetings on {}.", day.format("%a %b %-d"));
+                return Ok(());
+            }
+            
+            println!("{}", style::heading(format!("Meetings on {}", day.format("%a %b %-d"))));
+            for meeting in &meetings {
+                println!();
+                println!("{}  {}", style::muted(meeting.start_time.with_timezone(&chrono::Local).format("%H:%M")), style::meeting(&meeting.summary, meeting.meeting_url.as_deref()));
+                match meeting.follow_up_label() {
+                    Some(label) => println!("       {}", style::success(label)),
+                    None => println!("       {}", style::muted("No notes or recording")),
+                }
+                for attachment in &meeting.attachments {
+                    println!("       {:<11} {}", attachment.kind().label(), attachment.url);
+                }
+            }
+        }
+        
+        MeetingsCommands::Whoami => {
+            let config = Config::load()?;
+            let google_account = config.google_account(account)?;
+            let token_path = Config::google_token_path(account)?;
+            
+            println!("Account:     {}", account.unwrap_or("default"));
+            println!("Client ID:   {}", google_account.client_id);
+            let flow = match google_account.flow {
+                config::GoogleAuthFlow::Installed => "browser redirect",
+                config::GoogleAuthFlow::Device => "device code",
+                config::GoogleAuthFlow::Paste => "pasted code",
+            };
+            println!("Sign-in:     {}", flow);
+            println!("Token file:  {}", token_path.display());
+            
+            let tokens = google::saved_tokens(&token_path);
+            if tokens.is_empty() {
+                println!();
+                println!("Not signed in. Run 'qq auth google login' to sign in.");
+                return Ok(());
+            }
+            
+            println!();
+            println!("Tokens:");
+            let now = chrono::Utc::now();
+            let span = |d: chrono::Duration| match d.num_hours() {
+                0 => format!("{}m", d.num_minutes()),
+                h if h < 48 => format!("{}h", h),
+                _ => format!("{}d", d.num_days()),
+            };
+            for token in &tokens {
+                let expiry = match token.expires_at {
+                    Some(at) if at > now => format!("expires in {}", span(at - now)),
+                    Some(at) => format!("expired {} ago", span(now - at)),
+                    None => "no expiry".to_string(),
+                };
+                let refresh = if token.refreshable { "refreshable" } else { "no refresh token" };
+                let scopes: Vec<&str> = token.scopes.iter()
+                    .map(|s| s.trim_start_matches("https://www.googleapis.com/auth/"))
+                    .collect();
+                println!("  {:<40} {}, {}", scopes.join(" "), expiry, refresh);
+            }
+            
+            // Never start a sign-in from here; this command is for finding out why one is needed
+            let client = BlockingCalendarClient::new(&google_account, token_path)?;
+            if let Err(e) = client.ensure_authorized(false) {
+                println!();
+                println!("Signed in as: unknown ({:#})", e);
+                return Ok(());
+            }
+            
+            let calendars = client.list_calendars()?;
+            let identity = calendars.iter()
+                .find(|c| c.primary)
+                .map(|c| c.id.as_str())
+                .unwrap_or("unknown");
+            println!();
+            println!("Signed in as: {}", identity);
+            println!();
+            println!("Calendars ({}):", calendars.len());
+            for calendar in &calendars {
+                let marker = if calendar.primary { "*" } else { " " };
+                println!("  {} {}", marker, calendar.name);
+            }
+        }
+        
+        MeetingsCommands::Decline { id, note } => {
+            let client = calendar_client(account)?;
+            let meeting = find_meeting(&client, &id)?;
+            
+            if meeting.is_organizer {
+                anyhow::bail!("You organize '{}'. Use 'qq meetings cancel' to cancel it for all attendees.", meeting.summary);
+            }
+            client.ensure_write_access(std::io::IsTerminal::is_terminal(&std::io::stdin()), "Declining a meeting")?;
+            
+            println!("Declining meeting: {}", meeting.summary);
+            client.decline_meeting(&meeting.id, note.as_deref())?;
+            println!("{}", style::success("Meeting declined successfully!"));
+        }
+        
+        MeetingsCommands::Remind { within } => {
+            let config = Config::load()?;
+            let client = calendar_client(account)?;
+            let meetings = client.list_meetings()?;
+            
+            // Reminders run often, so they're what delivers notifications held during a meeting,
+            // and what catches calendar reminders up with snoozes changed in the interactive views
+            notify::flush_held(&config.notify, &config.do_not_disturb)?;
+            sync_reminders();
+            
+            let now = chrono::Utc::now();
+            let horizon = now + chrono::Duration::minutes(within);
+            notify::SentLog::edit(|sent| -> Result<()> {
+                for meeting in meetings.iter().filter(|m| m.start_time > now && m.start_time <= horizon && !m.is_low_priority()) {
+                    let key = format!("meeting:{}:{}", meeting.id, meeting.start_time.timestamp());
+                    if sent.was_sent(&key) {
+                        continue;
+                    }
+                    
+                    let minutes = (meeting.start_time.with_timezone(&chrono::Utc) - now).num_minutes();
+                    let notification = notify::Notification {
+                        title: format!("{} starts in {} min", meeting.summary, minutes.max(1)),
+                        body: meeting.time_range(),
+                        url: meeting.meeting_url.clone(),
+                        urgent: true,
+                    };
+                    notify::send(&config.notify, &config.do_not_disturb, &notification)?;
+                    sent.mark_sent(&key);
+                }
+                Ok(())
+            })??;
+        }
+        
+        MeetingsCommands::AfterHours => {
+            let client = calendar_client(account)?;
+            let meetings = client.list_meetings()?;
+            let offenders: Vec<&Meeting> = meetings.iter()
+                .filter(|m| m.hours_conflict.is_some())
+                .collect();
+            
+            if offenders.is_empty() {
+                println!("No meetings outside your working hours in the next 7 days.");
+            }
+            for meeting in &offenders {
+                let conflict = meeting.hours_conflict.map(|c| c.label()).unwrap_or_default();
+                let organizer = if meeting.is_organizer { " [you organize]" } else { "" };
+                println!("{}  {}  ({}){}", meeting.time_range(), style::meeting(&meeting.summary, meeting.meeting_url.as_deref()), conflict, organizer);
+            }
+        }
+        
+        MeetingsCommands::Book { attendee, duration, title, days, ticket } => {
+            // Enough choices to find one that suits without scrolling
+            const PROPOSED_SLOTS: usize = 8;
+            
+            if !attendee.contains('@') {
+                anyhow::bail!("Use {}'s email address, e.g. {}@yourcompany.com", attendee, attendee);
+            }
+            let length = chrono::Duration::seconds(timesheet::parse_duration(&duration)? as i64);
+            if !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+                anyhow::bail!("'qq meetings book' asks you to pick a time, so run it in a terminal");
+            }
+            
+            let client = calendar_client(account)?;
+            client.ensure_write_access(true, "Booking a meeting")?;
+            let now = chrono::Utc::now();
+            let until = now + chrono::Duration::days(days);
+            println!("{}", style::muted(format!("Checking when you and {} are free...", attendee)));
+            let busy = client.busy_times(&["primary", &attendee], now, until)?;
+            let slots = client.working_hours().free_slots(&busy, now, until, length, PROPOSED_SLOTS);
+            if slots.is_empty() {
+                anyhow::bail!("No free {} in your working hours over the next {} days. Try a larger --days.", duration, days);
+            }
+            
+            let labels = slots.iter()
+                .map(|start| format!("{} - {}", start.format("%a %b %d, %l:%M %p"), (*start + length).format("%l:%M %p")))
+                .collect();
+            let Some(idx) = ui::ListPicker::show(&format!(" Book {} with {} ", duration, attendee), labels, theme)? else {
+                println!("Nothing booked.");
+                return Ok(());
+            };
+            
+            let start = slots[idx].with_timezone(&chrono::Utc);
+            let name = attendee.split('@').next().unwrap_or(&attendee);
+            let title = title.unwrap_or_else(|| format!("1:1 with {}", name));
+            let ticket = ticket.map(|key| key.to_uppercase());
+            let meeting = client.book_meeting(&title, &attendee, start, start + length, ticket.as_deref())?;
+            println!("{}", style::success(format!("Booked '{}' and invited {}", meeting.summary, attendee)));
+            println!("{}", meeting.time_range());
+            if let Some(url) = &meeting.meeting_url {
+                println!("Join: {}", url);
+            }
+        }
+        
+        MeetingsCommands::Cancel { id } => {
+            let client = calendar_client(account)?;
+            let meeting = find_meeting(&client, &id)?;
+            
+            if !meeting.is_organizer {
+                anyhow::bail!("You don't organize '{}'. Use 'qq meetings decline' instead.", meeting.summary);
+            }
+            client.ensure_write_access(std::io::IsTerminal::is_terminal(&std::io::stdin()), "Cancelling a meeting")?;
+            
+            println!("Cancelling meeting: {}", meeting.summary);
+            client.cancel_meeting(&meeting.id)?;
+            println!("Meeting cancelled and attendees notified!");
+        }
+    }
+    
+    Ok(())
+}
+
+// Ticket detection from the current git branch, shared by the jira and pr commands
+fn get_current_branch() -> Result<String> {
+    // discover walks up from subdirectories and linked worktrees to the enclosing repo
+    let repo = git2::Repository::discover(".").context("Not inside a git repository")?;
+    let head = repo.head().context("Failed to get HEAD reference")?;
+    let branch = head.shorthand().unwrap_or("HEAD");
+    Ok(branch.to_string())
+}
+
+fn extract_ticket_id(branch_name: &str) -> Result<String> {
+    let patterns = vec![
+        r"^([A-Z]+-\d+)",
+        r"([A-Z]+-\d+)",
+        r"^feature/([A-Z]+-\d+)",
+        r"^bugfix/([A-Z]+-\d+)",
+        r"^hotfix/([A-Z]+-\d+)",
+    ];
+    
+    for pattern in patterns {
+        let re = regex::Regex::new(pattern)?;
+        if let Some(captures) = re.captures(branch_name) {
+            if let Some(ticket_id) = captures.get(1) {
+                return Ok(ticket_id.as_str().to_string());
+            }
+        }
+    }
+    
+    anyhow::bail!("No JIRA ticket ID found in branch name: {}. Run 'qq jira relate-branch <KEY>' to set one.", branch_name)
+}
+
+// A ticket related with `qq jira relate-branch` wins over one parsed from the branch name
+fn resolve_ticket() -> Result<String> {
+    let branch = get_current_branch()?;
+    if let Ok(repo) = git2::Repository::discover(".")
+        && let Some(ticket) = branch_map::BranchMap::load().lookup(&repo, &branch)
+    {
+        return Ok(ticket.to_string());
+    }
+    extract_ticket_id(&branch)
+}
+
+// Falls back to asking for a key when there is no usable branch (e.g. outside a repo)
+fn current_ticket_id() -> Result<String> {
+    use std::io::{BufRead, IsTerminal, Write};
+    
+    let reason = match resolve_ticket() {
+        Ok(ticket_id) => return Ok(ticket_id),
+        Err(e) => e,
+    };
+    
+    if !std::io::stdin().is_terminal() {
+        return Err(reason);
+    }
+    
+    eprintln!("{}", reason);
+    eprint!("Enter a JIRA ticket key (e.g., PROJ-123): ");
+    std::io::stderr().flush()?;
+    
+    let mut input = String::new();
+    std::io::stdin().lock().read_line(&mut input)?;
+    let ticket_id = input.trim().to_uppercase();
+    
+    let re = regex::Regex::new(r"^[A-Z][A-Z0-9]*-\d+$")?;
+    if !re.is_match(&ticket_id) {
+        anyhow::bail!("Invalid JIRA ticket key: {}", input.trim());
+    }
+    
+    Ok(ticket_id)
+}
+
+fn handle_jira_command(command: JiraCommands, output: Output, theme: &ui::Theme) -> Result<()> {
+    use git2::Repository;
+    use jira::JiraClient;
+    use ui::JiraIssueDisplay;
+    
+    // Project key of the ticket from the current branch, e.g. PROJ for feature/PROJ-12, or the
+    // repository's default project from `qq jira projects`
+    fn current_project() -> Result<String> {
+        if let Ok(ticket) = resolve_ticket() {
+            return Ok(ticket.split('-').next().unwrap_or_default().to_string());
+        }
+        projects::RepoProjects::current()
+            .context("Pass --project, run from a branch that names a ticket, or set a default with 'qq jira projects'")
+    }
+    
+    // Creates and checks out feature/<ticket>; outside a repository this is skipped so the
+    // ticket can still be picked up. An existing branch for the ticket, local or on origin,
+    // is reused instead of starting a second one.
+    // Returns the branch that was checked out, or None outside a git repository
+    fn create_feature_branch(ticket: &str, push: bool) -> Result<Option<String>> {
+        use std::io::{BufRead, IsTerminal, Write};
+        
+        let branch_name = format!("feature/{}", ticket);
+        
+        let Ok(repo) = Repository::discover(".") else {
+            eprintln!("Not inside a git repository, skipping branch creation.");
+            return Ok(None);
+        };
+        
+        if let Some(existing) = git::find_local_branch(&repo, ticket) {
+            git::checkout_local(&repo, &existing)?;
+            println!("Branch '{}' already exists, switched to it", existing);
+            if push {
+                git::push_with_upstream(&repo, &existing)?;
+                println!("Pushed '{}' to origin and set it as upstream", existing);
+            }
+            return Ok(Some(existing));
+        }
+        
+        if let Some(remote_branch) = git::find_remote_branch(&repo, ticket) {
+            println!("Found '{}' on origin for {}", remote_branch, ticket);
+            let use_remote = if std::io::stdin().is_terminal() {
+                print!("Check it out with tracking instead of creating '{}'? [Y/n] ", branch_name);
+                std::io::stdout().flush()?;
+                let mut input = String::new();
+                std::io::stdin().lock().read_line(&mut input)?;
+                !input.trim().eq_ignore_ascii_case("n")
+            } else {
+                true
+            };
+            
+            if use_remote {
+                git::checkout_tracking(&repo, &remote_branch)?;
+                println!("Switched to branch '{}' tracking origin/{}", remote_branch, remote_branch);
+                return Ok(Some(remote_branch));
+            }
+        }
+        
+        // Get the current HEAD commit
+        let head = repo.head().context("Failed to get HEAD reference")?;
+        let target = head.target().context("Failed to get HEAD target")?;
+        let commit = repo.find_commit(target).context("Failed to find HEAD commit")?;
+        
+        // Create the new branch
+        repo.branch(&branch_name, &commit, false)
+            .context(format!("Failed to create branch '{}'", branch_name))?;
+        
+        git::checkout_local(&repo, &branch_name)?;
+        println!("Created and switched to branch: {}", style::key(&branch_name));
+        
+        if push {
+            git::push_with_upstream(&repo, &branch_name)?;
+            println!("Pushed '{}' to origin and set it as upstream", branch_name);
+        }
+        Ok(Some(branch_name))
+    }
+    
+    // The rest of `qq jira start --draft-pr`: push, open the draft, and link it on the ticket
+    // The cached status path must stay fast and quiet, so it runs before loading config
+    if let JiraCommands::Status { cached: true } = command {
+        let Ok(ticket_id) = resolve_ticket() else {
+            return Ok(());
+        };
+        
+        match IssueCache::load().get(&ticket_id) {
+            Some(issue) => println!("{}", issue.one_line()),
+            None => println!("{} (not cached, run 'qq jira status' to fetch)", ticket_id),
+        }
+        return Ok(());
+    }
+    
+    // Local results need neither the network nor a valid token
+    if let JiraCommands::Find { words, remote, limit } = &command {
+        let cache = IssueCache::load();
+        let hits = find::Index::build(&cache).search(&words.join(" "), *limit);
+        for hit in &hits {
+            println!("{}", hit.issue.one_line());
+            if let Some(snippet) = &hit.snippet {
+                println!("    {}", style::muted(snippet));
+            }
+        }
+        if !hits.is_empty() {
+            return Ok(());
+        }
+        if !remote {
+            println!("No cached tickets match. Add --remote to search Jira too.");
+            return Ok(());
+        }
+        println!("{}", style::muted("No cached tickets match; searching Jira..."));
+    }
+    
+    // Runs on every commit, so it never fails one: branches without a ticket are left alone, and
+    // without Jira the epic is skipped
+    if let JiraCommands::CommitMsg { file, install } = &command {
+        if *install {
+            let repo = Repository::discover(".").context("Not inside a git repository")?;
+            let path = trailers::install_hook(&repo)?;
+            println!("{}", style::success(format!("Installed the commit-msg hook at {}", path.display())));
+            return Ok(());
+        }
+        let Ok(ticket_id) = resolve_ticket() else {
+            return Ok(());
+        };
+        let client = Config::load().ok().map(JiraClient::new);
+        if let Some(file) = file {
+            trailers::add_to_file(file, &trailers::for_ticket(client.as_ref(), &ticket_id))?;
+        }
+        return Ok(());
+    }
+    
+    // Scratch notes are local files, so only posting them needs Jira
+    if let JiraCommands::Scratch { ticket, list, comment: false } = &command {
+        if *list {
+            let notes = scratch::list()?;
+            if notes.is_empty() {
+                println!("No scratch notes yet. Run 'qq jira scratch' on a ticket branch to start one.");
+            }
+            for note in notes {
+                let modified = note.modified.map(|m| m.format("%b %d %H:%M").to_string()).unwrap_or_default();
+                println!("{} {}  {}", style::key(format!("{:<12}", note.key)), style::muted(format!("{:<12}", modified)), note.first_line);
+            }
+        } else {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket.to_uppercase(),
+                None => current_ticket_id()?,
+            };
+            scratch::edit(&ticket_id)?;
+        }
+        return Ok(());
+    }
+    
+    let config = ensure_jira_auth(Config::load()?)?;
+    let formats = config.formats.clone();
+    let searches = config.searches.clone();
+    let verify_config = config.verify.clone();
+    let inherit = config.create.inherit.clone();
+    for action in config.hooks.unknown_actions() {
+        style::warning(format!("unknown hook action '{}' in [hooks] (expected one of: {})", action, hooks::ACTIONS.join(", ")));
+    }
+    ui::set_jira_label(config.jira_label());
+    style::set_jira_url(&config.active_jira().jira_url);
+    sla::init(&config.sla);
+    
+    let client = JiraClient::new(config);
+    
+    let run_hooks = |stage: hooks::Stage, action: &'static str, ticket_id: &str| -> Result<()> {
+        for warning in hooks::run_for(&client, stage, action, ticket_id, false)? {
+            style::warning(warning);
+        }
+        Ok(())
+    };
+    
+    // Plain-text output for pipelines; nothing else is printed so the lines can be parsed
+    let print_issues = |issues: &[jira::JiraIssue], format: &str| -> Result<()> {
+        let template = output::resolve_format(format, &formats);
+        let rows = issues.iter()
+            .map(|issue| output::issue_values(issue, client.browse_url(&issue.key)))
+            .collect();
+        output::print_rows(&template, rows, output::ISSUE_PLACEHOLDERS)?;
+        
+        // On stderr so the lines stay parseable
+        for issue in issues.iter().filter(|issue| !issue.problems.is_empty()) {
+            style::warning(format!("{} has incomplete data: {}", issue.key, issue.problems.join("; ")));
+        }
+        Ok(())
+    };
+    
+    match command {
+        JiraCommands::Get { subcommand, follow } => {
+            let ticket_id = current_ticket_id()?;
+            if output.is_json() && follow.is_some() {
+                anyhow::bail!("--follow keeps a view open, so it can't be combined with --json");
+            }
+            
+            match subcommand.as_ref().unwrap_or(&GetSubcommands::Info) {
+                GetSubcommands::Info => {
+                    output.progress(format!("Fetching details for ticket: {}", ticket_id));
+                    let issue = client.get_issue(&ticket_id)?;
+                    IssueCache::record(&[&issue]);
+                    if output.is_json() {
+                        return Output::print_json(&issue);
+                    }
+                    
+                    match follow {
+                        Some(seconds) => {
+                            let interval = std::time::Duration::from_secs(seconds.max(5));
+                            JiraIssueDisplay::follow(issue, &client, interval, theme)?;
+                        }
+                        // Use the new Ratatui UI to display the issue
+                        None => JiraIssueDisplay::show(&issue, &client, theme)?,
+                    }
+                }
+                GetSubcommands::Parent => {
+                    use ui::EpicListDisplay;
+                    
+                    if follow.is_some() {
+                        anyhow::bail!("--follow only applies to ticket details, not 'get parent'");
+                    }
+                    
+                    output.progress(format!("Fetching parent epic for ticket: {}", ticket_id));
+                    let (mut issue, candidates) = client.get_issue_with_parent(&ticket_id)?;
+                    
+                    if issue.fields.parent.is_none() && !candidates.is_empty() {
+                        use ui::ListPicker;
+                        
+                        if output.is_json() {
+                            let keys: Vec<&str> = candidates.iter().map(|c| c.issue.key.as_str()).collect();
+                            anyhow::bail!("{} has several parent candidates ({}); run without --json to pick one", ticket_id, keys.join(", "));
+                        }
+                        
+                        let field_names: std::collections::HashMap<String, String> = client.get_fields()
+                            .map(|fields| fields.into_iter().map(|f| (f.id, f.name)).collect())
+                            .unwrap_or_default();
+                        
+                        let labels: Vec<String> = candidates.iter()
+                            .map(|c| {
+                                let field_name = field_names.get(&c.field_id).unwrap_or(&c.field_id);
+                                format!("{} - {}  (via {})", c.issue.key, c.issue.fields.summary, field_name)
+                            })
+                            .collect();
+                        
+                        if let Some(idx) = ListPicker::show(" Multiple parent candidates ", labels, theme)? {
+                            let mut candidate = candidates.into_iter().nth(idx).context("Invalid selection")?;
+                            let field_name = field_names.get(&candidate.field_id).cloned().unwrap_or_default();
+                            candidate.issue.linked_via = Some(format!("{} ({})", field_name, candidate.field_id));
+                            issue.fields.parent = Some(Box::new(candidate.issue));
+                        } else {
+                            println!("No parent selected.");
+                            return Ok(());
+                        }
+                    }
+                    
+                    if let Some(parent) = &issue.fields.parent {
+                        if output.is_json() {
+                            let children = client.get_epic_children(&parent.key)?;
+                            return Output::print_json(&serde_json::json!({ "epic": parent, "children": children }));
+                        }
+                        println!("Found parent epic: {}", style::issue(&parent.key));
+                        
+                        println!("{}", style::muted("Fetching child issues..."));
+                        let children = client.get_epic_children(&parent.key)?;
+                        
+                        // Display the epic and its children in interactive UI
+                        EpicListDisplay::show(parent, children, &client, theme)?;
+                    } else if output.is_json() {
+                        return Output::print_json(&serde_json::json!({ "epic": null, "children": [] }));
+                    } else {
+                        println!("This issue is not part of an epic.");
+                    }
+                }
+            }
+        }
+        
+        JiraCommands::Parent { action } => match action {
+            ParentCommands::Set { epic, ticket } => {
+                let ticket_id = match ticket {
+                    Some(ticket) => ticket,
+                    None => current_ticket_id()?,
+                };
+                
+                let epic_key = match epic {
+                    Some(epic) => epic,
+                    None => {
+                        use ui::ListPicker;
+                        
+                        println!("{}", style::muted("Fetching epics..."));
+                        let epics = client.get_all_epics()?;
+                        if epics.is_empty() {
+                            anyhow::bail!("No epics found");
+                        }
+                        
+                        let labels: Vec<String> = epics.iter()
+                            .map(|e| format!("{} - {}  [{}]", e.key, e.fields.summary, e.fields.status.name))
+                            .collect();
+                        match ListPicker::show(&format!(" Parent epic for {} ", ticket_id), labels, theme)? {
+                            Some(idx) => epics[idx].key.clone(),
+                            None => {
+                                println!("No epic selected.");
+                                return Ok(());
+                            }
+                        }
+                    }
+                };
+                
+                println!("Setting parent of {} to {}", ticket_id, epic_key);
+                client.set_parent(&ticket_id, Some(&epic_key))?;
+                println!("{}", style::success("Parent epic updated successfully!"));
+            }
+            ParentCommands::Remove { ticket } => {
+                let ticket_id = match ticket {
+                    Some(ticket) => ticket,
+                    None => current_ticket_id()?,
+                };
+                
+                println!("Removing parent epic from {}", ticket_id);
+                client.set_parent(&ticket_id, None)?;
+                println!("{}", style::success("Parent epic removed successfully!"));
+            }
+        },
+        
+        JiraCommands::Context { ticket } => {
+            use ui::ContextDisplay;
+            
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            let calendar = Config::load()?.google_account(None)
+                .ok()
+                .and_then(|_| calendar_client(None).ok());
+            
+            println!("{}", style::muted(format!("Gathering context for {}...", ticket_id)));
+            let context = context::TicketContext::gather(&client, calendar.as_ref(), &ticket_id)?;
+            IssueCache::record(&[&context.issue]);
+            IssueCache::record_comments(&context.issue.key, &context.comments);
+            
+            ContextDisplay::show(&context, theme)?;
+        }
+        
+        JiraCommands::Comment { message, template, set_values, edit_last, delete_last } => {
+            let ticket_id = current_ticket_id()?;
+            
+            let message = match template {
+                Some(name) => {
+                    let config = Config::load()?;
+                    let mut values = template::TemplateValues::new();
+                    values.set("ticket", ticket_id.clone());
+                    values.set_pairs(&set_values)?;
+                    Some(values.fill_all(config.comment_template(&name)?)?)
+                }
+                None => message,
+            };
+            
+            if delete_last {
+                use std::io::{BufRead, IsTerminal, Write};
+                
+                let comment = client.get_my_last_comment(&ticket_id)?;
+                // Scripts can't answer, so they delete without asking
+                if std::io::stdin().is_terminal() {
+                    let text = comment.body.as_ref().map(jira::adf::to_markdown).unwrap_or_default();
+                    println!("{}", style::muted(text.lines().next().unwrap_or_default()));
+                    print!("Delete this comment from {}? [y/N] ", ticket_id);
+                    std::io::stdout().flush()?;
+                    let mut input = String::new();
+                    std::io::stdin().lock().read_line(&mut input)?;
+                    if !input.trim().eq_ignore_ascii_case("y") {
+                        println!("Not deleted.");
+                        return Ok(());
+                    }
+                }
+                println!("Deleting your last comment on ticket: {}", ticket_id);
+                client.delete_comment(&ticket_id, &comment.id)?;
+                println!("{}", style::success("Comment deleted successfully!"));
+            } else if edit_last {
+                let message = message.context("Comment text is required")?;
+                let comment = client.get_my_last_comment(&ticket_id)?;
+                println!("Editing your last comment on ticket: {}", ticket_id);
+                client.update_comment(&ticket_id, &comment.id, &message)?;
+                println!("{}", style::success("Comment updated successfully!"));
+            } else {
+                let message = message.context("Comment text is required")?;
+                run_hooks(hooks::Stage::Before, "comment", &ticket_id)?;
+                println!("Adding comment to ticket: {}", ticket_id);
+                client.add_comment(&ticket_id, &message)?;
+                println!("{}", style::success("Comment added successfully!"));
+                run_hooks(hooks::Stage::After, "comment", &ticket_id)?;
+            }
+        }
+        
+        JiraCommands::Scratch { ticket, .. } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket.to_uppercase(),
+                None => current_ticket_id()?,
+            };
+            let notes = scratch::read(&ticket_id)
+                .context(format!("No scratch notes for {}", ticket_id))?;
+            
+            run_hooks(hooks::Stage::Before, "comment", &ticket_id)?;
+            println!("Posting scratch notes to ticket: {}", ticket_id);
+            client.add_comment(&ticket_id, notes.trim())?;
+            scratch::remove(&ticket_id)?;
+            println!("{}", style::success("Notes added as a comment and removed locally"));
+            run_hooks(hooks::Stage::After, "comment", &ticket_id)?;
+        }
+        
+        JiraCommands::Attach { file, stdin, ticket, comment } => {
+            use std::io::{IsTerminal, Read};
+            
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            
+            let (file_name, contents) = if stdin {
+                if std::io::stdin().is_terminal() {
+                    anyhow::bail!("--stdin expects piped input, e.g. 'make test 2>&1 | qq jira attach --stdin test-output.txt'");
+                }
+                let mut contents = Vec::new();
+                std::io::stdin().read_to_end(&mut contents).context("Failed to read standard input")?;
+                if contents.is_empty() {
+                    anyhow::bail!("Nothing was piped to qq, not attaching an empty file");
+                }
+                (file, contents)
+            } else {
+                let path = std::path::Path::new(&file);
+                let contents = std::fs::read(path).context(format!("Failed to read {}", file))?;
+                let file_name = path.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or(file);
+                (file_name, contents)
+            };
+            
+            println!("Attaching {} to {}...", file_name, ticket_id);
+            let attachment = client.add_attachment(&ticket_id, &file_name, contents)?;
+            println!("{}", style::success(format!("✓ Attached {} ({} bytes)", attachment.filename, attachment.size)));
+            
+            if let Some(text) = comment {
+                let text = text.trim();
+                let message = if text.is_empty() {
+                    format!("Attached {}: {}", attachment.filename, attachment.content)
+                } else {
+                    format!("{} {}", text, attachment.content)
+                };
+                client.add_comment(&ticket_id, &message)?;
+                println!("{}", style::success("✓ Comment added linking the attachment"));
+            }
+        }
+        
+        JiraCommands::Close { force, message } => {
+            use std::io::{BufRead, IsTerminal, Write};
+            
+            let ticket_id = current_ticket_id()?;
+            
+            if !force {
+                let report = verify::run(&client, &ticket_id, &verify_config)?;
+                if report.is_blocked() || report.has_warnings() {
+                    print_verify_report(&report);
+                }
+                if report.is_blocked() {
+                    anyhow::bail!("{} isn't ready to close. Fix the ✗ items or re-run with --force.", ticket_id);
+                }
+                // Scripts can't answer, so warnings alone don't stop a non-interactive close
+                if report.has_warnings() && std::io::stdin().is_terminal() {
+                    print!("Close {} anyway? [y/N] ", ticket_id);
+                    std::io::stdout().flush()?;
+                    let mut input = String::new();
+                    std::io::stdin().lock().read_line(&mut input)?;
+                    if !input.trim().eq_ignore_ascii_case("y") {
+                        println!("Not closed.");
+                        return Ok(());
+                    }
+                }
+            }
+            
+            run_hooks(hooks::Stage::Before, "close", &ticket_id)?;
+            println!("Closing ticket: {}", ticket_id);
+            client.close_issue(&ticket_id, message.as_deref())?;
+            println!("{}", style::success("Ticket closed successfully!"));
+            if message.as_deref().is_some_and(|m| !m.trim().is_empty()) {
+                println!("{}", style::success("✓ Resolution comment added"));
+            }
+            // A closed ticket has nothing left to come back for
+            if snooze::SnoozeStore::edit(|snoozes| snoozes.unsnooze(&ticket_id)).unwrap_or(false) {
+                sync_reminders();
+            }
+            run_hooks(hooks::Stage::After, "close", &ticket_id)?;
+        }
+        
+        JiraCommands::Verify { ticket } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            
+            let report = verify::run(&client, &ticket_id, &verify_config)?;
+            print_verify_report(&report);
+            if report.is_blocked() {
+                anyhow::bail!("{} isn't ready to close", ticket_id);
+            }
+        }
+        
+        JiraCommands::Transition { state, to, ticket, fields } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            
+            let mut field_values = serde_json::Map::new();
+            for field in fields {
+                let (id, value) = field.split_once('=')
+                    .context(format!("Invalid --field '{}', expected <id>=<value>", field))?;
+                // Plain strings are accepted as-is so simple values don't need JSON quoting
+                let value = serde_json::from_str(value)
+                    .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+                field_values.insert(id.to_string(), value);
+            }
+            
+            let from = client.get_issue(&ticket_id).ok().map(|issue| issue.fields.status.name);
+            let to = match state.or(to) {
+                Some(to) => to,
+                None => {
+                    use std::io::IsTerminal;
+                    
+                    if !std::io::stdin().is_terminal() {
+                        anyhow::bail!("A target state is required when not running in a terminal");
+                    }
+                    let from = from.as_deref().context(format!("Failed to fetch {}", ticket_id))?;
+                    let options = client.transition_options(&ticket_id)?;
+                    if options.is_empty() {
+                        anyhow::bail!("No transitions are available for {}", ticket_id);
+                    }
+                    
+                    println!("{}", style::muted("Ranking statuses by your project's history..."));
+                    let counts = workflow::next_status_counts(&client, workflow::project_of(&ticket_id), from);
+                    let ranked = workflow::rank(options, &counts);
+                    let labels = ranked.iter()
+                        .map(|(option, share)| {
+                            let mut label = option.status.clone();
+                            if !option.name.eq_ignore_ascii_case(&option.status) {
+                                label.push_str(&format!(" (via {})", option.name));
+                            }
+                            if let Some(share) = share {
+                                label.push_str(&format!("  {:.0}% of moves from {}", share * 100.0, from));
+                            }
+                            label
+                        })
+                        .collect();
+                    
+                    let title = format!(" Move {} from {} ", ticket_id, from);
+                    let Some(idx) = ui::ListPicker::show(&title, labels, theme)? else {
+                        return Ok(());
+                    };
+                    ranked[idx].0.id.clone()
+                }
+            };
+            
+            run_hooks(hooks::Stage::Before, "transition", &ticket_id)?;
+            let status = client.transition_to(&ticket_id, &to, field_values)?;
+            println!("{} moved to {}", style::issue(&ticket_id), style::success(&status));
+            if let Some(from) = from {
+                let _ = workflow::WorkflowStore::record(workflow::project_of(&ticket_id), &from, &status);
+            }
+            run_hooks(hooks::Stage::After, "transition", &ticket_id)?;
+        }
+        
+        JiraCommands::Review { ticket, reviewer, to, no_comment } => {
+            use std::io::IsTerminal;
+            
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            let issue = client.get_issue(&ticket_id)?;
+            
+            // Asking someone else for review, so leave myself out of the list
+            let me = client.get_current_user()?;
+            let users: Vec<jira::User> = client.get_assignable_users(&ticket_id)?
+                .into_iter()
+                .filter(|u| u.account_id != me.account_id)
+                .collect();
+            
+            let reviewer = match reviewer {
+                Some(query) => {
+                    let query = query.to_lowercase();
+                    let matches: Vec<&jira::User> = users.iter()
+                        .filter(|u| {
+                            u.display_name.to_lowercase().contains(&query)
+                                || u.email_address.as_deref().is_some_and(|e| e.to_lowercase().contains(&query))
+                        })
+                        .collect();
+                    match matches.as_slice() {
+                        [user] => Some((*user).clone()),
+                        [] => anyhow::bail!("No assignable user matches '{}'", query),
+                        _ => {
+                            let names: Vec<&str> = matches.iter().map(|u| u.display_name.as_str()).collect();
+                            anyhow::bail!("'{}' matches several users: {}", query, names.join(", "));
+                        }
+                    }
+                }
+                None if std::io::stdin().is_terminal() => {
+                    let labels = users.iter()
+                        .map(|u| match &u.email_address {
+                            Some(email) => format!("{} <{}>", u.display_name, email),
+                            None => u.display_name.clone(),
+                        })
+                        .collect();
+                    match ui::ListPicker::show(&format!(" Reviewer for {} ", ticket_id), labels, theme)? {
+                        Some(idx) => Some(users[idx].clone()),
+                        None => {
+                            println!("No reviewer picked; {} was not moved.", ticket_id);
+                            return Ok(());
+                        }
+                    }
+                }
+                None => None,
+            };
+            
+            let target = match to {
+                Some(to) => to,
+                None => client.review_target(&ticket_id)?,
+            };
+            run_hooks(hooks::Stage::Before, "transition", &ticket_id)?;
+            let status = client.transition_to(&ticket_id, &target, serde_json::Map::new())?;
+            println!("{} moved to {}", style::issue(&ticket_id), style::success(&status));
+            run_hooks(hooks::Stage::After, "transition", &ticket_id)?;
+            
+            let Some(reviewer) = reviewer else {
+                return Ok(());
+            };
+            
+            if let Some(field_id) = client.reviewer_field() {
+                client.set_user_field(&ticket_id, field_id, &reviewer.account_id)?;
+                println!("Reviewer set to {}", reviewer.display_name);
+            }
+            
+            if !no_comment {
+                // A "review" comment template overrides the default wording; {reviewer} becomes a mention
+                let template = Config::load()?.comment_templates.get("review").cloned()
+                    .unwrap_or_else(|| "{reviewer} could you review {ticket}: {summary}?".to_string());
+                let mut values = template::TemplateValues::new();
+                values.set("ticket", ticket_id.clone());
+                values.set("summary", issue.fields.summary.clone());
+                values.set("url", client.browse_url(&ticket_id));
+                let (text, _) = values.fill(&template);
+                
+                let doc = jira::adf::with_mention(&text, "{reviewer}", &reviewer.account_id, &reviewer.display_name);
+                client.add_comment_adf(&ticket_id, doc)?;
+                println!("Asked {} for review", reviewer.display_name);
+            }
+        }
+        
+        JiraCommands::MoveToSprint { sprint, ticket } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            
+            let Some(target) = sprint::pick(&client, &ticket_id, sprint.as_deref(), " Move to sprint ", theme)? else {
+                return Ok(());
+            };
+            
+            client.move_to_sprint(target.id, &[&ticket_id])?;
+            println!("{} moved to {}", style::issue(&ticket_id), style::success(&target.name));
+        }
+        
+        JiraCommands::DuplicateCheck { summary, ticket, project } => {
+            use std::io::IsTerminal;
+            
+            // An existing ticket is compared against the rest of its project
+            let (summary, existing) = match summary {
+                Some(summary) => (summary, None),
+                None => {
+                    let ticket_id = match ticket {
+                        Some(ticket) => ticket,
+                        None => current_ticket_id()?,
+                    };
+                    let issue = client.get_issue(&ticket_id)?;
+                    (issue.fields.summary.clone(), Some(issue))
+                }
+            };
+            let project = project.or_else(|| {
+                existing.as_ref().and_then(|issue| issue.key.split('-').next().map(|p| p.to_string()))
+            });
+            
+            println!("{}", style::muted(format!("Searching for tickets like \"{}\"...", summary)));
+            let exclude = existing.as_ref().map(|issue| issue.key.as_str());
+            let candidates = duplicate::find(&client, &summary, project.as_deref(), exclude)?;
+            if candidates.is_empty() {
+                println!("No likely duplicates found.");
+                return Ok(());
+            }
+            
+            println!();
+            for candidate in &candidates {
+                println!(
+                    "{:<12} {:<16} {:>4.0}%  {}",
+                    candidate.issue.key,
+                    candidate.issue.fields.status.name,
+                    candidate.score * 100.0,
+                    candidate.issue.fields.summary
+                );
+            }
+            println!();
+            
+            let Some(issue) = existing else {
+                // Non-zero exit so scripts can stop before creating the ticket
+                anyhow::bail!("Found {} likely duplicate(s); review them before creating a new ticket", candidates.len());
+            };
+            if !std::io::stdin().is_terminal() {
+                return Ok(());
+            }
+            
+            let labels = candidates.iter()
+                .map(|c| format!("{}  {:.0}%  {}", c.issue.key, c.score * 100.0, c.issue.fields.summary))
+                .collect();
+            let title = format!(" Link {} as a duplicate of... (Esc to keep it) ", issue.key);
+            match ui::ListPicker::show(&title, labels, theme)? {
+                Some(idx) => {
+                    let original = &candidates[idx].issue.key;
+                    client.link_duplicate(&issue.key, original)?;
+                    println!("{} linked as a duplicate of {}", style::issue(&issue.key), style::issue(original));
+                }
+                None => println!("{} left as is.", issue.key),
+            }
+        }
+        
+        JiraCommands::Search { jql, saved, limit, format } => {
+            let jql = match (jql, saved) {
+                (Some(jql), _) => jql,
+                (None, Some(name)) => searches.get(&name)
+                    .cloned()
+                    .context(format!("No saved search named '{}'. Run 'qq jira search' to list them.", name))?,
+                (None, None) => {
+                    if searches.is_empty() {
+                        println!("No saved searches. Build one with 'qq jira query-builder'.");
+                    } else {
+                        let mut names: Vec<_> = searches.keys().collect();
+                        names.sort();
+                        for name in names {
+                            println!("{:<16} {}", name, searches[name]);
+                        }
+                    }
+                    return Ok(());
+                }
+            };
+            
+            // Piped output and --format keep printing lines for scripts
+            if format.is_none() && std::io::IsTerminal::is_terminal(&std::io::stdout()) {
+                let issues = ui::SearchResultsDisplay::show(&jql, limit, &client, theme)?;
+                IssueCache::record(&issues.iter().collect::<Vec<_>>());
+                return Ok(());
+            }
+            
+            let issues = client.search_jql(&jql, limit.unwrap_or(50))?;
+            IssueCache::record(&issues.iter().collect::<Vec<_>>());
+            print_issues(&issues, format.as_deref().unwrap_or(output::DEFAULT_ISSUE_FORMAT))?;
+        }
+        
+        // Only reached with --remote after the cache had no matches
+        JiraCommands::Find { words, limit, .. } => {
+            let jql = format!("text ~ {} ORDER BY updated DESC", jql::quote(&words.join(" ")));
+            let issues = client.search_jql(&jql, limit)?;
+            if issues.is_empty() {
+                println!("No tickets match in Jira either.");
+                return Ok(());
+            }
+            IssueCache::record(&issues.iter().collect::<Vec<_>>());
+            print_issues(&issues, output::DEFAULT_ISSUE_FORMAT)?;
+        }
+        
+        JiraCommands::Export { assignee, since, out } => {
+            use std::io::Write;
+            
+            let since = since
+                .map(|since| chrono::NaiveDate::parse_from_str(&since, "%Y-%m-%d")
+                    .context(format!("Invalid --since '{}', expected YYYY-MM-DD", since)))
+                .transpose()?;
+            let jql = archive::jql(&assignee, since);
+            
+            let mut writer: Box<dyn Write> = match &out {
+                Some(path) => Box::new(std::io::BufWriter::new(
+                    std::fs::File::create(path).context(format!("Failed to create {}", path.display()))?,
+                )),
+                None => Box::new(std::io::stdout().lock()),
+            };
+            eprintln!("{}", style::muted_stderr(format!("Exporting {}", jql)));
+            let written = archive::export(&client, &jql, &mut writer, |written| {
+                eprint!("\r{}", style::muted_stderr(format!("Exported {} ticket(s)...", written)));
+            })?;
+            eprintln!();
+            
+            match out {
+                Some(path) => println!("{}", style::success(format!("Wrote {} ticket(s) to {}", written, path.display()))),
+                None => eprintln!("{}", style::success_stderr(format!("Wrote {} ticket(s)", written))),
+            }
+        }
+        
+        JiraCommands::QueryBuilder => {
+            match ui::QueryBuilderDisplay::show(&client, theme)? {
+                Some(ui::QueryBuilderOutcome::Run(jql)) => {
+                    println!("{}\n", jql);
+                    let issues = client.search_jql(&jql, 50)?;
+                    IssueCache::record(&issues.iter().collect::<Vec<_>>());
+                    print_issues(&issues, output::DEFAULT_ISSUE_FORMAT)?;
+                }
+                Some(ui::QueryBuilderOutcome::Save { name, jql }) => {
+                    let mut config = Config::load()?;
+                    let replaced = config.searches.insert(name.clone(), jql.clone()).is_some();
+                    config.save()?;
+                    
+                    println!("{}", jql);
+                    println!("{} search '{}'. Run it with: qq jira search --saved {}",
+                        if replaced { "Updated" } else { "Saved" }, name, name);
+                }
+                None => {}
+            }
+        }
+        
+        JiraCommands::Label { command } => {
+            edit_list_field(&client, jira::ListField::Label, command, current_ticket_id)?;
+        }
+        
+        JiraCommands::Component { command } => {
+            edit_list_field(&client, jira::ListField::Component, command, current_ticket_id)?;
+        }
+        
+        JiraCommands::Rename { summary } => {
+            let ticket_id = current_ticket_id()?;
+            
+            println!("Renaming ticket: {}", ticket_id);
+            client.rename_issue(&ticket_id, &summary)?;
+            
+            if let Ok(issue) = client.get_issue(&ticket_id) {
+                IssueCache::record(&[&issue]);
+            }
+            println!("{}", style::success("Ticket summary updated successfully!"));
+        }
+        
+        JiraCommands::Start { ticket, push, draft_pr } => {
+            if draft_pr && Repository::discover(".").is_err() {
+                anyhow::bail!("--draft-pr opens the pull request from the current repository, so run it inside one");
+            }
+            run_hooks(hooks::Stage::Before, "start", &ticket)?;
+            // With --draft-pr the push waits until the branch has a commit to open the PR for
+            let branch = create_feature_branch(&ticket, push && !draft_pr)?;
+            
+            // Now assign the ticket to yourself and move it to In Progress
+            println!("Picking up ticket: {}", ticket);
+            client.pickup_issue(&ticket)?;
+            println!("{}", style::success("Ticket assigned to you and moved to In Progress!"));
+            
+            if let Some(branch) = branch.filter(|_| draft_pr) {
+                let github = Config::load()?.github;
+                let issue = client.get_issue(&ticket)?;
+                open_pull_request(&client, github.as_ref(), &issue, &branch, None, None, true)?;
+            }
+            run_hooks(hooks::Stage::After, "start", &ticket)?;
+        }
+        
+        JiraCommands::GrabNext { project, labels, max_points, dry_run } => {
+            let mut filter = Config::load()?.grab_next;
+            if project.is_some() {
+                filter.project = project;
+            }
+            if !labels.is_empty() {
+                filter.labels = labels;
+            }
+            if max_points.is_some() {
+                filter.max_points = max_points;
+            }
+            
+            println!("{}", style::muted("Looking for the next backlog issue..."));
+            let Some(issue) = client.next_backlog_issue(&filter)? else {
+                println!("No unassigned issues match your grab-next filters.");
+                return Ok(());
+            };
+            
+            let points = issue.fields.story_points
+                .map(|p| format!(" ({} pts)", p))
+                .unwrap_or_default();
+            println!("Picked {}: {}{}", style::issue(&issue.key), issue.fields.summary, points);
+            if dry_run {
+                return Ok(());
+            }
+            
+            run_hooks(hooks::Stage::Before, "start", &issue.key)?;
+            create_feature_branch(&issue.key, false)?;
+            match &filter.transition {
+                Some(target) => {
+                    let user = client.get_current_user()?;
+                    client.assign_issue(&issue.key, Some(&user.account_id))?;
+                    let status = client.transition_to(&issue.key, target, serde_json::Map::new())?;
+                    println!("{}", style::success(format!("Ticket assigned to you and moved to {}!", status)));
+                }
+                None => {
+                    client.pickup_issue(&issue.key)?;
+                    println!("{}", style::success("Ticket assigned to you and moved to In Progress!"));
+                }
+            }
+            run_hooks(hooks::Stage::After, "start", &issue.key)?;
+        }
+        
+        JiraCommands::Swimlane { epic } => {
+            println!("{}", style::muted(format!("Fetching epic details for: {}", epic)));
+            let epic_issue = client.get_issue(&epic)?;
+            println!("{}", style::muted("Fetching child issues..."));
+            let children = client.get_epic_children(&epic)?;
+            
+            ui::SwimlaneDisplay::show(&epic_issue, children, &client, theme)?;
+        }
+        
+        JiraCommands::Create { summary, epic, project, issue_type, description, no_inherit, sprint } => {
+            use std::io::IsTerminal;
+            
+            let summary = summary.trim();
+            if summary.is_empty() {
+                anyhow::bail!("Summary cannot be empty");
+            }
+            let project = match (project, &epic) {
+                (Some(project), _) => project,
+                (None, Some(epic)) => epic.split('-').next().unwrap_or(epic).to_string(),
+                (None, None) => current_project()?,
+            };
+            
+            // Same search as `qq jira duplicate-check`, before anything is created
+            println!("{}", style::muted(format!("Checking {} for tickets like \"{}\"...", project, summary)));
+            let candidates = duplicate::find(&client, summary, Some(&project), None)?;
+            let mut duplicate_of = None;
+            if !candidates.is_empty() {
+                if std::io::stdin().is_terminal() {
+                    let mut labels = vec!["Create it anyway".to_string()];
+                    labels.extend(candidates.iter().map(|c| format!(
+                        "Create it as a duplicate of {}  {:.0}%  {}",
+                        c.issue.key,
+                        c.score * 100.0,
+                        c.issue.fields.summary
+                    )));
+                    let title = format!(" {} similar ticket(s) found (Esc to cancel) ", candidates.len());
+                    match ui::ListPicker::show(&title, labels, theme)? {
+                        None => {
+                            println!("Nothing was created.");
+                            return Ok(());
+                        }
+                        Some(0) => {}
+                        Some(idx) => duplicate_of = Some(candidates[idx - 1].issue.key.clone()),
+                    }
+                } else {
+                    style::warning(format!(
+                        "{} similar ticket(s) exist: {}",
+                        candidates.len(),
+                        candidates.iter().map(|c| c.issue.key.as_str()).collect::<Vec<_>>().join(", ")
+                    ));
+                }
+            }
+            
+            // Picked before creating, so closing the picker leaves nothing behind
+            let sprint = match sprint {
+                Some(query) => {
+                    let query = Some(query.as_str()).filter(|q| !q.trim().is_empty());
+                    match sprint::pick(&client, &project, query, " Add to sprint ", theme)? {
+                        Some(sprint) => Some(sprint),
+                        None => {
+                            println!("Nothing was created.");
+                            return Ok(());
+                        }
+                    }
+                }
+                None => None,
+            };
+            
+            let mut fields = serde_json::Map::new();
+            if let Some(epic) = &epic
+                && !no_inherit
+                && !inherit.is_empty()
+            {
+                println!("{}", style::muted(format!("Reading fields to inherit from {}...", epic)));
+                fields = client.inherited_fields(epic, &inherit)?;
+            }
+            // --sprint wins over the epic's sprint
+            if sprint.is_some()
+                && let Some(sprint_field) = client.sprint_field()
+            {
+                fields.remove(sprint_field);
+            }
+            let inherited: Vec<&str> = fields.keys()
+                .filter_map(|field| match field.as_str() {
+                    "labels" => Some("labels"),
+                    "fixVersions" => Some("fix versions"),
+                    "components" => Some("components"),
+                    field if client.sprint_field() == Some(field) => Some("sprint"),
+                    _ => None,
+                })
+                .collect();
+            
+            let (key, parented) = client.create_under_epic(
+                &project,
+                &issue_type,
+                summary,
+                description.as_deref(),
+                epic.as_deref(),
+                fields,
+            )?;
+            println!("{} {}", style::success("✓ Created"), style::issue(&key));
+            parented?;
+            if let Some(epic) = &epic {
+                println!("  under {}", style::issue(epic));
+            }
+            if !inherited.is_empty() {
+                println!("  {}", style::muted(format!("inherited {} from the epic", inherited.join(", "))));
+            }
+            if let Some(sprint) = &sprint {
+                client.move_to_sprint(sprint.id, &[&key])
+                    .context(format!("Created {}, but couldn't add it to {}", key, sprint.name))?;
+                println!("  in {}", sprint.name);
+            }
+            if let Some(original) = &duplicate_of {
+                client.link_duplicate(&key, original)
+                    .context(format!("Created {}, but couldn't link it as a duplicate of {}", key, original))?;
+                println!("  linked as a duplicate of {}", style::issue(original));
+            }
+            println!("  {}", client.browse_url(&key));
+        }
+        
+        JiraCommands::Import { file, epic, project, issue_type, yes, dry_run } => {
+            use std::io::{BufRead, IsTerminal, Write};
+            
+            let drafts = import::read(&file)?;
+            let project = match (project, &epic) {
+                (Some(project), _) => project,
+                (None, Some(epic)) => epic.split('-').next().unwrap_or(epic).to_string(),
+                (None, None) => current_project()?,
+            };
+            
+            let summary_width = drafts.iter().map(|d| d.summary.chars().count()).max().unwrap_or(0).min(60);
+            let type_width = drafts.iter()
+                .map(|d| d.issue_type.as_deref().unwrap_or(&issue_type).chars().count())
+                .max()
+                .unwrap_or(0);
+            println!("{}", style::heading(format!(
+                "{} tickets for {}{}",
+                drafts.len(),
+                project,
+                epic.as_deref().map(|e| format!(" under {}", e)).unwrap_or_default()
+            )));
+            for (i, draft) in drafts.iter().enumerate() {
+                let summary: String = draft.summary.chars().take(summary_width).collect();
+                let description = draft.description.as_deref()
+                    .and_then(|d| d.lines().find(|line| !line.trim().is_empty()))
+                    .unwrap_or_default();
+                println!(
+                    "{:>3}  {:<type_width$}  {:<summary_width$}  {}",
+                    i + 1,
+                    draft.issue_type.as_deref().unwrap_or(&issue_type),
+                    summary,
+                    style::muted(description.chars().take(50).collect::<String>()),
+                );
+            }
+            println!();
+            
+            if dry_run {
+                println!("Dry run; nothing was created.");
+                return Ok(());
+            }
+            if !yes {
+                if !std::io::stdin().is_terminal() {
+                    anyhow::bail!("Pass --yes to create tickets without a terminal to confirm in");
+                }
+                print!("Create {} tickets? [y/N] ", drafts.len());
+                std::io::stdout().flush()?;
+                let mut input = String::new();
+                std::io::stdin().lock().read_line(&mut input)?;
+                if !input.trim().eq_ignore_ascii_case("y") {
+                    println!("Nothing was created.");
+                    return Ok(());
+                }
+            }
+            
+            let mut inherited = serde_json::Map::new();
+            if let Some(epic) = &epic
+                && !inherit.is_empty()
+            {
+                println!("{}", style::muted(format!("Reading fields to inherit from {}...", epic)));
+                inherited = client.inherited_fields(epic, &inherit)?;
+            }
+            
+            // Keeps going past failures so one bad row doesn't leave the rest uncreated
+            let mut created = Vec::new();
+            let mut failed = 0;
+            for draft in &drafts {
+                let result = client.create_under_epic(
+                    &project,
+                    draft.issue_type.as_deref().unwrap_or(&issue_type),
+                    &draft.summary,
+                    draft.description.as_deref(),
+                    epic.as_deref(),
+                    inherited.clone(),
+                ).and_then(|(key, parented)| parented.map(|()| key));
+                match result {
+                    Ok(key) => {
+                        println!("{} {}  {}", style::success("✓"), style::issue(&key), draft.summary);
+                        created.push(key);
+                    }
+                    Err(e) => {
+                        println!("{} {}: {:#}", style::failure("✗"), draft.summary, e);
+                        failed += 1;
+                    }
+                }
+            }
+            
+            println!();
+            if !created.is_empty() {
+                println!("Created {}", created.join(" "));
+            }
+            if failed > 0 {
+                anyhow::bail!("{} of {} tickets could not be created", failed, drafts.len());
+            }
+        }
+        
+        JiraCommands::Poker { epic } => {
+            if client.story_points_field().is_none() {
+                anyhow::bail!("Story points field not configured. Run 'qq jira fields --pin' first.");
+            }
+            println!("{}", style::muted(format!("Fetching epic details for: {}", epic)));
+            let epic_issue = client.get_issue(&epic)?;
+            println!("{}", style::muted("Fetching child issues..."));
+            let mut children = client.get_epic_children(&epic)?;
+            client.fill_extra_fields(&mut children);
+            children.retain(|issue| !issue.fields.is_done() && issue.fields.story_points.is_none());
+            
+            if children.is_empty() {
+                println!("Every open ticket in {} already has an estimate.", epic);
+                return Ok(());
+            }
+            let Some(estimates) = ui::PokerDisplay::show(&epic_issue, children, theme)? else {
+                println!("Estimates discarded; nothing was written.");
+                return Ok(());
+            };
+            if estimates.is_empty() {
+                println!("No estimates entered.");
+                return Ok(());
+            }
+            
+            let total = estimates.len();
+            let mut failed = 0;
+            for (idx, ((ticket, points), result)) in estimates.iter().zip(client.set_story_points(&estimates)?).enumerate() {
+                let position = format!("[{}/{}]", idx + 1, total);
+                match result {
+                    Ok(()) => println!("{} {}", style::muted(position), style::success(format!("✓ {}: {} points", ticket, points))),
+                    Err(e) => {
+                        failed += 1;
+                        println!("{} {}", style::muted(position), style::failure(format!("✗ {}: {:#}", ticket, e)));
+                    }
+                }
+            }
+            if failed > 0 {
+                anyhow::bail!("Saved {} of {} estimate(s); {} failed", total - failed, total, failed);
+            }
+            let points: f64 = estimates.iter().map(|(_, points)| points).sum();
+            println!("Saved {} estimate(s), {} points in total.", total, points);
+        }
+        
+        JiraCommands::Epic { ticket, format } => {
+            use ui::{EpicListDisplay, AllEpicsDisplay};
+            
+            output.check_format(&format)?;
+            if output.is_json() {
+                if ticket == "list" {
+                    return Output::print_json(&client.get_all_epics()?);
+                }
+                let epic = client.get_issue(&ticket)?;
+                let mut children = client.get_epic_children(&ticket)?;
+                pin::PinStore::load().sort(&mut children);
+                return Output::print_json(&serde_json::json!({ "epic": epic, "children": children }));
+            }
+            if let Some(format) = format {
+                let mut issues = if ticket == "list" {
+                    client.get_all_epics()?
+                } else {
+                    client.get_epic_children(&ticket)?
+                };
+                pin::PinStore::load().sort(&mut issues);
+                return print_issues(&issues, &format);
+            }
+            
+            if ticket == "list" {
+                // Show all epics
+                println!("{}", style::muted("Fetching all epics..."));
+                
+                let epics = client.get_all_epics()?;
+                
+                if epics.is_empty() {
+                    println!("No epics found.");
+                } else {
+                    println!("Found {} epic(s).", epics.len());
+                    // Display all epics in interactive UI
+                    AllEpicsDisplay::show(epics, &client, theme)?;
+                }
+            } else {
+                // Show specific epic
+                println!("{}", style::muted(format!("Fetching epic details for: {}", ticket)));
+                let epic = client.get_issue(&ticket)?;
+                
+                println!("{}", style::muted("Fetching child issues..."));
+                let children = client.get_epic_children(&ticket)?;
+                
+                // Display the epic and its children in interactive UI
+                EpicListDisplay::show(&epic, children, &client, theme)?;
+            }
+        }
+        
+        JiraCommands::Dependencies { command: DependencyCommands::Ready { since, all } } => {
+            let checked_at = chrono::Utc::now();
+            // First run looks back a day, so there's something to see
+            let since = match since {
+                _ if all => None,
+                Some(since) => Some(dependencies::parse_since(&since)?),
+                None => Some(dependencies::DependencyStore::last_checked()
+                    .unwrap_or_else(|| checked_at - chrono::Duration::days(1))),
+            };
+            
+            let ready = dependencies::ready(&client, since)?;
+            dependencies::DependencyStore::mark_checked(checked_at)?;
+            IssueCache::record(&ready.iter().map(|r| &r.issue).collect::<Vec<_>>());
+            
+            let window = since
+                .map(|since| format!(" since {}", since.with_timezone(&chrono::Local).format("%b %-d %-I:%M %p")))
+                .unwrap_or_default();
+            if ready.is_empty() {
+                println!("No tickets unblocked{}.", window);
+                return Ok(());
+            }
+            println!("{}", style::muted(format!("{} ticket(s) unblocked{}:", ready.len(), window)));
+            for item in &ready {
+                let unblocked = item.unblocked_at.map(dependencies::ago).unwrap_or_else(|| "unknown".to_string());
+                println!("{} [{}] {} {}", style::issue(&item.issue.key), style::warn(&item.issue.fields.status.name),
+                    item.issue.fields.summary, style::muted(format!("— unblocked {}", unblocked)));
+                for blocker in &item.blockers {
+                    let resolved = blocker.resolved_at.map(|at| format!(" (resolved {})", dependencies::ago(at))).unwrap_or_default();
+                    println!("    {} {} {}{}", style::success("✓"), style::issue(&blocker.key), blocker.summary, style::muted(resolved));
+                }
+            }
+        }
+        
+        JiraCommands::BlockedByMe => {
+            let blocked = dependencies::blocked_by_me(&client)?;
+            if output.is_json() {
+                return Output::print_json(&blocked);
+            }
+            
+            if blocked.is_empty() {
+                println!("Nobody is waiting on your tickets.");
+                return Ok(());
+            }
+            println!("{}", style::muted(format!("{} ticket(s) waiting on yours:", blocked.len())));
+            for item in &blocked {
+                let since = item.blocked_since.map(dependencies::ago).unwrap_or_else(|| "at an unknown time".to_string());
+                println!("{} [{}] {} {}", style::issue(&item.key), style::warn(&item.status),
+                    item.summary, style::muted(format!("— {}, blocked {}", item.assignee, since)));
+                for blocker in &item.blockers {
+                    println!("    {} {} {}", style::failure("⛔"), style::issue(&blocker.key), blocker.summary);
+                }
+            }
+        }
+        
+        JiraCommands::Epics { command: EpicsCommands::Export { csv } } => {
+            eprintln!("Fetching all epics...");
+            let epics = client.get_all_epics()?;
+            
+            eprintln!("Fetching child issues for {} epic(s)...", epics.len());
+            let keys: Vec<String> = epics.iter().map(|epic| epic.key.clone()).collect();
+            let mut rows = Vec::with_capacity(epics.len());
+            for (epic, children) in epics.iter().zip(client.get_children_of_epics(&keys)) {
+                let children = children.context(format!("Failed to fetch child issues of {}", epic.key))?;
+                rows.push(epic_report::EpicRollup::build(epic, &children));
+            }
+            
+            if csv {
+                print!("{}", epic_report::to_csv(&rows));
+            } else {
+                print!("{}", epic_report::to_table(&rows));
+            }
+        }
+        
+        JiraCommands::Mine { format } => {
             use ui::MyIssuesDisplay;
             
-            println!("Fetching issues assigned to you...");
-            let issues = client.get_my_issues()?;
+            output.check_format(&format)?;
+            if format.is_none() {
+                output.progress("Fetching issues assigned to you...");
+            }
+            let mut issues = client.get_my_issues()?;
+            IssueCache::record(&issues.iter().collect::<Vec<_>>());
+            
+            let snoozes = snooze::SnoozeStore::load();
+            let before = issues.len();
+            issues.retain(|issue| !snoozes.is_snoozed(&issue.key));
+            pin::PinStore::load().sort(&mut issues);
+            if output.is_json() {
+                return Output::print_json(&issues);
+            }
+            if let Some(format) = format {
+                return print_issues(&issues, &format);
+            }
+            if issues.len() < before {
+                println!("Hiding {} snoozed issue(s). Run 'qq jira snoozed' to list them.", before - issues.len());
+            }
             
             if issues.is_empty() {
                 println!("No issues currently assigned to you.");
             } else {
                 println!("Found {} issue(s) assigned to you.", issues.len());
                 // Display the issues in interactive UI
-                MyIssuesDisplay::show(issues, &client)?;
+                MyIssuesDisplay::show(issues, &client, theme)?;
+            }
+        }
+        
+        JiraCommands::Snooze { until, ticket } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            let until = snooze::parse_until(&until)?;
+            
+            snooze::SnoozeStore::edit(|snoozes| snoozes.snooze(&ticket_id, until))?;
+            
+            println!("Snoozed {} until {}", style::issue(&ticket_id), until.with_timezone(&chrono::Local).format("%a %b %-d %H:%M"));
+            sync_reminders();
+        }
+        
+        JiraCommands::Unsnooze { ticket } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket,
+                None => current_ticket_id()?,
+            };
+            
+            if snooze::SnoozeStore::edit(|snoozes| snoozes.unsnooze(&ticket_id))? {
+                println!("{} is back in 'qq jira mine'", ticket_id);
+                sync_reminders();
+            } else {
+                println!("{} isn't snoozed.", ticket_id);
+            }
+        }
+        
+        JiraCommands::Snoozed => {
+            let snoozes = snooze::SnoozeStore::load();
+            let active = snoozes.active();
+            
+            if active.is_empty() {
+                println!("No snoozed issues.");
+            } else {
+                let cache = IssueCache::load();
+                for (key, until) in active {
+                    let summary = cache.get(key)
+                        .map(|issue| issue.summary.as_str())
+                        .unwrap_or("");
+                    println!("{:<12} until {}  {}", key, until.with_timezone(&chrono::Local).format("%a %b %-d %H:%M"), summary);
+                }
+            }
+        }
+        
+        JiraCommands::Pinned => {
+            let pins = pin::PinStore::load();
+            
+            if pins.keys().is_empty() {
+                println!("No pinned issues. Press P in 'qq jira mine' or an epic view to pin one.");
+            } else {
+                let cache = IssueCache::load();
+                for key in pins.keys() {
+                    match cache.get(key) {
+                        Some(issue) => println!("{}", issue.one_line()),
+                        None => println!("{}", key),
+                    }
+                }
+            }
+        }
+        
+        JiraCommands::Projects { set_default } => {
+            if let Some(project) = set_default {
+                projects::RepoProjects::set_current(&project)?;
+                println!("{} is now this repository's default project", style::key(project.to_uppercase()));
+                return Ok(());
+            }
+            
+            println!("{}", style::muted("Fetching projects..."));
+            let summaries = projects::list(&client)?;
+            if summaries.is_empty() {
+                println!("No projects visible to you.");
+                return Ok(());
+            }
+            ui::ProjectsDisplay::show(summaries, projects::RepoProjects::current(), &client, theme)?;
+        }
+        
+        JiraCommands::Components { project } => {
+            let project = match project {
+                Some(project) => project,
+                None => current_project()?,
+            };
+            let meta = meta::MetaCache::project(&client, &project)?;
+            
+            println!("{} — {}", meta.key, meta.name);
+            println!("\nComponents:");
+            if meta.components.is_empty() {
+                println!("  (none)");
+            }
+            for component in &meta.components {
+                println!("  {}", component);
+            }
+            
+            println!("\nVersions:");
+            let versions: Vec<_> = meta.versions.iter().filter(|v| !v.archived).collect();
+            if versions.is_empty() {
+                println!("  (none)");
+            }
+            for version in versions {
+                println!("  {}{}", version.name, if version.released { " (released)" } else { "" });
+            }
+        }
+        
+        JiraCommands::Meta { command: MetaCommands::Refresh { projects } } => {
+            let mut projects: Vec<String> = projects.iter().map(|p| p.to_uppercase()).collect();
+            if projects.is_empty() {
+                projects = meta::MetaCache::load().project_keys();
+            }
+            if projects.is_empty() {
+                projects.push(current_project()?);
+            }
+            
+            for project in projects {
+                let meta = meta::MetaCache::refresh(&client, &project)?;
+                println!(
+                    "✓ {}: {} components, {} versions, {} issue types",
+                    meta.key,
+                    meta.components.len(),
+                    meta.versions.len(),
+                    meta.issue_types.len()
+                );
+            }
+        }
+        
+        JiraCommands::Fields { pin } => {
+            use ui::FieldsDisplay;
+            
+            println!("{}", style::muted("Fetching fields..."));
+            let fields = client.get_fields()?;
+            let mut config = Config::load()?;
+            
+            if pin {
+                let detected = jira::detect_fields(&fields);
+                
+                for (label, detected_id, slot) in [
+                    ("Story points", detected.story_points, &mut config.story_points_field),
+                    ("Epic link", detected.epic_link, &mut config.epic_link_field),
+                    ("Sprint", detected.sprint, &mut config.sprint_field),
+                    ("Reviewer", detected.reviewer, &mut config.reviewer_field),
+                    ("Flagged", detected.flagged, &mut config.flagged_field),
+                ] {
+                    match detected_id {
+                        Some(id) => {
+                            println!("{}: {}", label, id);
+                            *slot = Some(id);
+                        }
+                        None => println!("{}: not found", label),
+                    }
+                }
+                
+                config.save()?;
+                println!("Pinned fields saved to config.");
+            } else {
+                let pinned: Vec<(String, &'static str)> = [
+                    (config.story_points_field, "story points"),
+                    (config.epic_link_field, "epic link"),
+                    (config.sprint_field, "sprint"),
+                    (config.reviewer_field, "reviewer"),
+                    (config.flagged_field, "flagged"),
+                ]
+                .into_iter()
+                .filter_map(|(id, role)| id.map(|id| (id, role)))
+                .collect();
+                
+                FieldsDisplay::show(fields, pinned, theme)?;
+            }
+        }
+        
+        JiraCommands::Burnrate { project } => {
+            use ui::BurnrateDisplay;
+            
+            let sprint_field = client.sprint_field()
+                .context("Sprint field not configured. Run 'qq jira fields --pin' first.")?
+                .to_string();
+            let story_points_field = client.story_points_field().map(|f| f.to_string());
+            
+            let jql = match &project {
+                Some(project) => format!("project = \"{}\" AND sprint in openSprints()", project),
+                None => "sprint in openSprints()".to_string(),
+            };
+            let mut fields = format!("summary,status,assignee,created,resolutiondate,{}", sprint_field);
+            if let Some(points) = &story_points_field {
+                fields.push(',');
+                fields.push_str(points);
+            }
+            
+            output.progress("Fetching active sprint issues...");
+            let issues = client.search_raw(&jql, &fields, Some("changelog"))?;
+            let report = sprint::build_report(&issues, &sprint_field, story_points_field.as_deref())?;
+            
+            if output.is_json() {
+                Output::print_json(&report)?;
+            } else {
+                BurnrateDisplay::show(&report, theme)?;
+            }
+        }
+        
+        JiraCommands::Log { duration, comment, ticket, list } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket.to_uppercase(),
+                None => current_ticket_id()?,
+            };
+            
+            if list {
+                let worklogs = client.get_worklogs(&ticket_id)?;
+                if worklogs.is_empty() {
+                    println!("No time logged on {}.", ticket_id);
+                    return Ok(());
+                }
+                for worklog in &worklogs {
+                    let started = worklog.started_at()
+                        .map(|at| at.with_timezone(&chrono::Local).format("%a %b %d %H:%M").to_string())
+                        .unwrap_or_default();
+                    let author = worklog.author.as_ref().map(|a| a.display_name.as_str()).unwrap_or("Unknown");
+                    let comment = worklog.comment.as_ref()
+                        .map(|c| jira::adf::to_markdown(c).lines().next().unwrap_or_default().to_string())
+                        .unwrap_or_default();
+                    println!(
+                        "{}  {:<8} {:<20} {}",
+                        style::muted(started),
+                        timesheet::format_duration(worklog.time_spent_seconds),
+                        author,
+                        comment
+                    );
+                }
+                let total: u64 = worklogs.iter().map(|w| w.time_spent_seconds).sum();
+                let issue = client.get_issue(&ticket_id)?;
+                let remaining = issue.fields.timetracking.remaining_estimate.unwrap_or_else(|| "—".to_string());
+                println!();
+                println!("Total {} logged, {} remaining", timesheet::format_duration(total), remaining);
+                return Ok(());
+            }
+            
+            let duration = duration.context("A duration is required, e.g. 'qq jira log 2h'")?;
+            let seconds = timesheet::parse_duration(&duration)?;
+            // Logged as ending now, so the worklog covers the time just spent
+            let started = chrono::Local::now() - chrono::Duration::seconds(seconds as i64);
+            client.add_worklog(&ticket_id, started, seconds, comment.as_deref())?;
+            println!("{}", style::success(format!("✓ Logged {} on {}", timesheet::format_duration(seconds), ticket_id)));
+        }
+        
+        JiraCommands::Timesheet { week, last, csv, fill } => {
+            use chrono::TimeZone;
+            use std::io::{BufRead, IsTerminal, Write};
+            
+            let date = match week {
+                Some(week) => chrono::NaiveDate::parse_from_str(&week, "%Y-%m-%d")
+                    .context(format!("Invalid --week '{}', expected YYYY-MM-DD", week))?,
+                None if last => chrono::Local::now().date_naive() - chrono::Duration::weeks(1),
+                None => chrono::Local::now().date_naive(),
+            };
+            let (from, to) = timesheet::week_of(date);
+            
+            if !csv {
+                println!("{}", style::muted(format!("Fetching worklogs from {} to {}...", from, to)));
+            }
+            let worklogs = client.get_my_worklogs(from, to)?;
+            let sheet = timesheet::Timesheet::build(from, &worklogs);
+            
+            if csv {
+                print!("{}", sheet.to_csv());
+                return Ok(());
+            }
+            
+            let working_hours = Config::load()?.working_hours;
+            if !fill {
+                ui::TimesheetDisplay::show(&sheet, &working_hours, theme)?;
+                return Ok(());
+            }
+            
+            if !std::io::stdin().is_terminal() {
+                anyhow::bail!("--fill asks which ticket to log time on, so it needs a terminal");
+            }
+            let short_days = sheet.short_days(&working_hours);
+            if short_days.is_empty() {
+                println!("Every working day from {} to {} has a full day logged.", from, to);
+                return Ok(());
+            }
+            
+            // The ticket with the most time this week, or the current branch's, is the usual answer
+            let suggested = sheet.rows.iter()
+                .max_by_key(|row| row.total())
+                .map(|row| row.key.clone())
+                .or_else(|| resolve_ticket().ok());
+            let prompt = |text: &str| -> Result<String> {
+                print!("{}", text);
+                std::io::stdout().flush()?;
+                let mut input = String::new();
+                std::io::stdin().lock().read_line(&mut input)?;
+                Ok(input.trim().to_string())
+            };
+            
+            for (date, missing) in short_days {
+                let idx = sheet.days.iter().position(|d| *d == date).unwrap_or_default();
+                let logged = sheet.day_total(idx);
+                println!();
+                println!("{}: {} logged, {} missing", date.format("%a %b %-d"), timesheet::format_duration(logged), timesheet::format_duration(missing));
+                
+                let ticket = match &suggested {
+                    Some(key) => prompt(&format!("  Ticket (Enter for {}, 's' to skip): ", key))?,
+                    None => prompt("  Ticket (Enter to skip): ")?,
+                };
+                let ticket = match (ticket.as_str(), &suggested) {
+                    ("s" | "S", _) | ("", None) => continue,
+                    ("", Some(key)) => key.clone(),
+                    (key, _) => key.to_uppercase(),
+                };
+                let time = prompt(&format!("  Time (Enter for {}): ", timesheet::format_duration(missing)))?;
+                let seconds = if time.is_empty() { missing } else { timesheet::parse_duration(&time)? };
+                
+                // Start after what's already logged that day so entries don't overlap
+                let start = chrono::Local.from_local_datetime(&date.and_time(working_hours.start))
+                    .earliest()
+                    .unwrap_or_else(chrono::Local::now)
+                    + chrono::Duration::seconds(logged as i64);
+                client.add_worklog(&ticket, start, seconds, None)?;
+                println!("  {}", style::success(format!("✓ Logged {} on {}", timesheet::format_duration(seconds), ticket)));
+            }
+        }
+        
+        JiraCommands::Sla { project, all, format } => {
+            use ui::SlaDisplay;
+            
+            if sla::rules().is_empty() {
+                anyhow::bail!("No SLA rules configured. Add [[sla]] sections to your config (see the README).");
+            }
+            let rules: Vec<String> = sla::rules().iter()
+                .map(|(rule, _)| format!("({})", rule.jql()))
+                .collect();
+            let mut jql = rules.join(" OR ");
+            if let Some(project) = &project {
+                jql = format!("project = \"{}\" AND ({})", project, jql);
+            }
+            
+            if format.is_none() {
+                println!("{}", style::muted("Fetching open issues covered by SLA rules..."));
+            }
+            let raw = client.search_raw(&format!("{} ORDER BY created ASC", jql), "key,summary,status,assignee,priority,issuetype,created,updated", None)?;
+            let mut rows: Vec<(jira::JiraIssue, sla::SlaStatus)> = raw.into_iter()
+                .filter_map(|value| serde_json::from_value::<jira::JiraIssue>(value).ok())
+                .filter_map(|issue| sla::status(&issue).map(|status| (issue, status)))
+                .filter(|(_, status)| all || status.state != sla::SlaState::OnTrack)
+                .collect();
+            rows.sort_by_key(|(_, status)| (status.state, status.deadline));
+            
+            match format {
+                Some(format) => {
+                    let issues: Vec<jira::JiraIssue> = rows.into_iter().map(|(issue, _)| issue).collect();
+                    print_issues(&issues, &format)?;
+                }
+                None => SlaDisplay::show(rows, &client, theme)?,
+            }
+        }
+        
+        JiraCommands::ActivityFeed { project, epic, days } => {
+            use ui::ActivityFeedDisplay;
+            
+            if days < 1 {
+                anyhow::bail!("--days must be at least 1");
+            }
+            let scope = match (epic, project) {
+                (Some(epic), _) => activity::Scope::Epic(epic.to_uppercase()),
+                (None, Some(project)) => activity::Scope::Project(project.to_uppercase()),
+                (None, None) => activity::Scope::Project(current_project()?),
+            };
+            
+            if output.is_json() {
+                let mut events = Vec::new();
+                activity::load(&client, &scope, days, |page| {
+                    events.extend(page);
+                    true
+                })?;
+                events.sort_by_key(|event| std::cmp::Reverse(event.at));
+                return Output::print_json(&events);
+            }
+            ActivityFeedDisplay::show(scope, days, &client, theme)?;
+        }
+        
+        JiraCommands::Commit { message, ticket, git_args } => {
+            let ticket_id = match ticket {
+                Some(ticket) => ticket.to_uppercase(),
+                None => current_ticket_id()?,
+            };
+            let trailers = trailers::for_ticket(Some(&client), &ticket_id);
+            
+            let mut command = std::process::Command::new("git");
+            command.arg("commit").args(trailers::git_args(&trailers));
+            if let Some(message) = message {
+                command.args(["-m", &message]);
+            }
+            let status = command.args(&git_args)
+                .status()
+                .context("Failed to run 'git commit'")?;
+            if !status.success() {
+                std::process::exit(status.code().unwrap_or(1));
+            }
+        }
+        
+        // Handled before the config is loaded, above
+        JiraCommands::CommitMsg { .. } => {}
+        
+        JiraCommands::FromCommit { sha } => {
+            let repo = Repository::discover(".").context("Not inside a git repository")?;
+            let commit = repo.revparse_single(&sha)
+                .and_then(|object| object.peel_to_commit())
+                .context(format!("No commit '{}' in this repository", sha))?;
+            let message = commit.message().unwrap_or_default();
+            let short = commit.as_object().short_id().ok()
+                .and_then(|id| id.as_str().map(str::to_string))
+                .unwrap_or(sha);
+            
+            // Commits made before the trailers usually still mention the key in the summary
+            let mut keys = trailers::values(message, trailers::TICKET);
+            if keys.is_empty() {
+                let key = extract_ticket_id(commit.summary().unwrap_or_default())
+                    .map_err(|_| anyhow::anyhow!("Commit {} has no {} trailer and doesn't mention a ticket", short, trailers::TICKET))?;
+                output.progress(format!("Commit {} has no {} trailer; using {} from its message", short, trailers::TICKET, key));
+                keys.push(key);
+            }
+            
+            let issues = keys.iter()
+                .map(|key| client.get_issue(key))
+                .collect::<Result<Vec<_>>>()?;
+            IssueCache::record(&issues.iter().collect::<Vec<_>>());
+            if output.is_json() {
+                return Output::print_json(&issues);
+            }
+            for issue in &issues {
+                let assignee = issue.fields.assignee.as_ref().map(|a| a.display_name.as_str()).unwrap_or("Unassigned");
+                println!("{} [{}] {} — {}", style::issue(&issue.key), style::warn(&issue.fields.status.name),
+                    issue.fields.summary, style::muted(assignee));
+            }
+            for epic in trailers::values(message, trailers::EPIC) {
+                println!("{} {}", style::muted("Epic:"), style::issue(&epic));
+            }
+        }
+        
+        JiraCommands::RelateBranch { ticket, branch, clear } => {
+            let repo = Repository::discover(".").context("Not inside a git repository")?;
+            let branch = match branch {
+                Some(branch) => branch,
+                None => get_current_branch()?,
+            };
+            
+            if clear {
+                match branch_map::BranchMap::unrelate(&repo, &branch)? {
+                    Some(previous) => println!("Branch '{}' is no longer related to {}", branch, previous),
+                    None => println!("Branch '{}' has no related ticket", branch),
+                }
+                return Ok(());
+            }
+            
+            let ticket_id = ticket.unwrap_or_default().to_uppercase();
+            let issue = client.get_issue(&ticket_id)
+                .context(format!("Failed to find {}", ticket_id))?;
+            let previous = branch_map::BranchMap::relate(&repo, &branch, &issue.key)?;
+            println!("{}", style::success(format!("Branch '{}' related to {}: {}", branch, issue.key, issue.fields.summary)));
+            if let Some(previous) = previous.filter(|p| *p != issue.key) {
+                println!("{}", style::muted(format!("Replaces {}", previous)));
+            }
+        }
+        
+        JiraCommands::Status { .. } => {
+            let ticket_id = current_ticket_id()?;
+            
+            let issue = client.get_issue(&ticket_id)?;
+            let cached = IssueCache::edit(|cache| {
+                cache.update(&issue);
+                cache.get(&ticket_id).cloned()
+            })?;
+            
+            if let Some(cached) = cached {
+                println!("{}", cached.one_line());
             }
         }
     }
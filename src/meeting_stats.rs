@@ -0,0 +1,148 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::google::{Meeting, WorkingHours};
+
+#[derive(Debug, Serialize)]
+pub struct DayLoad {
+    pub date: NaiveDate,
+    // Within working hours, with overlapping meetings counted once
+    pub meeting_hours: f64,
+    pub longest_free_minutes: i64,
+    // Start and end of the longest free block, e.g. "13:30-17:00"
+    pub longest_free_block: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurringLoad {
+    pub summary: String,
+    pub occurrences: usize,
+    pub hours: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MeetingStats {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub meetings: usize,
+    // Every accepted meeting in the window, including outside working hours
+    pub total_hours: f64,
+    pub working_hours: f64,
+    pub meeting_percent: f64,
+    pub days: Vec<DayLoad>,
+    pub top_recurring: Vec<RecurringLoad>,
+}
+
+// Monday of the week `weeks - 1` weeks ago through Sunday of this week
+pub fn window(weeks: u32) -> (NaiveDate, NaiveDate) {
+    let today = Local::now().date_naive();
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let from = this_monday - Duration::weeks(weeks.saturating_sub(1) as i64);
+    (from, this_monday + Duration::days(6))
+}
+
+pub fn start_of(date: NaiveDate) -> DateTime<Local> {
+    Local.from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .earliest()
+        .unwrap_or_else(Local::now)
+}
+
+pub fn compute(meetings: &[Meeting], hours: &WorkingHours, from: NaiveDate, to: NaiveDate, top: usize) -> MeetingStats {
+    // All-day events, time off, and meetings I declined don't take up my time
+    let counted: Vec<&Meeting> = meetings.iter()
+        .filter(|m| !m.is_all_day && !m.is_out_of_office && !m.is_declined && m.status != "cancelled")
+        .collect();
+    let intervals: Vec<(DateTime<Local>, DateTime<Local>)> = counted.iter()
+        .map(|m| (m.start_time.with_timezone(&Local), m.end_time.with_timezone(&Local)))
+        .collect();
+    let busy = merge(intervals);
+
+    let total_minutes: i64 = busy.iter().map(|(start, end)| (*end - *start).num_minutes()).sum();
+
+    let mut days = Vec::new();
+    let mut working_minutes = 0;
+    let mut meeting_minutes = 0;
+    let mut date = from;
+    while date <= to {
+        if hours.days.contains(&date.weekday())
+            && let (Some(day_start), Some(day_end)) = (
+                Local.from_local_datetime(&date.and_time(hours.start)).earliest(),
+                Local.from_local_datetime(&date.and_time(hours.end)).earliest(),
+            )
+        {
+            let clipped: Vec<(DateTime<Local>, DateTime<Local>)> = busy.iter()
+                .filter(|(start, end)| *start < day_end && *end > day_start)
+                .map(|(start, end)| ((*start).max(day_start), (*end).min(day_end)))
+                .collect();
+            let busy_minutes: i64 = clipped.iter().map(|(start, end)| (*end - *start).num_minutes()).sum();
+
+            // Gaps between meetings, plus before the first and after the last
+            let mut free_start = day_start;
+            let mut longest: Option<(DateTime<Local>, DateTime<Local>)> = None;
+            for (start, end) in clipped.iter().copied().chain(std::iter::once((day_end, day_end))) {
+                if start > free_start && longest.is_none_or(|(s, e)| start - free_start > e - s) {
+                    longest = Some((free_start, start));
+                }
+                free_start = free_start.max(end);
+            }
+
+            working_minutes += (day_end - day_start).num_minutes();
+            meeting_minutes += busy_minutes;
+            days.push(DayLoad {
+                date,
+                meeting_hours: to_hours(busy_minutes),
+                longest_free_minutes: longest.map(|(s, e)| (e - s).num_minutes()).unwrap_or(0),
+                longest_free_block: longest.map(|(s, e)| format!("{}-{}", s.format("%H:%M"), e.format("%H:%M"))),
+            });
+        }
+        date += Duration::days(1);
+    }
+
+    // Occurrences of the same series share a recurring event ID
+    let mut recurring: HashMap<&str, (&str, usize, i64)> = HashMap::new();
+    for meeting in &counted {
+        if let Some(id) = meeting.recurring_event_id.as_deref() {
+            let (_, occurrences, minutes) = recurring.entry(id).or_insert((&meeting.summary, 0, 0));
+            *occurrences += 1;
+            *minutes += (meeting.end_time - meeting.start_time).num_minutes();
+        }
+    }
+    let mut top_recurring: Vec<RecurringLoad> = recurring.into_values()
+        .map(|(summary, occurrences, minutes)| RecurringLoad {
+            summary: summary.to_string(),
+            occurrences,
+            hours: to_hours(minutes),
+        })
+        .collect();
+    top_recurring.sort_by(|a, b| b.hours.total_cmp(&a.hours).then_with(|| a.summary.cmp(&b.summary)));
+    top_recurring.truncate(top);
+
+    MeetingStats {
+        from,
+        to,
+        meetings: counted.len(),
+        total_hours: to_hours(total_minutes),
+        working_hours: to_hours(working_minutes),
+        meeting_percent: if working_minutes > 0 { meeting_minutes as f64 / working_minutes as f64 * 100.0 } else { 0.0 },
+        days,
+        top_recurring,
+    }
+}
+
+// Sorts and merges overlapping intervals so double-booked time is only counted once
+fn merge(mut intervals: Vec<(DateTime<Local>, DateTime<Local>)>) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+    intervals.sort();
+    let mut merged: Vec<(DateTime<Local>, DateTime<Local>)> = Vec::new();
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn to_hours(minutes: i64) -> f64 {
+    (minutes as f64 / 60.0 * 10.0).round() / 10.0
+}
@@ -0,0 +1,71 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::jira::{JiraClient, ProjectMeta};
+use crate::store;
+
+// Components and versions change rarely; `qq jira meta refresh` forces an update sooner
+const MAX_AGE_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedMeta {
+    meta: ProjectMeta,
+    fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct MetaCache {
+    #[serde(default)]
+    projects: HashMap<String, CachedMeta>,
+}
+
+impl MetaCache {
+    pub fn load() -> Self {
+        Self::cache_path()
+            .map(|path| store::read_json(&path))
+            .unwrap_or_default()
+    }
+
+    // Cached metadata for the project, fetched first when missing or older than a day. A
+    // stale copy is still returned when Jira can't be reached.
+    pub fn project(client: &JiraClient, project_key: &str) -> Result<ProjectMeta> {
+        let project_key = project_key.to_uppercase();
+        let cached = Self::load().projects.remove(&project_key);
+        if let Some(cached) = &cached
+            && Utc::now() - cached.fetched_at < Duration::hours(MAX_AGE_HOURS)
+        {
+            return Ok(cached.meta.clone());
+        }
+
+        match Self::refresh(client, &project_key) {
+            Ok(meta) => Ok(meta),
+            Err(e) => cached.map(|c| c.meta).ok_or(e),
+        }
+    }
+
+    // Fetches the project's metadata and stores it, regardless of the cached copy's age
+    pub fn refresh(client: &JiraClient, project_key: &str) -> Result<ProjectMeta> {
+        let meta = client.get_project_meta(project_key)
+            .context(format!("Failed to fetch metadata for project {}", project_key))?;
+        let cached = CachedMeta { meta: meta.clone(), fetched_at: Utc::now() };
+        store::edit_json(&Self::cache_path()?, |cache: &mut Self| {
+            cache.projects.insert(meta.key.clone(), cached);
+        })?;
+        Ok(meta)
+    }
+
+    pub fn project_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.projects.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    fn cache_path() -> Result<PathBuf> {
+        let cache_dir = dirs::cache_dir()
+            .context("Failed to determine cache directory")?;
+        Ok(cache_dir.join("qq").join("meta.json"))
+    }
+}
@@ -0,0 +1,262 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::presence::{self, Presence};
+use crate::store;
+use crate::style;
+
+const DEFAULT_NTFY_SERVER: &str = "https://ntfy.sh";
+
+// One entry per `[[notify]]` table in config.toml
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NotifyBackend {
+    Desktop,
+    Bell,
+    Slack {
+        webhook_url: String,
+    },
+    Ntfy {
+        topic: String,
+        #[serde(default)]
+        server: Option<String>,
+        #[serde(default)]
+        token: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub title: String,
+    pub body: String,
+    pub url: Option<String>,
+    // Urgent notifications go out even during meetings
+    #[serde(default)]
+    pub urgent: bool,
+}
+
+// `[do_not_disturb]` in config.toml
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DoNotDisturb {
+    // Hold non-urgent notifications while a calendar meeting is in progress
+    #[serde(default = "default_during_meetings")]
+    pub during_meetings: bool,
+}
+
+fn default_during_meetings() -> bool {
+    true
+}
+
+impl Default for DoNotDisturb {
+    fn default() -> Self {
+        Self { during_meetings: default_during_meetings() }
+    }
+}
+
+pub enum Delivery {
+    Sent,
+    // Queued until the meeting (or run of back-to-back meetings) ends
+    Held { until: DateTime<Utc> },
+}
+
+impl NotifyBackend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotifyBackend::Desktop => "desktop",
+            NotifyBackend::Bell => "bell",
+            NotifyBackend::Slack { .. } => "slack",
+            NotifyBackend::Ntfy { .. } => "ntfy",
+        }
+    }
+
+    fn send(&self, notification: &Notification) -> Result<()> {
+        match self {
+            NotifyBackend::Desktop => send_desktop(notification),
+            NotifyBackend::Bell => {
+                let mut stderr = std::io::stderr();
+                writeln!(stderr, "\x07{}: {}", notification.title, notification.body)?;
+                Ok(())
+            }
+            NotifyBackend::Slack { webhook_url } => {
+                let mut text = format!("*{}*\n{}", notification.title, notification.body);
+                if let Some(url) = &notification.url {
+                    text.push_str(&format!("\n{}", url));
+                }
+
+                let response = reqwest::blocking::Client::new()
+                    .post(webhook_url)
+                    .json(&serde_json::json!({ "text": text }))
+                    .send()
+                    .context("Failed to reach Slack webhook")?;
+                if !response.status().is_success() {
+                    anyhow::bail!("Slack webhook returned {}", response.status());
+                }
+                Ok(())
+            }
+            NotifyBackend::Ntfy { topic, server, token } => {
+                let server = server.as_deref().unwrap_or(DEFAULT_NTFY_SERVER);
+                let url = format!("{}/{}", server.trim_end_matches('/'), topic);
+
+                let mut request = reqwest::blocking::Client::new()
+                    .post(&url)
+                    .header("Title", &notification.title)
+                    .body(notification.body.clone());
+                if let Some(click) = &notification.url {
+                    request = request.header("Click", click);
+                }
+                if let Some(token) = token {
+                    request = request.bearer_auth(token);
+                }
+
+                let response = request.send().context("Failed to reach ntfy server")?;
+                if !response.status().is_success() {
+                    anyhow::bail!("ntfy returned {}", response.status());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Uses the platform's own notifier so no extra system libraries are needed
+fn send_desktop(notification: &Notification) -> Result<()> {
+    let status = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {:?} with title {:?}",
+            notification.body, notification.title
+        );
+        Command::new("osascript").args(["-e", &script]).status()
+    } else {
+        Command::new("notify-send")
+            .args(["--app-name=qq", &notification.title, &notification.body])
+            .status()
+    };
+
+    let status = status.context("Failed to run the desktop notifier (notify-send or osascript)")?;
+    if !status.success() {
+        anyhow::bail!("Desktop notifier exited with {}", status);
+    }
+    Ok(())
+}
+
+// Delivers now, or holds a non-urgent notification while I'm in a meeting. Anything held
+// from an earlier meeting goes out first.
+pub fn send(backends: &[NotifyBackend], dnd: &DoNotDisturb, notification: &Notification) -> Result<Delivery> {
+    if !notification.urgent
+        && dnd.during_meetings
+        && let Presence::InMeeting { until, .. } = presence::current()
+    {
+        let notification = notification.clone();
+        store::edit_json(&held_path()?, |held: &mut HeldNotifications| held.items.push(notification))?;
+        return Ok(Delivery::Held { until });
+    }
+
+    flush_held(backends, dnd)?;
+    deliver(backends, notification)?;
+    Ok(Delivery::Sent)
+}
+
+// Sends what was held during a meeting once it's over, as one batched notification when
+// there's more than one. Returns how many were delivered.
+pub fn flush_held(backends: &[NotifyBackend], dnd: &DoNotDisturb) -> Result<usize> {
+    if dnd.during_meetings && presence::current() != Presence::Available {
+        return Ok(0);
+    }
+
+    let path = held_path()?;
+    let held = store::edit_json(&path, |held: &mut HeldNotifications| std::mem::take(&mut held.items))?;
+    let result = match held.as_slice() {
+        [] => return Ok(0),
+        [single] => deliver(backends, single),
+        many => deliver(backends, &Notification {
+            title: format!("{} notifications while you were in a meeting", many.len()),
+            body: many.iter().map(|n| format!("• {}", n.title)).collect::<Vec<_>>().join("\n"),
+            url: None,
+            urgent: false,
+        }),
+    };
+
+    // Put them back rather than lose them if every backend failed
+    if let Err(e) = result {
+        let count = held.len();
+        store::edit_json(&path, |queue: &mut HeldNotifications| {
+            queue.items.splice(0..0, held);
+        })?;
+        return Err(e.context(format!("Failed to deliver {} held notification(s)", count)));
+    }
+    Ok(held.len())
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HeldNotifications {
+    #[serde(default)]
+    items: Vec<Notification>,
+}
+
+fn held_path() -> Result<PathBuf> {
+    let cache_dir = dirs::cache_dir()
+        .context("Failed to determine cache directory")?;
+    Ok(cache_dir.join("qq").join("held_notifications.json"))
+}
+
+// Delivers to every configured backend; one failing output doesn't stop the others.
+// With nothing configured, falls back to a desktop notification.
+fn deliver(backends: &[NotifyBackend], notification: &Notification) -> Result<()> {
+    let fallback = [NotifyBackend::Desktop];
+    let backends = if backends.is_empty() { &fallback[..] } else { backends };
+
+    let failures: Vec<String> = backends.iter()
+        .filter_map(|backend| {
+            backend.send(notification)
+                .err()
+                .map(|e| format!("{}: {}", backend.name(), e))
+        })
+        .collect();
+
+    if failures.len() == backends.len() {
+        anyhow::bail!("All notification backends failed ({})", failures.join("; "));
+    }
+    for failure in failures {
+        style::warning(format!("notification backend failed: {}", failure));
+    }
+    Ok(())
+}
+
+// Remembers what has already been announced so repeated runs (e.g. from cron) stay quiet
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SentLog {
+    #[serde(default)]
+    sent: HashMap<String, DateTime<Utc>>,
+}
+
+impl SentLog {
+    // Holds the log's lock for the whole change, so overlapping runs can't announce the
+    // same event twice
+    pub fn edit<R>(change: impl FnOnce(&mut Self) -> R) -> Result<R> {
+        store::edit_json(&Self::log_path()?, |log: &mut Self| {
+            let result = change(log);
+            let cutoff = Utc::now() - Duration::days(7);
+            log.sent.retain(|_, at| *at > cutoff);
+            result
+        })
+    }
+
+    pub fn was_sent(&self, key: &str) -> bool {
+        self.sent.contains_key(key)
+    }
+
+    pub fn mark_sent(&mut self, key: &str) {
+        self.sent.insert(key.to_string(), Utc::now());
+    }
+
+    fn log_path() -> Result<PathBuf> {
+        let cache_dir = dirs::cache_dir()
+            .context("Failed to determine cache directory")?;
+        Ok(cache_dir.join("qq").join("notified.json"))
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::google::Meeting;
+use crate::jira::JiraIssue;
+use crate::sla::SlaState;
+use crate::template::TemplateValues;
+
+pub const ISSUE_PLACEHOLDERS: &str = "key, summary, status, assignee, priority, type, points, parent, due, sla, url, description";
+// Used by list commands that print plain lines even without --format
+pub const DEFAULT_ISSUE_FORMAT: &str = "{key}\t[{status}]\t{summary}";
+pub const MEETING_PLACEHOLDERS: &str = "id, summary, start, end, time, status, url, location, ticket";
+
+// --format takes either a name from the `[formats]` config section or a template.
+// Backslash escapes are expanded so `"{key}\t{summary}"` works without $'...' quoting.
+pub fn resolve_format(format: &str, named: &HashMap<String, String>) -> String {
+    let template = named.get(format).map(|t| t.as_str()).unwrap_or(format);
+    template
+        .replace("\\t", "\t")
+        .replace("\\n", "\n")
+}
+
+pub fn issue_values(issue: &JiraIssue, url: String) -> TemplateValues {
+    let fields = &issue.fields;
+    let sla = crate::sla::status(issue);
+    let mut values = TemplateValues::new();
+    let key = match sla.as_ref().map(|s| s.state) {
+        Some(SlaState::Breached) => crate::style::failure(&issue.key),
+        Some(SlaState::AtRisk) => crate::style::warn(&issue.key),
+        _ => crate::style::key(&issue.key),
+    };
+    values.set("key", crate::style::link(key, &url));
+    values.set("summary", fields.summary.clone());
+    values.set("status", fields.status.name.clone());
+    values.set("assignee", fields.assignee.as_ref().map(|a| a.display_name.clone()).unwrap_or_default());
+    values.set("priority", fields.priority.as_ref().map(|p| p.name.clone()).unwrap_or_default());
+    values.set("type", fields.issuetype.as_ref().map(|t| t.name.clone()).unwrap_or_default());
+    values.set("points", fields.story_points.map(|p| p.to_string()).unwrap_or_default());
+    values.set("parent", fields.parent.as_ref().map(|p| p.key.clone()).unwrap_or_default());
+    values.set("due", fields.duedate.clone().unwrap_or_default());
+    values.set("sla", sla.map(|s| s.remaining_label()).unwrap_or_default());
+    values.set("url", url);
+    values.set("description", fields.description.as_ref().map(crate::jira::adf::to_markdown).unwrap_or_default());
+    values
+}
+
+pub fn meeting_values(meeting: &Meeting) -> TemplateValues {
+    let now = chrono::Utc::now();
+    let status = if now >= meeting.start_time && now <= meeting.end_time {
+        "in-progress"
+    } else if now < meeting.start_time {
+        "upcoming"
+    } else {
+        "ended"
+    };
+
+    let mut values = TemplateValues::new();
+    values.set("id", meeting.id.clone());
+    values.set("summary", crate::style::meeting(&meeting.summary, meeting.meeting_url.as_deref()));
+    values.set("start", meeting.start_time.to_rfc3339());
+    values.set("end", meeting.end_time.to_rfc3339());
+    values.set("time", meeting.time_range());
+    values.set("status", status);
+    values.set("url", meeting.meeting_url.clone().unwrap_or_default());
+    values.set("location", meeting.location.clone().unwrap_or_default());
+    values.set("ticket", meeting.ticket.clone().unwrap_or_default());
+    values
+}
+
+// Quotes a value for CSV output when it contains a separator, quote, or newline
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Prints one line per row, failing up front if the template names an unknown placeholder
+pub fn print_rows(template: &str, rows: Vec<TemplateValues>, available: &str) -> Result<()> {
+    let mut lines = Vec::with_capacity(rows.len());
+    for row in rows {
+        let (line, missing) = row.fill(template);
+        if !missing.is_empty() {
+            anyhow::bail!("Unknown placeholder(s) in --format: {}. Available: {}", missing.join(", "), available);
+        }
+        lines.push(line);
+    }
+
+    for line in lines {
+        println!("{}", line);
+    }
+    Ok(())
+}
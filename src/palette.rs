@@ -0,0 +1,143 @@
+use chrono::{DateTime, Local, Utc};
+use std::collections::HashMap;
+
+use crate::alias::Alias;
+use crate::cache::IssueCache;
+use crate::google::Meeting;
+
+// What running bare `qq` offers: every subcommand, aliases, recently seen tickets, and today's meetings
+#[derive(Debug, Clone)]
+pub enum PaletteAction {
+    // Arguments after `qq`; `needs_input` when the command has required arguments to type in
+    Run { args: Vec<String>, needs_input: bool },
+    OpenUrl(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub section: &'static str,
+    pub label: String,
+    pub detail: String,
+    pub action: PaletteAction,
+}
+
+impl PaletteEntry {
+    // Text the fuzzy search runs against
+    pub fn search_text(&self) -> String {
+        format!("{} {}", self.label, self.detail)
+    }
+}
+
+// Every runnable command in the clap tree, e.g. "jira mine". Commands whose subcommand is
+// optional are listed alongside their subcommands.
+pub fn command_entries(root: &clap::Command) -> Vec<PaletteEntry> {
+    let mut entries = Vec::new();
+    collect_commands(root, &mut Vec::new(), &mut entries);
+    entries
+}
+
+fn collect_commands(command: &clap::Command, path: &mut Vec<String>, entries: &mut Vec<PaletteEntry>) {
+    let subcommands: Vec<&clap::Command> = command.get_subcommands()
+        .filter(|sub| !sub.is_hide_set() && sub.get_name() != "help")
+        .collect();
+
+    if !path.is_empty() && (subcommands.is_empty() || !command.is_subcommand_required_set()) {
+        entries.push(PaletteEntry {
+            section: "Command",
+            label: format!("qq {}", path.join(" ")),
+            detail: command.get_about().map(|about| about.to_string()).unwrap_or_default(),
+            action: PaletteAction::Run {
+                args: path.clone(),
+                needs_input: command.get_arguments().any(|arg| arg.is_required_set()),
+            },
+        });
+    }
+
+    for sub in subcommands {
+        path.push(sub.get_name().to_string());
+        collect_commands(sub, path, entries);
+        path.pop();
+    }
+}
+
+pub fn alias_entries(aliases: &HashMap<String, Alias>) -> Vec<PaletteEntry> {
+    let mut entries: Vec<PaletteEntry> = aliases.iter()
+        .map(|(name, alias)| PaletteEntry {
+            section: "Alias",
+            label: format!("qq {}", name),
+            detail: alias.describe(),
+            action: PaletteAction::Run {
+                args: vec![name.clone()],
+                needs_input: false,
+            },
+        })
+        .collect();
+    entries.sort_by(|a, b| a.label.cmp(&b.label));
+    entries
+}
+
+// The most recently fetched tickets from the local cache; selecting one opens its context view
+pub fn ticket_entries(cache: &IssueCache, limit: usize) -> Vec<PaletteEntry> {
+    cache.recent(limit)
+        .into_iter()
+        .map(|issue| PaletteEntry {
+            section: "Ticket",
+            label: issue.key.clone(),
+            detail: format!("[{}] {}", issue.status, issue.summary),
+            action: PaletteAction::Run {
+                args: vec!["jira".to_string(), "context".to_string(), issue.key.clone()],
+                needs_input: false,
+            },
+        })
+        .collect()
+}
+
+// Meetings that haven't ended yet; selecting one joins it, or opens the list when there's no link
+pub fn meeting_entries(meetings: &[Meeting], now: DateTime<Utc>) -> Vec<PaletteEntry> {
+    meetings.iter()
+        .filter(|m| !m.is_all_day && m.end_time > now)
+        .map(|m| PaletteEntry {
+            section: "Meeting",
+            label: m.start_time.with_timezone(&Local).format("%H:%M").to_string(),
+            detail: m.summary.clone(),
+            action: match &m.meeting_url {
+                Some(url) => PaletteAction::OpenUrl(url.clone()),
+                None => PaletteAction::Run {
+                    args: vec!["meetings".to_string(), "list".to_string()],
+                    needs_input: false,
+                },
+            },
+        })
+        .collect()
+}
+
+// Splits typed arguments on whitespace, keeping "quoted text" or 'quoted text' together
+pub fn split_args(input: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut has_arg = false;
+
+    for c in input.chars() {
+        match c {
+            '"' | '\'' if quote.is_none_or(|q| q == c) => {
+                quote = if quote.is_some() { None } else { Some(c) };
+                has_arg = true;
+            }
+            c if c.is_whitespace() && quote.is_none() => {
+                if has_arg {
+                    args.push(std::mem::take(&mut current));
+                    has_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_arg = true;
+            }
+        }
+    }
+    if has_arg {
+        args.push(current);
+    }
+    args
+}
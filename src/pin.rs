@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::jira::JiraIssue;
+use crate::store;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PinStore {
+    // In the order they were pinned
+    #[serde(default)]
+    issues: Vec<String>,
+}
+
+impl PinStore {
+    pub fn load() -> Self {
+        Self::store_path()
+            .map(|path| store::read_json(&path))
+            .unwrap_or_default()
+    }
+
+    // Pins the issue if it isn't pinned and unpins it otherwise, returning whether it is now pinned
+    pub fn toggle(issue_key: &str) -> Result<bool> {
+        store::edit_json(&Self::store_path()?, |pins: &mut Self| {
+            if pins.is_pinned(issue_key) {
+                pins.issues.retain(|key| key != issue_key);
+                false
+            } else {
+                pins.issues.push(issue_key.to_string());
+                true
+            }
+        })
+    }
+
+    pub fn is_pinned(&self, issue_key: &str) -> bool {
+        self.issues.iter().any(|key| key == issue_key)
+    }
+
+    pub fn keys(&self) -> &[String] {
+        &self.issues
+    }
+
+    // Moves pinned issues to the top, keeping the existing order within each group
+    pub fn sort(&self, issues: &mut [JiraIssue]) {
+        issues.sort_by_key(|issue| !self.is_pinned(&issue.key));
+    }
+
+    fn store_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .context("Failed to determine data directory")?;
+        Ok(data_dir.join("qq").join("pinned.json"))
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Mutex;
+use std::thread;
+
+// Runs `f` over `items` on up to `workers` threads, returning results in input order.
+// Used to fan out blocking HTTP calls (e.g. batched JIRA searches) without going async.
+pub fn run_parallel<T, R, F>(items: Vec<T>, workers: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let total = items.len();
+    let queue: Mutex<Vec<(usize, T)>> = Mutex::new(items.into_iter().enumerate().rev().collect());
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..total).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, total.max(1)) {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().pop();
+                let Some((idx, item)) = next else {
+                    break;
+                };
+
+                let result = f(item);
+                results.lock().unwrap()[idx] = Some(result);
+            });
+        }
+    });
+
+    results.into_inner().unwrap().into_iter().flatten().collect()
+}
@@ -0,0 +1,122 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+use crate::google::{Attendee, BlockingCalendarClient, Meeting};
+use crate::jira::{JiraClient, JiraIssue};
+
+// Upper bound on simultaneous Jira requests when looking up tickets and attendees
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+// How far back to look for the last occurrence; covers weekly through monthly series
+const PREVIOUS_LOOKBACK_DAYS: i64 = 35;
+const MAX_ATTENDEE_TICKETS: usize = 50;
+
+pub struct AttendeeTickets {
+    pub attendee: Attendee,
+    pub issues: Vec<JiraIssue>,
+}
+
+// What's worth reading before a meeting, for `qq meetings prep`
+pub struct MeetingPrep {
+    pub meeting: Meeting,
+    // Tickets whose keys appear in the invite
+    pub tickets: Vec<JiraIssue>,
+    // The last occurrence of a recurring meeting, with whatever notes were attached to it
+    pub previous: Option<Meeting>,
+    // Open tickets assigned to other attendees that I reported, watch, or share an epic with
+    pub attendee_tickets: Vec<AttendeeTickets>,
+    // False when Jira isn't configured, so the ticket sections are left out
+    pub has_jira: bool,
+}
+
+impl MeetingPrep {
+    pub fn gather(calendar: &BlockingCalendarClient, jira: Option<&JiraClient>, meeting: Meeting) -> Result<Self> {
+        // The calendar and Jira lookups don't depend on each other
+        let (previous, tickets, attendee_tickets) = std::thread::scope(|scope| {
+            let tickets = scope.spawn(|| jira.map(|client| invite_tickets(client, &meeting)).unwrap_or_default());
+            let attendee_tickets = scope.spawn(|| jira.map_or(Ok(Vec::new()), |client| shared_tickets(client, &meeting.attendees)));
+            let previous = previous_occurrence(calendar, &meeting);
+            (previous, tickets.join(), attendee_tickets.join())
+        });
+        let tickets = tickets.map_err(|_| anyhow::anyhow!("Ticket fetch thread panicked"))?;
+        let attendee_tickets = attendee_tickets.map_err(|_| anyhow::anyhow!("Attendee ticket fetch thread panicked"))??;
+
+        Ok(Self {
+            meeting,
+            tickets,
+            previous: previous?,
+            attendee_tickets,
+            has_jira: jira.is_some(),
+        })
+    }
+}
+
+// Keys that look like tickets but don't exist (or aren't visible) are skipped
+fn invite_tickets(client: &JiraClient, meeting: &Meeting) -> Vec<JiraIssue> {
+    let text = [Some(meeting.summary.as_str()), meeting.ticket.as_deref(), meeting.description.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n");
+    crate::pool::run_parallel(crate::jira::issue_keys(&text), MAX_CONCURRENT_REQUESTS, |key| client.get_issue(&key).ok())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn previous_occurrence(calendar: &BlockingCalendarClient, meeting: &Meeting) -> Result<Option<Meeting>> {
+    let Some(series) = meeting.recurring_event_id.as_deref() else {
+        return Ok(None);
+    };
+    let start = meeting.start_time.with_timezone(&Utc);
+    let earlier = calendar.list_meetings_between(start - Duration::days(PREVIOUS_LOOKBACK_DAYS), start)?;
+    Ok(earlier.into_iter()
+        .filter(|m| m.recurring_event_id.as_deref() == Some(series) && m.id != meeting.id)
+        .max_by_key(|m| m.start_time))
+}
+
+// Attendees are matched to Jira accounts by email; people Jira doesn't know (or hides the
+// email of) are left out
+fn shared_tickets(client: &JiraClient, attendees: &[Attendee]) -> Result<Vec<AttendeeTickets>> {
+    let accounts: Vec<(Attendee, String)> = crate::pool::run_parallel(attendees.to_vec(), MAX_CONCURRENT_REQUESTS, |attendee| {
+        let users = client.search_users(&attendee.email).ok()?;
+        let user = users.iter()
+            .find(|u| u.email_address.as_deref().is_some_and(|e| e.eq_ignore_ascii_case(&attendee.email)))
+            .or(if users.len() == 1 { users.first() } else { None })?;
+        Some((attendee, user.account_id.clone()))
+    })
+    .into_iter()
+    .flatten()
+    .collect();
+    if accounts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let epics: Vec<String> = client.get_my_issues()?
+        .iter()
+        .filter_map(|issue| issue.fields.parent.as_ref().map(|parent| parent.key.clone()))
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let mut shared = vec!["reporter = currentUser()".to_string(), "watcher = currentUser()".to_string()];
+    if !epics.is_empty() {
+        shared.push(format!("parent in ({})", epics.join(",")));
+    }
+    let ids: Vec<String> = accounts.iter().map(|(_, id)| format!("\"{}\"", id)).collect();
+    let jql = format!(
+        "assignee in ({}) AND statusCategory != Done AND ({}) ORDER BY updated DESC",
+        ids.join(","),
+        shared.join(" OR ")
+    );
+    let issues = client.search_jql(&jql, MAX_ATTENDEE_TICKETS)?;
+
+    Ok(accounts.into_iter()
+        .map(|(attendee, account_id)| {
+            let issues = issues.iter()
+                .filter(|issue| issue.fields.assignee.as_ref().is_some_and(|a| a.account_id == account_id))
+                .cloned()
+                .collect();
+            AttendeeTickets { attendee, issues }
+        })
+        .filter(|tickets| !tickets.issues.is_empty())
+        .collect())
+}
@@ -0,0 +1,89 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[cfg(feature = "calendar")]
+use crate::google::Meeting;
+use crate::store;
+
+// Whether I'm in a meeting right now. Every calendar fetch records its busy times here, so
+// notifiers can check presence from a cron job without a network call or a Google sign-in.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BusyWindow {
+    summary: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+}
+
+// Busy times per Google account, so fetching one account doesn't forget another's meetings
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BusyTimes {
+    #[serde(default)]
+    calendars: HashMap<String, Vec<BusyWindow>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Presence {
+    Available,
+    InMeeting {
+        summary: String,
+        // End of the current meeting, or of the last one in a back-to-back run
+        until: DateTime<Utc>,
+    },
+}
+
+// Meetings I'm actually attending; all-day events and out-of-office blocks don't count
+#[cfg(feature = "calendar")]
+fn is_busy(meeting: &Meeting) -> bool {
+    !meeting.is_declined && !meeting.is_all_day && !meeting.is_out_of_office
+}
+
+// Only calendar fetches record; without the calendar client presence is always available
+#[cfg(feature = "calendar")]
+pub fn record(calendar: &str, meetings: &[Meeting]) -> Result<()> {
+    let windows: Vec<BusyWindow> = meetings.iter()
+        .filter(|m| is_busy(m))
+        .map(|m| BusyWindow {
+            summary: m.summary.clone(),
+            start: m.start_time.with_timezone(&Utc),
+            end: m.end_time.with_timezone(&Utc),
+        })
+        .collect();
+
+    store::edit_json(&busy_path()?, |busy: &mut BusyTimes| {
+        busy.calendars.insert(calendar.to_string(), windows);
+    })
+}
+
+// Unknown presence (nothing recorded yet, unreadable file) counts as available
+pub fn current() -> Presence {
+    let Ok(path) = busy_path() else {
+        return Presence::Available;
+    };
+    let busy: BusyTimes = store::read_json(&path);
+    let mut windows: Vec<&BusyWindow> = busy.calendars.values().flatten().collect();
+    windows.sort_by_key(|w| w.start);
+
+    let now = Utc::now();
+    let Some(meeting) = windows.iter().find(|w| w.start <= now && now < w.end) else {
+        return Presence::Available;
+    };
+
+    let mut until = meeting.end;
+    for window in &windows {
+        if window.start <= until && window.end > until {
+            until = window.end;
+        }
+    }
+
+    Presence::InMeeting { summary: meeting.summary.clone(), until }
+}
+
+fn busy_path() -> Result<PathBuf> {
+    let cache_dir = dirs::cache_dir()
+        .context("Failed to determine cache directory")?;
+    Ok(cache_dir.join("qq").join("presence.json"))
+}
@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use git2::Repository;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::branch_map::repo_key;
+use crate::jira::{JiraClient, Project};
+use crate::store;
+
+// A project visible to me, for `qq jira projects`
+pub struct ProjectSummary {
+    pub project: Project,
+    // My unresolved issues in the project
+    pub open_mine: usize,
+}
+
+pub fn list(client: &JiraClient) -> Result<Vec<ProjectSummary>> {
+    let (projects, mine) = std::thread::scope(|scope| {
+        let mine = scope.spawn(|| client.search_raw("assignee = currentUser() AND statusCategory != Done", "project", None));
+        (client.get_projects(), mine.join())
+    });
+    let mine = mine.map_err(|_| anyhow::anyhow!("Issue count thread panicked"))??;
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for issue in &mine {
+        if let Some(key) = issue.pointer("/fields/project/key").and_then(Value::as_str) {
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+    Ok(projects?.into_iter()
+        .map(|project| {
+            let open_mine = counts.get(project.key.as_str()).copied().unwrap_or(0);
+            ProjectSummary { project, open_mine }
+        })
+        .collect())
+}
+
+// The issue lists the project browser opens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectView {
+    Backlog,
+    Board,
+    Epics,
+}
+
+impl ProjectView {
+    pub const ALL: [ProjectView; 3] = [ProjectView::Backlog, ProjectView::Board, ProjectView::Epics];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProjectView::Backlog => "Backlog",
+            ProjectView::Board => "Board",
+            ProjectView::Epics => "Epics",
+        }
+    }
+
+    pub fn jql(self, project: &str) -> String {
+        let project = crate::jql::quote(project);
+        match self {
+            // Not done and not in a running sprint, in backlog order
+            ProjectView::Backlog => format!(
+                "project = {} AND statusCategory != Done AND (sprint IS EMPTY OR sprint NOT IN openSprints()) ORDER BY rank ASC",
+                project
+            ),
+            ProjectView::Board => format!("project = {} AND sprint IN openSprints() ORDER BY status ASC, rank ASC", project),
+            ProjectView::Epics => format!("project = {} AND issuetype = Epic AND statusCategory != Done ORDER BY updated DESC", project),
+        }
+    }
+}
+
+// Default project per repository, used when the branch doesn't name a ticket. Keyed like
+// BranchMap, so worktrees share their repository's default.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RepoProjects {
+    // Repository git dir -> project key
+    #[serde(default)]
+    repos: HashMap<String, String>,
+}
+
+impl RepoProjects {
+    pub fn default_for(repo: &Repository) -> Option<String> {
+        let store: Self = store::read_json(&Self::store_path().ok()?);
+        store.repos.get(&repo_key(repo)).cloned()
+    }
+
+    // Default for the repository in the current directory
+    pub fn current() -> Option<String> {
+        Repository::discover(".").ok().and_then(|repo| Self::default_for(&repo))
+    }
+
+    pub fn set_current(project: &str) -> Result<()> {
+        let repo = Repository::discover(".").context("Not inside a git repository")?;
+        store::edit_json(&Self::store_path()?, |store: &mut Self| {
+            store.repos.insert(repo_key(&repo), project.to_uppercase());
+        })
+    }
+
+    fn store_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .context("Failed to determine data directory")?;
+        Ok(data_dir.join("qq").join("repo_projects.json"))
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use reqwest::header::{ACCEPT, USER_AGENT};
+use serde::Deserialize;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/miriarte33/QQ-CLI/releases";
+
+#[derive(Debug, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub published_at: Option<String>,
+}
+
+pub fn current_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+pub fn fetch_latest() -> Result<Release> {
+    let url = format!("{}/latest", RELEASES_URL);
+    get_json(&url).context("Failed to fetch latest release")
+}
+
+pub fn fetch_releases() -> Result<Vec<Release>> {
+    get_json(RELEASES_URL).context("Failed to fetch releases")
+}
+
+// Compares dotted numeric versions, ignoring a leading 'v' and any pre-release suffix
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    fn parse(version: &str) -> Vec<u64> {
+        version.trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or("")
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+
+    parse(candidate) > parse(current)
+}
+
+fn get_json<T: for<'de> Deserialize<'de>>(url: &str) -> Result<T> {
+    let response = Client::new()
+        .get(url)
+        .header(USER_AGENT, format!("qq/{}", current_version()))
+        .header(ACCEPT, "application/vnd.github+json")
+        .send()
+        .context("Failed to send request to GitHub")?;
+
+    let status = response.status();
+    let response_text = response.text()?;
+
+    if !status.is_success() {
+        anyhow::bail!("GitHub API error: {}", status);
+    }
+
+    serde_json::from_str(&response_text).context("Failed to parse GitHub response")
+}
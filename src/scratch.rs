@@ -0,0 +1,88 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use std::path::PathBuf;
+use std::process::Command;
+
+// Private markdown notes per ticket for `qq jira scratch`, kept as ~/.config/qq/scratch/KEY.md so
+// they can also be opened, grepped, or synced like any other file
+
+pub struct Note {
+    pub key: String,
+    pub modified: Option<DateTime<Local>>,
+    // First non-blank line, as a preview
+    pub first_line: String,
+}
+
+fn scratch_dir() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .context("Failed to determine config directory")?;
+    Ok(config_dir.join("qq").join("scratch"))
+}
+
+pub fn path(key: &str) -> Result<PathBuf> {
+    Ok(scratch_dir()?.join(format!("{}.md", key.to_uppercase())))
+}
+
+// The note's text, or None when there isn't one or it's blank
+pub fn read(key: &str) -> Option<String> {
+    let text = std::fs::read_to_string(path(key).ok()?).ok()?;
+    (!text.trim().is_empty()).then_some(text)
+}
+
+// Opens the note in $VISUAL or $EDITOR (vi when neither is set) and waits for it to close
+pub fn edit(key: &str) -> Result<()> {
+    let path = path(key)?;
+    std::fs::create_dir_all(scratch_dir()?)
+        .context("Failed to create the scratch directory")?;
+
+    let editor = std::env::var("VISUAL").ok()
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    // Through the shell, so editors configured with flags like "code --wait" work
+    let status = Command::new("sh")
+        .args(["-c", &format!("{} \"$1\"", editor), "sh"])
+        .arg(&path)
+        .status()
+        .context(format!("Failed to run editor '{}'", editor))?;
+    if !status.success() {
+        anyhow::bail!("Editor '{}' exited with {}", editor, status);
+    }
+    Ok(())
+}
+
+pub fn remove(key: &str) -> Result<()> {
+    let path = path(key)?;
+    if path.exists() {
+        std::fs::remove_file(&path)
+            .context(format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(())
+}
+
+// Every non-blank note, most recently edited first
+pub fn list() -> Result<Vec<Note>> {
+    let dir = scratch_dir()?;
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+
+    let mut notes: Vec<Note> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+                return None;
+            }
+            let key = path.file_stem()?.to_str()?.to_string();
+            let text = std::fs::read_to_string(&path).ok()?;
+            let first_line = text.lines().map(str::trim).find(|line| !line.is_empty())?.to_string();
+            let modified = entry.metadata().ok()
+                .and_then(|metadata| metadata.modified().ok())
+                .map(DateTime::<Local>::from);
+            Some(Note { key, modified, first_line })
+        })
+        .collect();
+    notes.sort_by_key(|note| std::cmp::Reverse(note.modified));
+    Ok(notes)
+}
@@ -0,0 +1,41 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Zsh,
+    Bash,
+}
+
+const ZSH_HOOK: &str = r#"# qq: show ticket context when the git branch changes
+_qq_branch_hook() {
+  local branch
+  branch=$(git symbolic-ref --short HEAD 2>/dev/null)
+  if [[ "$branch" != "$_QQ_LAST_BRANCH" ]]; then
+    _QQ_LAST_BRANCH="$branch"
+    [[ -n "$branch" ]] && qq jira status --cached 2>/dev/null
+  fi
+}
+autoload -Uz add-zsh-hook
+add-zsh-hook precmd _qq_branch_hook
+"#;
+
+const BASH_HOOK: &str = r#"# qq: show ticket context when the git branch changes
+_qq_branch_hook() {
+  local branch
+  branch=$(git symbolic-ref --short HEAD 2>/dev/null)
+  if [[ "$branch" != "$_QQ_LAST_BRANCH" ]]; then
+    _QQ_LAST_BRANCH="$branch"
+    [[ -n "$branch" ]] && qq jira status --cached 2>/dev/null
+  fi
+}
+if [[ ";${PROMPT_COMMAND};" != *";_qq_branch_hook;"* ]]; then
+  PROMPT_COMMAND="_qq_branch_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}"
+fi
+"#;
+
+pub fn hook_script(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Zsh => ZSH_HOOK,
+        Shell::Bash => BASH_HOOK,
+    }
+}
@@ -0,0 +1,141 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::jira::JiraIssue;
+
+// Resolution targets from `[[sla]]` in the config, e.g. critical bugs within 3 days. Lists
+// highlight issues that breached their target or are close to it, and `qq jira sla` reports them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaRule {
+    pub name: String,
+    // Unset matches every issue type or priority
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    // "3d", "12h", "2w", or combinations like "1d 12h"
+    pub resolve_within: String,
+}
+
+impl SlaRule {
+    fn matches(&self, issue: &JiraIssue) -> bool {
+        let fields = &issue.fields;
+        let same = |wanted: &Option<String>, actual: Option<&str>| {
+            wanted.as_ref().is_none_or(|w| actual.is_some_and(|a| a.eq_ignore_ascii_case(w)))
+        };
+        same(&self.issue_type, fields.issuetype.as_ref().map(|t| t.name.as_str()))
+            && same(&self.priority, fields.priority.as_ref().map(|p| p.name.as_str()))
+    }
+
+    // JQL for the open issues this rule covers, used by the report
+    pub fn jql(&self) -> String {
+        let mut clauses = vec!["statusCategory != Done".to_string()];
+        if let Some(issue_type) = &self.issue_type {
+            clauses.push(format!("issuetype = \"{}\"", issue_type));
+        }
+        if let Some(priority) = &self.priority {
+            clauses.push(format!("priority = \"{}\"", priority));
+        }
+        clauses.join(" AND ")
+    }
+}
+
+// Issues in the last quarter of their window count as at risk
+const AT_RISK_SHARE: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SlaState {
+    Breached,
+    AtRisk,
+    OnTrack,
+}
+
+#[derive(Debug, Clone)]
+pub struct SlaStatus {
+    pub rule: String,
+    pub state: SlaState,
+    pub deadline: DateTime<Utc>,
+}
+
+impl SlaStatus {
+    // "2d overdue" or "5h left"
+    pub fn remaining_label(&self) -> String {
+        let remaining = self.deadline - Utc::now();
+        if remaining < Duration::zero() {
+            format!("{} overdue", span(-remaining))
+        } else {
+            format!("{} left", span(remaining))
+        }
+    }
+}
+
+fn span(d: Duration) -> String {
+    match d.num_hours() {
+        0 => format!("{}m", d.num_minutes()),
+        h if h < 48 => format!("{}h", h),
+        _ => format!("{}d", d.num_days()),
+    }
+}
+
+// "3d", "12h", "2w", "1d 12h"
+pub fn parse_window(input: &str) -> Result<Duration> {
+    let mut total = Duration::zero();
+    for part in input.split_whitespace() {
+        let (amount, unit) = part.split_at(part.len() - part.chars().last().map_or(0, |c| c.len_utf8()));
+        let amount: i64 = amount.parse()
+            .map_err(|_| anyhow::anyhow!("Could not understand '{}'. Use a duration like 3d, 12h, or 1w", input))?;
+        total += match unit {
+            "w" => Duration::weeks(amount),
+            "d" => Duration::days(amount),
+            "h" => Duration::hours(amount),
+            _ => anyhow::bail!("Unknown duration unit in '{}' (use w, d, or h)", part),
+        };
+    }
+    if total <= Duration::zero() {
+        anyhow::bail!("Duration must be positive");
+    }
+    Ok(total)
+}
+
+static RULES: OnceLock<Vec<(SlaRule, Duration)>> = OnceLock::new();
+
+// Rules with an unreadable window are skipped with a warning rather than failing every command
+pub fn init(rules: &[SlaRule]) {
+    let parsed = rules.iter()
+        .filter_map(|rule| match parse_window(&rule.resolve_within) {
+            Ok(window) => Some((rule.clone(), window)),
+            Err(e) => {
+                crate::style::warning(format!("ignoring [[sla]] rule '{}': {}", rule.name, e));
+                None
+            }
+        })
+        .collect();
+    let _ = RULES.set(parsed);
+}
+
+pub fn rules() -> &'static [(SlaRule, Duration)] {
+    RULES.get().map(|r| r.as_slice()).unwrap_or_default()
+}
+
+// The first matching rule decides; done issues and issues without a created date have none
+pub fn status(issue: &JiraIssue) -> Option<SlaStatus> {
+    if issue.fields.is_done() {
+        return None;
+    }
+    let created = issue.fields.created_at()?.with_timezone(&Utc);
+    let (rule, window) = rules().iter().find(|(rule, _)| rule.matches(issue))?;
+
+    let deadline = created + *window;
+    let remaining = deadline - Utc::now();
+    let state = if remaining < Duration::zero() {
+        SlaState::Breached
+    } else if (remaining.num_seconds() as f64) < window.num_seconds() as f64 * AT_RISK_SHARE {
+        SlaState::AtRisk
+    } else {
+        SlaState::OnTrack
+    };
+
+    Some(SlaStatus { rule: rule.name.clone(), state, deadline })
+}
@@ -0,0 +1,145 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::cache::IssueCache;
+use crate::google::BlockingCalendarClient;
+use crate::store;
+
+// Date-only snoozes wake at the start of the working day
+const WAKE_TIME: (u32, u32) = (9, 0);
+// Kind of reminder event snoozes are written as, see `[reminders]`
+const CALENDAR_KIND: &str = "snooze";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SnoozeStore {
+    #[serde(default)]
+    issues: HashMap<String, DateTime<Utc>>,
+}
+
+impl SnoozeStore {
+    pub fn load() -> Self {
+        Self::store_path()
+            .map(|path| store::read_json(&path))
+            .unwrap_or_default()
+    }
+
+    // Applies a change under the store's lock so concurrent qq processes don't lose updates
+    pub fn edit<R>(change: impl FnOnce(&mut Self) -> R) -> Result<R> {
+        store::edit_json(&Self::store_path()?, |snoozes: &mut Self| {
+            let result = change(snoozes);
+            // Expired snoozes have already reappeared, so there is no reason to keep them around
+            let now = Utc::now();
+            snoozes.issues.retain(|_, until| *until > now);
+            result
+        })
+    }
+
+    pub fn snooze(&mut self, issue_key: &str, until: DateTime<Utc>) {
+        self.issues.insert(issue_key.to_string(), until);
+    }
+
+    // True when the issue was snoozed
+    pub fn unsnooze(&mut self, issue_key: &str) -> bool {
+        self.issues.remove(issue_key).is_some()
+    }
+
+    pub fn is_snoozed(&self, issue_key: &str) -> bool {
+        self.issues.get(issue_key).is_some_and(|until| *until > Utc::now())
+    }
+
+    // Active snoozes, soonest to wake first
+    pub fn active(&self) -> Vec<(&String, &DateTime<Utc>)> {
+        let now = Utc::now();
+        let mut active: Vec<_> = self.issues.iter()
+            .filter(|(_, until)| **until > now)
+            .collect();
+        active.sort_by_key(|(_, until)| **until);
+        active
+    }
+
+    fn store_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .context("Failed to determine data directory")?;
+        Ok(data_dir.join("qq").join("snoozed.json"))
+    }
+}
+
+// Accepts a duration like 30m, 4h, 2d, 1w, the word "tomorrow", or a local date/datetime
+pub fn parse_until(input: &str) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+    let now = Local::now();
+
+    if input.eq_ignore_ascii_case("tomorrow") {
+        let tomorrow = now.date_naive() + Duration::days(1);
+        return local_to_utc(tomorrow.and_hms_opt(WAKE_TIME.0, WAKE_TIME.1, 0).context("Invalid wake time")?);
+    }
+
+    if let Some(unit) = input.chars().last()
+        && let Ok(amount) = input[..input.len() - unit.len_utf8()].parse::<i64>()
+    {
+        let duration = match unit {
+            'm' => Duration::minutes(amount),
+            'h' => Duration::hours(amount),
+            'd' => Duration::days(amount),
+            'w' => Duration::weeks(amount),
+            _ => anyhow::bail!("Unknown duration unit '{}' (use m, h, d, or w)", unit),
+        };
+        if duration <= Duration::zero() {
+            anyhow::bail!("Snooze duration must be positive");
+        }
+        return Ok((now + duration).with_timezone(&Utc));
+    }
+
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+        return local_to_utc(datetime);
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        let wake = NaiveTime::from_hms_opt(WAKE_TIME.0, WAKE_TIME.1, 0).context("Invalid wake time")?;
+        return local_to_utc(date.and_time(wake));
+    }
+
+    anyhow::bail!("Could not understand '{}'. Use a duration (30m, 4h, 2d, 1w), 'tomorrow', or a date (YYYY-MM-DD [HH:MM])", input)
+}
+
+fn local_to_utc(datetime: NaiveDateTime) -> Result<DateTime<Utc>> {
+    let local = Local.from_local_datetime(&datetime)
+        .earliest()
+        .context("That time does not exist in your local timezone")?;
+    if local <= Local::now() {
+        anyhow::bail!("Snooze time must be in the future");
+    }
+    Ok(local.with_timezone(&Utc))
+}
+
+// Makes the calendar's snooze events match the active snoozes: each gets one event at its wake
+// time, and events for snoozes that were cleared or moved are deleted. Past events are left as
+// a record of when the ticket came back.
+pub fn sync_calendar(calendar: &BlockingCalendarClient) -> Result<()> {
+    let snoozes = SnoozeStore::load();
+    let mut pending: HashMap<&String, &DateTime<Utc>> = snoozes.active().into_iter().collect();
+
+    for event in calendar.list_reminder_events(CALENDAR_KIND)? {
+        let current = pending.get(&event.ticket)
+            .is_some_and(|until| (**until - event.at).num_seconds().abs() < 60);
+        if current {
+            pending.remove(&event.ticket);
+        } else {
+            calendar.delete_reminder_event(&event.id)?;
+        }
+    }
+
+    let cache = IssueCache::load();
+    for (key, until) in pending {
+        let summary = match cache.get(key) {
+            Some(issue) => format!("⏰ {}: {}", key, issue.summary),
+            None => format!("⏰ {}", key),
+        };
+        let description = format!("{} was snoozed with qq and is back in 'qq jira mine'.", key);
+        calendar.create_reminder_event(CALENDAR_KIND, key, &summary, &description, *until)?;
+    }
+    Ok(())
+}
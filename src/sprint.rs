@@ -0,0 +1,241 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::jira::{parse_jira_datetime, JiraClient, Sprint};
+use crate::ui::Theme;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SprintInfo {
+    pub id: u64,
+    pub name: String,
+    pub start: Option<DateTime<FixedOffset>>,
+    pub end: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DailyProgress {
+    pub date: NaiveDate,
+    pub completed_points: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssigneeLoad {
+    pub name: String,
+    pub issues: usize,
+    pub points: f64,
+    pub completed_points: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SprintReport {
+    pub sprint: SprintInfo,
+    pub committed_points: f64,
+    pub added_points: f64,
+    pub completed_points: f64,
+    pub added_issues: Vec<String>,
+    pub daily: Vec<DailyProgress>,
+    pub assignees: Vec<AssigneeLoad>,
+}
+
+impl SprintReport {
+    pub fn total_points(&self) -> f64 {
+        self.committed_points + self.added_points
+    }
+
+    pub fn remaining_points(&self) -> f64 {
+        (self.total_points() - self.completed_points).max(0.0)
+    }
+}
+
+// An active or future sprint of the board for `issue_key` (a project key works too): the one
+// `query` names by id, name, or part of a name, or one picked from a list without a query.
+// None when the list is closed.
+pub fn pick(client: &JiraClient, issue_key: &str, query: Option<&str>, title: &str, theme: &Theme) -> Result<Option<Sprint>> {
+    let mut sprints = client.get_open_sprints(issue_key)?;
+    if sprints.is_empty() {
+        anyhow::bail!("The board has no active or future sprints");
+    }
+
+    let index = match query {
+        Some(query) => {
+            let query = query.to_lowercase();
+            let index = sprints.iter()
+                .position(|s| s.id.to_string() == query || s.name.to_lowercase() == query)
+                .or_else(|| sprints.iter().position(|s| s.name.to_lowercase().contains(&query)))
+                .context(format!(
+                    "No active or future sprint matches '{}'. Open sprints: {}",
+                    query,
+                    sprints.iter().map(|s| s.name.as_str()).collect::<Vec<_>>().join(", ")
+                ))?;
+            Some(index)
+        }
+        None => crate::ui::ListPicker::show(title, sprints.iter().map(|s| s.label()).collect(), theme)?,
+    };
+    Ok(index.map(|index| sprints.swap_remove(index)))
+}
+
+// Picks the active sprint out of an issue's sprint field value
+pub fn active_sprint(issue: &Value, sprint_field: &str) -> Option<SprintInfo> {
+    issue.get("fields")?
+        .get(sprint_field)?
+        .as_array()?
+        .iter()
+        .find(|s| s.get("state").and_then(|v| v.as_str()) == Some("active"))
+        .map(|s| SprintInfo {
+            id: s.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+            name: s.get("name").and_then(|v| v.as_str()).unwrap_or("(unnamed sprint)").to_string(),
+            start: s.get("startDate").and_then(|v| v.as_str()).and_then(parse_jira_datetime),
+            end: s.get("endDate").and_then(|v| v.as_str()).and_then(parse_jira_datetime),
+        })
+}
+
+pub fn build_report(issues: &[Value], sprint_field: &str, story_points_field: Option<&str>) -> Result<SprintReport> {
+    let sprint = issues.iter()
+        .find_map(|issue| active_sprint(issue, sprint_field))
+        .context("No active sprint found for these issues")?;
+
+    let mut committed_points = 0.0;
+    let mut added_points = 0.0;
+    let mut added_issues = Vec::new();
+    let mut completions: Vec<(NaiveDate, f64)> = Vec::new();
+    let mut assignees: BTreeMap<String, AssigneeLoad> = BTreeMap::new();
+
+    for issue in issues {
+        let key = issue.get("key").and_then(|k| k.as_str()).unwrap_or("");
+        let fields = issue.get("fields").unwrap_or(&Value::Null);
+
+        // Only count issues that are actually in the sprint we're reporting on
+        if active_sprint(issue, sprint_field).map(|s| s.id) != Some(sprint.id) {
+            continue;
+        }
+
+        let points = story_points_field
+            .and_then(|f| fields.get(f))
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+
+        let is_done = fields.get("status")
+            .and_then(|s| s.get("statusCategory"))
+            .and_then(|c| c.get("key"))
+            .and_then(|k| k.as_str())
+            == Some("done");
+
+        if was_added_after_start(issue, &sprint) {
+            added_points += points;
+            added_issues.push(key.to_string());
+        } else {
+            committed_points += points;
+        }
+
+        if is_done {
+            let resolved = fields.get("resolutiondate")
+                .and_then(|d| d.as_str())
+                .and_then(parse_jira_datetime);
+            if let Some(resolved) = resolved {
+                completions.push((resolved.date_naive(), points));
+            }
+        }
+
+        let assignee_name = fields.get("assignee")
+            .and_then(|a| a.get("displayName"))
+            .and_then(|n| n.as_str())
+            .unwrap_or("Unassigned")
+            .to_string();
+        let load = assignees.entry(assignee_name.clone()).or_insert(AssigneeLoad {
+            name: assignee_name,
+            issues: 0,
+            points: 0.0,
+            completed_points: 0.0,
+        });
+        load.issues += 1;
+        load.points += points;
+        if is_done {
+            load.completed_points += points;
+        }
+    }
+
+    let completed_points = completions.iter().map(|(_, p)| p).sum();
+    let daily = daily_progress(&sprint, &completions);
+
+    let mut assignees: Vec<AssigneeLoad> = assignees.into_values().collect();
+    assignees.sort_by(|a, b| b.points.total_cmp(&a.points));
+
+    Ok(SprintReport {
+        sprint,
+        committed_points,
+        added_points,
+        completed_points,
+        added_issues,
+        daily,
+        assignees,
+    })
+}
+
+// An issue counts as scope change if it joined the sprint (or was created) after the sprint started
+fn was_added_after_start(issue: &Value, sprint: &SprintInfo) -> bool {
+    let Some(start) = sprint.start else {
+        return false;
+    };
+    let sprint_id = sprint.id.to_string();
+
+    let histories = issue.get("changelog")
+        .and_then(|c| c.get("histories"))
+        .and_then(|h| h.as_array());
+
+    for history in histories.into_iter().flatten() {
+        let Some(created) = history.get("created").and_then(|c| c.as_str()).and_then(parse_jira_datetime) else {
+            continue;
+        };
+        if created <= start {
+            continue;
+        }
+
+        let items = history.get("items").and_then(|i| i.as_array());
+        for item in items.into_iter().flatten() {
+            if item.get("field").and_then(|f| f.as_str()) != Some("Sprint") {
+                continue;
+            }
+            let contains = |side: &str| {
+                item.get(side)
+                    .and_then(|v| v.as_str())
+                    .map(|ids| ids.split(',').any(|id| id.trim() == sprint_id))
+                    .unwrap_or(false)
+            };
+            if contains("to") && !contains("from") {
+                return true;
+            }
+        }
+    }
+
+    issue.get("fields")
+        .and_then(|f| f.get("created"))
+        .and_then(|c| c.as_str())
+        .and_then(parse_jira_datetime)
+        .map(|created| created > start)
+        .unwrap_or(false)
+}
+
+fn daily_progress(sprint: &SprintInfo, completions: &[(NaiveDate, f64)]) -> Vec<DailyProgress> {
+    let today = chrono::Local::now().date_naive();
+    let Some(start) = sprint.start.map(|s| s.date_naive()) else {
+        return Vec::new();
+    };
+    let end = sprint.end
+        .map(|e| e.date_naive())
+        .unwrap_or(today)
+        .min(today);
+
+    start.iter_days()
+        .take_while(|day| *day <= end)
+        .map(|date| DailyProgress {
+            date,
+            completed_points: completions.iter()
+                .filter(|(d, _)| *d <= date)
+                .map(|(_, p)| p)
+                .sum(),
+        })
+        .collect()
+}
@@ -0,0 +1,108 @@
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+// Several qq processes can run at once (the shell hook, a cron reminder, an interactive
+// view), so every shared file is written under an advisory lock and replaced with a rename.
+// Readers never take the lock: a rename is atomic, so they see either the old or new file.
+
+// Exclusive lock on `<file>.lock`, released when dropped
+pub struct FileLock {
+    _file: File,
+}
+
+pub fn lock(path: &Path) -> Result<FileLock> {
+    create_parent(path)?;
+    let lock_path = sibling(path, ".lock");
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .context(format!("Failed to open lock file {}", lock_path.display()))?;
+    file.lock()
+        .context(format!("Failed to lock {}", path.display()))?;
+
+    Ok(FileLock { _file: file })
+}
+
+// Writes to a temporary file next to `path` and renames it into place. Files are created
+// private to the user since the config and token files hold credentials.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    create_parent(path)?;
+    let tmp_path = sibling(path, &format!(".{}.tmp", std::process::id()));
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(&tmp_path)
+        .context(format!("Failed to create {}", tmp_path.display()))?;
+    file.write_all(contents)
+        .and_then(|_| file.sync_all())
+        .context(format!("Failed to write {}", tmp_path.display()))?;
+
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        anyhow::Error::new(e).context(format!("Failed to replace {}", path.display()))
+    })
+}
+
+// A missing or unreadable file is treated as empty, which suits caches; use `edit_json` to change one
+pub fn read_json<T: DeserializeOwned + Default>(path: &Path) -> T {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+// Read-modify-write of a JSON file under its lock, so concurrent updates aren't lost
+pub fn edit_json<T, R>(path: &Path, change: impl FnOnce(&mut T) -> R) -> Result<R>
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let _lock = lock(path)?;
+    // Unlike `read_json`, a file that exists but doesn't parse is set aside first: writing the
+    // default over it would wipe whatever it held
+    let mut value: T = match fs::read_to_string(path) {
+        Ok(contents) => match serde_json::from_str(&contents) {
+            Ok(value) => value,
+            Err(e) => {
+                let backup = sibling(path, ".bak");
+                fs::rename(path, &backup)
+                    .context(format!("Failed to parse {} ({}) or move it aside", path.display(), e))?;
+                crate::style::warning(format!("couldn't parse {} ({}); moved it to {} and started over", path.display(), e, backup.display()));
+                T::default()
+            }
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => T::default(),
+        Err(e) => return Err(anyhow::Error::new(e).context(format!("Failed to read {}", path.display()))),
+    };
+    let result = change(&mut value);
+
+    let json = serde_json::to_string_pretty(&value)?;
+    write_atomic(path, json.as_bytes())?;
+
+    Ok(result)
+}
+
+fn create_parent(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .context(format!("Failed to create directory {}", parent.display()))?;
+    }
+    Ok(())
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
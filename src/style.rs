@@ -0,0 +1,142 @@
+use crossterm::style::{Color, Stylize};
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::ui::Theme;
+
+// Colors for plain (non-TUI) output, using the same theme as the interactive views.
+// Turned off by --no-color or a non-empty NO_COLOR (https://no-color.org), and per stream
+// whenever stdout or stderr isn't a terminal, so pipes and $(...) always get plain text.
+static DISABLED: AtomicBool = AtomicBool::new(false);
+// Plain output has no draw functions to pass the theme through, so it keeps its own copy
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+pub fn init(no_color: bool) {
+    let env_off = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    DISABLED.store(no_color || env_off, Ordering::Relaxed);
+}
+
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+fn stdout_enabled() -> bool {
+    !DISABLED.load(Ordering::Relaxed) && std::io::stdout().is_terminal()
+}
+
+fn stderr_enabled() -> bool {
+    !DISABLED.load(Ordering::Relaxed) && std::io::stderr().is_terminal()
+}
+
+fn paint(text: impl Display, color: ratatui::style::Color, enabled: bool) -> String {
+    if enabled {
+        text.to_string().with(Color::from(color)).to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+// For lines written to stderr, colored only when stderr is a terminal
+pub fn muted_stderr(text: impl Display) -> String {
+    paint(text, theme().muted, stderr_enabled())
+}
+
+pub fn success_stderr(text: impl Display) -> String {
+    paint(text, theme().success, stderr_enabled())
+}
+
+// Ticket keys and other identifiers
+pub fn key(text: impl Display) -> String {
+    paint(text, theme().accent, stdout_enabled())
+}
+
+pub fn success(text: impl Display) -> String {
+    paint(text, theme().success, stdout_enabled())
+}
+
+pub fn failure(text: impl Display) -> String {
+    paint(text, theme().error, stdout_enabled())
+}
+
+pub fn warn(text: impl Display) -> String {
+    paint(text, theme().highlight, stdout_enabled())
+}
+
+// Progress lines and secondary details
+pub fn muted(text: impl Display) -> String {
+    paint(text, theme().muted, stdout_enabled())
+}
+
+pub fn heading(text: impl Display) -> String {
+    if stdout_enabled() {
+        text.to_string().with(Color::from(theme().label)).bold().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+// Clickable links (OSC 8) around issue keys and meeting names. Only sent to terminals known to
+// support them, since others can show the escape codes as garbage; FORCE_HYPERLINK=1 or 0
+// overrides the guess. Pipes and $(...) never get them.
+static JIRA_URL: OnceLock<String> = OnceLock::new();
+
+pub fn set_jira_url(url: &str) {
+    let _ = JIRA_URL.set(url.trim_end_matches('/').to_string());
+}
+
+fn links_enabled() -> bool {
+    static SUPPORTED: OnceLock<bool> = OnceLock::new();
+    *SUPPORTED.get_or_init(terminal_supports_links) && std::io::stdout().is_terminal()
+}
+
+fn terminal_supports_links() -> bool {
+    let var = |name: &str| std::env::var(name).unwrap_or_default();
+    if let Ok(force) = std::env::var("FORCE_HYPERLINK") {
+        return !force.is_empty() && force != "0";
+    }
+    let term = var("TERM");
+    if term == "dumb" || term == "linux" {
+        return false;
+    }
+    ["WT_SESSION", "KONSOLE_VERSION", "DOMTERM"].iter().any(|name| std::env::var_os(name).is_some())
+        || var("VTE_VERSION").parse::<u32>().is_ok_and(|version| version >= 5000)
+        || ["iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper"].contains(&var("TERM_PROGRAM").as_str())
+        || ["xterm-kitty", "alacritty", "foot", "xterm-ghostty", "wezterm"].iter().any(|prefix| term.starts_with(prefix))
+}
+
+pub fn link(text: impl Display, url: &str) -> String {
+    if url.is_empty() || !links_enabled() {
+        return text.to_string();
+    }
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+// An issue key, colored and linked to the issue in Jira
+pub fn issue(issue_key: impl Display) -> String {
+    let issue_key = issue_key.to_string();
+    let url = JIRA_URL.get()
+        .map(|base| format!("{}/browse/{}", base, issue_key))
+        .unwrap_or_default();
+    link(key(&issue_key), &url)
+}
+
+// A meeting's title, linked to its video call when it has one
+pub fn meeting(summary: impl Display, meeting_url: Option<&str>) -> String {
+    link(summary, meeting_url.unwrap_or_default())
+}
+
+// "Warning: ..." on stderr
+pub fn warning(message: impl Display) {
+    eprintln!("{} {}", paint("Warning:", theme().highlight, stderr_enabled()), message);
+}
+
+// Same layout as returning the error from main, with a colored prefix
+pub fn print_error(error: &anyhow::Error) {
+    eprintln!("{} {:?}", paint("Error:", theme().error, stderr_enabled()), error);
+}
@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+// Values for `{name}` placeholders: explicit values first, then QQ_<NAME> from the environment
+pub struct TemplateValues {
+    values: HashMap<String, String>,
+}
+
+impl TemplateValues {
+    pub fn new() -> Self {
+        Self { values: HashMap::new() }
+    }
+
+    pub fn set(&mut self, name: &str, value: impl Into<String>) {
+        self.values.insert(name.to_string(), value.into());
+    }
+
+    // Parses NAME=VALUE pairs as given to --set
+    pub fn set_pairs(&mut self, pairs: &[String]) -> Result<()> {
+        for pair in pairs {
+            let (name, value) = pair.split_once('=')
+                .context(format!("Invalid --set '{}', expected <name>=<value>", pair))?;
+            self.set(name.trim(), value);
+        }
+        Ok(())
+    }
+
+    fn get(&self, name: &str) -> Option<String> {
+        self.values.get(name)
+            .cloned()
+            .or_else(|| std::env::var(format!("QQ_{}", name.to_uppercase())).ok())
+    }
+
+    // Fills every placeholder it has a value for, leaving the rest untouched.
+    // Returns the text along with the names that had no value.
+    pub fn fill(&self, template: &str) -> (String, Vec<String>) {
+        let mut output = String::new();
+        let mut missing = Vec::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            output.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let Some(end) = after.find('}') else {
+                output.push_str(&rest[start..]);
+                rest = "";
+                break;
+            };
+
+            let name = &after[..end];
+            let is_placeholder = !name.is_empty()
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            match self.get(name) {
+                Some(value) if is_placeholder => output.push_str(&value),
+                _ => {
+                    if is_placeholder && !missing.iter().any(|m| m == name) {
+                        missing.push(name.to_string());
+                    }
+                    output.push_str(&rest[start..start + end + 2]);
+                }
+            }
+            rest = &after[end + 1..];
+        }
+        output.push_str(rest);
+
+        (output, missing)
+    }
+
+    // Like fill, but every placeholder must have a value
+    pub fn fill_all(&self, template: &str) -> Result<String> {
+        let (text, missing) = self.fill(template);
+        if !missing.is_empty() {
+            let flags: Vec<String> = missing.iter().map(|m| format!("--set {}=...", m)).collect();
+            anyhow::bail!("Template needs values for: {}", flags.join(" "));
+        }
+        Ok(text)
+    }
+}
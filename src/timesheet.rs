@@ -0,0 +1,131 @@
+use anyhow::Result;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use std::collections::BTreeMap;
+
+use crate::google::WorkingHours;
+use crate::jira::{JiraIssue, Worklog};
+use crate::output::csv_field;
+
+// Time logged on one ticket, one column per day of the week
+#[derive(Debug, Clone)]
+pub struct TicketRow {
+    pub key: String,
+    pub summary: String,
+    pub seconds: Vec<u64>,
+}
+
+impl TicketRow {
+    pub fn total(&self) -> u64 {
+        self.seconds.iter().sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Timesheet {
+    // Monday of the week
+    pub from: NaiveDate,
+    pub days: Vec<NaiveDate>,
+    pub rows: Vec<TicketRow>,
+}
+
+// Monday through Sunday of the week containing `date`
+pub fn week_of(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let monday = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+    (monday, monday + Duration::days(6))
+}
+
+impl Timesheet {
+    pub fn build(from: NaiveDate, worklogs: &[(JiraIssue, Worklog)]) -> Self {
+        let days: Vec<NaiveDate> = (0..7).map(|offset| from + Duration::days(offset)).collect();
+
+        let mut rows: BTreeMap<String, TicketRow> = BTreeMap::new();
+        for (issue, worklog) in worklogs {
+            let Some(date) = worklog.started_at().map(|s| s.with_timezone(&Local).date_naive()) else {
+                continue;
+            };
+            let Some(day) = days.iter().position(|d| *d == date) else {
+                continue;
+            };
+            let row = rows.entry(issue.key.clone()).or_insert_with(|| TicketRow {
+                key: issue.key.clone(),
+                summary: issue.fields.summary.clone(),
+                seconds: vec![0; days.len()],
+            });
+            row.seconds[day] += worklog.time_spent_seconds;
+        }
+
+        Self { from, days, rows: rows.into_values().collect() }
+    }
+
+    pub fn day_total(&self, day: usize) -> u64 {
+        self.rows.iter().map(|row| row.seconds[day]).sum()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.rows.iter().map(|row| row.total()).sum()
+    }
+
+    // Working days up to today that have less logged than a full working day
+    pub fn short_days(&self, hours: &WorkingHours) -> Vec<(NaiveDate, u64)> {
+        let expected = expected_seconds(hours);
+        let today = Local::now().date_naive();
+        self.days.iter()
+            .enumerate()
+            .filter(|(_, date)| hours.days.contains(&date.weekday()) && **date <= today)
+            .map(|(idx, date)| (*date, self.day_total(idx)))
+            .filter(|(_, logged)| *logged < expected)
+            .map(|(date, logged)| (date, expected - logged))
+            .collect()
+    }
+
+    // One line per day and ticket, for pasting into a timesheet tool
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("date,key,summary,hours\n");
+        for (idx, date) in self.days.iter().enumerate() {
+            for row in self.rows.iter().filter(|row| row.seconds[idx] > 0) {
+                csv.push_str(&format!(
+                    "{},{},{},{:.2}\n",
+                    date,
+                    csv_field(&row.key),
+                    csv_field(&row.summary),
+                    row.seconds[idx] as f64 / 3600.0
+                ));
+            }
+        }
+        csv
+    }
+}
+
+pub fn expected_seconds(hours: &WorkingHours) -> u64 {
+    (hours.end - hours.start).num_seconds().max(0) as u64
+}
+
+// "1h 30m", or "—" for nothing logged
+pub fn format_duration(seconds: u64) -> String {
+    let minutes = seconds / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, 0) => "—".to_string(),
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+// Accepts "2h", "45m", "1h 30m", or "1.5h"
+pub fn parse_duration(input: &str) -> Result<u64> {
+    let mut total = 0.0;
+    for part in input.split_whitespace() {
+        let (amount, unit) = part.split_at(part.len() - part.chars().last().map_or(0, |c| c.len_utf8()));
+        let amount: f64 = amount.parse()
+            .map_err(|_| anyhow::anyhow!("Could not understand '{}'. Use a duration like 2h, 45m, or 1h 30m", input))?;
+        total += match unit {
+            "h" => amount * 3600.0,
+            "m" => amount * 60.0,
+            _ => anyhow::bail!("Unknown duration unit in '{}' (use h or m)", part),
+        };
+    }
+    if total <= 0.0 {
+        anyhow::bail!("Duration must be positive");
+    }
+    Ok(total.round() as u64)
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+
+use crate::google::{BlockingCalendarClient, Meeting};
+use crate::jira::{JiraClient, JiraIssue};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Badge {
+    InProgress,
+    InReview,
+    Blocked,
+    DueThisWeek,
+    MeetingsLeft,
+}
+
+impl Badge {
+    pub const ALL: [Badge; 5] = [
+        Badge::InProgress,
+        Badge::InReview,
+        Badge::Blocked,
+        Badge::DueThisWeek,
+        Badge::MeetingsLeft,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Badge::InProgress => "In progress",
+            Badge::InReview => "In review",
+            Badge::Blocked => "Blocked",
+            Badge::DueThisWeek => "Due this week",
+            Badge::MeetingsLeft => "Meetings left",
+        }
+    }
+
+    // Number key that opens the badge's list in the dashboard
+    pub fn hotkey(&self) -> char {
+        match self {
+            Badge::InProgress => '1',
+            Badge::InReview => '2',
+            Badge::Blocked => '3',
+            Badge::DueThisWeek => '4',
+            Badge::MeetingsLeft => '5',
+        }
+    }
+
+    pub fn from_hotkey(key: char) -> Option<Badge> {
+        Self::ALL.into_iter().find(|b| b.hotkey() == key)
+    }
+
+    fn matches(&self, issue: &JiraIssue, week_end: NaiveDate) -> bool {
+        let status = issue.fields.status.name.to_lowercase();
+        match self {
+            Badge::InProgress => status.contains("progress"),
+            Badge::InReview => status.contains("review"),
+            Badge::Blocked => issue.fields.is_blocked(),
+            Badge::DueThisWeek => issue.fields.due_date().is_some_and(|due| due <= week_end),
+            Badge::MeetingsLeft => false,
+        }
+    }
+}
+
+pub struct TodaySummary {
+    pub issues: Vec<JiraIssue>,
+    // None when Google Calendar isn't configured or couldn't be reached
+    pub meetings: Option<Vec<Meeting>>,
+    week_end: NaiveDate,
+}
+
+impl TodaySummary {
+    // Fetches assigned issues and today's meetings side by side
+    pub fn fetch(client: &JiraClient, calendar: Option<&BlockingCalendarClient>) -> Result<Self> {
+        let (issues, meetings) = std::thread::scope(|scope| {
+            let issues = scope.spawn(|| client.get_my_issues());
+            let meetings = calendar.and_then(|c| c.list_meetings().ok());
+            (issues.join(), meetings)
+        });
+        let issues = issues.map_err(|_| anyhow::anyhow!("Issue fetch thread panicked"))??;
+
+        let now = chrono::Utc::now();
+        let today = Local::now().date_naive();
+        let meetings = meetings.map(|meetings| {
+            meetings.into_iter()
+                .filter(|m| m.end_time > now && m.start_time.with_timezone(&Local).date_naive() == today)
+                .filter(|m| !m.is_low_priority())
+                .collect()
+        });
+
+        let days_left = 6 - today.weekday().num_days_from_monday() as i64;
+        Ok(Self {
+            issues,
+            meetings,
+            week_end: today + Duration::days(days_left),
+        })
+    }
+
+    pub fn count(&self, badge: Badge) -> Option<usize> {
+        match badge {
+            Badge::MeetingsLeft => self.meetings.as_ref().map(|m| m.len()),
+            _ => Some(self.issues_for(badge).len()),
+        }
+    }
+
+    pub fn issues_for(&self, badge: Badge) -> Vec<JiraIssue> {
+        self.issues.iter()
+            .filter(|issue| badge.matches(issue, self.week_end))
+            .cloned()
+            .collect()
+    }
+}
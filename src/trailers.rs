@@ -0,0 +1,100 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::jira::JiraClient;
+
+// Git trailers that tie a commit to its ticket, e.g. "Jira: PROJ-123" and "Epic: PROJ-4".
+// Git itself writes them (`git commit --trailer`, `git interpret-trailers`), so they're
+// formatted the way other tools expect, and `qq jira from-commit` reads them back.
+
+pub const TICKET: &str = "Jira";
+pub const EPIC: &str = "Epic";
+
+// Marks hooks written by `qq jira commit-msg --install`, so reinstalling can replace them
+const HOOK_MARKER: &str = "# Added by qq";
+
+// The ticket's trailer, and its epic's when the parent is an epic. Without Jira, only the
+// ticket's is returned, so a commit never waits on the network to go through.
+pub fn for_ticket(client: Option<&JiraClient>, ticket: &str) -> Vec<(&'static str, String)> {
+    let mut trailers = vec![(TICKET, ticket.to_string())];
+    let epic = client
+        .and_then(|client| client.get_issue_with_parent(ticket).ok())
+        .and_then(|(issue, _)| issue.fields.parent)
+        .filter(|parent| parent.fields.issuetype.as_ref().is_some_and(|t| t.name.eq_ignore_ascii_case("Epic")));
+    if let Some(epic) = epic {
+        trailers.push((EPIC, epic.key));
+    }
+    trailers
+}
+
+// `--trailer` arguments for `git commit` and `git interpret-trailers`
+pub fn git_args(trailers: &[(&str, String)]) -> Vec<String> {
+    trailers.iter()
+        .flat_map(|(name, value)| ["--trailer".to_string(), format!("{}: {}", name, value)])
+        .collect()
+}
+
+// Adds the trailers to a commit message file; ones the author already wrote are kept
+pub fn add_to_file(path: &Path, trailers: &[(&str, String)]) -> Result<()> {
+    let status = Command::new("git")
+        .args(["interpret-trailers", "--in-place", "--if-exists", "doNothing"])
+        .args(git_args(trailers))
+        .arg(path)
+        .status()
+        .context("Failed to run 'git interpret-trailers'")?;
+    if !status.success() {
+        anyhow::bail!("'git interpret-trailers' exited with {}", status);
+    }
+    Ok(())
+}
+
+// Values of every `name` trailer in a commit message, e.g. the keys of its "Jira:" trailers
+pub fn values(message: &str, name: &str) -> Vec<String> {
+    let Ok(trailers) = git2::message_trailers_strs(message) else {
+        return Vec::new();
+    };
+    trailers.iter()
+        .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.trim().to_string())
+        .collect()
+}
+
+// Writes a commit-msg hook that runs `qq jira commit-msg`. An existing hook is only replaced
+// when qq wrote it. Returns where the hook went.
+pub fn install_hook(repo: &git2::Repository) -> Result<PathBuf> {
+    // Git resolves core.hooksPath and the shared hooks of linked worktrees
+    let workdir = repo.workdir().unwrap_or(repo.path());
+    let output = Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks"])
+        .current_dir(workdir)
+        .output()
+        .context("Failed to run 'git rev-parse'")?;
+    if !output.status.success() {
+        anyhow::bail!("Couldn't find the hooks directory: {}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    let hooks_dir = workdir.join(String::from_utf8_lossy(&output.stdout).trim());
+    std::fs::create_dir_all(&hooks_dir)
+        .context(format!("Failed to create {}", hooks_dir.display()))?;
+
+    let path = hooks_dir.join("commit-msg");
+    if let Ok(existing) = std::fs::read_to_string(&path)
+        && !existing.contains(HOOK_MARKER)
+    {
+        anyhow::bail!(
+            "{} already exists. Add 'qq jira commit-msg \"$1\"' to it instead.",
+            path.display()
+        );
+    }
+
+    let script = format!("#!/bin/sh\n{} to tag commits with their Jira ticket and epic\nexec qq jira commit-msg \"$1\"\n", HOOK_MARKER);
+    std::fs::write(&path, script)
+        .context(format!("Failed to write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .context(format!("Failed to make {} executable", path.display()))?;
+    }
+    Ok(path)
+}
@@ -1,413 +1,8025 @@
 use anyhow::Result;
-use chrono::Timelike;
+use chrono::{Datelike, Timelike};
 use chrono_tz::America::New_York;
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, List, ListItem, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Clear, Gauge, List, ListItem, ListState, Paragraph, Row, Table, Tabs, Wrap},
     Terminal, Frame,
 };
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use std::collections::{HashMap, HashSet};
 use std::io;
+use std::sync::{mpsc, OnceLock};
+use std::time::{Duration, Instant};
 use serde_json::Value;
 
-use crate::jira::{JiraIssue, JiraClient, User};
+use crate::activity::{EventKind, FeedEvent, Scope};
+use crate::config::{SelectionStyle, ThemeConfig, ThemePreset};
+use crate::hooks::Stage;
+use crate::google::{AttachmentKind, BlockingCalendarClient, Importance, Meeting};
+use crate::forge::{PrState, PrStatus};
+use crate::jira::{JiraField, JiraIssue, JiraClient, ListField, TransitionOption, User};
+use crate::palette::PaletteEntry;
+use crate::pin::PinStore;
+use crate::sla::{SlaState, SlaStatus};
+
+// How often views wake up without input so toasts can expire
+const TICK_RATE: Duration = Duration::from_millis(200);
+const MAX_VISIBLE_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Info,
+    Success,
+    Error,
+}
 
-pub struct JiraIssueDisplay {
-    scroll_offset: u16,
+impl Severity {
+    fn color(&self, theme: &Theme) -> Color {
+        match self {
+            Severity::Info => theme.highlight,
+            Severity::Success => theme.success,
+            Severity::Error => theme.error,
+        }
+    }
+
+    fn lifetime(&self) -> Duration {
+        match self {
+            Severity::Error => Duration::from_secs(8),
+            _ => Duration::from_secs(4),
+        }
+    }
 }
 
-impl JiraIssueDisplay {
-    pub fn show(issue: &JiraIssue) -> Result<()> {
-        // Setup terminal
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
+struct Toast {
+    text: String,
+    severity: Severity,
+    created: Instant,
+}
 
-        let mut app = Self { scroll_offset: 0 };
-        let mut should_quit = false;
+// Stack of short-lived messages shared by the interactive views. Everything pushed is
+// also kept in a history that `:messages` opens as an overlay.
+#[derive(Default)]
+struct Toasts {
+    active: Vec<Toast>,
+    history: Vec<(chrono::DateTime<chrono::Local>, Severity, String)>,
+    command_mode: bool,
+    command_input: String,
+    show_history: bool,
+    history_scroll: u16,
+}
 
-        // Main loop
-        while !should_quit {
-            terminal.draw(|f| app.draw(f, issue))?;
+impl Toasts {
+    fn info(&mut self, text: impl Into<String>) {
+        self.push(Severity::Info, text);
+    }
 
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
-                    KeyCode::Up => app.scroll_offset = app.scroll_offset.saturating_sub(1),
-                    KeyCode::Down => app.scroll_offset = app.scroll_offset.saturating_add(1),
-                    _ => {}
+    fn success(&mut self, text: impl Into<String>) {
+        self.push(Severity::Success, text);
+    }
+
+    fn error(&mut self, text: impl Into<String>) {
+        self.push(Severity::Error, text);
+    }
+
+    fn push(&mut self, severity: Severity, text: impl Into<String>) {
+        let text = text.into();
+        self.history.push((chrono::Local::now(), severity, text.clone()));
+        self.active.push(Toast { text, severity, created: Instant::now() });
+    }
+
+    // Prints the messages pushed since the history had `start` entries, for once the view
+    // that showed them has closed
+    fn print_since(&self, start: usize) {
+        for (_, severity, text) in self.history.iter().skip(start) {
+            match severity {
+                Severity::Success => println!("{}", crate::style::success(text)),
+                Severity::Error => println!("{}", crate::style::failure(text)),
+                Severity::Info => println!("{}", text),
+            }
+        }
+    }
+
+    // Drops expired toasts and waits up to one tick for input; true when a key event is ready
+    fn poll(&mut self) -> Result<bool> {
+        self.active.retain(|t| t.created.elapsed() < t.severity.lifetime());
+        Ok(event::poll(TICK_RATE)?)
+    }
+
+    // Handles the `:` command line and the history overlay. Returns true when the key
+    // was consumed, so views only see keys meant for them.
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if self.show_history {
+            match key.code {
+                KeyCode::Up => self.history_scroll = self.history_scroll.saturating_sub(1),
+                KeyCode::Down => self.history_scroll = self.history_scroll.saturating_add(1),
+                _ => self.show_history = false,
+            }
+            return true;
+        }
+
+        if self.command_mode {
+            match key.code {
+                KeyCode::Esc => {
+                    self.command_mode = false;
+                    self.command_input.clear();
+                }
+                KeyCode::Enter => {
+                    self.command_mode = false;
+                    match std::mem::take(&mut self.command_input).trim() {
+                        "messages" | "mes" => {
+                            self.show_history = true;
+                            self.history_scroll = 0;
+                        }
+                        "" => {}
+                        other => self.error(format!("✗ Unknown command: {}", other)),
+                    }
                 }
+                // Backspace on an empty command line leaves command mode, like vim
+                KeyCode::Backspace if self.command_input.is_empty() => self.command_mode = false,
+                KeyCode::Backspace => {
+                    self.command_input.pop();
+                }
+                KeyCode::Char(c) => self.command_input.push(c),
+                _ => {}
             }
+            return true;
         }
 
-        // Restore terminal
-        disable_raw_mode()?;
-        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-        terminal.show_cursor()?;
+        if key.code == KeyCode::Char(':') {
+            self.command_mode = true;
+            return true;
+        }
 
-        Ok(())
+        false
     }
 
-    fn draw(&self, f: &mut Frame, issue: &JiraIssue) {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .margin(1)
-            .constraints([
-                Constraint::Length(9),  // Header info (increased for assignee)
-                Constraint::Min(0),     // Description
-                Constraint::Length(2),  // Help text
-            ])
-            .split(f.area());
+    fn render(&self, f: &mut Frame, theme: &Theme) {
+        let area = f.area();
 
-        self.render_header(f, chunks[0], issue);
-        self.render_description(f, chunks[1], &issue.fields.description);
-        self.render_help(f, chunks[2]);
-    }
+        if self.show_history {
+            self.render_history(f, area, theme);
+            return;
+        }
 
-    fn render_header(&self, f: &mut Frame, area: Rect, issue: &JiraIssue) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" JIRA Issue Details ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+        // Stack toasts upward from just above the help line, newest at the bottom
+        let width = area.width.saturating_sub(4).min(60);
+        let mut bottom = area.height.saturating_sub(3);
+        for toast in self.active.iter().rev().take(MAX_VISIBLE_TOASTS) {
+            if bottom < 3 {
+                break;
+            }
+            let rect = Rect {
+                x: area.width.saturating_sub(width + 2),
+                y: bottom - 3,
+                width,
+                height: 3,
+            };
+            let color = toast.severity.color(theme);
+            let paragraph = Paragraph::new(toast.text.as_str())
+                .style(Style::default().fg(color))
+                .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(color)));
+            f.render_widget(Clear, rect);
+            f.render_widget(paragraph, rect);
+            bottom -= 3;
+        }
 
-        let assignee_text = match &issue.fields.assignee {
-            Some(user) => user.display_name.clone(),
-            None => "Unassigned".to_string(),
+        if self.command_mode {
+            let rect = Rect { x: area.x, y: area.height.saturating_sub(1), width: area.width, height: 1 };
+            let line = Line::from(vec![
+                Span::styled(":", Style::default().fg(theme.label)),
+                Span::raw(&self.command_input),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]);
+            f.render_widget(Clear, rect);
+            f.render_widget(Paragraph::new(line), rect);
+        }
+    }
+
+    fn render_history(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let rect = Rect {
+            x: area.width / 10,
+            y: area.height / 6,
+            width: area.width * 4 / 5,
+            height: area.height * 2 / 3,
         };
 
-        let header_text = vec![
-            Line::from(vec![
-                Span::styled("Ticket: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(&issue.key),
-            ]),
-            Line::from(vec![
-                Span::styled("Status: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(&issue.fields.status.name),
-            ]),
-            Line::from(vec![
-                Span::styled("Assignee: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(&assignee_text),
-            ]),
-            Line::from(vec![
-                Span::styled("Summary: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(&issue.fields.summary),
-            ]),
-        ];
+        let lines: Vec<Line> = if self.history.is_empty() {
+            vec![Line::from(Span::styled("(No messages yet)", Style::default().fg(theme.muted)))]
+        } else {
+            self.history.iter()
+                .map(|(at, severity, text)| Line::from(vec![
+                    Span::styled(format!("{}  ", at.format("%H:%M:%S")), Style::default().fg(theme.muted)),
+                    Span::styled(text.clone(), Style::default().fg(severity.color(theme))),
+                ]))
+                .collect()
+        };
 
-        let paragraph = Paragraph::new(header_text).wrap(Wrap { trim: true });
-        f.render_widget(paragraph, inner);
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(" Messages (↑/↓ to scroll, any other key to close) ")
+                .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)))
+            .wrap(Wrap { trim: false })
+            .scroll((self.history_scroll, 0));
+        f.render_widget(Clear, rect);
+        f.render_widget(paragraph, rect);
     }
+}
 
-    fn render_description(&self, f: &mut Frame, area: Rect, description: &Option<Value>) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Description ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+// What `/` matches against in issue lists
+fn issue_search_text(issue: &JiraIssue) -> String {
+    format!("{} {}", issue.key, issue.fields.summary)
+}
 
-        if let Some(desc) = description {
-            self.render_jira_description(f, inner, desc);
-        } else {
-            let text = Paragraph::new("(No description provided)")
-                .style(Style::default().fg(Color::DarkGray));
-            f.render_widget(text, inner);
+// Case-insensitive subsequence match, so "pay ref" finds "PAY-12 Refactor payments"
+fn fuzzy_match(query: &str, text: &str) -> bool {
+    let text = text.to_lowercase();
+    let mut chars = text.chars();
+    query.to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|q| chars.any(|c| c == q))
+}
+
+// Vim-style movement shared by every list and text view, alongside the arrow keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Motion {
+    Up,
+    Down,
+    Top,
+    Bottom,
+    HalfPageUp,
+    HalfPageDown,
+}
+
+impl Motion {
+    fn from_key(key: &KeyEvent) -> Option<Self> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('d') if ctrl => Some(Self::HalfPageDown),
+            KeyCode::Char('u') if ctrl => Some(Self::HalfPageUp),
+            _ if ctrl => None,
+            KeyCode::Up | KeyCode::Char('k') => Some(Self::Up),
+            KeyCode::Down | KeyCode::Char('j') => Some(Self::Down),
+            KeyCode::Home | KeyCode::Char('g') => Some(Self::Top),
+            KeyCode::End | KeyCode::Char('G') => Some(Self::Bottom),
+            _ => None,
         }
     }
 
-    fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help = Paragraph::new("Press 'q' or ESC to quit, ↑/↓ to scroll")
-            .style(Style::default().fg(Color::DarkGray))
-            .alignment(Alignment::Center);
-        f.render_widget(help, area);
+    // Pickers that filter as you type only move on keys that can't be typed
+    fn from_key_while_typing(key: &KeyEvent) -> Option<Self> {
+        match key.code {
+            KeyCode::Char(_) if !key.modifiers.contains(KeyModifiers::CONTROL) => None,
+            _ => Self::from_key(key),
+        }
     }
 
-    fn render_jira_description(&self, f: &mut Frame, area: Rect, value: &Value) {
-        let mut remaining_area = area;
+    // New selection in a list of `len` rows with `page` rows visible
+    fn apply(self, index: usize, len: usize, page: usize) -> usize {
+        let half = (page / 2).max(1);
+        let index = match self {
+            Self::Up => index.saturating_sub(1),
+            Self::Down => index + 1,
+            Self::Top => 0,
+            Self::Bottom => len,
+            Self::HalfPageUp => index.saturating_sub(half),
+            Self::HalfPageDown => index + half,
+        };
+        index.min(len.saturating_sub(1))
+    }
+}
 
-        if let Some(content) = value.get("content").and_then(|c| c.as_array()) {
-            for item in content {
-                if remaining_area.height == 0 {
-                    break;
-                }
+// `/` search in lists and text views. Enter jumps to the next row containing the term, then
+// `n`/`N` jump to the next or previous match, wrapping around like vim.
+#[derive(Default)]
+struct Search {
+    // Some while the prompt is open
+    input: Option<String>,
+    query: String,
+    // Shown on the prompt line until the next key
+    not_found: bool,
+}
 
-                let block_type = item.get("type").and_then(|t| t.as_str()).unwrap_or("");
-                
-                match block_type {
-                    "paragraph" => {
-                        if let Some(text) = self.extract_text(item) {
-                            let paragraph = Paragraph::new(text)
-                                .wrap(Wrap { trim: true });
-                            let height = 2.min(remaining_area.height);
-                            f.render_widget(paragraph, Rect { height, ..remaining_area });
-                            
-                            remaining_area.y += height;
-                            remaining_area.height = remaining_area.height.saturating_sub(height);
-                        }
-                    }
-                    "heading" => {
-                        if let Some(text) = self.extract_text(item) {
-                            let level = item.get("attrs")
-                                .and_then(|a| a.get("level"))
-                                .and_then(|l| l.as_u64())
-                                .unwrap_or(1);
-                            
-                            let heading = format!("{} {}", "#".repeat(level as usize), text);
-                            let paragraph = Paragraph::new(heading)
-                                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
-                            
-                            let height = 2.min(remaining_area.height);
-                            f.render_widget(paragraph, Rect { height, ..remaining_area });
-                            
-                            remaining_area.y += height;
-                            remaining_area.height = remaining_area.height.saturating_sub(height);
-                        }
-                    }
-                    "table" => {
-                        if let Some((table_widget, height)) = self.create_table_widget(item, remaining_area.width) {
-                            let table_height = height.min(remaining_area.height);
-                            f.render_widget(table_widget, Rect { height: table_height, ..remaining_area });
-                            
-                            remaining_area.y += table_height + 1;
-                            remaining_area.height = remaining_area.height.saturating_sub(table_height + 1);
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        } else if let Some(text) = value.as_str() {
-            let paragraph = Paragraph::new(text)
-                .wrap(Wrap { trim: true })
-                .scroll((self.scroll_offset, 0));
-            f.render_widget(paragraph, area);
-        }
+enum SearchStep {
+    Ignored,
+    Handled,
+    Jump { forward: bool },
+}
+
+impl Search {
+    // While the prompt is open every key is typed into it, including the `:` that opens the command line
+    fn is_typing(&self) -> bool {
+        self.input.is_some()
     }
 
-    fn create_table_widget(&self, item: &Value, available_width: u16) -> Option<(Table, u16)> {
-        if let Some(rows) = item.get("content").and_then(|c| c.as_array()) {
-            let mut table_rows = Vec::new();
-            let mut header_row = None;
-            let mut max_cols = 0;
-            
-            // First pass: collect all rows and determine column count
-            let mut all_cells: Vec<Vec<String>> = Vec::new();
-            for row in rows {
-                if row.get("type").and_then(|t| t.as_str()) == Some("tableRow") {
-                    if let Some(cells) = row.get("content").and_then(|c| c.as_array()) {
-                        let row_content: Vec<String> = cells.iter()
-                            .map(|cell| self.extract_cell_text(cell).unwrap_or_default())
-                            .collect();
-                        max_cols = max_cols.max(row_content.len());
-                        all_cells.push(row_content);
+    fn handle_key(&mut self, key: &KeyEvent) -> SearchStep {
+        self.not_found = false;
+        if let Some(input) = &mut self.input {
+            match key.code {
+                KeyCode::Esc => self.input = None,
+                KeyCode::Enter => {
+                    let query = self.input.take().unwrap_or_default();
+                    if !query.trim().is_empty() {
+                        self.query = query;
+                        return SearchStep::Jump { forward: true };
                     }
                 }
+                KeyCode::Backspace if input.is_empty() => self.input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
             }
+            return SearchStep::Handled;
+        }
 
-            if all_cells.is_empty() || max_cols == 0 {
-                return None;
+        match key.code {
+            KeyCode::Char('/') => {
+                self.input = Some(String::new());
+                SearchStep::Handled
             }
+            KeyCode::Char('n') if !self.query.is_empty() => SearchStep::Jump { forward: true },
+            KeyCode::Char('N') if !self.query.is_empty() => SearchStep::Jump { forward: false },
+            _ => SearchStep::Ignored,
+        }
+    }
 
-            // Create constraints for equal-width columns
-            let constraints: Vec<Constraint> = (0..max_cols)
-                .map(|_| Constraint::Percentage(100 / max_cols as u16))
-                .collect();
+    // The first row after `from` (before it, going backward) containing the term, case-insensitively
+    fn find<S: AsRef<str>>(&mut self, rows: &[S], from: usize, forward: bool) -> Option<usize> {
+        let query = self.query.to_lowercase();
+        let len = rows.len();
+        let found = (1..=len)
+            .map(|step| if forward { (from + step) % len } else { (from + len - step) % len })
+            .find(|&idx| rows[idx].as_ref().to_lowercase().contains(&query));
+        self.not_found = found.is_none();
+        found
+    }
 
-            // Calculate actual column width based on available width
-            // Account for borders: 1 char per column separator + 2 for outer borders
-            // Account for padding: 1 space on each side per column
-            let borders_width = (max_cols - 1) + 2; // column separators + outer borders
-            let padding_width = max_cols * 2; // 2 spaces per column
-            let content_width = available_width.saturating_sub((borders_width + padding_width) as u16);
-            let approx_col_width = (content_width / max_cols as u16).max(10) as usize;
-            
-            let mut total_height = 0u16;
-            
-            // Create table rows with text wrapping
-            for (idx, row_content) in all_cells.iter().enumerate() {
-                let mut max_lines_in_row = 1;
-                let mut wrapped_cells: Vec<Vec<String>> = Vec::new();
-                
-                // Wrap text in each cell
-                for cell_text in row_content {
-                    let wrapped = textwrap::wrap(cell_text, approx_col_width);
-                    let wrapped_lines: Vec<String> = wrapped.iter().map(|cow| cow.to_string()).collect();
-                    max_lines_in_row = max_lines_in_row.max(wrapped_lines.len());
-                    wrapped_cells.push(wrapped_lines);
+    // Motion and search keys for a list; returns true when the key was one of them.
+    // `rows` gives the searchable text of each row and is only built when searching.
+    fn navigate(&mut self, key: &KeyEvent, selected: &mut usize, len: usize, page: usize, rows: impl FnOnce() -> Vec<String>) -> bool {
+        match self.handle_key(key) {
+            SearchStep::Handled => true,
+            SearchStep::Jump { forward } => {
+                if let Some(idx) = self.find(&rows(), *selected, forward) {
+                    *selected = idx;
                 }
-                
-                // Pad cells to have the same number of lines
-                for cell_lines in &mut wrapped_cells {
-                    while cell_lines.len() < max_lines_in_row {
-                        cell_lines.push(String::new());
-                    }
+                true
+            }
+            SearchStep::Ignored => match Motion::from_key(key) {
+                Some(motion) => {
+                    *selected = motion.apply(*selected, len, page);
+                    true
                 }
-                
-                // Create cells with multi-line content
-                let cells: Vec<Cell> = wrapped_cells.into_iter()
-                    .map(|lines| {
-                        let text = lines.join("\n");
-                        if idx == 0 {
-                            Cell::from(text).style(
-                                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
-                            )
-                        } else {
-                            Cell::from(text)
-                        }
-                    })
-                    .collect();
+                None => false,
+            },
+        }
+    }
 
-                let row = Row::new(cells).height(max_lines_in_row as u16);
-                total_height += max_lines_in_row as u16;
-                
-                if idx == 0 {
-                    header_row = Some(row);
-                } else {
-                    table_rows.push(row);
-                }
-            }
+    // Drawn over the bottom line, where the `:` command line also goes
+    fn render(&self, f: &mut Frame, theme: &Theme) {
+        let line = match &self.input {
+            Some(input) => Line::from(vec![
+                Span::styled("/", Style::default().fg(theme.label)),
+                Span::raw(input.as_str()),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+            None if self.not_found => Line::from(Span::styled(
+                format!("Pattern not found: {}", self.query),
+                Style::default().fg(theme.error),
+            )),
+            None => return,
+        };
+        let area = f.area();
+        let rect = Rect { x: area.x, y: area.height.saturating_sub(1), width: area.width, height: 1 };
+        f.render_widget(Clear, rect);
+        f.render_widget(Paragraph::new(line), rect);
+    }
+}
+
+// Scroll position for the read-only text views. Each draw records the page height and every
+// line's text and wrapped height, so `G`, half pages, and `/` search land on the text itself.
+#[derive(Default)]
+struct TextScroll {
+    offset: u16,
+    page: u16,
+    lines: Vec<(String, u16)>,
+    search: Search,
+}
+
+impl TextScroll {
+    // The paragraph to render in `area`, which should already be inside any borders
+    fn paragraph<'a>(&mut self, lines: Vec<Line<'a>>, area: Rect) -> Paragraph<'a> {
+        self.page = area.height;
+        self.lines = lines.iter()
+            .map(|line| {
+                let text = line.spans.iter().map(|s| s.content.as_ref()).collect();
+                let rows = Paragraph::new(line.clone()).wrap(Wrap { trim: false }).line_count(area.width);
+                (text, rows as u16)
+            })
+            .collect();
+        self.offset = self.offset.min(self.max_offset());
+        // Trimming would strip list and quote indentation
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.offset, 0))
+    }
+
+    fn max_offset(&self) -> u16 {
+        let total: u16 = self.lines.iter().map(|(_, rows)| rows).sum();
+        total.saturating_sub(self.page)
+    }
 
-            let mut table = Table::new(table_rows, constraints)
-                .block(Block::default().borders(Borders::ALL));
+    // Index of the line at the top of the view
+    fn top_line(&self) -> usize {
+        let mut rows = 0;
+        self.lines.iter()
+            .position(|(_, height)| {
+                rows += height;
+                rows > self.offset
+            })
+            .unwrap_or(0)
+    }
 
-            if let Some(header) = header_row {
-                table = table.header(header);
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        match self.search.handle_key(key) {
+            SearchStep::Handled => return true,
+            SearchStep::Jump { forward } => {
+                let texts: Vec<&str> = self.lines.iter().map(|(text, _)| text.as_str()).collect();
+                if let Some(line) = self.search.find(&texts, self.top_line(), forward) {
+                    let rows: u16 = self.lines[..line].iter().map(|(_, rows)| rows).sum();
+                    self.offset = rows.min(self.max_offset());
+                }
+                return true;
             }
+            SearchStep::Ignored => {}
+        }
 
-            // Total height includes borders and spacing
-            let height = (total_height + 4).min(20);
-            Some((table, height))
-        } else {
-            None
+        let Some(motion) = Motion::from_key(key) else {
+            return false;
+        };
+        let half = (self.page / 2).max(1);
+        self.offset = match motion {
+            Motion::Up => self.offset.saturating_sub(1),
+            Motion::Down => self.offset.saturating_add(1),
+            Motion::Top => 0,
+            Motion::Bottom => u16::MAX,
+            Motion::HalfPageUp => self.offset.saturating_sub(half),
+            Motion::HalfPageDown => self.offset.saturating_add(half),
         }
+        .min(self.max_offset());
+        true
+    }
+
+    fn scroll_by(&mut self, rows: i16) {
+        self.offset = self.offset.saturating_add_signed(rows).min(self.max_offset());
+    }
+}
+
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{:.1}", points)
+    }
+}
+
+// Selection arrow, then ✓ for issues marked for a bulk edit and a pin for pinned issues
+fn row_indicator(selected: bool, marked: bool, pinned: bool) -> String {
+    format!(
+        "{}{}{}",
+        if selected { "➤" } else { " " },
+        if marked { "✓" } else { "" },
+        if pinned { "📌" } else { "" }
+    )
+}
+
+// Issue key with a warning marker when some of its fields couldn't be read, colored when
+// the issue breached its SLA or is close to it
+fn key_line(issue: &JiraIssue, theme: &Theme) -> Line<'static> {
+    let key_style = match crate::sla::status(issue).map(|s| s.state) {
+        Some(SlaState::Breached) => Style::default().fg(theme.error).add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        Some(SlaState::AtRisk) => Style::default().fg(theme.highlight).add_modifier(Modifier::UNDERLINED),
+        _ => Style::default(),
+    };
+    let mut spans = vec![Span::styled(issue.key.clone(), key_style)];
+    if !issue.problems.is_empty() {
+        spans.push(Span::styled(" ⚠", Style::default().fg(theme.highlight)));
+    }
+    Line::from(spans)
+}
+
+// Below this width list views stack each row into a card instead of squeezing the table
+const CARD_LAYOUT_WIDTH: u16 = 60;
+// Lines per card: the key and status, the summary, then whatever else the row has
+const CARD_HEIGHT: u16 = 3;
+
+// A column of a list view. When the columns don't all fit, the ones with the highest
+// `drop_rank` go first; rank 0 columns always stay.
+struct ListColumn {
+    header: &'static str,
+    width: Constraint,
+    drop_rank: u8,
+}
+
+impl ListColumn {
+    const fn new(header: &'static str, width: Constraint, drop_rank: u8) -> Self {
+        Self { header, width, drop_rank }
     }
+}
+
+// One row of a list view, cell by cell. Lines rather than cells, so text can be cut to the
+// width its column ends up with.
+struct ListRow<'a> {
+    cells: Vec<Line<'a>>,
+    style: Style,
+}
+
+impl<'a> ListRow<'a> {
+    fn new(cells: Vec<Line<'a>>) -> Self {
+        Self { cells, style: Style::default() }
+    }
+
+    fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
 
-    fn extract_text(&self, item: &Value) -> Option<String> {
-        if let Some(content) = item.get("content").and_then(|c| c.as_array()) {
-            let texts: Vec<String> = content.iter()
-                .filter_map(|c| c.get("text").and_then(|t| t.as_str()))
-                .map(|s| s.to_string())
+// How many rows of a list view fit in `height` lines, counting the header
+fn list_capacity(width: u16, height: u16) -> usize {
+    if width < CARD_LAYOUT_WIDTH {
+        (height / CARD_HEIGHT).max(1) as usize
+    } else {
+        height.saturating_sub(1).max(1) as usize
+    }
+}
+
+// A list view's table fitted to `width`: low-priority columns are dropped until the rest fit,
+// text too long for its column ends in an ellipsis, and very narrow terminals get cards
+fn fitted_table<'a>(columns: &[ListColumn], rows: Vec<ListRow<'a>>, width: u16, theme: &Theme) -> Table<'a> {
+    if width < CARD_LAYOUT_WIDTH {
+        let rows: Vec<Row> = rows.into_iter()
+            .map(|row| Row::new([Cell::from(card(columns, row.cells, width, theme))]).height(CARD_HEIGHT).style(row.style))
+            .collect();
+        return Table::new(rows, [Constraint::Fill(1)]);
+    }
+
+    let kept = fitting_columns(columns, width);
+    let constraints: Vec<Constraint> = kept.iter().map(|&idx| columns[idx].width).collect();
+    // Laid out the way Table does it, to learn how wide each column really is
+    let areas = Layout::horizontal(constraints.clone())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(Rect::new(0, 0, width, 1));
+
+    let header_style = Style::default().fg(theme.label).add_modifier(Modifier::BOLD);
+    let header = Row::new(kept.iter().map(|&idx| Cell::from(columns[idx].header).style(header_style)));
+    let rows: Vec<Row> = rows.into_iter()
+        .map(|row| {
+            let mut cells: Vec<Option<Line>> = row.cells.into_iter().map(Some).collect();
+            let cells: Vec<Cell> = kept.iter()
+                .zip(areas.iter())
+                .map(|(&idx, area)| {
+                    let line = cells.get_mut(idx).and_then(Option::take).unwrap_or_default();
+                    Cell::from(ellipsize(line, area.width as usize))
+                })
                 .collect();
-            
-            if !texts.is_empty() {
-                Some(texts.join(""))
-            } else {
-                None
-            }
-        } else {
-            None
+            Row::new(cells).style(row.style)
+        })
+        .collect();
+    Table::new(rows, constraints).header(header)
+}
+
+// Indices of the columns that fit in `width`, dropping the highest `drop_rank` first
+fn fitting_columns(columns: &[ListColumn], width: u16) -> Vec<usize> {
+    let min_width = |constraint: Constraint| match constraint {
+        Constraint::Length(n) | Constraint::Min(n) | Constraint::Max(n) => n,
+        _ => 0,
+    };
+    let mut kept: Vec<usize> = (0..columns.len()).collect();
+    loop {
+        let needed: u16 = kept.iter().map(|&idx| min_width(columns[idx].width)).sum::<u16>()
+            + kept.len().saturating_sub(1) as u16;
+        if needed <= width {
+            return kept;
+        }
+        let droppable = kept.iter()
+            .enumerate()
+            .filter(|(_, idx)| columns[**idx].drop_rank > 0)
+            .max_by_key(|(_, idx)| columns[**idx].drop_rank)
+            .map(|(position, _)| position);
+        match droppable {
+            Some(position) => kept.remove(position),
+            None => return kept,
+        };
+    }
+}
+
+// A row stacked for narrow terminals: the always-shown columns on the first line, the
+// flexible column (the summary, usually) on the second, and the rest joined on the third
+fn card<'a>(columns: &[ListColumn], cells: Vec<Line<'a>>, width: u16, theme: &Theme) -> ratatui::text::Text<'a> {
+    let mut head = Vec::new();
+    let mut body = None;
+    let mut details = Vec::new();
+    for (column, cell) in columns.iter().zip(cells) {
+        match column.width {
+            Constraint::Min(_) | Constraint::Fill(_) if body.is_none() => body = Some(cell),
+            _ if column.drop_rank == 0 => head.push(cell),
+            _ if cell.spans.iter().all(|span| span.content.trim().is_empty()) => {}
+            _ => details.push(cell),
         }
     }
 
-    fn extract_cell_text(&self, cell: &Value) -> Option<String> {
-        if let Some(content) = cell.get("content").and_then(|c| c.as_array()) {
-            let texts: Vec<String> = content.iter()
-                .filter_map(|item| self.extract_text(item))
-                .collect();
-            
-            if !texts.is_empty() {
-                Some(texts.join(" "))
-            } else {
-                None
+    let join = |cells: Vec<Line<'a>>, separator: &'static str| {
+        let mut spans = Vec::new();
+        for (idx, cell) in cells.into_iter().enumerate() {
+            if idx > 0 {
+                spans.push(Span::styled(separator, Style::default().fg(theme.muted)));
             }
-        } else {
-            None
+            let line_style = cell.style;
+            spans.extend(cell.spans.into_iter().map(|span| {
+                let style = line_style.patch(span.style);
+                span.style(style)
+            }));
         }
+        Line::from(spans)
+    };
+    let mut body = body.unwrap_or_default();
+    body.spans.insert(0, Span::raw("   "));
+
+    let width = width as usize;
+    ratatui::text::Text::from(vec![
+        ellipsize(join(head, " "), width),
+        ellipsize(body, width),
+        ellipsize(join(details, " · "), width),
+    ])
+}
+
+// The line cut to `width` columns, ending in "…" when anything was cut
+fn ellipsize(line: Line<'_>, width: usize) -> Line<'_> {
+    if line.width() <= width {
+        return line;
+    }
+    let budget = width.saturating_sub(1);
+    let mut used = 0;
+    let mut spans = Vec::new();
+    for span in line.spans {
+        if used >= budget {
+            break;
+        }
+        let mut text = String::new();
+        for c in span.content.chars() {
+            let char_width = textwrap::core::display_width(c.encode_utf8(&mut [0; 4]));
+            if used + char_width > budget {
+                used = budget;
+                break;
+            }
+            used += char_width;
+            text.push(c);
+        }
+        spans.push(Span::styled(text, span.style));
     }
+    if width > 0 {
+        spans.push(Span::raw("…"));
+    }
+    Line { spans, ..line }
 }
 
-pub struct EpicListDisplay {
-    selected_index: usize,
-    children: Vec<JiraIssue>,
-    scroll_offset: usize,
-    viewport_height: usize,
+// Failing pre-close checks for an issue; Enter closes anyway unless one of them blocks
+// Confirmation shown by `c`: the pre-close checks, plus an optional resolution comment that's
+// posted with the close
+struct CloseChecklist {
+    report: crate::verify::Report,
+    comment: String,
 }
 
-impl EpicListDisplay {
-    fn update_scroll_offset(&mut self, viewport_height: usize) {
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
-        } else if self.selected_index >= self.scroll_offset + viewport_height {
-            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+impl CloseChecklist {
+    fn check(client: &JiraClient, issue_key: &str) -> Result<Self> {
+        let config = crate::config::Config::load()
+            .map(|c| c.verify)
+            .unwrap_or_default();
+        let report = crate::verify::run(client, issue_key, &config)?;
+        Ok(Self { report, comment: String::new() })
+    }
+
+    // Handles a key while the dialog is open; returns the comment to close with once confirmed
+    fn handle_key(&mut self, key: &KeyEvent) -> Option<Option<String>> {
+        match key.code {
+            KeyCode::Enter if !self.report.is_blocked() => {
+                let comment = self.comment.trim();
+                Some((!comment.is_empty()).then(|| comment.to_string()))
+            }
+            KeyCode::Backspace => {
+                self.comment.pop();
+                None
+            }
+            KeyCode::Char(c) if !self.report.is_blocked() => {
+                self.comment.push(c);
+                None
+            }
+            _ => None,
         }
     }
-    
-    pub fn show(epic: &JiraIssue, children: Vec<JiraIssue>, client: &JiraClient) -> Result<()> {
-        // Setup terminal
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
+
+    fn render(&self, f: &mut Frame, theme: &Theme) {
+        use crate::verify::{Outcome, Severity};
+
+        let area = f.area();
+        let width = area.width.saturating_sub(4).min(80);
+        let height = (self.report.results.len() as u16 + 6).min(area.height);
+        let rect = Rect {
+            x: (area.width - width) / 2,
+            y: (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let mut lines: Vec<Line> = self.report.results.iter()
+            .map(|result| {
+                let color = match (result.outcome, result.severity) {
+                    (Outcome::Pass, _) => theme.success,
+                    (Outcome::Unknown, _) => theme.muted,
+                    (Outcome::Fail, Severity::Block) => theme.error,
+                    (Outcome::Fail, _) => theme.highlight,
+                };
+                Line::from(vec![
+                    Span::styled(format!("{} ", result.symbol()), Style::default().fg(color)),
+                    Span::styled(format!("{}: ", result.label), Style::default().fg(theme.label)),
+                    Span::raw(result.detail.clone()),
+                ])
+            })
+            .collect();
+        lines.push(Line::from(""));
+        if !self.report.is_blocked() {
+            lines.push(Line::from(vec![
+                Span::styled("Comment (optional): ", Style::default().fg(theme.label)),
+                Span::raw(self.comment.as_str()),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]));
+            lines.push(Line::from(""));
+        }
+        let help = if self.report.is_blocked() {
+            "Fix the ✗ items first, or use 'qq jira close --force' | ESC: Back"
+        } else if self.report.has_warnings() {
+            "Enter: Close anyway | ESC: Cancel"
+        } else {
+            "Enter: Close | ESC: Cancel"
+        };
+        lines.push(Line::from(Span::styled(help, Style::default().fg(theme.muted))));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Close {}? ", self.report.key))
+                .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)))
+            .wrap(Wrap { trim: false });
+        f.render_widget(Clear, rect);
+        f.render_widget(paragraph, rect);
+    }
+}
+
+// Label or component change for the marked issues, or the selected one when none are marked.
+// Labels are typed in; components are picked from the project before this is created.
+struct BulkEdit {
+    field: ListField,
+    add: bool,
+    keys: Vec<String>,
+    input: String,
+    // Issues finished so far, once the change is being applied
+    progress: Option<usize>,
+}
+
+impl BulkEdit {
+    const ACTIONS: [(&'static str, ListField, bool); 4] = [
+        ("Add label", ListField::Label, true),
+        ("Remove label", ListField::Label, false),
+        ("Add component", ListField::Component, true),
+        ("Remove component", ListField::Component, false),
+    ];
+
+    // Asks which change to make, and for components which one. Runs outside the alternate
+    // screen like the other nested pickers. None when cancelled.
+    fn pick(client: &JiraClient, keys: Vec<String>, toasts: &mut Toasts, theme: &Theme) -> Result<Option<Self>> {
+        let labels = Self::ACTIONS.iter()
+            .map(|(label, _, _)| format!("{} ({} issue(s))", label, keys.len()))
+            .collect();
+        let Some(idx) = ListPicker::show(" Bulk edit ", labels, theme)? else {
+            return Ok(None);
+        };
+        let (_, field, add) = Self::ACTIONS[idx];
+        let mut edit = Self { field, add, keys, input: String::new(), progress: None };
+
+        if field == ListField::Component {
+            let project = edit.keys[0].split('-').next().unwrap_or_default().to_string();
+            let components = match crate::meta::MetaCache::project(client, &project) {
+                Ok(meta) => meta.components,
+                Err(e) => {
+                    toasts.error(format!("✗ Failed to load components for {}: {}", project, e));
+                    return Ok(None);
+                }
+            };
+            if components.is_empty() {
+                toasts.info(format!("{} has no components", project));
+                return Ok(None);
+            }
+            match ListPicker::show(" Component ", components.clone(), theme)? {
+                Some(idx) => edit.input = components[idx].clone(),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(edit))
+    }
+
+    // Components are already chosen; labels still need typing
+    fn needs_input(&self) -> bool {
+        self.field == ListField::Label
+    }
+
+    fn describe(&self) -> String {
+        let verb = if self.add { "Add" } else { "Remove" };
+        format!("{} {} '{}'", verb, self.field.name(), self.input.trim())
+    }
+
+    // Applies the change in batches, redrawing after each so the progress bar moves.
+    // Returns the toast summarizing the outcome: Ok when every issue was updated.
+    fn apply<B: ratatui::backend::Backend>(
+        mut self,
+        terminal: &mut Terminal<B>,
+        client: &JiraClient,
+        mut draw: impl FnMut(&mut Frame, &BulkEdit),
+    ) -> Result<std::result::Result<String, String>> {
+        if let Err(e) = self.field.validate(&self.input) {
+            return Ok(Err(format!("✗ {}", e)));
+        }
+        let value = self.input.trim().to_string();
+        let keys = self.keys.clone();
+        let mut failed = Vec::new();
+
+        self.progress = Some(0);
+        terminal.draw(|f| draw(f, &self))?;
+        for batch in keys.chunks(crate::jira::BULK_EDIT_BATCH_SIZE) {
+            for (key, result) in batch.iter().zip(client.edit_list_field(batch, self.field, self.add, &value)) {
+                if result.is_err() {
+                    failed.push(key.clone());
+                }
+            }
+            self.progress = self.progress.map(|done| done + batch.len());
+            terminal.draw(|f| draw(f, &self))?;
+        }
+
+        Ok(if failed.is_empty() {
+            Ok(format!("✓ {} on {} issue(s)", self.describe(), keys.len()))
+        } else {
+            Err(format!("✗ {} failed on {}", self.describe(), failed.join(", ")))
+        })
+    }
+
+    fn render(&self, f: &mut Frame, theme: &Theme) {
+        let area = f.area();
+        let width = area.width.saturating_sub(4).min(70);
+        let rect = Rect {
+            x: (area.width - width) / 2,
+            y: area.height.saturating_sub(5) / 2,
+            width,
+            height: 5.min(area.height),
+        };
+        let title = format!(" {} issue(s): {} ", self.keys.len(), self.keys.join(", "));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        f.render_widget(Clear, rect);
+
+        match self.progress {
+            Some(done) => {
+                let gauge = Gauge::default()
+                    .block(block)
+                    .gauge_style(Style::default().fg(theme.success))
+                    .ratio(done as f64 / self.keys.len().max(1) as f64)
+                    .label(format!("{}: {}/{}", self.describe(), done, self.keys.len()));
+                f.render_widget(gauge, rect);
+            }
+            None => {
+                let lines = vec![
+                    Line::from(vec![
+                        Span::styled(format!("{} label: ", if self.add { "Add" } else { "Remove" }), Style::default().fg(theme.label)),
+                        Span::raw(&self.input),
+                        Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+                    ]),
+                    Line::from(""),
+                    Line::from(Span::styled("Enter: Apply | ESC: Cancel", Style::default().fg(theme.muted))),
+                ];
+                f.render_widget(Paragraph::new(lines).block(block), rect);
+            }
+        }
+    }
+}
+
+// Keys for a bulk edit: the marked issues in display order, or just the selected one
+fn bulk_keys<'a>(issues: impl Iterator<Item = &'a JiraIssue>, marked: &HashSet<String>, selected: Option<&JiraIssue>) -> Vec<String> {
+    if marked.is_empty() {
+        return selected.map(|issue| vec![issue.key.clone()]).unwrap_or_default();
+    }
+    issues.filter(|issue| marked.contains(&issue.key)).map(|issue| issue.key.clone()).collect()
+}
+
+fn toggle_mark(marked: &mut HashSet<String>, key: &str) {
+    if !marked.remove(key) {
+        marked.insert(key.to_string());
+    }
+}
+
+// What a key press in the create form asks the view to do
+enum FormAction {
+    Submit,
+    Cancel,
+    PickAssignee,
+}
+
+// `n` in the epic view: a new child of the epic, filled in without leaving the list
+struct CreateForm {
+    epic_key: String,
+    summary: String,
+    issue_types: Vec<String>,
+    type_index: usize,
+    points: String,
+    // Account ID and display name; None leaves the issue unassigned
+    assignee: Option<(String, String)>,
+    // Fetched the first time the assignee is switched to "me"
+    me: Option<User>,
+    field: usize,
+    error: Option<String>,
+}
+
+impl CreateForm {
+    const FIELDS: [&'static str; 4] = ["Summary", "Type", "Points", "Assignee"];
+
+    // None when the project's issue types can't be loaded
+    fn open(client: &JiraClient, epic_key: &str, toasts: &mut Toasts) -> Option<Self> {
+        let project = epic_key.split('-').next().unwrap_or_default();
+        let issue_types: Vec<String> = match crate::meta::MetaCache::project(client, project) {
+            Ok(meta) => meta.issue_types.into_iter()
+                .filter(|name| {
+                    let name = name.to_lowercase();
+                    name != "epic" && !name.replace('-', "").contains("subtask")
+                })
+                .collect(),
+            Err(e) => {
+                toasts.error(format!("✗ Failed to load issue types for {}: {}", project, e));
+                return None;
+            }
+        };
+        let issue_types = if issue_types.is_empty() { vec!["Task".to_string()] } else { issue_types };
+        let type_index = issue_types.iter().position(|name| name == "Task").unwrap_or(0);
+        Some(Self {
+            epic_key: epic_key.to_string(),
+            summary: String::new(),
+            issue_types,
+            type_index,
+            points: String::new(),
+            assignee: None,
+            me: None,
+            field: 0,
+            error: None,
+        })
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent, client: &JiraClient) -> Option<FormAction> {
+        self.error = None;
+        match (key.code, Self::FIELDS[self.field]) {
+            (KeyCode::Esc, _) => return Some(FormAction::Cancel),
+            (KeyCode::Enter, _) => {
+                if self.summary.trim().is_empty() {
+                    self.error = Some("A summary is required".to_string());
+                } else if !self.points.is_empty() && self.points.parse::<f64>().is_err() {
+                    self.error = Some(format!("'{}' isn't a number of points", self.points));
+                } else {
+                    return Some(FormAction::Submit);
+                }
+            }
+            (KeyCode::Tab | KeyCode::Down, _) => self.field = (self.field + 1) % Self::FIELDS.len(),
+            (KeyCode::BackTab | KeyCode::Up, _) => self.field = (self.field + Self::FIELDS.len() - 1) % Self::FIELDS.len(),
+            (KeyCode::Backspace, "Summary") => {
+                self.summary.pop();
+            }
+            (KeyCode::Char(c), "Summary") => self.summary.push(c),
+            (KeyCode::Backspace, "Points") => {
+                self.points.pop();
+            }
+            (KeyCode::Char(c), "Points") if c.is_ascii_digit() || c == '.' => self.points.push(c),
+            (KeyCode::Left, "Type") => self.type_index = (self.type_index + self.issue_types.len() - 1) % self.issue_types.len(),
+            (KeyCode::Right, "Type") => self.type_index = (self.type_index + 1) % self.issue_types.len(),
+            (KeyCode::Char(' '), "Assignee") => return Some(FormAction::PickAssignee),
+            (KeyCode::Left | KeyCode::Right, "Assignee") => {
+                if self.assignee.is_some() {
+                    self.assignee = None;
+                } else {
+                    if self.me.is_none() {
+                        match client.get_current_user() {
+                            Ok(user) => self.me = Some(user),
+                            Err(e) => self.error = Some(format!("Failed to look you up: {}", e)),
+                        }
+                    }
+                    self.assignee = self.me.as_ref().map(|me| (me.account_id.clone(), "me".to_string()));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    // Creates the issue under the epic, then sets what the create screen may not take.
+    // Returns the new issue and a note about anything that couldn't be set.
+    fn create(&self, client: &JiraClient) -> Result<(JiraIssue, Option<String>)> {
+        let project = self.epic_key.split('-').next().unwrap_or_default();
+        let inherited = if client.create_inherit().is_empty() {
+            serde_json::Map::new()
+        } else {
+            client.inherited_fields(&self.epic_key, client.create_inherit())?
+        };
+        let (key, parented) = client.create_under_epic(
+            project,
+            &self.issue_types[self.type_index],
+            self.summary.trim(),
+            None,
+            Some(&self.epic_key),
+            inherited,
+        )?;
+
+        let mut failed = Vec::new();
+        if parented.is_err() {
+            failed.push(format!("add it to {}", self.epic_key));
+        }
+        let points = self.points.parse::<f64>().ok();
+        if let Some(points) = points {
+            match client.set_story_points(&[(key.clone(), points)]) {
+                Ok(results) if results.iter().all(|r| r.is_ok()) => {}
+                _ => failed.push("set its points".to_string()),
+            }
+        }
+        if let Some((account_id, _)) = &self.assignee
+            && client.assign_issue(&key, Some(account_id)).is_err()
+        {
+            failed.push("assign it".to_string());
+        }
+
+        let mut issue = client.get_issue(&key)?;
+        issue.fields.story_points = issue.fields.story_points.or(points);
+        let note = (!failed.is_empty()).then(|| format!("couldn't {}", failed.join(", ")));
+        Ok((issue, note))
+    }
+
+    fn render(&self, f: &mut Frame, theme: &Theme) {
+        let area = f.area();
+        let width = area.width.saturating_sub(4).min(70);
+        let height = 10.min(area.height);
+        let rect = Rect {
+            x: (area.width - width) / 2,
+            y: area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" New issue under {} ", self.epic_key))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        f.render_widget(Clear, rect);
+
+        let assignee = self.assignee.as_ref().map(|(_, name)| name.as_str()).unwrap_or("Unassigned");
+        let values = [
+            self.summary.clone(),
+            format!("◂ {} ▸", self.issue_types[self.type_index]),
+            self.points.clone(),
+            format!("◂ {} ▸", assignee),
+        ];
+        let mut lines: Vec<Line> = Self::FIELDS.iter()
+            .zip(values)
+            .enumerate()
+            .map(|(idx, (label, value))| {
+                let selected = idx == self.field;
+                let mut spans = vec![
+                    Span::styled(if selected { "➤ " } else { "  " }, Style::default().fg(theme.accent)),
+                    Span::styled(format!("{:<10}", label), Style::default().fg(theme.label)),
+                    Span::raw(value),
+                ];
+                if selected && matches!(*label, "Summary" | "Points") {
+                    spans.push(Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(match &self.error {
+            Some(error) => Line::from(Span::styled(error.clone(), Style::default().fg(theme.error))),
+            None => Line::from(""),
+        });
+        let hint = match Self::FIELDS[self.field] {
+            "Type" => "←/→: Change type",
+            "Assignee" => "←/→: Me or unassigned | Space: Pick someone",
+            _ => "Type to edit",
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{} | Tab/↑/↓: Field | Enter: Create | ESC: Cancel", hint),
+            Style::default().fg(theme.muted),
+        )));
+        f.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: false }), rect);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SortField {
+    Updated,
+    Key,
+    Status,
+}
+
+// Order of the list views, cycled with f and reversed with F. Pinned issues stay on top.
+#[derive(Debug, Clone, Copy)]
+struct IssueSort {
+    field: SortField,
+    descending: bool,
+}
+
+impl Default for IssueSort {
+    fn default() -> Self {
+        Self { field: SortField::Updated, descending: true }
+    }
+}
+
+impl IssueSort {
+    // Most recent first is the useful default for dates; A to Z for the rest
+    fn next_field(&mut self) {
+        self.field = match self.field {
+            SortField::Updated => SortField::Key,
+            SortField::Key => SortField::Status,
+            SortField::Status => SortField::Updated,
+        };
+        self.descending = self.field == SortField::Updated;
+    }
+
+    fn reverse(&mut self) {
+        self.descending = !self.descending;
+    }
+
+    fn label(&self) -> String {
+        let field = match self.field {
+            SortField::Updated => "updated",
+            SortField::Key => "key",
+            SortField::Status => "status",
+        };
+        format!("{} {}", field, if self.descending { "↓" } else { "↑" })
+    }
+
+    fn apply(&self, issues: &mut [JiraIssue], pins: &PinStore) {
+        issues.sort_by(|a, b| {
+            let order = match self.field {
+                SortField::Updated => a.fields.updated_at().cmp(&b.fields.updated_at()),
+                SortField::Key => compare_keys(&a.key, &b.key),
+                SortField::Status => a.fields.status.name.to_lowercase().cmp(&b.fields.status.name.to_lowercase()),
+            };
+            if self.descending { order.reverse() } else { order }
+        });
+        pins.sort(issues);
+    }
+}
+
+// PROJ-9 before PROJ-10
+fn compare_keys(a: &str, b: &str) -> std::cmp::Ordering {
+    let split = |key: &str| {
+        let (project, number) = key.rsplit_once('-').unwrap_or((key, ""));
+        (project.to_string(), number.parse::<u64>().unwrap_or(0))
+    };
+    split(a).cmp(&split(b))
+}
+
+// "3h ago" from the issue's updated timestamp
+fn updated_ago(issue: &JiraIssue) -> String {
+    let Some(updated) = issue.fields.updated_at() else {
+        return "—".to_string();
+    };
+    let elapsed = chrono::Utc::now().signed_duration_since(updated);
+    match elapsed.num_minutes() {
+        m if m < 1 => "just now".to_string(),
+        m if m < 60 => format!("{}m ago", m),
+        m if m < 48 * 60 => format!("{}h ago", m / 60),
+        _ if elapsed.num_days() < 14 => format!("{}d ago", elapsed.num_days()),
+        _ if elapsed.num_days() < 365 => format!("{}w ago", elapsed.num_weeks()),
+        _ => format!("{}y ago", elapsed.num_days() / 365),
+    }
+}
+
+// "2/5 done, 1 in progress" for a story's subtasks; None when it has none
+fn subtask_rollup(subtasks: &[crate::jira::LinkedIssue]) -> Option<String> {
+    if subtasks.is_empty() {
+        return None;
+    }
+    let done = subtasks.iter().filter(|s| s.fields.is_done()).count();
+    let in_progress = subtasks.iter()
+        .filter(|s| s.fields.status.category.as_ref().is_some_and(|c| c.key == "indeterminate"))
+        .count();
+    let mut rollup = format!("{}/{} done", done, subtasks.len());
+    if in_progress > 0 {
+        rollup.push_str(&format!(", {} in progress", in_progress));
+    }
+    Some(rollup)
+}
+
+// Toggles the pin on the issue at `index` and re-sorts so pinned issues stay on top.
+// Returns where that issue ended up.
+fn toggle_pin(pins: &mut PinStore, issues: &mut [JiraIssue], index: usize, toasts: &mut Toasts) -> usize {
+    let Some(issue_key) = issues.get(index).map(|issue| issue.key.clone()) else {
+        return index;
+    };
+
+    match PinStore::toggle(&issue_key) {
+        Ok(pinned) => {
+            *pins = PinStore::load();
+            pins.sort(issues);
+            let action = if pinned { "pinned" } else { "unpinned" };
+            toasts.success(format!("✓ {} {}", issue_key, action));
+            issues.iter().position(|issue| issue.key == issue_key).unwrap_or(index)
+        }
+        Err(e) => {
+            toasts.error(format!("✗ Failed to pin {}: {}", issue_key, e));
+            index
+        }
+    }
+}
+
+// What a background action reports back: the toast, and the issue re-fetched after it
+// succeeded so the row shows the new state
+struct JobResult {
+    key: String,
+    severity: Severity,
+    message: String,
+    refreshed: Option<JiraIssue>,
+}
+
+// Jira writes started from the list views run on their own threads, so the list keeps
+// redrawing and taking keys while they're in flight. Results are picked up on the next tick.
+struct Jobs {
+    sender: mpsc::Sender<JobResult>,
+    receiver: mpsc::Receiver<JobResult>,
+    // Keys with an action in flight; a second action on the same issue waits for the first
+    pending: HashSet<String>,
+    // Results taken off the channel by `wait`, handed out by the next `finished`
+    ready: Vec<JobResult>,
+    threads: Vec<std::thread::JoinHandle<()>>,
+}
+
+impl Default for Jobs {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver, pending: HashSet::new(), ready: Vec::new(), threads: Vec::new() }
+    }
+}
+
+impl Jobs {
+    // Runs `action` on `key` in the background. Its Ok value is the success toast; an error
+    // is shown after `failure`.
+    fn spawn<F>(&mut self, client: &JiraClient, key: &str, failure: String, toasts: &mut Toasts, action: F)
+    where
+        F: FnOnce(&JiraClient, &str) -> Result<String> + Send + 'static,
+    {
+        if !self.pending.insert(key.to_string()) {
+            toasts.info(format!("Still working on {}...", key));
+            return;
+        }
+        let client = client.clone();
+        let key = key.to_string();
+        let sender = self.sender.clone();
+        self.threads.retain(|thread| !thread.is_finished());
+        self.threads.push(std::thread::spawn(move || {
+            let result = match action(&client, &key) {
+                Ok(message) => JobResult { severity: Severity::Success, message, refreshed: client.get_issue(&key).ok(), key },
+                Err(e) => JobResult { severity: Severity::Error, message: format!("✗ {}: {}", failure, e), refreshed: None, key },
+            };
+            let _ = sender.send(result);
+        }));
+    }
+
+    fn finished(&mut self) -> Vec<JobResult> {
+        let mut results = std::mem::take(&mut self.ready);
+        results.extend(self.receiver.try_iter());
+        for result in &results {
+            self.pending.remove(&result.key);
+        }
+        results
+    }
+
+    fn in_flight(&self) -> usize {
+        self.pending.len()
+    }
+
+    // Blocks up to `timeout` for the next result
+    fn wait(&mut self, timeout: Duration) {
+        if let Ok(result) = self.receiver.recv_timeout(timeout) {
+            self.ready.push(result);
+        }
+    }
+
+    fn join(&mut self) {
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+// Runs `action` between its [hooks], the same ones the CLI runs. Hook output is captured so
+// it can't draw over the view, and failed after-hooks are appended to the result message.
+fn with_hooks(client: &JiraClient, action: &'static str, key: &str, run: impl FnOnce() -> Result<String>) -> Result<String> {
+    crate::hooks::run_for(client, Stage::Before, action, key, true)?;
+    let message = run()?;
+    let warnings = crate::hooks::run_for(client, Stage::After, action, key, true)
+        .unwrap_or_else(|e| vec![e.to_string()]);
+    if warnings.is_empty() {
+        Ok(message)
+    } else {
+        Ok(format!("{} ({})", message, warnings.join("; ")))
+    }
+}
+
+// Moves `issue_key` to the transition picked for it in the background, and records the move
+// for the ranking `qq jira transition` uses
+fn spawn_transition(jobs: &mut Jobs, client: &JiraClient, issue_key: &str, from: String, option: TransitionOption, toasts: &mut Toasts) {
+    toasts.info(format!("Moving {} to {}...", issue_key, option.status));
+    let failure = format!("Failed to move {} to {}", issue_key, option.status);
+    jobs.spawn(client, issue_key, failure, toasts, move |client, key| {
+        with_hooks(client, "transition", key, || {
+            let status = client.transition_to(key, &option.id, serde_json::Map::new())?;
+            let _ = crate::workflow::WorkflowStore::record(crate::workflow::project_of(key), &from, &status);
+            Ok(format!("✓ {} moved to {}", key, status))
+        })
+    });
+}
+
+// Picker labels for transitions: the status each leads to, and the transition's own name
+// when it's different
+fn transition_labels(options: &[TransitionOption]) -> Vec<String> {
+    options.iter()
+        .map(|option| {
+            if option.name.eq_ignore_ascii_case(&option.status) {
+                option.status.clone()
+            } else {
+                format!("{} (via {})", option.status, option.name)
+            }
+        })
+        .collect()
+}
+
+// Colors shared by every view, from `[ui.theme]` in the config. Built once at startup and
+// passed down to the draw functions. NO_COLOR turns them all off; bold, reverse, and the
+// selection arrow still mark what matters.
+#[derive(Clone)]
+pub struct Theme {
+    // Titles and the selection arrow
+    pub accent: Color,
+    // Field labels and table headers
+    pub label: Color,
+    // In-progress work, filters, and warnings
+    pub highlight: Color,
+    // Help lines and secondary details
+    pub muted: Color,
+    pub text: Color,
+    pub success: Color,
+    pub error: Color,
+    // Reviews, mentions, and the Jira profile badge
+    pub special: Color,
+    pub link: Color,
+    pub selection: Style,
+    no_color: bool,
+}
+
+impl Theme {
+    pub fn new(config: &ThemeConfig) -> Result<Self> {
+        let mut theme = match config.preset {
+            ThemePreset::Dark => Self {
+                accent: Color::Green,
+                label: Color::Cyan,
+                highlight: Color::Yellow,
+                muted: Color::DarkGray,
+                text: Color::White,
+                success: Color::Green,
+                error: Color::Red,
+                special: Color::Magenta,
+                link: Color::Blue,
+                selection: Style::default(),
+                no_color: false,
+            },
+            // Yellow, cyan, and white wash out on a light background
+            ThemePreset::Light => Self {
+                accent: Color::Blue,
+                label: Color::Blue,
+                highlight: Color::Magenta,
+                muted: Color::DarkGray,
+                text: Color::Reset,
+                success: Color::Green,
+                error: Color::Red,
+                special: Color::Magenta,
+                link: Color::Blue,
+                selection: Style::default(),
+                no_color: false,
+            },
+        };
+
+        if let Some(accent) = &config.accent {
+            theme.accent = accent.parse()
+                .map_err(|_| anyhow::anyhow!("Invalid [ui.theme] accent color '{}'", accent))?;
+        }
+        theme.selection = match config.selection {
+            SelectionStyle::Arrow => Style::default(),
+            SelectionStyle::Bold => Style::default().add_modifier(Modifier::BOLD),
+            SelectionStyle::Reverse => Style::default().add_modifier(Modifier::REVERSED),
+            SelectionStyle::Underline => Style::default().add_modifier(Modifier::UNDERLINED),
+        };
+
+        // https://no-color.org: any non-empty value disables color
+        if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            theme = Self {
+                accent: Color::Reset,
+                label: Color::Reset,
+                highlight: Color::Reset,
+                muted: Color::Reset,
+                text: Color::Reset,
+                success: Color::Reset,
+                error: Color::Reset,
+                special: Color::Reset,
+                link: Color::Reset,
+                // Without colors the selected row needs another cue
+                selection: Style::default().add_modifier(Modifier::REVERSED),
+                no_color: true,
+            };
+        }
+        Ok(theme)
+    }
+
+    // Colors that come from data, like Google Calendar event colors
+    pub fn paint(&self, color: Color) -> Color {
+        if self.no_color { Color::Reset } else { color }
+    }
+
+    pub fn selected(&self, selected: bool) -> Style {
+        if selected { self.selection } else { Style::default() }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new(&ThemeConfig::default()).expect("default theme is valid")
+    }
+}
+
+// Active Jira profile, shown in the top-right corner of Jira views. Set once at startup.
+static JIRA_LABEL: OnceLock<String> = OnceLock::new();
+
+pub fn set_jira_label(label: String) {
+    let _ = JIRA_LABEL.set(label);
+}
+
+// Drawn into the top margin row, which every view leaves empty
+fn render_jira_label(f: &mut Frame, theme: &Theme) {
+    let Some(label) = JIRA_LABEL.get() else {
+        return;
+    };
+    let area = f.area();
+    let width = (label.chars().count() as u16 + 2).min(area.width);
+    let badge = Rect { x: area.width - width, y: 0, width, height: 1 };
+    f.render_widget(
+        Paragraph::new(format!(" {} ", label)).style(Style::default().fg(theme.special)),
+        badge,
+    );
+}
+
+// Optional and low-priority meetings fade into the background; important ones stand out
+fn meeting_style(meeting: &Meeting, theme: &Theme) -> Style {
+    if meeting.is_dimmed() {
+        Style::default().fg(theme.muted)
+    } else if meeting.importance == Importance::High {
+        Style::default().add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    }
+}
+
+pub struct JiraIssueDisplay {
+    scroll: TextScroll,
+    // Follow mode only: refresh interval, fields that changed in the latest refresh,
+    // and the comments seen so far
+    follow_interval: Option<Duration>,
+    changed: Vec<&'static str>,
+    comment_ids: Vec<String>,
+    new_comments: usize,
+    last_checked: Option<chrono::DateTime<chrono::Local>>,
+    // The comments pane, toggled with c. Comments are fetched the first time it opens;
+    // while it is open the scroll and search keys move through it instead of the description.
+    comments: Option<Vec<crate::jira::Comment>>,
+    show_comments: bool,
+    comment_scroll: TextScroll,
+    reply: Option<String>,
+    // Set while the box holds one of your comments being edited (e) rather than a new reply
+    editing: Option<String>,
+    // Your comment waiting on y to be deleted (d)
+    pending_delete: Option<crate::jira::Comment>,
+    // Your account ID, looked up the first time e or d is pressed
+    me: Option<String>,
+}
+
+impl JiraIssueDisplay {
+    fn new(follow_interval: Option<Duration>) -> Self {
+        Self {
+            scroll: TextScroll::default(),
+            follow_interval,
+            changed: Vec::new(),
+            comment_ids: Vec::new(),
+            new_comments: 0,
+            last_checked: None,
+            comments: None,
+            show_comments: false,
+            comment_scroll: TextScroll::default(),
+            reply: None,
+            editing: None,
+            pending_delete: None,
+            me: None,
+        }
+    }
+
+    pub fn show(issue: &JiraIssue, client: &JiraClient, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self::new(None);
+        let mut toasts = Toasts::default();
+        let issue = issue.clone();
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| {
+                app.draw(f, &issue, theme);
+                toasts.render(f, theme);
+            })?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if app.reply.is_some() {
+                    app.handle_reply_key(&key, &issue.key, client, &mut toasts);
+                    continue;
+                }
+                if app.pending_delete.is_some() {
+                    app.handle_delete_key(&key, &issue.key, client, &mut toasts);
+                    continue;
+                }
+
+                if !app.focused().search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                if app.focused().handle_key(&key) {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc if app.show_comments => app.show_comments = false,
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char('c') => {
+                        app.show_comments = !app.show_comments;
+                        if app.show_comments && app.comments.is_none() {
+                            toasts.info(format!("Loading comments on {}...", issue.key));
+                            terminal.draw(|f| {
+                                app.draw(f, &issue, theme);
+                                toasts.render(f, theme);
+                            })?;
+                            app.load_comments(&issue.key, client, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('C') => {
+                        if app.comments.is_none() {
+                            app.load_comments(&issue.key, client, &mut toasts);
+                        }
+                        app.show_comments = true;
+                        app.reply = Some(String::new());
+                    }
+                    KeyCode::Char(c @ ('e' | 'd')) => {
+                        let action = if c == 'e' { "Edit" } else { "Delete" };
+                        let Some(comment) = app.pick_own_comment(&mut terminal, &issue.key, client, &mut toasts, action, theme)? else {
+                            continue;
+                        };
+                        if c == 'e' {
+                            app.reply = Some(comment.body.as_ref().map(plain_text).unwrap_or_default());
+                            app.editing = Some(comment.id);
+                        } else {
+                            app.pending_delete = Some(comment);
+                        }
+                    }
+                    KeyCode::Char(c @ '1'..='9') => {
+                        if let Some(url) = Self::links(&issue).get(c as usize - '1' as usize) {
+                            open_link(url, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('u') => {
+                        let links = Self::links(&issue);
+                        if links.is_empty() {
+                            toasts.info("No links in the description");
+                            continue;
+                        }
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = ListPicker::show(" Links ", links.clone(), theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        if let Ok(Some(idx)) = picked {
+                            open_link(&links[idx], &mut toasts);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    // Keeps the issue open and re-fetches it every `interval`, highlighting status, assignee,
+    // and other fields that changed since the previous refresh, plus any new comments
+    pub fn follow(issue: JiraIssue, client: &JiraClient, interval: Duration, theme: &Theme) -> Result<()> {
+        let mut issue = issue;
+        let mut app = Self::new(Some(interval));
+        app.comment_ids = client.get_comments(&issue.key)
+            .map(|comments| comments.into_iter().map(|c| c.id).collect())
+            .unwrap_or_default();
+        app.last_checked = Some(chrono::Local::now());
+
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut toasts = Toasts::default();
+        let mut last_refresh = Instant::now();
+        let mut refresh_now = false;
+        let mut should_quit = false;
+
+        while !should_quit {
+            terminal.draw(|f| {
+                app.draw(f, &issue, theme);
+                toasts.render(f, theme);
+            })?;
+
+            if refresh_now || last_refresh.elapsed() >= interval {
+                refresh_now = false;
+                last_refresh = Instant::now();
+                match client.get_issue(&issue.key) {
+                    Ok(updated) => {
+                        let comments = client.get_comments(&issue.key).unwrap_or_default();
+                        if app.comments.is_some() {
+                            app.comments = Some(comments.clone());
+                        }
+                        for change in app.record_changes(&issue, &updated, comments) {
+                            toasts.info(format!("{}: {}", issue.key, change));
+                        }
+                        issue = updated;
+                    }
+                    Err(e) => toasts.error(format!("✗ Failed to refresh {}: {}", issue.key, e)),
+                }
+                continue;
+            }
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if app.reply.is_some() {
+                    app.handle_reply_key(&key, &issue.key, client, &mut toasts);
+                    continue;
+                }
+                if app.pending_delete.is_some() {
+                    app.handle_delete_key(&key, &issue.key, client, &mut toasts);
+                    continue;
+                }
+
+                if !app.focused().search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                if app.focused().handle_key(&key) {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc if app.show_comments => app.show_comments = false,
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char('c') => {
+                        app.show_comments = !app.show_comments;
+                        if app.show_comments && app.comments.is_none() {
+                            toasts.info(format!("Loading comments on {}...", issue.key));
+                            terminal.draw(|f| {
+                                app.draw(f, &issue, theme);
+                                toasts.render(f, theme);
+                            })?;
+                            app.load_comments(&issue.key, client, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('C') => {
+                        if app.comments.is_none() {
+                            app.load_comments(&issue.key, client, &mut toasts);
+                        }
+                        app.show_comments = true;
+                        app.reply = Some(String::new());
+                    }
+                    KeyCode::Char(c @ ('e' | 'd')) => {
+                        let action = if c == 'e' { "Edit" } else { "Delete" };
+                        let Some(comment) = app.pick_own_comment(&mut terminal, &issue.key, client, &mut toasts, action, theme)? else {
+                            continue;
+                        };
+                        if c == 'e' {
+                            app.reply = Some(comment.body.as_ref().map(plain_text).unwrap_or_default());
+                            app.editing = Some(comment.id);
+                        } else {
+                            app.pending_delete = Some(comment);
+                        }
+                    }
+                    KeyCode::Char('r') => refresh_now = true,
+                    KeyCode::Char(c @ '1'..='9') => {
+                        if let Some(url) = Self::links(&issue).get(c as usize - '1' as usize) {
+                            open_link(url, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('u') => {
+                        let links = Self::links(&issue);
+                        if links.is_empty() {
+                            toasts.info("No links in the description");
+                            continue;
+                        }
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = ListPicker::show(" Links ", links.clone(), theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        if let Ok(Some(idx)) = picked {
+                            open_link(&links[idx], &mut toasts);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    // Links in the description, in the order of their footnote numbers
+    fn links(issue: &JiraIssue) -> Vec<String> {
+        issue.fields.description.as_ref().map(crate::jira::adf::links).unwrap_or_default()
+    }
+
+    // The pane the scroll and search keys apply to
+    fn focused(&mut self) -> &mut TextScroll {
+        if self.show_comments {
+            &mut self.comment_scroll
+        } else {
+            &mut self.scroll
+        }
+    }
+
+    fn load_comments(&mut self, issue_key: &str, client: &JiraClient, toasts: &mut Toasts) {
+        match client.get_comments(issue_key) {
+            Ok(comments) => self.comments = Some(comments),
+            Err(e) => toasts.error(format!("✗ Failed to load comments on {}: {}", issue_key, e)),
+        }
+    }
+
+    // One of your comments, for e and d: the only one, or one picked from a list. Opens the
+    // comments pane, loading them first if needed.
+    fn pick_own_comment(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        issue_key: &str,
+        client: &JiraClient,
+        toasts: &mut Toasts,
+        action: &str,
+        theme: &Theme,
+    ) -> Result<Option<crate::jira::Comment>> {
+        if self.comments.is_none() {
+            self.load_comments(issue_key, client, toasts);
+        }
+        self.show_comments = true;
+        if self.me.is_none() {
+            match client.get_current_user() {
+                Ok(user) => self.me = Some(user.account_id),
+                Err(e) => {
+                    toasts.error(format!("✗ Failed to look you up: {}", e));
+                    return Ok(None);
+                }
+            }
+        }
+
+        // Newest first, since that's usually the one to fix
+        let mut own: Vec<crate::jira::Comment> = self.comments.iter()
+            .flatten()
+            .filter(|c| c.author.as_ref().map(|a| &a.account_id) == self.me.as_ref())
+            .cloned()
+            .collect();
+        own.reverse();
+        if own.len() <= 1 {
+            if own.is_empty() {
+                toasts.info(format!("You have no comments on {}", issue_key));
+            }
+            return Ok(own.pop());
+        }
+
+        let labels: Vec<String> = own.iter()
+            .map(|comment| {
+                let when = crate::jira::parse_jira_datetime(&comment.created)
+                    .map(|at| at.with_timezone(&chrono::Local).format("%a %b %-d %H:%M").to_string())
+                    .unwrap_or_default();
+                let text = comment.body.as_ref().map(plain_text).unwrap_or_default();
+                format!("{}  {}", when, text.lines().next().unwrap_or_default())
+            })
+            .collect();
+
+        // Temporarily restore terminal for nested UI
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        let picked = ListPicker::show(&format!(" {} which comment? ", action), labels, theme);
+
+        // Re-setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        *terminal = Terminal::new(backend)?;
+
+        Ok(picked?.map(|idx| own.swap_remove(idx)))
+    }
+
+    fn handle_reply_key(&mut self, key: &KeyEvent, issue_key: &str, client: &JiraClient, toasts: &mut Toasts) {
+        let Some(reply) = self.reply.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.reply = None;
+                toasts.info(if self.editing.take().is_some() { "Edit cancelled" } else { "Reply cancelled" });
+            }
+            KeyCode::Enter => {
+                let text = self.reply.take().unwrap_or_default();
+                if text.trim().is_empty() {
+                    toasts.error("✗ Comment is empty");
+                    self.editing = None;
+                    return;
+                }
+                let result = match &self.editing {
+                    Some(id) => client.update_comment(issue_key, id, text.trim())
+                        .map(|_| format!("✓ Comment on {} updated", issue_key)),
+                    None => with_hooks(client, "comment", issue_key, || {
+                        client.add_comment(issue_key, text.trim())?;
+                        Ok(format!("✓ Comment added to {}", issue_key))
+                    }),
+                };
+                match result {
+                    Ok(message) if self.editing.take().is_some() => {
+                        toasts.success(message);
+                        self.load_comments(issue_key, client, toasts);
+                    }
+                    Ok(message) => {
+                        toasts.success(message);
+                        self.load_comments(issue_key, client, toasts);
+                        // The new comment is the last one
+                        self.comment_scroll.offset = u16::MAX;
+                    }
+                    Err(e) => {
+                        toasts.error(format!("✗ Failed to save the comment on {}: {}", issue_key, e));
+                        self.reply = Some(text);
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                reply.pop();
+            }
+            KeyCode::Char(c) => reply.push(c),
+            _ => {}
+        }
+    }
+
+    // y deletes the comment picked with d; any other key keeps it
+    fn handle_delete_key(&mut self, key: &KeyEvent, issue_key: &str, client: &JiraClient, toasts: &mut Toasts) {
+        let Some(comment) = self.pending_delete.take() else {
+            return;
+        };
+        if !matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            toasts.info("Comment kept");
+            return;
+        }
+        match client.delete_comment(issue_key, &comment.id) {
+            Ok(()) => {
+                toasts.success(format!("✓ Comment on {} deleted", issue_key));
+                self.load_comments(issue_key, client, toasts);
+            }
+            Err(e) => toasts.error(format!("✗ Failed to delete the comment on {}: {}", issue_key, e)),
+        }
+    }
+
+    // Compares a refreshed issue with the previous one; returns a description of each change
+    fn record_changes(&mut self, old: &JiraIssue, new: &JiraIssue, comments: Vec<crate::jira::Comment>) -> Vec<String> {
+        let assignee = |issue: &JiraIssue| issue.fields.assignee.as_ref()
+            .map(|a| a.display_name.clone())
+            .unwrap_or_else(|| "Unassigned".to_string());
+        let priority = |issue: &JiraIssue| issue.fields.priority.as_ref()
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+
+        let fields = [
+            ("Status", old.fields.status.name.clone(), new.fields.status.name.clone()),
+            ("Assignee", assignee(old), assignee(new)),
+            ("Priority", priority(old), priority(new)),
+            ("Summary", old.fields.summary.clone(), new.fields.summary.clone()),
+        ];
+
+        let mut messages = Vec::new();
+        self.changed.clear();
+        for (name, before, after) in fields {
+            if before != after {
+                self.changed.push(name);
+                messages.push(format!("{} {} → {}", name, before, after));
+            }
+        }
+        if old.fields.description != new.fields.description {
+            self.changed.push("Description");
+            messages.push("Description edited".to_string());
+        }
+
+        let new_comments: Vec<_> = comments.iter()
+            .filter(|c| !self.comment_ids.contains(&c.id))
+            .collect();
+        self.new_comments = new_comments.len();
+        for comment in &new_comments {
+            let author = comment.author.as_ref().map(|a| a.display_name.as_str()).unwrap_or("Someone");
+            messages.push(format!("New comment from {}", author));
+        }
+        self.comment_ids = comments.into_iter().map(|c| c.id).collect();
+        self.last_checked = Some(chrono::Local::now());
+
+        messages
+    }
+
+    fn draw(&mut self, f: &mut Frame, issue: &JiraIssue, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(10), // Header info (increased for assignee and time)
+                Constraint::Min(0),     // Description
+                Constraint::Length(2),  // Help text
+            ])
+            .split(f.area());
+
+        self.render_header(f, chunks[0], issue, theme);
+        if self.show_comments {
+            let body = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+                .split(chunks[1]);
+            self.render_description(f, body[0], &issue.fields.description, theme);
+            self.render_comments(f, body[1], theme);
+        } else {
+            self.render_description(f, chunks[1], &issue.fields.description, theme);
+        }
+        self.render_help(f, chunks[2], theme);
+        self.focused().search.render(f, theme);
+    }
+
+    fn render_comments(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let title = match &self.comments {
+            Some(comments) => format!(" Comments ({}) ", comments.len()),
+            None => " Comments ".to_string(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        // The reply box sits at the bottom of the pane while typing, as does the question
+        // before deleting a comment
+        let box_height = match (&self.reply, &self.pending_delete) {
+            (Some(reply), _) => (reply.lines().count().max(1) as u16 + 2).min(8),
+            (None, Some(_)) => 3,
+            (None, None) => 0,
+        };
+        let (list_area, reply_area) = if box_height > 0 {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(box_height)])
+                .split(inner);
+            (parts[0], Some(parts[1]))
+        } else {
+            (inner, None)
+        };
+
+        match &self.comments {
+            Some(comments) if !comments.is_empty() => {
+                let mut lines = Vec::new();
+                for comment in comments {
+                    let author = comment.author.as_ref()
+                        .map(|a| a.display_name.clone())
+                        .unwrap_or_else(|| "Unknown".to_string());
+                    let when = crate::jira::parse_jira_datetime(&comment.created)
+                        .map(|at| at.with_timezone(&chrono::Local).format("%a %b %-d %H:%M").to_string())
+                        .unwrap_or_default();
+                    let edited = if comment.updated != comment.created { " (edited)" } else { "" };
+                    if !lines.is_empty() {
+                        lines.push(Line::from(""));
+                    }
+                    lines.push(Line::from(vec![
+                        Span::styled(author, Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                        Span::styled(format!("  {}{}", when, edited), Style::default().fg(theme.muted)),
+                    ]));
+                    if let Some(body) = &comment.body {
+                        lines.extend(crate::jira::adf::to_text(body, theme).lines);
+                    }
+                }
+                let paragraph = self.comment_scroll.paragraph(lines, list_area);
+                f.render_widget(paragraph, list_area);
+            }
+            Some(_) => {
+                let text = Paragraph::new("(No comments yet)")
+                    .style(Style::default().fg(theme.muted));
+                f.render_widget(text, list_area);
+            }
+            None => {
+                let text = Paragraph::new("(Comments couldn't be loaded)")
+                    .style(Style::default().fg(theme.muted));
+                f.render_widget(text, list_area);
+            }
+        }
+
+        if let (Some(reply), Some(area)) = (&self.reply, reply_area) {
+            let mut lines: Vec<Line> = reply.split('\n').map(|line| Line::from(line.to_string())).collect();
+            if let Some(last) = lines.last_mut() {
+                last.push_span(Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)));
+            }
+            let title = if self.editing.is_some() {
+                " Edit comment (Enter: Save, ESC: Cancel) "
+            } else {
+                " Reply (Enter: Post, ESC: Cancel) "
+            };
+            let input = Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .border_style(Style::default().fg(theme.accent)));
+            f.render_widget(input, area);
+        } else if let (Some(comment), Some(area)) = (&self.pending_delete, reply_area) {
+            let text = comment.body.as_ref().map(plain_text).unwrap_or_default();
+            let question = Paragraph::new(text.lines().next().unwrap_or_default().to_string())
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Delete this comment? (y: Delete, any other key: Keep) ")
+                    .border_style(Style::default().fg(theme.error)));
+            f.render_widget(question, area);
+        }
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect, issue: &JiraIssue, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" JIRA Issue Details ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let assignee_text = match &issue.fields.assignee {
+            Some(user) => user.display_name.clone(),
+            None => "Unassigned".to_string(),
+        };
+
+        // Values that changed in the latest follow-mode refresh are highlighted
+        let value = |name: &str, text: &str| {
+            if self.changed.contains(&name) {
+                Span::styled(format!("{} ●", text), Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD))
+            } else {
+                Span::raw(text.to_string())
+            }
+        };
+
+        let mut header_text = vec![
+            Line::from(vec![
+                Span::styled("Ticket: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(&issue.key),
+            ]),
+            Line::from(vec![
+                Span::styled("Status: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                value("Status", &issue.fields.status.name),
+            ]),
+            Line::from(vec![
+                Span::styled("Assignee: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                value("Assignee", &assignee_text),
+            ]),
+            Line::from(vec![
+                Span::styled("Summary: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                value("Summary", &issue.fields.summary),
+            ]),
+        ];
+
+        let time = &issue.fields.timetracking;
+        if time.time_spent.is_some() || time.remaining_estimate.is_some() {
+            let amount = |v: &Option<String>| v.clone().unwrap_or_else(|| "—".to_string());
+            header_text.push(Line::from(vec![
+                Span::styled("Time: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{} spent, {} remaining", amount(&time.time_spent), amount(&time.remaining_estimate))),
+            ]));
+        }
+
+        if !issue.problems.is_empty() {
+            header_text.push(Line::from(Span::styled(
+                format!("⚠ Incomplete data from JIRA: {}", issue.problems.join("; ")),
+                Style::default().fg(theme.highlight),
+            )));
+        }
+
+        if let Some(checked) = self.last_checked {
+            let comments = if self.new_comments > 0 {
+                Span::styled(
+                    format!("{} ({} new) ●", self.comment_ids.len(), self.new_comments),
+                    Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::raw(self.comment_ids.len().to_string())
+            };
+            header_text.push(Line::from(vec![
+                Span::styled("Comments: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                comments,
+            ]));
+            header_text.push(Line::from(Span::styled(
+                format!("Last checked {}", checked.format("%H:%M:%S")),
+                Style::default().fg(theme.muted),
+            )));
+        }
+
+        let paragraph = Paragraph::new(header_text).wrap(Wrap { trim: true });
+        f.render_widget(paragraph, inner);
+    }
+
+    fn render_description(&mut self, f: &mut Frame, area: Rect, description: &Option<Value>, theme: &Theme) {
+        let title = if self.changed.contains(&"Description") {
+            " Description (edited) "
+        } else {
+            " Description "
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if let Some(desc) = description {
+            let mut lines = crate::jira::adf::to_text(desc, theme).lines;
+            lines.extend(link_footnotes(&crate::jira::adf::links(desc), true, theme));
+            let paragraph = self.scroll.paragraph(lines, inner);
+            f.render_widget(paragraph, inner);
+        } else {
+            let text = Paragraph::new("(No description provided)")
+                .style(Style::default().fg(theme.muted));
+            f.render_widget(text, inner);
+        }
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let text = match self.follow_interval {
+            Some(interval) => format!(
+                "Following: refreshes every {}s | r: Refresh now | ↑/↓ j/k: Scroll | /: Search | c: Comments | C: Reply | e/d: Edit/delete yours | 1-9/u: Open link | q/ESC: Quit",
+                interval.as_secs()
+            ),
+            None => "Press 'q' or ESC to quit, ↑/↓ or j/k to scroll, / to search, c for comments, C to reply, e/d to edit/delete yours, 1-9 or u to open a link".to_string(),
+        };
+        let help = Paragraph::new(text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+// A comment or description as plain text, for editing it in a text box. Only the text is
+// kept, so the default theme's colors don't matter.
+fn plain_text(value: &Value) -> String {
+    crate::jira::adf::to_text(value, &Theme::default()).lines.iter()
+        .map(|line| line.spans.iter().map(|span| span.content.as_ref()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// List of links shown under a description. When numbered, the numbers are the keys that open them.
+fn link_footnotes(links: &[String], numbered: bool, theme: &Theme) -> Vec<Line<'static>> {
+    if links.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled("Links", Style::default().fg(theme.label).add_modifier(Modifier::BOLD))),
+    ];
+    for (idx, url) in links.iter().enumerate() {
+        let number = if numbered && idx < 9 { format!("[{}] ", idx + 1) } else { "    ".to_string() };
+        lines.push(Line::from(vec![
+            Span::styled(number, Style::default().fg(theme.accent)),
+            Span::styled(url.clone(), Style::default().fg(theme.muted)),
+        ]));
+    }
+    lines
+}
+
+// Toast text for opening a link in the browser
+fn open_link(url: &str, toasts: &mut Toasts) {
+    match webbrowser::open(url) {
+        Ok(_) => toasts.success(format!("✓ Opened {}", url)),
+        Err(e) => toasts.error(format!("✗ Failed to open {}: {}", url, e)),
+    }
+}
+
+// A row in the epic tree: a child of the epic, or one of that child's subtasks
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TreeRow {
+    child: usize,
+    subtask: Option<usize>,
+}
+
+pub struct EpicListDisplay {
+    // Index into the visible rows, which include expanded subtasks
+    selected_index: usize,
+    children: Vec<JiraIssue>,
+    // Fetched the first time a story is expanded
+    subtasks: HashMap<String, Vec<JiraIssue>>,
+    expanded: HashSet<String>,
+    pins: PinStore,
+    sort: IssueSort,
+    scroll_offset: usize,
+    viewport_height: usize,
+    rename_mode: bool,
+    rename_input: String,
+    checklist: Option<CloseChecklist>,
+    // Issue keys marked with Space for a bulk label or component edit
+    marked: HashSet<String>,
+    bulk: Option<BulkEdit>,
+    // Open while `n` is filling in a new child
+    form: Option<CreateForm>,
+    search: Search,
+    jobs: Jobs,
+}
+
+impl EpicListDisplay {
+    fn update_scroll_offset(&mut self, viewport_height: usize) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+        }
+    }
+    
+    fn rows(&self) -> Vec<TreeRow> {
+        let mut rows = Vec::new();
+        for (child, issue) in self.children.iter().enumerate() {
+            rows.push(TreeRow { child, subtask: None });
+            if self.expanded.contains(&issue.key)
+                && let Some(subtasks) = self.subtasks.get(&issue.key)
+            {
+                rows.extend((0..subtasks.len()).map(|idx| TreeRow { child, subtask: Some(idx) }));
+            }
+        }
+        rows
+    }
+
+    fn issue_at(&self, row: TreeRow) -> Option<&JiraIssue> {
+        let child = self.children.get(row.child)?;
+        match row.subtask {
+            None => Some(child),
+            Some(idx) => self.subtasks.get(&child.key)?.get(idx),
+        }
+    }
+
+    fn selected_issue(&self) -> Option<&JiraIssue> {
+        self.rows().get(self.selected_index).and_then(|row| self.issue_at(*row))
+    }
+
+    fn selected_issue_mut(&mut self) -> Option<&mut JiraIssue> {
+        let row = *self.rows().get(self.selected_index)?;
+        match row.subtask {
+            None => self.children.get_mut(row.child),
+            Some(idx) => {
+                let key = self.children.get(row.child)?.key.clone();
+                self.subtasks.get_mut(&key)?.get_mut(idx)
+            }
+        }
+    }
+
+    // Swaps in a refreshed copy of whichever row holds the issue, which may no longer be the
+    // selected one by the time a background action finishes. Keeps the supplementary fields
+    // the single-issue endpoint doesn't fill in.
+    fn replace_issue(&mut self, mut updated: JiraIssue) {
+        let issue = self.children.iter_mut()
+            .chain(self.subtasks.values_mut().flatten())
+            .find(|issue| issue.key == updated.key);
+        if let Some(issue) = issue {
+            updated.fields.story_points = updated.fields.story_points.or(issue.fields.story_points);
+            *issue = updated;
+        }
+    }
+
+    fn apply_jobs(&mut self, toasts: &mut Toasts) {
+        for result in self.jobs.finished() {
+            toasts.push(result.severity, result.message);
+            if let Some(updated) = result.refreshed {
+                self.replace_issue(updated);
+            }
+        }
+    }
+
+    fn close_issue(&mut self, client: &JiraClient, issue_key: &str, comment: Option<String>, toasts: &mut Toasts) {
+        toasts.info(format!("Closing {}...", issue_key));
+        self.jobs.spawn(client, issue_key, format!("Failed to close {}", issue_key), toasts, move |client, key| {
+            with_hooks(client, "close", key, || {
+                client.close_issue(key, comment.as_deref())?;
+                // A closed ticket has nothing left to come back for
+                let _ = crate::snooze::SnoozeStore::edit(|snoozes| snoozes.unsnooze(key));
+                Ok(format!("✓ {} closed successfully", key))
+            })
+        });
+    }
+
+    fn resort(&mut self) {
+        let selected_key = self.rows().get(self.selected_index).map(|row| self.children[row.child].key.clone());
+        self.sort.apply(&mut self.children, &self.pins);
+        let child = selected_key
+            .and_then(|key| self.children.iter().position(|issue| issue.key == key))
+            .unwrap_or(0);
+        self.select_child(child);
+    }
+
+    fn select_child(&mut self, child: usize) {
+        self.selected_index = self.rows().iter()
+            .position(|row| row.child == child && row.subtask.is_none())
+            .unwrap_or(0);
+        self.update_scroll_offset(self.viewport_height);
+    }
+
+    // Adds a newly created child in its sorted place and selects it
+    fn insert_child(&mut self, issue: JiraIssue) {
+        let key = issue.key.clone();
+        self.children.push(issue);
+        self.sort.apply(&mut self.children, &self.pins);
+        let child = self.children.iter().position(|issue| issue.key == key).unwrap_or(0);
+        self.select_child(child);
+    }
+
+    pub fn show(epic: &JiraIssue, mut children: Vec<JiraIssue>, client: &JiraClient, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let pins = PinStore::load();
+        let sort = IssueSort::default();
+        sort.apply(&mut children, &pins);
+        let mut app = Self {
+            selected_index: 0,
+            children,
+            subtasks: HashMap::new(),
+            expanded: HashSet::new(),
+            pins,
+            sort,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+            rename_mode: false,
+            rename_input: String::new(),
+            checklist: None,
+            marked: HashSet::new(),
+            bulk: None,
+            form: None,
+            search: Search::default(),
+            jobs: Jobs::default(),
+        };
+        
+        let mut should_quit = false;
+        let mut toasts = Toasts::default();
+
+        // Main loop
+        while !should_quit {
+            app.apply_jobs(&mut toasts);
+            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if let Some(checklist) = &mut app.checklist {
+                    if key.code == KeyCode::Esc {
+                        app.checklist = None;
+                    } else if let Some(comment) = checklist.handle_key(&key) {
+                        let issue_key = checklist.report.key.clone();
+                        app.checklist = None;
+                        app.close_issue(client, &issue_key, comment, &mut toasts);
+                    } else if key.code == KeyCode::Char('q') && checklist.report.is_blocked() {
+                        app.checklist = None;
+                    }
+                    continue;
+                }
+                
+                if let Some(form) = &mut app.form {
+                    match form.handle_key(&key, client) {
+                        Some(FormAction::Cancel) => app.form = None,
+                        Some(FormAction::Submit) => {
+                            toasts.info(format!("Creating an issue under {}...", epic.key));
+                            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                            if let Some(form) = app.form.take() {
+                                match form.create(client) {
+                                    Ok((issue, note)) => {
+                                        match note {
+                                            Some(note) => toasts.error(format!("✗ Created {}, but {}", issue.key, note)),
+                                            None => toasts.success(format!("✓ Created {}", issue.key)),
+                                        }
+                                        app.insert_child(issue);
+                                    }
+                                    Err(e) => {
+                                        toasts.error(format!("✗ Failed to create the issue: {}", e));
+                                        // Keep what was typed so it can be fixed and retried
+                                        app.form = Some(form);
+                                    }
+                                }
+                            }
+                        }
+                        Some(FormAction::PickAssignee) => {
+                            // Temporarily restore terminal for assignee selection
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            println!("Fetching assignable users for {}...", epic.key);
+                            let (current_user, users) = std::thread::scope(|scope| {
+                                let current_user = scope.spawn(|| client.get_current_user());
+                                let users = client.get_assignable_users(&epic.key);
+                                let current_user = current_user.join()
+                                    .unwrap_or_else(|_| Err(anyhow::anyhow!("Current user fetch thread panicked")));
+                                (current_user, users)
+                            });
+                            let picked = match (current_user, users) {
+                                (Ok(current_user), Ok(users)) => {
+                                    AssigneeSelector::show(users.clone(), current_user.account_id, theme)?
+                                        .map(|account_id| (account_id, users))
+                                }
+                                (Err(e), _) | (_, Err(e)) => {
+                                    toasts.error(format!("✗ Failed to fetch users: {}", e));
+                                    None
+                                }
+                            };
+                            
+                            // Re-setup terminal
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                            
+                            if let Some((account_id, users)) = picked {
+                                form.assignee = users.into_iter()
+                                    .find(|user| user.account_id == account_id)
+                                    .map(|user| (user.account_id, user.display_name));
+                            }
+                        }
+                        None => {}
+                    }
+                    continue;
+                }
+                
+                if let Some(bulk) = &mut app.bulk {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.bulk = None;
+                            toasts.info("Bulk edit cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            if let Some(bulk) = app.bulk.take() {
+                                match bulk.apply(&mut terminal, client, |f, bulk| {
+                                    app.draw(f, epic, &toasts, theme);
+                                    bulk.render(f, theme);
+                                })? {
+                                    Ok(message) => {
+                                        app.marked.clear();
+                                        toasts.success(message);
+                                    }
+                                    Err(message) => toasts.error(message),
+                                }
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            bulk.input.pop();
+                        }
+                        KeyCode::Char(c) => bulk.input.push(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if app.rename_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.rename_mode = false;
+                            app.rename_input.clear();
+                            toasts.info("Rename cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            app.rename_mode = false;
+                            let summary = std::mem::take(&mut app.rename_input);
+                            
+                            if let Some(issue) = app.selected_issue() {
+                                let issue_key = issue.key.clone();
+                                toasts.info(format!("Renaming {}...", issue_key));
+                                terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                                
+                                match client.rename_issue(&issue_key, &summary) {
+                                    Ok(_) => {
+                                        toasts.success(format!("✓ {} renamed", issue_key));
+                                        if let Some(issue) = app.selected_issue_mut() {
+                                            issue.fields.summary = summary.trim().to_string();
+                                        }
+                                    }
+                                    Err(e) => {
+                                        toasts.error(format!("✗ Failed to rename {}: {}", issue_key, e));
+                                    }
+                                }
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            app.rename_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.rename_input.push(c);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                let texts: Vec<String> = app.rows().into_iter()
+                    .filter_map(|row| app.issue_at(row))
+                    .map(issue_search_text)
+                    .collect();
+                if app.search.navigate(&key, &mut app.selected_index, texts.len(), app.viewport_height, || texts) {
+                    app.update_scroll_offset(app.viewport_height);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc if !app.marked.is_empty() => app.marked.clear(),
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char(' ') => {
+                        if let Some(key) = app.selected_issue().map(|issue| issue.key.clone()) {
+                            toggle_mark(&mut app.marked, &key);
+                            if app.selected_index + 1 < app.rows().len() {
+                                app.selected_index += 1;
+                                app.update_scroll_offset(app.viewport_height);
+                            }
+                        }
+                    }
+                    KeyCode::Char('L') => {
+                        let keys = bulk_keys(app.children.iter().chain(app.subtasks.values().flatten()), &app.marked, app.selected_issue());
+                        if keys.is_empty() {
+                            continue;
+                        }
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = BulkEdit::pick(client, keys, &mut toasts, theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        match picked {
+                            Ok(Some(bulk)) if bulk.needs_input() => app.bulk = Some(bulk),
+                            Ok(Some(bulk)) => {
+                                match bulk.apply(&mut terminal, client, |f, bulk| {
+                                    app.draw(f, epic, &toasts, theme);
+                                    bulk.render(f, theme);
+                                })? {
+                                    Ok(message) => {
+                                        app.marked.clear();
+                                        toasts.success(message);
+                                    }
+                                    Err(message) => toasts.error(message),
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(e) => toasts.error(format!("✗ Bulk edit failed: {}", e)),
+                        }
+                    }
+                    KeyCode::Char('R') => {
+                        if let Some(issue) = app.selected_issue() {
+                            app.rename_input = issue.fields.summary.clone();
+                            app.rename_mode = true;
+                        }
+                    }
+                    KeyCode::Char('n') => {
+                        app.form = CreateForm::open(client, &epic.key, &mut toasts);
+                    }
+                    KeyCode::Char('P') => {
+                        match app.rows().get(app.selected_index).copied() {
+                            Some(TreeRow { child, subtask: None }) => {
+                                let child = toggle_pin(&mut app.pins, &mut app.children, child, &mut toasts);
+                                app.select_child(child);
+                            }
+                            Some(_) => toasts.info("Only the epic's own children can be pinned".to_string()),
+                            None => {}
+                        }
+                    }
+                    KeyCode::Char('f') => {
+                        app.sort.next_field();
+                        app.resort();
+                    }
+                    KeyCode::Char('F') => {
+                        app.sort.reverse();
+                        app.resort();
+                    }
+                    KeyCode::Right => {
+                        let Some(row) = app.rows().get(app.selected_index).copied() else {
+                            continue;
+                        };
+                        let story = &app.children[row.child];
+                        if row.subtask.is_some() || story.fields.subtasks.is_empty() {
+                            continue;
+                        }
+                        let story_key = story.key.clone();
+                        if !app.subtasks.contains_key(&story_key) {
+                            toasts.info(format!("Loading subtasks of {}...", story_key));
+                            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                            match client.get_subtasks(&story_key) {
+                                Ok(subtasks) => {
+                                    app.subtasks.insert(story_key.clone(), subtasks);
+                                }
+                                Err(e) => {
+                                    toasts.error(format!("✗ Failed to load subtasks of {}: {}", story_key, e));
+                                    continue;
+                                }
+                            }
+                        }
+                        app.expanded.insert(story_key);
+                    }
+                    KeyCode::Left => {
+                        if let Some(row) = app.rows().get(app.selected_index).copied() {
+                            let story_key = app.children[row.child].key.clone();
+                            app.expanded.remove(&story_key);
+                            app.select_child(row.child);
+                        }
+                    }
+                    KeyCode::Char('a') => {
+                        if let Some(issue) = app.selected_issue() {
+                            let issue_key = issue.key.clone();
+                            // Temporarily restore terminal for assignee selection
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            println!("Fetching assignable users for {}...", issue_key);
+                            
+                            // Get current user and assignable users
+                            let (current_user, users) = std::thread::scope(|scope| {
+                                let current_user = scope.spawn(|| client.get_current_user());
+                                let users = client.get_assignable_users(&issue_key);
+                                let current_user = current_user.join()
+                                    .unwrap_or_else(|_| Err(anyhow::anyhow!("Current user fetch thread panicked")));
+                                (current_user, users)
+                            });
+                            let (current_user_id, selected_account_id) = match (current_user, users) {
+                                (Ok(current_user), Ok(users)) => {
+                                    let current_id = current_user.account_id.clone();
+                                    let selected = AssigneeSelector::show(users, current_user.account_id, theme)?;
+                                    (current_id, selected)
+                                }
+                                (Err(e), _) | (_, Err(e)) => {
+                                    eprintln!("Failed to fetch users: {}", e);
+                                    (String::new(), None)
+                                }
+                            };
+                            
+                            // Re-setup terminal
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                            
+                            if let Some(account_id) = selected_account_id {
+                                if account_id == "UNASSIGN" {
+                                    // Handle unassignment
+                                    toasts.info(format!("Unassigning {} ...", issue_key));
+                                    let failure = format!("Failed to unassign {}", issue_key);
+                                    app.jobs.spawn(client, &issue_key, failure, &mut toasts, |client, key| {
+                                        client.assign_issue(key, None)?;
+                                        Ok(format!("✓ {} unassigned", key))
+                                    });
+                                } else {
+                                    // Handle normal assignment
+                                    toasts.info(format!("Assigning {} ...", issue_key));
+                                    let assignee_text = if account_id == current_user_id {
+                                        "you"
+                                    } else {
+                                        "selected user"
+                                    };
+                                    let failure = format!("Failed to assign {}", issue_key);
+                                    app.jobs.spawn(client, &issue_key, failure, &mut toasts, move |client, key| {
+                                        client.assign_issue(key, Some(&account_id))?;
+                                        Ok(format!("✓ {} assigned to {}", key, assignee_text))
+                                    });
+                                }
+                            } else {
+                                toasts.info("Assignment cancelled".to_string());
+                            }
+                        }
+                    }
+                    KeyCode::Char('p') => {
+                        if let Some(issue) = app.selected_issue() {
+                            let issue_key = issue.key.clone();
+                            toasts.info(format!("Moving {} to In Progress...", issue_key));
+                            let failure = format!("Failed to move {} to In Progress", issue_key);
+                            app.jobs.spawn(client, &issue_key, failure, &mut toasts, |client, key| {
+                                with_hooks(client, "start", key, || {
+                                    client.transition_to_in_progress(key)?;
+                                    Ok(format!("✓ {} moved to In Progress", key))
+                                })
+                            });
+                        }
+                    }
+                    KeyCode::Char('t') => {
+                        let Some(issue) = app.selected_issue() else {
+                            continue;
+                        };
+                        let issue_key = issue.key.clone();
+                        let from = issue.fields.status.name.clone();
+                        let options = match client.transition_options(&issue_key) {
+                            Ok(options) if options.is_empty() => {
+                                toasts.info(format!("No transitions are available for {}", issue_key));
+                                continue;
+                            }
+                            Ok(options) => options,
+                            Err(e) => {
+                                toasts.error(format!("✗ Failed to load transitions for {}: {}", issue_key, e));
+                                continue;
+                            }
+                        };
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = ListPicker::show(&format!(" Move {} from {} ", issue_key, from), transition_labels(&options), theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        if let Ok(Some(idx)) = picked
+                            && let Some(option) = options.into_iter().nth(idx)
+                        {
+                            spawn_transition(&mut app.jobs, client, &issue_key, from, option, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('s') => {
+                        if let Some(issue) = app.selected_issue() {
+                            let issue_key = issue.key.clone();
+                            toasts.info(format!("Starting {}...", issue_key));
+                            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                            
+                            // Create feature branch
+                            use git2::Repository;
+                            match Repository::discover(".") {
+                                Ok(repo) => {
+                                    let branch_name = format!("feature/{}", issue_key);
+                                    
+                                    // Get the current HEAD commit
+                                    match repo.head()
+                                        .and_then(|head| head.target().ok_or_else(|| git2::Error::from_str("No HEAD target")))
+                                        .and_then(|target| repo.find_commit(target))
+                                    {
+                                        Ok(commit) => {
+                                            // Create and checkout the new branch
+                                            match repo.branch(&branch_name, &commit, false) {
+                                                Ok(_) => {
+                                                    if let Ok(obj) = repo.revparse_single(&format!("refs/heads/{}", branch_name)) {
+                                                        let _ = repo.checkout_tree(&obj, None);
+                                                        let _ = repo.set_head(&format!("refs/heads/{}", branch_name));
+                                                        
+                                                        // Now pickup the issue
+                                                        let result = with_hooks(client, "start", &issue_key, || {
+                                                            client.pickup_issue(&issue_key)?;
+                                                            Ok(format!("✓ Created branch '{}' and picked up {}", branch_name, issue_key))
+                                                        });
+                                                        match result {
+                                                            Ok(message) => {
+                                                                toasts.success(message);
+                                                                should_quit = true; // Exit after successful start
+                                                            }
+                                                            Err(e) => {
+                                                                toasts.error(format!("✗ Branch created but failed to pickup: {}", e));
+                                                            }
+                                                        }
+                                                    } else {
+                                                        toasts.error(format!("✗ Failed to checkout branch '{}'", branch_name));
+                                                    }
+                                                }
+                                                Err(e) => {
+                                                    toasts.error(format!("✗ Failed to create branch: {}", e));
+                                                }
+                                            }
+                                        }
+                                        Err(e) => {
+                                            toasts.error(format!("✗ Failed to get HEAD commit: {}", e));
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    toasts.error(format!("✗ Failed to open git repository: {}", e));
+                                }
+                            }
+                        }
+                    }
+                    KeyCode::Char('v') => {
+                        if let Some(issue) = app.selected_issue() {
+                            let issue_key = issue.key.clone();
+                            // Temporarily restore terminal for nested UI
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            // Show the issue details
+                            println!("Viewing issue: {}", issue_key);
+                            let _ = JiraIssueDisplay::show(issue, client, theme);
+                            
+                            // Re-setup terminal for epic list
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                            
+                            toasts.info(format!("Returned from viewing {}", issue_key));
+                        }
+                    }
+                    KeyCode::Char('S') => {
+                        if let Some(issue) = app.selected_issue() {
+                            let issue_key = issue.key.clone();
+                            toasts.info("Loading sprints...".to_string());
+                            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                            
+                            match client.get_open_sprints(&issue_key) {
+                                Ok(sprints) if sprints.is_empty() => {
+                                    toasts.info("The board has no active or future sprints".to_string());
+                                }
+                                Ok(sprints) => {
+                                    // Temporarily restore terminal for nested UI
+                                    disable_raw_mode()?;
+                                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                                    terminal.show_cursor()?;
+                                    
+                                    let labels = sprints.iter().map(|s| s.label()).collect();
+                                    let picked = ListPicker::show(&format!(" Move {} to sprint ", issue_key), labels, theme);
+                                    
+                                    // Re-setup terminal
+                                    enable_raw_mode()?;
+                                    let mut stdout = io::stdout();
+                                    execute!(stdout, EnterAlternateScreen)?;
+                                    let backend = CrosstermBackend::new(stdout);
+                                    terminal = Terminal::new(backend)?;
+                                    
+                                    if let Ok(Some(idx)) = picked {
+                                        let sprint = &sprints[idx];
+                                        match client.move_to_sprint(sprint.id, &[&issue_key]) {
+                                            Ok(_) => toasts.success(format!("✓ {} moved to {}", issue_key, sprint.name)),
+                                            Err(e) => toasts.error(format!("✗ Failed to move {}: {}", issue_key, e)),
+                                        }
+                                    }
+                                }
+                                Err(e) => toasts.error(format!("✗ Failed to load sprints: {}", e)),
+                            }
+                        }
+                    }
+                    KeyCode::Char('c') => {
+                        if let Some(issue) = app.selected_issue() {
+                            let issue_key = issue.key.clone();
+                            toasts.info(format!("Checking {}...", issue_key));
+                            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                            
+                            match CloseChecklist::check(client, &issue_key) {
+                                Ok(checklist) => app.checklist = Some(checklist),
+                                Err(e) => toasts.error(format!("✗ Failed to check {}: {}", issue_key, e)),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Exiting ends the process, which would cut off writes still running in the
+        // background, so wait for them and print how they went once the view is closed
+        let outcomes = toasts.history.len();
+        let waited = app.jobs.in_flight() > 0;
+        if waited {
+            toasts.info(format!("Waiting for {} action(s)...", app.jobs.in_flight()));
+            while app.jobs.in_flight() > 0 {
+                terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+                app.jobs.wait(TICK_RATE);
+                app.apply_jobs(&mut toasts);
+            }
+        }
+        app.jobs.join();
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+        if waited {
+            // Skips the "Waiting for" note itself
+            toasts.print_since(outcomes + 1);
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame, epic: &JiraIssue, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(5),    // Epic header
+                Constraint::Min(0),       // Children table
+                Constraint::Length(2),    // Message area
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        self.render_epic_header(f, chunks[0], epic, theme);
+        self.render_children_table(f, chunks[1], theme);
+        self.render_input(f, chunks[2], theme);
+        self.render_help(f, chunks[3], theme);
+        if let Some(checklist) = &self.checklist {
+            checklist.render(f, theme);
+        }
+        if let Some(bulk) = &self.bulk {
+            bulk.render(f, theme);
+        }
+        if let Some(form) = &self.form {
+            form.render(f, theme);
+        }
+        self.search.render(f, theme);
+        toasts.render(f, theme);
+    }
+
+    fn render_epic_header(&self, f: &mut Frame, area: Rect, epic: &JiraIssue, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Epic Details ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let mut header_text = vec![
+            Line::from(vec![
+                Span::styled("Epic: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(&epic.key),
+                Span::raw(" - "),
+                Span::raw(&epic.fields.summary),
+            ]),
+            Line::from(vec![
+                Span::styled("Status: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(&epic.fields.status.name),
+            ]),
+        ];
+
+        if let Some(linked_via) = &epic.linked_via {
+            header_text.push(Line::from(vec![
+                Span::styled("Linked via: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::styled(linked_via, Style::default().fg(theme.muted)),
+            ]));
+        }
+
+        let paragraph = Paragraph::new(header_text);
+        f.render_widget(paragraph, inner);
+    }
+
+    fn render_children_table(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Child Issues ({}) ", self.children.len()))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if self.children.is_empty() {
+            let text = Paragraph::new("(No child issues in this epic)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(text, inner);
+            return;
+        }
+
+        // Calculate viewport dimensions for table (accounting for the title line)
+        let viewport_height = list_capacity(inner.width, inner.height.saturating_sub(1));
+        self.viewport_height = viewport_height; // Store for use in key handlers
+        
+        // Use the persisted scroll_offset
+        let tree_rows = self.rows();
+        let visible_start = self.scroll_offset;
+        let visible_end = (self.scroll_offset + viewport_height).min(tree_rows.len());
+        
+        let rows: Vec<ListRow> = tree_rows[visible_start..visible_end]
+            .iter()
+            .enumerate()
+            .filter_map(|(visible_idx, row)| Some((visible_idx, *row, self.issue_at(*row)?)))
+            .map(|(visible_idx, row, issue)| {
+                let actual_idx = visible_start + visible_idx;
+                let assignee = issue.fields.assignee.as_ref()
+                    .map(|u| u.display_name.clone())
+                    .unwrap_or_else(|| "Unassigned".to_string());
+                
+                // Color code the status
+                let status_style = match issue.fields.status.name.to_lowercase().as_str() {
+                    s if s.contains("done") || s.contains("closed") => Style::default().fg(theme.success),
+                    s if s.contains("progress") => Style::default().fg(theme.highlight),
+                    s if s.contains("review") => Style::default().fg(theme.special),
+                    _ => Style::default().fg(theme.text),
+                };
+                
+                // Selection indicator
+                let selected = actual_idx == self.selected_index;
+                let indicator = row_indicator(selected, self.marked.contains(&issue.key), row.subtask.is_none() && self.pins.is_pinned(&issue.key));
+                
+                // ▸/▾ on stories with subtasks, which are indented beneath them when expanded
+                let tree_prefix = match row.subtask {
+                    Some(_) => "  └ ",
+                    None if issue.fields.subtasks.is_empty() => "  ",
+                    None if self.expanded.contains(&issue.key) => "▾ ",
+                    None => "▸ ",
+                };
+                let mut key_spans = vec![Span::styled(tree_prefix, Style::default().fg(theme.muted)), Span::raw(issue.key.clone())];
+                if !issue.problems.is_empty() {
+                    key_spans.push(Span::styled(" ⚠", Style::default().fg(theme.highlight)));
+                }
+                
+                let mut summary_spans = vec![Span::raw(issue.fields.summary.clone())];
+                if row.subtask.is_none()
+                    && let Some(rollup) = subtask_rollup(&issue.fields.subtasks)
+                {
+                    summary_spans.push(Span::styled(format!("  [{}]", rollup), Style::default().fg(theme.muted)));
+                }
+                
+                let points = issue.fields.story_points
+                    .map(format_points)
+                    .unwrap_or_default();
+                let priority = issue.fields.priority.as_ref()
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default();
+                
+                let cells = vec![
+                    Line::from(indicator).style(Style::default().fg(theme.accent)),
+                    Line::from(key_spans),
+                    Line::from(issue.fields.status.name.clone()).style(status_style),
+                    Line::from(summary_spans),
+                    Line::from(points),
+                    Line::from(priority),
+                    Line::from(assignee),
+                    Line::from(updated_ago(issue)).style(Style::default().fg(theme.muted)),
+                ];
+                
+                ListRow::new(cells).style(theme.selected(selected))
+            })
+            .collect();
+
+        // Add scroll indicators in the title
+        let title = if tree_rows.len() > viewport_height {
+            format!(" Child Issues ({}) [{}-{} of {}] · {} ", 
+                self.children.len(),
+                visible_start + 1,
+                visible_end,
+                tree_rows.len(),
+                self.sort.label()
+            )
+        } else {
+            format!(" Child Issues ({}) · {} ", self.children.len(), self.sort.label())
+        };
+        let title = match self.marked.len() {
+            0 => title,
+            marked => format!("{}· {} marked ", title, marked),
+        };
+
+        const COLUMNS: [ListColumn; 8] = [
+            ListColumn::new("", Constraint::Length(4), 0),            // Arrow, mark, and pin
+            ListColumn::new("Key", Constraint::Length(16), 0),        // Tree marker and key
+            ListColumn::new("Status", Constraint::Length(15), 0),
+            ListColumn::new("Summary", Constraint::Min(20), 0),       // Takes remaining space
+            ListColumn::new("Pts", Constraint::Length(5), 2),
+            ListColumn::new("Priority", Constraint::Length(10), 3),
+            ListColumn::new("Assignee", Constraint::Length(20), 4),
+            ListColumn::new("Updated", Constraint::Length(9), 1),
+        ];
+        let table = fitted_table(&COLUMNS, rows, inner.width, theme)
+            .block(Block::default().title(title));
+
+        f.render_widget(table, inner);
+    }
+
+    fn render_input(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        if self.rename_mode {
+            let line = Line::from(vec![
+                Span::styled("New summary: ", Style::default().fg(theme.label)),
+                Span::raw(&self.rename_input),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]);
+            f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+        }
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.rename_mode {
+            "Edit the summary | Enter: Save | ESC: Cancel"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | →/←: Expand/Collapse | v: View | a: Assign to... | p: In Progress | c: Close | s: Start | S: Sprint | P: Pin | R: Rename | n: New issue | t: Transition | Space: Mark | L: Labels/Components | f/F: Sort | q/ESC: Quit"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+// Toggled with 1-5 in My Issues. They work on the already-fetched list, so switching is
+// instant; with several on, an issue has to match all of them, like Jira board quick filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuickFilter {
+    InProgress,
+    InReview,
+    Blocked,
+    DueSoon,
+    Flagged,
+}
+
+impl QuickFilter {
+    const ALL: [QuickFilter; 5] = [
+        QuickFilter::InProgress,
+        QuickFilter::InReview,
+        QuickFilter::Blocked,
+        QuickFilter::DueSoon,
+        QuickFilter::Flagged,
+    ];
+    
+    // Overdue issues count as due soon too
+    const DUE_SOON_DAYS: i64 = 7;
+
+    fn from_hotkey(key: char) -> Option<QuickFilter> {
+        let index = key.to_digit(10)?.checked_sub(1)?;
+        Self::ALL.get(index as usize).copied()
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            QuickFilter::InProgress => "In Progress",
+            QuickFilter::InReview => "In Review",
+            QuickFilter::Blocked => "Blocked",
+            QuickFilter::DueSoon => "Due soon",
+            QuickFilter::Flagged => "Flagged",
+        }
+    }
+
+    fn matches(self, issue: &JiraIssue) -> bool {
+        let status = issue.fields.status.name.to_lowercase();
+        match self {
+            QuickFilter::InProgress => status.contains("progress"),
+            QuickFilter::InReview => status.contains("review"),
+            QuickFilter::Blocked => issue.fields.is_blocked(),
+            QuickFilter::DueSoon => {
+                let soon = chrono::Local::now().date_naive() + chrono::Duration::days(Self::DUE_SOON_DAYS);
+                !issue.fields.is_done() && issue.fields.due_date().is_some_and(|due| due <= soon)
+            }
+            QuickFilter::Flagged => issue.fields.flagged,
+        }
+    }
+}
+
+pub struct MyIssuesDisplay {
+    selected_index: usize,
+    issues: Vec<JiraIssue>,
+    // Issues hidden by the active quick filters, put back when a filter is turned off
+    filtered_out: Vec<JiraIssue>,
+    quick_filters: Vec<QuickFilter>,
+    // Without a pinned Flagged field every issue reads as unflagged
+    flagged_known: bool,
+    pins: PinStore,
+    sort: IssueSort,
+    pull_requests: HashMap<String, PrStatus>,
+    // Pending while PR statuses load in the background
+    pull_request_loader: Option<mpsc::Receiver<HashMap<String, PrStatus>>>,
+    // Transitions fetched with the list; each is used once, since a move changes them
+    transitions: HashMap<String, Vec<TransitionOption>>,
+    details_loader: Option<mpsc::Receiver<crate::jira::MineDetails>>,
+    scroll_offset: usize,
+    viewport_height: usize,
+    rename_mode: bool,
+    rename_input: String,
+    snooze_mode: bool,
+    snooze_input: String,
+    comment_mode: bool,
+    comment_input: String,
+    checklist: Option<CloseChecklist>,
+    // Issue keys marked with Space for a bulk label or component edit
+    marked: HashSet<String>,
+    bulk: Option<BulkEdit>,
+    search: Search,
+    jobs: Jobs,
+}
+
+impl MyIssuesDisplay {
+    fn update_scroll_offset(&mut self, viewport_height: usize) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+        }
+    }
+    
+    // Swaps the partial parents from the search for the full issues
+    fn apply_parents(&mut self, parents: &HashMap<String, JiraIssue>) {
+        for issue in self.issues.iter_mut().chain(self.filtered_out.iter_mut()) {
+            if let Some(parent) = &mut issue.fields.parent
+                && let Some(full) = parents.get(&parent.key)
+            {
+                **parent = full.clone();
+            }
+        }
+    }
+    
+    fn resort(&mut self) {
+        let selected_key = self.issues.get(self.selected_index).map(|issue| issue.key.clone());
+        self.sort.apply(&mut self.issues, &self.pins);
+        self.selected_index = selected_key
+            .and_then(|key| self.issues.iter().position(|issue| issue.key == key))
+            .unwrap_or(0);
+        self.update_scroll_offset(self.viewport_height);
+    }
+
+    fn toggle_quick_filter(&mut self, filter: QuickFilter, toasts: &mut Toasts) {
+        if let Some(pos) = self.quick_filters.iter().position(|f| *f == filter) {
+            self.quick_filters.remove(pos);
+        } else {
+            if filter == QuickFilter::Flagged && !self.flagged_known {
+                toasts.info("Flagged field unknown; run 'qq jira fields --pin' to find it".to_string());
+            }
+            self.quick_filters.push(filter);
+        }
+
+        let selected_key = self.issues.get(self.selected_index).map(|issue| issue.key.clone());
+        let mut all = std::mem::take(&mut self.issues);
+        all.append(&mut self.filtered_out);
+        let filters = &self.quick_filters;
+        (self.issues, self.filtered_out) = all.into_iter()
+            .partition(|issue| filters.iter().all(|f| f.matches(issue)));
+
+        // Bulk edits only reach what's on screen, so drop marks on hidden issues
+        let issues = &self.issues;
+        self.marked.retain(|key| issues.iter().any(|issue| &issue.key == key));
+
+        self.sort.apply(&mut self.issues, &self.pins);
+        self.selected_index = selected_key
+            .and_then(|key| self.issues.iter().position(|issue| issue.key == key))
+            .unwrap_or(0);
+        self.scroll_offset = self.scroll_offset.min(self.selected_index);
+        self.update_scroll_offset(self.viewport_height);
+    }
+
+    fn apply_jobs(&mut self, toasts: &mut Toasts) {
+        for result in self.jobs.finished() {
+            toasts.push(result.severity, result.message);
+            if let Some(updated) = result.refreshed
+                && let Some(issue) = self.issues.iter_mut()
+                    .chain(self.filtered_out.iter_mut())
+                    .find(|issue| issue.key == updated.key)
+            {
+                *issue = updated;
+            }
+        }
+    }
+
+    fn close_issue(&mut self, client: &JiraClient, issue_key: &str, comment: Option<String>, toasts: &mut Toasts) {
+        toasts.info(format!("Closing {}...", issue_key));
+        self.jobs.spawn(client, issue_key, format!("Failed to close {}", issue_key), toasts, move |client, key| {
+            with_hooks(client, "close", key, || {
+                client.close_issue(key, comment.as_deref())?;
+                // A closed ticket has nothing left to come back for
+                let _ = crate::snooze::SnoozeStore::edit(|snoozes| snoozes.unsnooze(key));
+                Ok(format!("✓ {} closed successfully", key))
+            })
+        });
+    }
+
+    pub fn show(mut issues: Vec<JiraIssue>, client: &JiraClient, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let pins = PinStore::load();
+        let sort = IssueSort::default();
+        sort.apply(&mut issues, &pins);
+
+        // Each issue takes a few dev-status requests, so show the list while they run
+        let (sender, receiver) = mpsc::channel();
+        let loader_client = client.clone();
+        let loader_issues = issues.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(crate::forge::statuses_for(&loader_client, loader_issues));
+        });
+        let (details_sender, details_receiver) = mpsc::channel();
+        let loader_client = client.clone();
+        let loader_issues = issues.clone();
+        std::thread::spawn(move || {
+            let _ = details_sender.send(loader_client.mine_details(&loader_issues));
+        });
+
+        let mut app = Self {
+            selected_index: 0,
+            issues,
+            filtered_out: Vec::new(),
+            quick_filters: Vec::new(),
+            flagged_known: client.flagged_field().is_some(),
+            pins,
+            sort,
+            pull_requests: HashMap::new(),
+            pull_request_loader: Some(receiver),
+            transitions: HashMap::new(),
+            details_loader: Some(details_receiver),
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+            rename_mode: false,
+            rename_input: String::new(),
+            snooze_mode: false,
+            snooze_input: String::new(),
+            comment_mode: false,
+            comment_input: String::new(),
+            checklist: None,
+            marked: HashSet::new(),
+            bulk: None,
+            search: Search::default(),
+            jobs: Jobs::default(),
+        };
+        
+        let mut should_quit = false;
+        let mut toasts = Toasts::default();
+
+        // Main loop
+        while !should_quit {
+            app.apply_jobs(&mut toasts);
+            if let Some(loader) = &app.pull_request_loader {
+                match loader.try_recv() {
+                    Ok(statuses) => {
+                        app.pull_requests = statuses;
+                        app.pull_request_loader = None;
+                    }
+                    Err(mpsc::TryRecvError::Disconnected) => app.pull_request_loader = None,
+                    Err(mpsc::TryRecvError::Empty) => {}
+                }
+            }
+            if let Some(loader) = &app.details_loader {
+                match loader.try_recv() {
+                    Ok((parents, transitions)) => {
+                        app.apply_parents(&parents);
+                        app.transitions = transitions;
+                        app.details_loader = None;
+                    }
+                    Err(mpsc::TryRecvError::Disconnected) => app.details_loader = None,
+                    Err(mpsc::TryRecvError::Empty) => {}
+                }
+            }
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if let Some(checklist) = &mut app.checklist {
+                    if key.code == KeyCode::Esc {
+                        app.checklist = None;
+                    } else if let Some(comment) = checklist.handle_key(&key) {
+                        let issue_key = checklist.report.key.clone();
+                        app.checklist = None;
+                        app.close_issue(client, &issue_key, comment, &mut toasts);
+                    } else if key.code == KeyCode::Char('q') && checklist.report.is_blocked() {
+                        app.checklist = None;
+                    }
+                    continue;
+                }
+                
+                if let Some(bulk) = &mut app.bulk {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.bulk = None;
+                            toasts.info("Bulk edit cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            if let Some(bulk) = app.bulk.take() {
+                                match bulk.apply(&mut terminal, client, |f, bulk| {
+                                    app.draw(f, &toasts, theme);
+                                    bulk.render(f, theme);
+                                })? {
+                                    Ok(message) => {
+                                        app.marked.clear();
+                                        toasts.success(message);
+                                    }
+                                    Err(message) => toasts.error(message),
+                                }
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            bulk.input.pop();
+                        }
+                        KeyCode::Char(c) => bulk.input.push(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if app.rename_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.rename_mode = false;
+                            app.rename_input.clear();
+                            toasts.info("Rename cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            app.rename_mode = false;
+                            let summary = std::mem::take(&mut app.rename_input);
+                            
+                            if let Some(issue) = app.issues.get(app.selected_index) {
+                                let issue_key = issue.key.clone();
+                                toasts.info(format!("Renaming {}...", issue_key));
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                
+                                match client.rename_issue(&issue_key, &summary) {
+                                    Ok(_) => {
+                                        toasts.success(format!("✓ {} renamed", issue_key));
+                                        app.issues[app.selected_index].fields.summary = summary.trim().to_string();
+                                    }
+                                    Err(e) => {
+                                        toasts.error(format!("✗ Failed to rename {}: {}", issue_key, e));
+                                    }
+                                }
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            app.rename_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.rename_input.push(c);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if app.snooze_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.snooze_mode = false;
+                            app.snooze_input.clear();
+                            toasts.info("Snooze cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            app.snooze_mode = false;
+                            let input = std::mem::take(&mut app.snooze_input);
+                            
+                            if let Some(issue) = app.issues.get(app.selected_index) {
+                                let issue_key = issue.key.clone();
+                                let result = crate::snooze::parse_until(&input).and_then(|until| {
+                                    crate::snooze::SnoozeStore::edit(|snoozes| snoozes.snooze(&issue_key, until))?;
+                                    Ok(until)
+                                });
+                                
+                                match result {
+                                    Ok(until) => {
+                                        toasts.success(format!("✓ {} snoozed until {}", issue_key, until.with_timezone(&chrono::Local).format("%a %b %-d %H:%M")));
+                                        app.issues.remove(app.selected_index);
+                                        app.selected_index = app.selected_index.min(app.issues.len().saturating_sub(1));
+                                        app.update_scroll_offset(app.viewport_height);
+                                    }
+                                    Err(e) => {
+                                        toasts.error(format!("✗ Failed to snooze {}: {}", issue_key, e));
+                                    }
+                                }
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            app.snooze_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.snooze_input.push(c);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if app.comment_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.comment_mode = false;
+                            app.comment_input.clear();
+                            toasts.info("Comment cancelled".to_string());
+                        }
+                        KeyCode::Tab => {
+                            let templates = crate::config::Config::load()
+                                .map(|c| c.comment_templates)
+                                .unwrap_or_default();
+                            if templates.is_empty() {
+                                toasts.info("No comment templates configured".to_string());
+                                continue;
+                            }
+                            let mut templates: Vec<(String, String)> = templates.into_iter().collect();
+                            templates.sort();
+                            let labels = templates.iter()
+                                .map(|(name, text)| format!("{}: {}", name, text))
+                                .collect();
+                            
+                            // Temporarily restore terminal for nested UI
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            let picked = ListPicker::show(" Comment templates ", labels, theme);
+                            
+                            // Re-setup terminal
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                            
+                            if let Ok(Some(idx)) = picked
+                                && let Some(issue) = app.issues.get(app.selected_index)
+                            {
+                                // Placeholders without a value stay in the text to be edited by hand
+                                let mut values = crate::template::TemplateValues::new();
+                                values.set("ticket", issue.key.clone());
+                                let (text, missing) = values.fill(&templates[idx].1);
+                                app.comment_input = text;
+                                if !missing.is_empty() {
+                                    toasts.info(format!("Fill in: {}", missing.join(", ")));
+                                }
+                            }
+                        }
+                        KeyCode::Enter => {
+                            app.comment_mode = false;
+                            let comment = std::mem::take(&mut app.comment_input);
+                            
+                            if comment.trim().is_empty() {
+                                toasts.error("✗ Comment is empty".to_string());
+                            } else if let Some(issue) = app.issues.get(app.selected_index) {
+                                let issue_key = issue.key.clone();
+                                toasts.info(format!("Commenting on {}...", issue_key));
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                
+                                let result = with_hooks(client, "comment", &issue_key, || {
+                                    client.add_comment(&issue_key, comment.trim())?;
+                                    Ok(format!("✓ Comment added to {}", issue_key))
+                                });
+                                match result {
+                                    Ok(message) => toasts.success(message),
+                                    Err(e) => toasts.error(format!("✗ Failed to comment on {}: {}", issue_key, e)),
+                                }
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            app.comment_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.comment_input.push(c);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                if app.search.navigate(&key, &mut app.selected_index, app.issues.len(), app.viewport_height, || {
+                    app.issues.iter().map(issue_search_text).collect()
+                }) {
+                    app.update_scroll_offset(app.viewport_height);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc if !app.marked.is_empty() => app.marked.clear(),
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char(' ') => {
+                        if let Some(key) = app.issues.get(app.selected_index).map(|issue| issue.key.clone()) {
+                            toggle_mark(&mut app.marked, &key);
+                            if app.selected_index + 1 < app.issues.len() {
+                                app.selected_index += 1;
+                                app.update_scroll_offset(app.viewport_height);
+                            }
+                        }
+                    }
+                    KeyCode::Char('L') => {
+                        let keys = bulk_keys(app.issues.iter(), &app.marked, app.issues.get(app.selected_index));
+                        if keys.is_empty() {
+                            continue;
+                        }
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = BulkEdit::pick(client, keys, &mut toasts, theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        match picked {
+                            Ok(Some(bulk)) if bulk.needs_input() => app.bulk = Some(bulk),
+                            Ok(Some(bulk)) => {
+                                match bulk.apply(&mut terminal, client, |f, bulk| {
+                                    app.draw(f, &toasts, theme);
+                                    bulk.render(f, theme);
+                                })? {
+                                    Ok(message) => {
+                                        app.marked.clear();
+                                        toasts.success(message);
+                                    }
+                                    Err(message) => toasts.error(message),
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(e) => toasts.error(format!("✗ Bulk edit failed: {}", e)),
+                        }
+                    }
+                    KeyCode::Char(c @ '1'..='5') => {
+                        if let Some(filter) = QuickFilter::from_hotkey(c) {
+                            app.toggle_quick_filter(filter, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('z') if !app.issues.is_empty() => {
+                        app.snooze_mode = true;
+                        app.snooze_input.clear();
+                    }
+                    KeyCode::Char('C') if !app.issues.is_empty() => {
+                        app.comment_mode = true;
+                        app.comment_input.clear();
+                    }
+                    KeyCode::Char('R') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            app.rename_input = issue.fields.summary.clone();
+                            app.rename_mode = true;
+                        }
+                    }
+                    KeyCode::Char('P') => {
+                        app.selected_index = toggle_pin(&mut app.pins, &mut app.issues, app.selected_index, &mut toasts);
+                        app.update_scroll_offset(app.viewport_height);
+                    }
+                    KeyCode::Char('f') => {
+                        app.sort.next_field();
+                        app.resort();
+                    }
+                    KeyCode::Char('F') => {
+                        app.sort.reverse();
+                        app.resort();
+                    }
+                    KeyCode::Char('o') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            match app.pull_requests.get(&issue.key) {
+                                Some(pr) => {
+                                    if let Err(e) = webbrowser::open(&pr.url) {
+                                        toasts.error(format!("✗ Failed to open browser: {}", e));
+                                    }
+                                }
+                                None if app.pull_request_loader.is_some() => {
+                                    toasts.info("Still loading pull requests...".to_string());
+                                }
+                                None => toasts.info(format!("No pull request found for {}", issue.key)),
+                            }
+                        }
+                    }
+                    KeyCode::Char('v') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue_key = issue.key.clone();
+                            // Temporarily restore terminal for nested UI
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            // Show the issue details
+                            println!("Viewing issue: {}", issue_key);
+                            let _ = JiraIssueDisplay::show(issue, client, theme);
+                            
+                            // Re-setup terminal
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                            
+                            toasts.info(format!("Returned from viewing {}", issue_key));
+                        }
+                    }
+                    KeyCode::Char('p') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue_key = issue.key.clone();
+                            toasts.info(format!("Moving {} to In Progress...", issue_key));
+                            let failure = format!("Failed to move {}", issue_key);
+                            app.jobs.spawn(client, &issue_key, failure, &mut toasts, |client, key| {
+                                with_hooks(client, "start", key, || {
+                                    client.transition_to_in_progress(key)?;
+                                    Ok(format!("✓ {} moved to In Progress", key))
+                                })
+                            });
+                        }
+                    }
+                    KeyCode::Char('t') => {
+                        let Some(issue) = app.issues.get(app.selected_index) else {
+                            continue;
+                        };
+                        let issue_key = issue.key.clone();
+                        let from = issue.fields.status.name.clone();
+                        let fetched = match app.transitions.remove(&issue_key) {
+                            Some(options) => Ok(options),
+                            None => client.transition_options(&issue_key),
+                        };
+                        let options = match fetched {
+                            Ok(options) if options.is_empty() => {
+                                toasts.info(format!("No transitions are available for {}", issue_key));
+                                continue;
+                            }
+                            Ok(options) => options,
+                            Err(e) => {
+                                toasts.error(format!("✗ Failed to load transitions for {}: {}", issue_key, e));
+                                continue;
+                            }
+                        };
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = ListPicker::show(&format!(" Move {} from {} ", issue_key, from), transition_labels(&options), theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        if let Ok(Some(idx)) = picked
+                            && let Some(option) = options.into_iter().nth(idx)
+                        {
+                            spawn_transition(&mut app.jobs, client, &issue_key, from, option, &mut toasts);
+                        }
+                    }
+                    KeyCode::Char('s') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue_key = issue.key.clone();
+                            toasts.info(format!("Starting {}...", issue_key));
+                            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                            
+                            // Create feature branch
+                            use git2::Repository;
+                            match Repository::discover(".") {
+                                Ok(repo) => {
+                                    let branch_name = format!("feature/{}", issue_key);
+                                    
+                                    // Get the current HEAD commit
+                                    match repo.head()
+                                        .and_then(|head| head.target().ok_or_else(|| git2::Error::from_str("No HEAD target")))
+                                        .and_then(|target| repo.find_commit(target))
+                                    {
+                                        Ok(commit) => {
+                                            // Create and checkout the new branch
+                                            match repo.branch(&branch_name, &commit, false) {
+                                                Ok(_) => {
+                                                    if let Ok(obj) = repo.revparse_single(&format!("refs/heads/{}", branch_name)) {
+                                                        let _ = repo.checkout_tree(&obj, None);
+                                                        let _ = repo.set_head(&format!("refs/heads/{}", branch_name));
+                                                        
+                                                        // Now pickup the issue
+                                                        let result = with_hooks(client, "start", &issue_key, || {
+                                                            client.pickup_issue(&issue_key)?;
+                                                            Ok(format!("✓ Created branch '{}' and picked up {}", branch_name, issue_key))
+                                                        });
+                                                        match result {
+                                                            Ok(message) => {
+                                                                toasts.success(message);
+                                                                should_quit = true; // Exit after successful start
+                                                            }
+                                                            Err(e) => {
+                                                                toasts.error(format!("✗ Branch created but failed to pickup: {}", e));
+                                                            }
+                                                        }
+                                                    } else {
+                                                        toasts.error(format!("✗ Failed to checkout branch '{}'", branch_name));
+                                                    }
+                                                }
+                                                Err(e) => {
+                                                    toasts.error(format!("✗ Failed to create branch: {}", e));
+                                                }
+                                            }
+                                        }
+                                        Err(e) => {
+                                            toasts.error(format!("✗ Failed to get HEAD commit: {}", e));
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    toasts.error(format!("✗ Failed to open git repository: {}", e));
+                                }
+                            }
+                        }
+                    }
+                    KeyCode::Char('S') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue_key = issue.key.clone();
+                            toasts.info("Loading sprints...".to_string());
+                            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                            
+                            match client.get_open_sprints(&issue_key) {
+                                Ok(sprints) if sprints.is_empty() => {
+                                    toasts.info("The board has no active or future sprints".to_string());
+                                }
+                                Ok(sprints) => {
+                                    // Temporarily restore terminal for nested UI
+                                    disable_raw_mode()?;
+                                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                                    terminal.show_cursor()?;
+                                    
+                                    let labels = sprints.iter().map(|s| s.label()).collect();
+                                    let picked = ListPicker::show(&format!(" Move {} to sprint ", issue_key), labels, theme);
+                                    
+                                    // Re-setup terminal
+                                    enable_raw_mode()?;
+                                    let mut stdout = io::stdout();
+                                    execute!(stdout, EnterAlternateScreen)?;
+                                    let backend = CrosstermBackend::new(stdout);
+                                    terminal = Terminal::new(backend)?;
+                                    
+                                    if let Ok(Some(idx)) = picked {
+                                        let sprint = &sprints[idx];
+                                        match client.move_to_sprint(sprint.id, &[&issue_key]) {
+                                            Ok(_) => toasts.success(format!("✓ {} moved to {}", issue_key, sprint.name)),
+                                            Err(e) => toasts.error(format!("✗ Failed to move {}: {}", issue_key, e)),
+                                        }
+                                    }
+                                }
+                                Err(e) => toasts.error(format!("✗ Failed to load sprints: {}", e)),
+                            }
+                        }
+                    }
+                    KeyCode::Char('c') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue_key = issue.key.clone();
+                            toasts.info(format!("Checking {}...", issue_key));
+                            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                            
+                            match CloseChecklist::check(client, &issue_key) {
+                                Ok(checklist) => app.checklist = Some(checklist),
+                                Err(e) => toasts.error(format!("✗ Failed to check {}: {}", issue_key, e)),
+                            }
+                        }
+                    }
+                    KeyCode::Char('e') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            if let Some(parent) = &issue.fields.parent {
+                                let parent_key = parent.key.clone();
+                                // Temporarily restore terminal
+                                disable_raw_mode()?;
+                                execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                                terminal.show_cursor()?;
+                                
+                                // Show the parent epic
+                                println!("Fetching epic details for: {}", parent_key);
+                                if let Ok(children) = client.get_epic_children(&parent_key) {
+                                    println!("Fetching child issues...");
+                                    let _ = EpicListDisplay::show(parent, children, client, theme);
+                                }
+                                
+                                // Re-setup terminal
+                                enable_raw_mode()?;
+                                let mut stdout = io::stdout();
+                                execute!(stdout, EnterAlternateScreen)?;
+                                let backend = CrosstermBackend::new(stdout);
+                                terminal = Terminal::new(backend)?;
+                                
+                                toasts.info(format!("Returned from viewing epic {}", parent_key));
+                            } else {
+                                toasts.info("This issue is not part of an epic".to_string());
+                            }
+                        }
+                    }
+                    KeyCode::Char('n') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue_key = issue.key.clone();
+                            // Temporarily restore terminal for the editor
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            let edited = crate::scratch::edit(&issue_key);
+                            
+                            // Re-setup terminal
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                            
+                            match edited {
+                                Ok(()) => toasts.success(format!("✓ Saved scratch notes for {}", issue_key)),
+                                Err(e) => toasts.error(format!("✗ {}", e)),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Exiting ends the process, which would cut off writes still running in the
+        // background, so wait for them and print how they went once the view is closed
+        let outcomes = toasts.history.len();
+        let waited = app.jobs.in_flight() > 0;
+        if waited {
+            toasts.info(format!("Waiting for {} action(s)...", app.jobs.in_flight()));
+            while app.jobs.in_flight() > 0 {
+                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                app.jobs.wait(TICK_RATE);
+                app.apply_jobs(&mut toasts);
+            }
+        }
+        app.jobs.join();
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+        if waited {
+            // Skips the "Waiting for" note itself
+            toasts.print_since(outcomes + 1);
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),    // Header
+                Constraint::Length(1),    // Quick filters
+                Constraint::Min(0),       // Issues table
+                Constraint::Length(2),    // Message area
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        self.render_header(f, chunks[0], theme);
+        self.render_quick_filters(f, chunks[1], theme);
+        self.render_issues_table(f, chunks[2], theme);
+        self.render_input(f, chunks[3], theme);
+        self.render_help(f, chunks[4], theme);
+        if let Some(checklist) = &self.checklist {
+            checklist.render(f, theme);
+        }
+        if let Some(bulk) = &self.bulk {
+            bulk.render(f, theme);
+        }
+        self.search.render(f, theme);
+        toasts.render(f, theme);
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" My Issues ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let total = if self.quick_filters.is_empty() {
+            self.issues.len().to_string()
+        } else {
+            format!("{} of {}", self.issues.len(), self.issues.len() + self.filtered_out.len())
+        };
+        let header_text = vec![
+            Line::from(vec![
+                Span::styled("Total Issues: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(total),
+            ]),
+        ];
+
+        let paragraph = Paragraph::new(header_text);
+        f.render_widget(paragraph, inner);
+    }
+
+    fn render_quick_filters(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let mut spans = vec![Span::styled(" Filters: ", Style::default().fg(theme.label))];
+        for (i, filter) in QuickFilter::ALL.iter().enumerate() {
+            let label = format!(" {} {} ", i + 1, filter.label());
+            let style = if self.quick_filters.contains(filter) {
+                Style::default().fg(theme.highlight).add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.muted)
+            };
+            spans.push(Span::styled(label, style));
+            spans.push(Span::raw(" "));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn render_issues_table(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Issues ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if self.issues.is_empty() {
+            let message = if self.quick_filters.is_empty() {
+                "(No issues assigned to you)"
+            } else {
+                "(No issues match the active filters)"
+            };
+            let text = Paragraph::new(message)
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(text, inner);
+            return;
+        }
+
+        // Calculate viewport dimensions for table (accounting for the title line)
+        let viewport_height = list_capacity(inner.width, inner.height.saturating_sub(1));
+        self.viewport_height = viewport_height; // Store for use in key handlers
+        
+        // Use the persisted scroll_offset
+        let visible_start = self.scroll_offset;
+        let visible_end = (self.scroll_offset + viewport_height).min(self.issues.len());
+        
+        let rows: Vec<ListRow> = self.issues[visible_start..visible_end]
+            .iter()
+            .enumerate()
+            .map(|(visible_idx, issue)| {
+                let actual_idx = visible_start + visible_idx;
+                let parent = issue.fields.parent.as_ref()
+                    .map(|p| p.key.clone())
+                    .unwrap_or_else(|| "—".to_string());
+                
+                // Color code the status
+                let status_style = match issue.fields.status.name.to_lowercase().as_str() {
+                    s if s.contains("done") || s.contains("closed") => Style::default().fg(theme.success),
+                    s if s.contains("progress") => Style::default().fg(theme.highlight),
+                    s if s.contains("review") => Style::default().fg(theme.special),
+                    _ => Style::default().fg(theme.text),
+                };
+                
+                // Selection indicator
+                let selected = actual_idx == self.selected_index;
+                let indicator = row_indicator(selected, self.marked.contains(&issue.key), self.pins.is_pinned(&issue.key));
+                
+                let cells = vec![
+                    Line::from(indicator).style(Style::default().fg(theme.accent)),
+                    key_line(issue, theme),
+                    Line::from(parent),
+                    Line::from(issue.fields.status.name.clone()).style(status_style),
+                    self.pull_request_line(&issue.key, theme),
+                    Line::from(issue.fields.summary.clone()),
+                    Line::from(updated_ago(issue)).style(Style::default().fg(theme.muted)),
+                ];
+                
+                ListRow::new(cells).style(theme.selected(selected))
+            })
+            .collect();
+
+        // Update title with scroll indicators
+        let title = if self.issues.len() > viewport_height {
+            format!(" Issues [{}-{} of {}] · {} ", 
+                visible_start + 1,
+                visible_end,
+                self.issues.len(),
+                self.sort.label()
+            )
+        } else {
+            format!(" Issues · {} ", self.sort.label())
+        };
+        let title = match self.marked.len() {
+            0 => title,
+            marked => format!("{}· {} marked ", title, marked),
+        };
+
+        const COLUMNS: [ListColumn; 7] = [
+            ListColumn::new("", Constraint::Length(4), 0),            // Arrow, mark, and pin
+            ListColumn::new("Key", Constraint::Length(12), 0),
+            ListColumn::new("Parent", Constraint::Length(12), 3),
+            ListColumn::new("Status", Constraint::Length(15), 0),
+            ListColumn::new("PR", Constraint::Length(23), 2),         // PR state and CI
+            ListColumn::new("Summary", Constraint::Min(20), 0),       // Takes remaining space
+            ListColumn::new("Updated", Constraint::Length(9), 1),
+        ];
+        let table = fitted_table(&COLUMNS, rows, inner.width, theme)
+            .block(Block::default().title(title));
+
+        f.render_widget(table, inner);
+    }
+
+    fn pull_request_line(&self, issue_key: &str, theme: &Theme) -> Line<'static> {
+        let Some(pr) = self.pull_requests.get(issue_key) else {
+            let placeholder = if self.pull_request_loader.is_some() { "…" } else { "—" };
+            return Line::from(placeholder).style(Style::default().fg(theme.muted));
+        };
+
+        let state_color = match pr.state {
+            PrState::Approved => theme.success,
+            PrState::ChangesRequested => theme.error,
+            PrState::Merged => theme.special,
+            PrState::Open => theme.highlight,
+            PrState::Declined => theme.muted,
+        };
+        let mut spans = vec![Span::styled(pr.state.label(), Style::default().fg(state_color))];
+        match pr.ci_passing {
+            Some(true) => spans.push(Span::styled(" CI ✓", Style::default().fg(theme.success))),
+            Some(false) => spans.push(Span::styled(" CI ✗", Style::default().fg(theme.error))),
+            None => {}
+        }
+        Line::from(spans)
+    }
+
+    fn render_input(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        if self.rename_mode {
+            let line = Line::from(vec![
+                Span::styled("New summary: ", Style::default().fg(theme.label)),
+                Span::raw(&self.rename_input),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]);
+            f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+        } else if self.snooze_mode {
+            let line = Line::from(vec![
+                Span::styled("Snooze for (4h, 2d, 1w, tomorrow, YYYY-MM-DD): ", Style::default().fg(theme.label)),
+                Span::raw(&self.snooze_input),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]);
+            f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+        } else if self.comment_mode {
+            let line = Line::from(vec![
+                Span::styled("Comment: ", Style::default().fg(theme.label)),
+                Span::raw(&self.comment_input),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]);
+            f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+        }
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.rename_mode {
+            "Edit the summary | Enter: Save | ESC: Cancel"
+        } else if self.snooze_mode {
+            "Type a duration or date | Enter: Snooze | ESC: Cancel"
+        } else if self.comment_mode {
+            "Type a comment | Tab: Templates | Enter: Post | ESC: Cancel"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | v: View | o: Open PR | c: Close | e: Epic | p: In Progress | t: Transition | s: Start | S: Sprint | z: Snooze | P: Pin | C: Comment | n: Notes | R: Rename | Space: Mark | L: Labels/Components | 1-5: Filters | f/F: Sort | q/ESC: Quit"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+pub struct AssigneeSelector {
+    selected_index: usize,
+    users: Vec<User>,
+    filtered_indices: Vec<usize>,
+    current_user_id: String,
+    search_query: String,
+    search_mode: bool,
+    scroll_offset: usize,
+    viewport_height: usize,
+}
+
+impl AssigneeSelector {
+    pub fn show(users: Vec<User>, current_user_id: String, theme: &Theme) -> Result<Option<String>> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let filtered_indices: Vec<usize> = (0..users.len()).collect();
+        let mut app = Self {
+            selected_index: 0,
+            users,
+            filtered_indices,
+            current_user_id,
+            search_query: String::new(),
+            search_mode: false,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+        };
+        
+        let mut selected_account_id: Option<String> = None;
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, theme))?;
+
+            if let Event::Key(key) = event::read()? {
+                if app.search_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Enter => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Backspace => {
+                            app.search_query.pop();
+                            app.update_filter();
+                        }
+                        KeyCode::Char(c) => {
+                            app.search_query.push(c);
+                            app.update_filter();
+                        }
+                        _ => {}
+                    }
+                } else if let Some(motion) = Motion::from_key(&key) {
+                    let total_items = app.filtered_indices.len() + 2; // +2 for "Myself" and "None"
+                    app.selected_index = motion.apply(app.selected_index, total_items, app.viewport_height);
+                    app.update_scroll_offset(app.viewport_height);
+                } else {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                        KeyCode::Char('/') => {
+                            app.search_mode = true;
+                        }
+                        KeyCode::Enter => {
+                            if app.selected_index == 0 {
+                                // "Myself" selected
+                                selected_account_id = Some(app.current_user_id.clone());
+                            } else if app.selected_index == 1 {
+                                // "None" selected - return a special marker
+                                selected_account_id = Some("UNASSIGN".to_string());
+                            } else if let Some(&user_idx) = app.filtered_indices.get(app.selected_index - 2) {
+                                if let Some(user) = app.users.get(user_idx) {
+                                    selected_account_id = Some(user.account_id.clone());
+                                }
+                            }
+                            should_quit = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(selected_account_id)
+    }
+    
+    fn update_filter(&mut self) {
+        self.filtered_indices.clear();
+        let query_lower = self.search_query.to_lowercase();
+        
+        for (idx, user) in self.users.iter().enumerate() {
+            if user.display_name.to_lowercase().contains(&query_lower) {
+                self.filtered_indices.push(idx);
+            }
+        }
+        
+        // Reset selection if current selection is out of bounds
+        let total_items = self.filtered_indices.len() + 1; // +1 for "Myself"
+        if self.selected_index >= total_items {
+            self.selected_index = 0;
+        }
+        
+        // Reset scroll to show selected item
+        self.scroll_offset = 0;
+        self.update_scroll_offset(self.viewport_height);
+    }
+    
+    fn update_scroll_offset(&mut self, viewport_height: usize) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+        }
+    }
+
+    fn draw(&mut self, f: &mut Frame, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(if self.search_mode { 5 } else { 3 }),    // Header (bigger in search mode)
+                Constraint::Min(0),       // User list
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        self.render_header(f, chunks[0], theme);
+        self.render_user_list(f, chunks[1], theme);
+        self.render_help(f, chunks[2], theme);
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Select Assignee ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if self.search_mode {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                ])
+                .split(inner);
+                
+            let header_text = vec![
+                Line::from(vec![
+                    Span::styled("Search for users", Style::default().fg(theme.label)),
+                ]),
+            ];
+            let paragraph = Paragraph::new(header_text);
+            f.render_widget(paragraph, chunks[0]);
+            
+            let search_text = vec![
+                Line::from(vec![
+                    Span::styled("Filter: ", Style::default().fg(theme.highlight)),
+                    Span::raw(&self.search_query),
+                    Span::styled("_", Style::default().fg(theme.highlight).add_modifier(Modifier::SLOW_BLINK)),
+                ]),
+            ];
+            let search_para = Paragraph::new(search_text);
+            f.render_widget(search_para, chunks[1]);
+            
+            let found_count = self.filtered_indices.len();
+            let count_text = vec![
+                Line::from(vec![
+                    Span::styled(format!("{} user(s) found", found_count), Style::default().fg(theme.muted)),
+                ]),
+            ];
+            let count_para = Paragraph::new(count_text);
+            f.render_widget(count_para, chunks[2]);
+        } else {
+            let header_text = vec![
+                Line::from(vec![
+                    Span::styled("Select a user to assign the issue to", Style::default().fg(theme.label)),
+                ]),
+            ];
+            let paragraph = Paragraph::new(header_text);
+            f.render_widget(paragraph, inner);
+        }
+    }
+
+    fn render_user_list(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Users ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let viewport_height = inner.height as usize;
+        self.viewport_height = viewport_height; // Store for use in key handlers
+        let mut items: Vec<ListItem> = Vec::new();
+        
+        // Calculate total items and adjust scroll
+        let total_items = self.filtered_indices.len() + 2; // +2 for "Myself" and "None"
+        
+        // Use the persisted scroll_offset
+        let scroll_offset = self.scroll_offset;
+        
+        // Show scroll indicators
+        if scroll_offset > 0 {
+            items.push(ListItem::new("↑ more above ↑").style(Style::default().fg(theme.muted)));
+        }
+        
+        let start_idx = if scroll_offset > 0 { scroll_offset + 1 } else { scroll_offset };
+        let end_idx = (start_idx + viewport_height.saturating_sub(if scroll_offset > 0 { 2 } else { 1 }))
+            .min(total_items);
+        
+        // Add visible items
+        for visible_idx in start_idx..end_idx {
+            if visible_idx == 0 {
+                // "Myself" option
+                let indicator = if self.selected_index == 0 { "➤ " } else { "  " };
+                let text = format!("{} Myself", indicator);
+                items.push(ListItem::new(text).style(Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)));
+            } else if visible_idx == 1 {
+                // "None" option (unassign)
+                let indicator = if self.selected_index == 1 { "➤ " } else { "  " };
+                let text = format!("{} None (unassign)", indicator);
+                items.push(ListItem::new(text).style(Style::default().fg(theme.error)));
+            } else if let Some(&user_idx) = self.filtered_indices.get(visible_idx - 2) {
+                if let Some(user) = self.users.get(user_idx) {
+                    let indicator = if visible_idx == self.selected_index { "➤ " } else { "  " };
+                    
+                    let display_text = if user.account_id == self.current_user_id {
+                        format!("{}{} (you)", indicator, user.display_name)
+                    } else {
+                        format!("{}{}", indicator, user.display_name)
+                    };
+                    
+                    items.push(ListItem::new(display_text));
+                }
+            }
+        }
+        
+        // Show scroll indicator at bottom
+        if end_idx < total_items {
+            items.push(ListItem::new("↓ more below ↓").style(Style::default().fg(theme.muted)));
+        }
+
+        let list = List::new(items)
+            .block(Block::default());
+
+        f.render_widget(list, inner);
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.search_mode {
+            "Type to filter | Enter: Confirm | ESC: Cancel search"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | Enter: Select | q/ESC: Cancel"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+pub struct ListPicker {
+    selected_index: usize,
+    items: Vec<String>,
+    filtered_indices: Vec<usize>,
+    search_query: String,
+    search_mode: bool,
+    scroll_offset: usize,
+    viewport_height: usize,
+}
+
+impl ListPicker {
+    // Shows a single-choice list with fuzzy search and returns the index of the chosen item
+    pub fn show(title: &str, items: Vec<String>, theme: &Theme) -> Result<Option<usize>> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let filtered_indices: Vec<usize> = (0..items.len()).collect();
+        let mut app = Self {
+            selected_index: 0,
+            items,
+            filtered_indices,
+            search_query: String::new(),
+            search_mode: false,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+        };
+        
+        let mut selected: Option<usize> = None;
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, title, theme))?;
+
+            if let Event::Key(key) = event::read()? {
+                if app.search_mode {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Enter => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Backspace => {
+                            app.search_query.pop();
+                            app.update_filter();
+                        }
+                        KeyCode::Char(c) => {
+                            app.search_query.push(c);
+                            app.update_filter();
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if let Some(motion) = Motion::from_key(&key) {
+                    app.selected_index = motion.apply(app.selected_index, app.filtered_indices.len(), app.viewport_height);
+                    app.update_scroll_offset();
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char('/') => {
+                        app.search_mode = true;
+                    }
+                    KeyCode::Enter => {
+                        selected = app.filtered_indices.get(app.selected_index).copied();
+                        should_quit = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(selected)
+    }
+    
+    fn update_scroll_offset(&mut self) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + self.viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(self.viewport_height - 1);
+        }
+    }
+    
+    fn update_filter(&mut self) {
+        self.filtered_indices = self.items.iter()
+            .enumerate()
+            .filter(|(_, item)| fuzzy_match(&self.search_query, item))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    fn draw(&mut self, f: &mut Frame, title: &str, theme: &Theme) {
+        render_jira_label(f, theme);
+        let show_search = self.search_mode || !self.search_query.is_empty();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(if show_search { 1 } else { 0 }),    // Search line
+                Constraint::Min(0),       // Item list
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        if show_search {
+            let line = Line::from(vec![
+                Span::styled("Search: ", Style::default().fg(theme.label)),
+                Span::raw(&self.search_query),
+                Span::styled(if self.search_mode { "_" } else { "" }, Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]);
+            f.render_widget(Paragraph::new(line), chunks[0]);
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title.to_string())
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(chunks[1]);
+        f.render_widget(block, chunks[1]);
+        
+        self.viewport_height = (inner.height as usize).max(1);
+        
+        let items: Vec<ListItem> = self.filtered_indices.iter()
+            .enumerate()
+            .skip(self.scroll_offset)
+            .take(self.viewport_height)
+            .map(|(idx, &item_idx)| {
+                let item = &self.items[item_idx];
+                if idx == self.selected_index {
+                    ListItem::new(format!("➤ {}", item)).style(Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD).patch(theme.selection))
+                } else {
+                    ListItem::new(format!("  {}", item))
+                }
+            })
+            .collect();
+        
+        f.render_widget(List::new(items), inner);
+
+        let help_text = if self.search_mode {
+            "Type to search | Enter/ESC: Exit search | Backspace: Delete"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | Enter: Select | q/ESC: Cancel"
+        };
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+    }
+}
+
+pub struct CommandPalette<'a> {
+    entries: &'a [PaletteEntry],
+    selected_index: usize,
+    filtered_indices: Vec<usize>,
+    query: String,
+    scroll_offset: usize,
+    viewport_height: usize,
+}
+
+impl<'a> CommandPalette<'a> {
+    // Type-to-filter launcher over commands, tickets, and meetings; returns the chosen entry
+    pub fn show(entries: &'a [PaletteEntry], theme: &Theme) -> Result<Option<usize>> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self {
+            entries,
+            selected_index: 0,
+            filtered_indices: (0..entries.len()).collect(),
+            query: String::new(),
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+        };
+        
+        let mut selected: Option<usize> = None;
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, theme))?;
+
+            if let Event::Key(key) = event::read()? {
+                if let Some(motion) = Motion::from_key_while_typing(&key) {
+                    app.selected_index = motion.apply(app.selected_index, app.filtered_indices.len(), app.viewport_height);
+                    app.update_scroll_offset();
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Esc => should_quit = true,
+                    KeyCode::Enter => {
+                        selected = app.filtered_indices.get(app.selected_index).copied();
+                        should_quit = true;
+                    }
+                    KeyCode::Backspace => {
+                        app.query.pop();
+                        app.update_filter();
+                    }
+                    KeyCode::Char(c) => {
+                        app.query.push(c);
+                        app.update_filter();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(selected)
+    }
+    
+    fn update_scroll_offset(&mut self) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + self.viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(self.viewport_height - 1);
+        }
+    }
+    
+    fn update_filter(&mut self) {
+        self.filtered_indices = self.entries.iter()
+            .enumerate()
+            .filter(|(_, entry)| fuzzy_match(&self.query, &entry.search_text()))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    fn draw(&mut self, f: &mut Frame, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),    // Query input
+                Constraint::Min(0),       // Matching entries
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled("> ", Style::default().fg(theme.accent)),
+            Span::raw(&self.query),
+            Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+        ]))
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(" qq ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)));
+        f.render_widget(input, chunks[0]);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} of {} ", self.filtered_indices.len(), self.entries.len()))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        let inner = block.inner(chunks[1]);
+        f.render_widget(block, chunks[1]);
+        
+        self.viewport_height = (inner.height as usize).max(1);
+
+        if self.filtered_indices.is_empty() {
+            let text = Paragraph::new("(Nothing matches)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(text, inner);
+        } else {
+            let rows: Vec<Row> = self.filtered_indices.iter()
+                .enumerate()
+                .skip(self.scroll_offset)
+                .take(self.viewport_height)
+                .map(|(idx, &entry_idx)| {
+                    let entry = &self.entries[entry_idx];
+                    let selected = idx == self.selected_index;
+                    let section_color = match entry.section {
+                        "Ticket" => theme.label,
+                        "Meeting" => theme.special,
+                        _ => theme.accent,
+                    };
+                    Row::new(vec![
+                        Cell::from(if selected { "➤" } else { "" }).style(Style::default().fg(theme.accent)),
+                        Cell::from(entry.section).style(Style::default().fg(section_color)),
+                        Cell::from(entry.label.clone()),
+                        Cell::from(entry.detail.clone()).style(Style::default().fg(theme.muted)),
+                    ])
+                    .style(theme.selected(selected))
+                })
+                .collect();
+
+            let table = Table::new(rows, [
+                Constraint::Length(2),      // Arrow indicator
+                Constraint::Length(8),      // Section
+                Constraint::Length(32),     // Command, key, or time
+                Constraint::Min(20),        // Description
+            ]);
+            f.render_widget(table, inner);
+        }
+
+        let help = Paragraph::new("Type to filter | ↑/↓ Ctrl-d/u: Navigate | Enter: Run | ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+    }
+}
+
+pub struct AllEpicsDisplay {
+    selected_index: usize,
+    epics: Vec<JiraIssue>,
+    pins: PinStore,
+    sort: IssueSort,
+    filtered_indices: Vec<usize>,
+    search_query: String,
+    search_mode: bool,
+    scroll_offset: usize,
+    viewport_height: usize,
+}
+
+impl AllEpicsDisplay {
+    pub fn show(mut epics: Vec<JiraIssue>, client: &JiraClient, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let epic_count = epics.len();
+        let filtered_indices: Vec<usize> = (0..epic_count).collect();
+        
+        let pins = PinStore::load();
+        let sort = IssueSort::default();
+        sort.apply(&mut epics, &pins);
+        let mut app = Self {
+            selected_index: 0,
+            epics,
+            pins,
+            sort,
+            filtered_indices,
+            search_query: String::new(),
+            search_mode: false,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+        };
+        
+        let mut should_quit = false;
+        let mut toasts = Toasts::default();
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if app.search_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Enter => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Backspace => {
+                            app.search_query.pop();
+                            app.update_filter();
+                        }
+                        KeyCode::Char(c) => {
+                            app.search_query.push(c);
+                            app.update_filter();
+                        }
+                        _ => {}
+                    }
+                } else {
+                    if toasts.handle_key(&key) {
+                        continue;
+                    }
+
+                    if let Some(motion) = Motion::from_key(&key) {
+                        app.selected_index = motion.apply(app.selected_index, app.filtered_indices.len(), app.viewport_height);
+                        app.update_scroll_offset(app.viewport_height);
+                        continue;
+                    }
+
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                        KeyCode::Char('/') => {
+                            app.search_mode = true;
+                        }
+                        KeyCode::Char(c @ ('f' | 'F')) => {
+                            if c == 'f' {
+                                app.sort.next_field();
+                            } else {
+                                app.sort.reverse();
+                            }
+                            let selected_key = app.filtered_indices.get(app.selected_index)
+                                .map(|&idx| app.epics[idx].key.clone());
+                            app.sort.apply(&mut app.epics, &app.pins);
+                            app.update_filter();
+                            if let Some(pos) = selected_key.and_then(|key| app.filtered_indices.iter().position(|&idx| app.epics[idx].key == key)) {
+                                app.selected_index = pos;
+                                app.update_scroll_offset(app.viewport_height);
+                            }
+                        }
+                        KeyCode::Char('P') => {
+                            if let Some(&epic_idx) = app.filtered_indices.get(app.selected_index) {
+                                let new_idx = toggle_pin(&mut app.pins, &mut app.epics, epic_idx, &mut toasts);
+                                app.update_filter();
+                                if let Some(pos) = app.filtered_indices.iter().position(|&idx| idx == new_idx) {
+                                    app.selected_index = pos;
+                                    app.update_scroll_offset(app.viewport_height);
+                                }
+                            }
+                        }
+                        KeyCode::Char('v') => {
+                            if let Some(&epic_idx) = app.filtered_indices.get(app.selected_index) {
+                                if let Some(epic) = app.epics.get(epic_idx) {
+                                    let epic_key = epic.key.clone();
+                                    // Temporarily restore terminal for nested UI
+                                    disable_raw_mode()?;
+                                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                                    terminal.show_cursor()?;
+                                    
+                                    // Fetch and show the epic with its children
+                                    println!("Fetching child issues for {}...", epic_key);
+                                    if let Ok(children) = client.get_epic_children(&epic_key) {
+                                        let _ = EpicListDisplay::show(epic, children, client, theme);
+                                    }
+                                    
+                                    // Re-setup terminal
+                                    enable_raw_mode()?;
+                                    let mut stdout = io::stdout();
+                                    execute!(stdout, EnterAlternateScreen)?;
+                                    let backend = CrosstermBackend::new(stdout);
+                                    terminal = Terminal::new(backend)?;
+                                    
+                                    toasts.info(format!("Returned from viewing {}", epic_key));
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+    
+    fn update_scroll_offset(&mut self, viewport_height: usize) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+        }
+    }
+    
+    fn update_filter(&mut self) {
+        self.filtered_indices.clear();
+        let query_lower = self.search_query.to_lowercase();
+        
+        for (idx, epic) in self.epics.iter().enumerate() {
+            let key_matches = epic.key.to_lowercase().contains(&query_lower);
+            let summary_matches = epic.fields.summary.to_lowercase().contains(&query_lower);
+            
+            if key_matches || summary_matches {
+                self.filtered_indices.push(idx);
+            }
+        }
+        
+        // Reset selection if current selection is out of bounds
+        if self.selected_index >= self.filtered_indices.len() {
+            self.selected_index = 0;
+        }
+        
+        // Reset scroll to show selected item
+        self.scroll_offset = 0;
+        self.update_scroll_offset(self.viewport_height);
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let constraints = if self.search_mode {
+            vec![
+                Constraint::Length(3),     // Header
+                Constraint::Length(3),     // Search bar
+                Constraint::Min(0),        // Epic list
+                Constraint::Length(2),     // Help text
+            ]
+        } else {
+            vec![
+                Constraint::Length(3),     // Header
+                Constraint::Min(0),        // Epic list
+                Constraint::Length(2),     // Help text
+            ]
+        };
+        
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints(constraints)
+            .split(f.area());
+
+        if self.search_mode {
+            self.render_header(f, chunks[0], theme);
+            self.render_search_bar(f, chunks[1], theme);
+            self.render_epics_table(f, chunks[2], theme);
+            self.render_help(f, chunks[3], theme);
+        } else {
+            self.render_header(f, chunks[0], theme);
+            self.render_epics_table(f, chunks[1], theme);
+            self.render_help(f, chunks[2], theme);
+        }
+        toasts.render(f, theme);
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" All Epics ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let header_text = if !self.search_query.is_empty() {
+            vec![
+                Line::from(vec![
+                    Span::styled("Filtered Epics: ", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+                    Span::raw(format!("{} / {}", self.filtered_indices.len(), self.epics.len())),
+                ]),
+            ]
+        } else {
+            vec![
+                Line::from(vec![
+                    Span::styled("Total Epics: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                    Span::raw(self.epics.len().to_string()),
+                ]),
+            ]
+        };
+
+        let paragraph = Paragraph::new(header_text);
+        f.render_widget(paragraph, inner);
+    }
+    
+    fn render_search_bar(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Search ")
+            .title_style(Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        
+        let search_text = vec![
+            Line::from(vec![
+                Span::styled("Filter: ", Style::default().fg(theme.label)),
+                Span::raw(&self.search_query),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ]),
+        ];
+        
+        let paragraph = Paragraph::new(search_text);
+        f.render_widget(paragraph, inner);
+    }
+
+    fn render_epics_table(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Epics ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if self.filtered_indices.is_empty() {
+            let text = if self.search_query.is_empty() {
+                "(No epics found)"
+            } else {
+                "(No epics match your search)"
+            };
+            let paragraph = Paragraph::new(text)
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, inner);
+            return;
+        }
+
+        // Calculate viewport dimensions for table (accounting for the title line)
+        let viewport_height = list_capacity(inner.width, inner.height.saturating_sub(1));
+        self.viewport_height = viewport_height; // Store for use in key handlers
+        
+        // Use the persisted scroll_offset with filtered indices
+        let visible_start = self.scroll_offset;
+        let visible_end = (self.scroll_offset + viewport_height).min(self.filtered_indices.len());
+        
+        let rows: Vec<ListRow> = self.filtered_indices[visible_start..visible_end]
+            .iter()
+            .enumerate()
+            .map(|(visible_idx, &epic_idx)| {
+                let epic = &self.epics[epic_idx];
+                let actual_idx = visible_start + visible_idx;
+                
+                // Color code the status
+                let status_style = match epic.fields.status.name.to_lowercase().as_str() {
+                    s if s.contains("done") || s.contains("closed") => Style::default().fg(theme.success),
+                    s if s.contains("progress") => Style::default().fg(theme.highlight),
+                    s if s.contains("review") => Style::default().fg(theme.special),
+                    _ => Style::default().fg(theme.text),
+                };
+                
+                // Selection indicator
+                let selected = actual_idx == self.selected_index;
+                let indicator = row_indicator(selected, false, self.pins.is_pinned(&epic.key));
+                
+                let cells = vec![
+                    Line::from(indicator).style(Style::default().fg(theme.accent)),
+                    key_line(epic, theme),
+                    Line::from(epic.fields.status.name.clone()).style(status_style),
+                    Line::from(epic.fields.summary.clone()),
+                    Line::from(updated_ago(epic)).style(Style::default().fg(theme.muted)),
+                ];
+                
+                ListRow::new(cells).style(theme.selected(selected))
+            })
+            .collect();
+
+        // Update title with scroll indicators
+        let title = if self.epics.len() > viewport_height {
+            format!(" Epics [{}-{} of {}] · {} ", 
+                visible_start + 1,
+                visible_end,
+                self.epics.len(),
+                self.sort.label()
+            )
+        } else {
+            format!(" Epics · {} ", self.sort.label())
+        };
+
+        const COLUMNS: [ListColumn; 5] = [
+            ListColumn::new("", Constraint::Length(3), 0),            // Arrow indicator
+            ListColumn::new("Key", Constraint::Length(12), 0),
+            ListColumn::new("Status", Constraint::Length(15), 0),
+            ListColumn::new("Summary", Constraint::Min(20), 0),       // Takes remaining space
+            ListColumn::new("Updated", Constraint::Length(9), 1),
+        ];
+        let table = fitted_table(&COLUMNS, rows, inner.width, theme)
+            .block(Block::default().title(title));
+
+        f.render_widget(table, inner);
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.search_mode {
+            "Type to search | Enter/ESC: Exit search | Backspace: Delete"
+        } else {
+            "↑/↓ j/k: Navigate | v: View Epic | /: Search | P: Pin | f/F: Sort | q/ESC: Quit"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+// What to do with the query once the builder closes
+pub enum QueryBuilderOutcome {
+    Run(String),
+    Save { name: String, jql: String },
+}
+
+const QUERY_STEPS: [&str; 6] = ["Project", "Type", "Status", "Assignee", "Text", "Order"];
+
+pub struct QueryBuilderDisplay {
+    selected_index: usize,
+    query: crate::jql::QueryBuilder,
+    // None while a count is in flight
+    count: Option<Result<u64, String>>,
+    projects: Option<Vec<crate::jira::Project>>,
+    issue_types: Option<Vec<String>>,
+    statuses: Option<Vec<String>>,
+    text_mode: bool,
+    text_input: String,
+    save_mode: bool,
+    save_input: String,
+}
+
+impl QueryBuilderDisplay {
+    pub fn show(client: &JiraClient, theme: &Theme) -> Result<Option<QueryBuilderOutcome>> {
+        use crate::jql::{NOT_DONE, ORDERINGS};
+        
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self {
+            selected_index: 0,
+            query: crate::jql::QueryBuilder::default(),
+            count: None,
+            projects: None,
+            issue_types: None,
+            statuses: None,
+            text_mode: false,
+            text_input: String::new(),
+            save_mode: false,
+            save_input: String::new(),
+        };
+        
+        let mut outcome = None;
+        let mut should_quit = false;
+        let mut changed = true;
+        let mut toasts = Toasts::default();
+        let mut clipboard = crate::clipboard::Clipboard::default();
+
+        // Main loop
+        while !should_quit {
+            if changed {
+                changed = false;
+                app.count = None;
+                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                app.count = Some(client.count_jql(&app.query.to_jql()).map_err(|e| e.to_string()));
+            }
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if app.text_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.text_mode = false;
+                            app.text_input.clear();
+                        }
+                        KeyCode::Enter => {
+                            app.text_mode = false;
+                            app.query.text = std::mem::take(&mut app.text_input).trim().to_string();
+                            changed = true;
+                        }
+                        KeyCode::Backspace => {
+                            app.text_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.text_input.push(c);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if app.save_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.save_mode = false;
+                            app.save_input.clear();
+                            toasts.info("Save cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            let name = app.save_input.trim().to_string();
+                            if name.is_empty() {
+                                toasts.error("✗ Enter a name for the search".to_string());
+                            } else {
+                                outcome = Some(QueryBuilderOutcome::Save { name, jql: app.query.to_jql() });
+                                should_quit = true;
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            app.save_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.save_input.push(c);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                
+                if toasts.handle_key(&key) {
+                    continue;
+                }
+
+                if let Some(motion) = Motion::from_key(&key) {
+                    app.selected_index = motion.apply(app.selected_index, QUERY_STEPS.len(), QUERY_STEPS.len());
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char('r') => {
+                        outcome = Some(QueryBuilderOutcome::Run(app.query.to_jql()));
+                        should_quit = true;
+                    }
+                    KeyCode::Char('s') => {
+                        app.save_mode = true;
+                        app.save_input.clear();
+                    }
+                    KeyCode::Char('y') => {
+                        match clipboard.copy(&app.query.to_jql()) {
+                            Ok(_) => toasts.success("✓ JQL copied to clipboard"),
+                            Err(e) => toasts.error(format!("✗ {}", e)),
+                        }
+                    }
+                    KeyCode::Enter => match app.selected_index {
+                        0 => {
+                            if app.projects.is_none() {
+                                toasts.info("Loading projects...".to_string());
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                match client.get_projects() {
+                                    Ok(projects) => app.projects = Some(projects),
+                                    Err(e) => toasts.error(format!("✗ Failed to load projects: {}", e)),
+                                }
+                            }
+                            if let Some(projects) = &app.projects {
+                                let mut items = vec!["(any project)".to_string()];
+                                items.extend(projects.iter().map(|p| format!("{}  {}", p.key, p.name)));
+                                if let Some(idx) = Self::pick(&mut terminal, " Project ", items, theme)? {
+                                    app.query.project = idx.checked_sub(1).map(|i| projects[i].key.clone());
+                                    app.issue_types = None;
+                                    changed = true;
+                                }
+                            }
+                        }
+                        1 => {
+                            if app.issue_types.is_none() {
+                                toasts.info("Loading issue types...".to_string());
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                // Within one project, only that project's types are valid
+                                let types = match app.query.project.as_deref() {
+                                    Some(project) => crate::meta::MetaCache::project(client, project).map(|meta| meta.issue_types),
+                                    None => client.get_issue_type_names(),
+                                };
+                                match types {
+                                    Ok(types) => app.issue_types = Some(types),
+                                    Err(e) => toasts.error(format!("✗ Failed to load issue types: {}", e)),
+                                }
+                            }
+                            if let Some(types) = &app.issue_types {
+                                let mut items = vec!["(any type)".to_string()];
+                                items.extend(types.iter().map(|t| checked(app.query.issue_types.contains(t), t)));
+                                match Self::pick(&mut terminal, " Type (Enter toggles) ", items, theme)? {
+                                    Some(0) => app.query.issue_types.clear(),
+                                    Some(idx) => app.query.toggle_issue_type(&types[idx - 1]),
+                                    None => continue,
+                                }
+                                changed = true;
+                            }
+                        }
+                        2 => {
+                            if app.statuses.is_none() {
+                                toasts.info("Loading statuses...".to_string());
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                match client.get_status_names() {
+                                    Ok(statuses) => app.statuses = Some(statuses),
+                                    Err(e) => toasts.error(format!("✗ Failed to load statuses: {}", e)),
+                                }
+                            }
+                            if let Some(statuses) = &app.statuses {
+                                let mut choices = vec![NOT_DONE.to_string()];
+                                choices.extend(statuses.iter().cloned());
+                                let mut items = vec!["(any status)".to_string()];
+                                items.extend(choices.iter().map(|s| checked(app.query.statuses.contains(s), s)));
+                                match Self::pick(&mut terminal, " Status (Enter toggles) ", items, theme)? {
+                                    Some(0) => app.query.statuses.clear(),
+                                    Some(idx) => app.query.toggle_status(&choices[idx - 1]),
+                                    None => continue,
+                                }
+                                changed = true;
+                            }
+                        }
+                        3 => {
+                            app.query.assignee = app.query.assignee.next();
+                            changed = true;
+                        }
+                        4 => {
+                            app.text_input = app.query.text.clone();
+                            app.text_mode = true;
+                        }
+                        _ => {
+                            let items = ORDERINGS.iter().map(|(label, _)| label.to_string()).collect();
+                            if let Some(idx) = Self::pick(&mut terminal, " Order by ", items, theme)? {
+                                app.query.order = idx;
+                                changed = true;
+                            }
+                        }
+                    },
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(outcome)
+    }
+    
+    // Runs a ListPicker on top of the builder and restores the builder's screen afterwards
+    fn pick(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, title: &str, items: Vec<String>, theme: &Theme) -> Result<Option<usize>> {
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+        
+        let picked = ListPicker::show(title, items, theme);
+        
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        *terminal = Terminal::new(backend)?;
+        
+        picked
+    }
+    
+    fn step_value(&self, step: usize) -> String {
+        let query = &self.query;
+        let or_any = |values: &[String], any: &str| {
+            if values.is_empty() { any.to_string() } else { values.join(", ") }
+        };
+        match step {
+            0 => query.project.clone().unwrap_or_else(|| "Any project".to_string()),
+            1 => or_any(&query.issue_types, "Any type"),
+            2 => or_any(&query.statuses, "Any status"),
+            3 => query.assignee.label().to_string(),
+            4 if query.text.is_empty() => "No text filter".to_string(),
+            4 => format!("\"{}\"", query.text),
+            _ => crate::jql::ORDERINGS.get(query.order).map(|(label, _)| label.to_string()).unwrap_or_default(),
+        }
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(QUERY_STEPS.len() as u16 + 2),   // Steps
+                Constraint::Min(5),         // JQL preview and count
+                Constraint::Length(2),      // Input area
+                Constraint::Length(2),      // Help text
+            ])
+            .split(f.area());
+
+        self.render_steps(f, chunks[0], theme);
+        self.render_preview(f, chunks[1], theme);
+        self.render_input(f, chunks[2], theme);
+        self.render_help(f, chunks[3], theme);
+        toasts.render(f, theme);
+    }
+
+    fn render_steps(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let rows: Vec<Row> = QUERY_STEPS.iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                let selected = idx == self.selected_index;
+                let indicator = if selected { "➤" } else { "" };
+                Row::new(vec![
+                    Cell::from(indicator).style(Style::default().fg(theme.accent)),
+                    Cell::from(*step).style(Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                    Cell::from(self.step_value(idx)),
+                ]).style(theme.selected(selected))
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            vec![
+                Constraint::Length(3),      // Arrow indicator
+                Constraint::Length(10),     // Step
+                Constraint::Min(20),        // Value
+            ]
+        )
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(" Query Builder ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)));
+
+        f.render_widget(table, area);
+    }
+
+    fn render_preview(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let count_line = match &self.count {
+            None => Line::from(Span::styled("Counting...", Style::default().fg(theme.muted))),
+            Some(Ok(count)) => Line::from(vec![
+                Span::styled("Matches: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("~{}", count), Style::default().fg(theme.success)),
+            ]),
+            Some(Err(e)) => Line::from(Span::styled(format!("✗ {}", e), Style::default().fg(theme.error))),
+        };
+
+        let text = vec![
+            Line::from(Span::styled(self.query.to_jql(), Style::default().fg(theme.text))),
+            Line::from(""),
+            count_line,
+        ];
+
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(" JQL ")
+                .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)));
+        f.render_widget(paragraph, area);
+    }
+
+    fn render_input(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let (label, input) = if self.text_mode {
+            ("Text to search for: ", &self.text_input)
+        } else if self.save_mode {
+            ("Save search as: ", &self.save_input)
+        } else {
+            return;
+        };
+        let line = Line::from(vec![
+            Span::styled(label, Style::default().fg(theme.label)),
+            Span::raw(input),
+            Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+        ]);
+        f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.text_mode {
+            "Type words to match in summary, description, or comments | Enter: Apply | ESC: Cancel"
+        } else if self.save_mode {
+            "Type a name | Enter: Save | ESC: Cancel"
+        } else {
+            "↑/↓ j/k: Step | Enter: Change | r: Run | s: Save | y: Copy JQL | q/ESC: Quit"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+// Picker item with a check mark for values already in a multi-choice step
+fn checked(selected: bool, label: &str) -> String {
+    format!("{} {}", if selected { "✓" } else { " " }, label)
+}
+
+pub struct FieldsDisplay {
+    selected_index: usize,
+    fields: Vec<JiraField>,
+    pinned: Vec<(String, &'static str)>,
+    filtered_indices: Vec<usize>,
+    search_query: String,
+    search_mode: bool,
+    scroll_offset: usize,
+    viewport_height: usize,
+}
+
+impl FieldsDisplay {
+    pub fn show(fields: Vec<JiraField>, pinned: Vec<(String, &'static str)>, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let filtered_indices: Vec<usize> = (0..fields.len()).collect();
+        
+        let mut app = Self {
+            selected_index: 0,
+            fields,
+            pinned,
+            filtered_indices,
+            search_query: String::new(),
+            search_mode: false,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+        };
+        
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, theme))?;
+
+            if let Event::Key(key) = event::read()? {
+                if app.search_mode {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Enter => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Backspace => {
+                            app.search_query.pop();
+                            app.update_filter();
+                        }
+                        KeyCode::Char(c) => {
+                            app.search_query.push(c);
+                            app.update_filter();
+                        }
+                        _ => {}
+                    }
+                } else if let Some(motion) = Motion::from_key(&key) {
+                    app.selected_index = motion.apply(app.selected_index, app.filtered_indices.len(), app.viewport_height);
+                    app.update_scroll_offset(app.viewport_height);
+                } else {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                        KeyCode::Char('/') => {
+                            app.search_mode = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+    
+    fn update_scroll_offset(&mut self, viewport_height: usize) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+        }
+    }
+    
+    fn update_filter(&mut self) {
+        self.filtered_indices.clear();
+        let query_lower = self.search_query.to_lowercase();
+        
+        for (idx, field) in self.fields.iter().enumerate() {
+            let id_matches = field.id.to_lowercase().contains(&query_lower);
+            let name_matches = field.name.to_lowercase().contains(&query_lower);
+            
+            if id_matches || name_matches {
+                self.filtered_indices.push(idx);
+            }
+        }
+        
+        if self.selected_index >= self.filtered_indices.len() {
+            self.selected_index = 0;
+        }
+        
+        self.scroll_offset = 0;
+        self.update_scroll_offset(self.viewport_height);
+    }
+    
+    fn pinned_role(&self, field_id: &str) -> &'static str {
+        self.pinned.iter()
+            .find(|(id, _)| id == field_id)
+            .map(|(_, role)| *role)
+            .unwrap_or("")
+    }
+
+    fn draw(&mut self, f: &mut Frame, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),     // Header / search bar
+                Constraint::Min(0),        // Fields table
+                Constraint::Length(2),     // Help text
+            ])
+            .split(f.area());
+
+        self.render_header(f, chunks[0], theme);
+        self.render_fields_table(f, chunks[1], theme);
+        self.render_help(f, chunks[2], theme);
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" JIRA Fields ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let line = if self.search_mode || !self.search_query.is_empty() {
+            Line::from(vec![
+                Span::styled("Filter: ", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+                Span::raw(&self.search_query),
+                Span::styled(if self.search_mode { "_" } else { "" }, Style::default().add_modifier(Modifier::SLOW_BLINK)),
+                Span::styled(
+                    format!("  ({} / {})", self.filtered_indices.len(), self.fields.len()),
+                    Style::default().fg(theme.muted),
+                ),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("Total Fields: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(self.fields.len().to_string()),
+            ])
+        };
+
+        f.render_widget(Paragraph::new(line), inner);
+    }
+
+    fn render_fields_table(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Fields ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if self.filtered_indices.is_empty() {
+            let paragraph = Paragraph::new("(No fields match your search)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, inner);
+            return;
+        }
+
+        let viewport_height = list_capacity(inner.width, inner.height);
+        self.viewport_height = viewport_height;
+        
+        let visible_start = self.scroll_offset;
+        let visible_end = (self.scroll_offset + viewport_height).min(self.filtered_indices.len());
+        
+        let rows: Vec<ListRow> = self.filtered_indices[visible_start..visible_end]
+            .iter()
+            .enumerate()
+            .map(|(visible_idx, &field_idx)| {
+                let field = &self.fields[field_idx];
+                let actual_idx = visible_start + visible_idx;
+                let selected = actual_idx == self.selected_index;
+                let indicator = if selected { "➤" } else { "" };
+                
+                let cells = vec![
+                    Line::from(indicator).style(Style::default().fg(theme.accent)),
+                    Line::from(field.id.clone()),
+                    Line::from(field.name.clone()),
+                    Line::from(field.type_description()),
+                    Line::from(if field.custom { "yes" } else { "" }),
+                    Line::from(self.pinned_role(&field.id)).style(Style::default().fg(theme.highlight)),
+                ];
+                
+                ListRow::new(cells).style(theme.selected(selected))
+            })
+            .collect();
+
+        const COLUMNS: [ListColumn; 6] = [
+            ListColumn::new("", Constraint::Length(3), 0),            // Arrow indicator
+            ListColumn::new("ID", Constraint::Length(20), 0),
+            ListColumn::new("Name", Constraint::Min(20), 0),
+            ListColumn::new("Type", Constraint::Length(18), 2),
+            ListColumn::new("Custom", Constraint::Length(7), 3),
+            ListColumn::new("Pinned", Constraint::Length(14), 1),     // Pinned role
+        ];
+        f.render_widget(fitted_table(&COLUMNS, rows, inner.width, theme), inner);
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.search_mode {
+            "Type to search | Enter/ESC: Exit search | Backspace: Delete"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | q/ESC: Quit"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+pub struct ProjectsDisplay {
+    selected_index: usize,
+    projects: Vec<crate::projects::ProjectSummary>,
+    // Default project of the repository qq was run from
+    default: Option<String>,
+    filtered_indices: Vec<usize>,
+    search_query: String,
+    search_mode: bool,
+    scroll_offset: usize,
+    viewport_height: usize,
+}
+
+impl ProjectsDisplay {
+    pub fn show(projects: Vec<crate::projects::ProjectSummary>, default: Option<String>, client: &JiraClient, theme: &Theme) -> Result<()> {
+        use crate::projects::ProjectView;
+
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let filtered_indices: Vec<usize> = (0..projects.len()).collect();
+        let mut app = Self {
+            selected_index: 0,
+            projects,
+            default,
+            filtered_indices,
+            search_query: String::new(),
+            search_mode: false,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+        };
+        let mut toasts = Toasts::default();
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if app.search_mode {
+                    match key.code {
+                        KeyCode::Esc | KeyCode::Enter => {
+                            app.search_mode = false;
+                        }
+                        KeyCode::Backspace => {
+                            app.search_query.pop();
+                            app.update_filter();
+                        }
+                        KeyCode::Char(c) => {
+                            app.search_query.push(c);
+                            app.update_filter();
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                if toasts.handle_key(&key) {
+                    continue;
+                }
+                if let Some(motion) = Motion::from_key(&key) {
+                    app.selected_index = motion.apply(app.selected_index, app.filtered_indices.len(), app.viewport_height);
+                    app.update_scroll_offset(app.viewport_height);
+                    continue;
+                }
+
+                let Some(project_key) = app.selected().map(|summary| summary.project.key.clone()) else {
+                    if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                        should_quit = true;
+                    } else if key.code == KeyCode::Char('/') {
+                        app.search_mode = true;
+                    }
+                    continue;
+                };
+                let view = match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => {
+                        should_quit = true;
+                        None
+                    }
+                    KeyCode::Char('/') => {
+                        app.search_mode = true;
+                        None
+                    }
+                    KeyCode::Char('b') => Some(ProjectView::Backlog),
+                    KeyCode::Char('s') => Some(ProjectView::Board),
+                    KeyCode::Char('e') => Some(ProjectView::Epics),
+                    KeyCode::Enter => {
+                        let labels = ProjectView::ALL.iter().map(|view| view.label().to_string()).collect();
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+
+                        let picked = ListPicker::show(&format!(" {} ", project_key), labels, theme);
+
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+
+                        picked.ok().flatten().map(|idx| ProjectView::ALL[idx])
+                    }
+                    KeyCode::Char('d') => {
+                        match crate::projects::RepoProjects::set_current(&project_key) {
+                            Ok(()) => {
+                                app.default = Some(project_key.clone());
+                                toasts.success(format!("✓ {} is now this repository's default project", project_key));
+                            }
+                            Err(e) => toasts.error(format!("✗ {}", e)),
+                        }
+                        None
+                    }
+                    _ => None,
+                };
+
+                if let Some(view) = view {
+                    // Temporarily restore terminal for nested UI
+                    disable_raw_mode()?;
+                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                    terminal.show_cursor()?;
+
+                    println!("Loading {} {}...", project_key, view.label().to_lowercase());
+                    let jql = view.jql(&project_key);
+                    let shown = match view {
+                        ProjectView::Epics => client.search_jql(&jql, 100)
+                            .and_then(|epics| AllEpicsDisplay::show(epics, client, theme)),
+                        _ => SearchResultsDisplay::show(&jql, None, client, theme).map(|_| ()),
+                    };
+
+                    // Re-setup terminal
+                    enable_raw_mode()?;
+                    let mut stdout = io::stdout();
+                    execute!(stdout, EnterAlternateScreen)?;
+                    let backend = CrosstermBackend::new(stdout);
+                    terminal = Terminal::new(backend)?;
+
+                    if let Err(e) = shown {
+                        toasts.error(format!("✗ Failed to load {} {}: {}", project_key, view.label().to_lowercase(), e));
+                    }
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn selected(&self) -> Option<&crate::projects::ProjectSummary> {
+        self.filtered_indices.get(self.selected_index).map(|&idx| &self.projects[idx])
+    }
+
+    fn update_scroll_offset(&mut self, viewport_height: usize) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + viewport_height {
+            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+        }
+    }
+
+    fn update_filter(&mut self) {
+        let query_lower = self.search_query.to_lowercase();
+        self.filtered_indices = self.projects.iter()
+            .enumerate()
+            .filter(|(_, summary)| {
+                summary.project.key.to_lowercase().contains(&query_lower)
+                    || summary.project.name.to_lowercase().contains(&query_lower)
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        if self.selected_index >= self.filtered_indices.len() {
+            self.selected_index = 0;
+        }
+        self.scroll_offset = 0;
+        self.update_scroll_offset(self.viewport_height);
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),     // Header / search bar
+                Constraint::Min(0),        // Projects table
+                Constraint::Length(2),     // Help text
+            ])
+            .split(f.area());
+
+        self.render_header(f, chunks[0], theme);
+        self.render_projects_table(f, chunks[1], theme);
+        self.render_help(f, chunks[2], theme);
+        toasts.render(f, theme);
+    }
+
+    fn render_header(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" JIRA Projects ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let line = if self.search_mode || !self.search_query.is_empty() {
+            Line::from(vec![
+                Span::styled("Filter: ", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+                Span::raw(&self.search_query),
+                Span::styled(if self.search_mode { "_" } else { "" }, Style::default().add_modifier(Modifier::SLOW_BLINK)),
+                Span::styled(
+                    format!("  ({} / {})", self.filtered_indices.len(), self.projects.len()),
+                    Style::default().fg(theme.muted),
+                ),
+            ])
+        } else {
+            let mine: usize = self.projects.iter().map(|summary| summary.open_mine).sum();
+            Line::from(vec![
+                Span::styled("Projects: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(self.projects.len().to_string()),
+                Span::styled("  My open issues: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(mine.to_string()),
+                Span::styled("  Repo default: ", Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::raw(self.default.clone().unwrap_or_else(|| "none".to_string())),
+            ])
+        };
+
+        f.render_widget(Paragraph::new(line), inner);
+    }
+
+    fn render_projects_table(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Projects ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if self.filtered_indices.is_empty() {
+            let paragraph = Paragraph::new("(No projects match your search)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, inner);
+            return;
+        }
+
+        let viewport_height = list_capacity(inner.width, inner.height);
+        self.viewport_height = viewport_height;
+
+        let visible_start = self.scroll_offset;
+        let visible_end = (self.scroll_offset + viewport_height).min(self.filtered_indices.len());
+
+        let rows: Vec<ListRow> = self.filtered_indices[visible_start..visible_end]
+            .iter()
+            .enumerate()
+            .map(|(visible_idx, &project_idx)| {
+                let summary = &self.projects[project_idx];
+                let selected = visible_start + visible_idx == self.selected_index;
+                let is_default = self.default.as_deref() == Some(summary.project.key.as_str());
+                let mine_style = if summary.open_mine > 0 {
+                    Style::default().fg(theme.highlight)
+                } else {
+                    Style::default().fg(theme.muted)
+                };
+
+                ListRow::new(vec![
+                    Line::from(if selected { "➤" } else { "" }).style(Style::default().fg(theme.accent)),
+                    Line::from(summary.project.key.clone()).style(Style::default().fg(theme.accent)),
+                    Line::from(summary.project.name.clone()),
+                    Line::from(summary.project.lead.as_ref().map(|lead| lead.display_name.clone()).unwrap_or_default()),
+                    Line::from(summary.open_mine.to_string()).style(mine_style),
+                    Line::from(if is_default { "★" } else { "" }).style(Style::default().fg(theme.highlight)),
+                ])
+                .style(theme.selected(selected))
+            })
+            .collect();
+
+        const COLUMNS: [ListColumn; 6] = [
+            ListColumn::new("", Constraint::Length(3), 0),           // Arrow indicator
+            ListColumn::new("Key", Constraint::Length(12), 0),
+            ListColumn::new("Name", Constraint::Min(20), 0),
+            ListColumn::new("Lead", Constraint::Length(20), 2),
+            ListColumn::new("Mine", Constraint::Length(5), 0),
+            ListColumn::new("Default", Constraint::Length(8), 1),
+        ];
+        f.render_widget(fitted_table(&COLUMNS, rows, inner.width, theme), inner);
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.search_mode {
+            "Type to search | Enter/ESC: Exit search | Backspace: Delete"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | Enter: Open | b: Backlog | s: Board | e: Epics | d: Set repo default | q/ESC: Quit"
+        };
+
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+pub struct ChangelogDisplay {
+    scroll: TextScroll,
+}
+
+impl ChangelogDisplay {
+    pub fn show(releases: &[crate::release::Release], theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self { scroll: TextScroll::default() };
+        let lines = Self::build_lines(releases, theme);
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, &lines, theme))?;
+
+            if let Event::Key(key) = event::read()? {
+                if app.scroll.handle_key(&key) {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::PageUp => app.scroll.scroll_by(-10),
+                    KeyCode::PageDown => app.scroll.scroll_by(10),
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn build_lines(releases: &[crate::release::Release], theme: &Theme) -> Vec<Line<'static>> {
+        let current = crate::release::current_version();
+        let mut lines = Vec::new();
+
+        for release in releases {
+            let is_current = release.tag_name.trim_start_matches('v') == current;
+            let title = release.name.clone()
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| release.tag_name.clone());
+            let date = release.published_at.as_deref()
+                .and_then(|d| d.get(..10))
+                .unwrap_or("");
+
+            lines.push(Line::from(vec![
+                Span::styled(title, Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  {}", date), Style::default().fg(theme.muted)),
+                Span::styled(if is_current { "  (installed)" } else { "" }, Style::default().fg(theme.success)),
+            ]));
+
+            for body_line in release.body.as_deref().unwrap_or("(No release notes)").lines() {
+                let line = if let Some(heading) = body_line.strip_prefix('#') {
+                    Line::from(Span::styled(
+                        heading.trim_start_matches('#').trim().to_string(),
+                        Style::default().fg(theme.label).add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(body_line.to_string())
+                };
+                lines.push(line);
+            }
+
+            lines.push(Line::from(""));
+        }
+
+        lines
+    }
+
+    fn draw(&mut self, f: &mut Frame, lines: &[Line<'static>], theme: &Theme) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(0),     // Release notes
+                Constraint::Length(2),  // Help text
+            ])
+            .split(f.area());
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Changelog (installed: {}) ", crate::release::current_version()))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        let inner = block.inner(chunks[0]);
+        f.render_widget(block, chunks[0]);
+
+        let paragraph = self.scroll.paragraph(lines.to_vec(), inner);
+        f.render_widget(paragraph, inner);
+
+        let help = Paragraph::new("↑/↓ j/k: Scroll | PgUp/PgDn: Page | /: Search | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+        self.scroll.search.render(f, theme);
+    }
+}
+
+pub struct BurnrateDisplay;
+
+impl BurnrateDisplay {
+    pub fn show(report: &crate::sprint::SprintReport, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| Self::draw(f, report, theme))?;
+
+            if let Event::Key(key) = event::read()?
+                && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+            {
+                should_quit = true;
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn draw(f: &mut Frame, report: &crate::sprint::SprintReport, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(7),                                      // Summary
+                Constraint::Min(8),                                         // Burn-up chart
+                Constraint::Length(report.assignees.len() as u16 + 3),      // Assignee load
+                Constraint::Length(2),                                      // Help text
+            ])
+            .split(f.area());
+
+        Self::render_summary(f, chunks[0], report, theme);
+        Self::render_chart(f, chunks[1], report, theme);
+        Self::render_assignees(f, chunks[2], report, theme);
+
+        let help = Paragraph::new("q/ESC: Quit | Use --json to export")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[3]);
+    }
+
+    fn render_summary(f: &mut Frame, area: Rect, report: &crate::sprint::SprintReport, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Sprint: {} ", report.sprint.name))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let label = Style::default().fg(theme.label).add_modifier(Modifier::BOLD);
+        let dates = match (report.sprint.start, report.sprint.end) {
+            (Some(start), Some(end)) => format!("{} → {}", start.format("%b %d"), end.format("%b %d")),
+            _ => "—".to_string(),
+        };
+        let total = report.total_points();
+        let percent = if total > 0.0 { report.completed_points / total * 100.0 } else { 0.0 };
+
+        let lines = vec![
+            Line::from(vec![Span::styled("Dates: ", label), Span::raw(dates)]),
+            Line::from(vec![
+                Span::styled("Committed: ", label),
+                Span::raw(format_points(report.committed_points)),
+                Span::styled("   Added after start: ", label),
+                Span::styled(
+                    format!("{} ({} issues)", format_points(report.added_points), report.added_issues.len()),
+                    Style::default().fg(if report.added_issues.is_empty() { theme.text } else { theme.highlight }),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Completed: ", label),
+                Span::styled(format!("{} ({:.0}%)", format_points(report.completed_points), percent), Style::default().fg(theme.success)),
+                Span::styled("   Remaining: ", label),
+                Span::raw(format_points(report.remaining_points())),
+            ]),
+            Line::from(vec![
+                Span::styled("Scope changes: ", label),
+                Span::styled(
+                    if report.added_issues.is_empty() { "none".to_string() } else { report.added_issues.join(", ") },
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+        ];
+
+        f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
+    }
+
+    fn render_chart(f: &mut Frame, area: Rect, report: &crate::sprint::SprintReport, theme: &Theme) {
+        use ratatui::widgets::{Bar, BarChart, BarGroup};
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Completed points by day ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        if report.daily.is_empty() {
+            let text = Paragraph::new("(Sprint has no start date)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        }
+
+        let bars: Vec<Bar> = report.daily.iter()
+            .map(|day| {
+                Bar::default()
+                    .value(day.completed_points.round() as u64)
+                    .label(Line::from(day.date.format("%d").to_string()))
+                    .style(Style::default().fg(theme.success))
+            })
+            .collect();
+
+        let chart = BarChart::default()
+            .block(block)
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(3)
+            .bar_gap(1)
+            .max(report.total_points().round().max(1.0) as u64);
+
+        f.render_widget(chart, area);
+    }
+
+    fn render_assignees(f: &mut Frame, area: Rect, report: &crate::sprint::SprintReport, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Load by assignee ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let header = Row::new(["Assignee", "Issues", "Points", "Done"]
+            .iter()
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.label).add_modifier(Modifier::BOLD))));
+
+        let rows: Vec<Row> = report.assignees.iter()
+            .map(|load| {
+                Row::new(vec![
+                    Cell::from(load.name.clone()),
+                    Cell::from(load.issues.to_string()),
+                    Cell::from(format_points(load.points)),
+                    Cell::from(format_points(load.completed_points)).style(Style::default().fg(theme.success)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(20),        // Assignee
+                Constraint::Length(8),      // Issues
+                Constraint::Length(8),      // Points
+                Constraint::Length(8),      // Done
+            ],
+        )
+        .header(header)
+        .block(block);
+
+        f.render_widget(table, area);
+    }
+}
+
+pub struct MeetingStatsDisplay;
+
+impl MeetingStatsDisplay {
+    pub fn show(stats: &crate::meeting_stats::MeetingStats, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| Self::draw(f, stats, theme))?;
+
+            if let Event::Key(key) = event::read()?
+                && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+            {
+                should_quit = true;
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn draw(f: &mut Frame, stats: &crate::meeting_stats::MeetingStats, theme: &Theme) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(5),                                          // Summary
+                Constraint::Min(8),                                             // Hours per day
+                Constraint::Length(stats.top_recurring.len().max(1) as u16 + 3), // Recurring meetings
+                Constraint::Length(2),                                          // Help text
+            ])
+            .split(f.area());
+
+        Self::render_summary(f, chunks[0], stats, theme);
+        Self::render_days(f, chunks[1], stats, theme);
+        Self::render_recurring(f, chunks[2], stats, theme);
+
+        let help = Paragraph::new("q/ESC: Quit | Use --json to export")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[3]);
+    }
+
+    fn render_summary(f: &mut Frame, area: Rect, stats: &crate::meeting_stats::MeetingStats, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Meeting load: {} → {} ", stats.from.format("%b %d"), stats.to.format("%b %d")))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let label = Style::default().fg(theme.label).add_modifier(Modifier::BOLD);
+        let load_color = match stats.meeting_percent {
+            p if p >= 50.0 => theme.error,
+            p if p >= 30.0 => theme.highlight,
+            _ => theme.success,
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("Meetings: ", label),
+                Span::raw(format!("{} ({:.1}h total)", stats.meetings, stats.total_hours)),
+            ]),
+            Line::from(vec![
+                Span::styled("Work week in meetings: ", label),
+                Span::styled(format!("{:.0}%", stats.meeting_percent), Style::default().fg(load_color)),
+                Span::styled(format!(" of {:.0}h working time", stats.working_hours), Style::default().fg(theme.muted)),
+            ]),
+        ];
+
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+
+    fn render_days(f: &mut Frame, area: Rect, stats: &crate::meeting_stats::MeetingStats, theme: &Theme) {
+        use ratatui::widgets::{Bar, BarChart, BarGroup};
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Meeting hours by day ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        if stats.days.is_empty() {
+            let text = Paragraph::new("(No working days in this window)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        }
+
+        // Bars share the width; labels shrink to fit longer windows
+        let bar_width = (area.width.saturating_sub(2) / stats.days.len() as u16).saturating_sub(1).max(1);
+        let label = |day: &crate::meeting_stats::DayLoad| match bar_width {
+            w if w >= 16 => format!("{} {:.1}h free", day.date.format("%a %d"), day.longest_free_minutes as f64 / 60.0),
+            w if w >= 6 => day.date.format("%a %d").to_string(),
+            _ => day.date.format("%d").to_string(),
+        };
+
+        // Bars are in tenths of an hour so short meetings still show up
+        let bars: Vec<Bar> = stats.days.iter()
+            .map(|day| {
+                Bar::default()
+                    .value((day.meeting_hours * 10.0).round() as u64)
+                    .text_value(format!("{:.1}h", day.meeting_hours))
+                    .label(Line::from(label(day)))
+                    .style(Style::default().fg(theme.accent))
+            })
+            .collect();
+
+        let max_hours = stats.days.iter().map(|d| d.meeting_hours).fold(1.0, f64::max);
+        let chart = BarChart::default()
+            .block(block)
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(bar_width)
+            .bar_gap(1)
+            .max((max_hours * 10.0).round() as u64);
+
+        f.render_widget(chart, area);
+    }
+
+    fn render_recurring(f: &mut Frame, area: Rect, stats: &crate::meeting_stats::MeetingStats, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Top recurring meetings ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        if stats.top_recurring.is_empty() {
+            let text = Paragraph::new("(No recurring meetings)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(text, area);
+            return;
+        }
+
+        let header = Row::new(["Meeting", "Times", "Hours"]
+            .iter()
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.label).add_modifier(Modifier::BOLD))));
+
+        let rows: Vec<Row> = stats.top_recurring.iter()
+            .map(|load| {
+                Row::new(vec![
+                    Cell::from(load.summary.clone()),
+                    Cell::from(load.occurrences.to_string()),
+                    Cell::from(format!("{:.1}", load.hours)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(20),        // Meeting
+                Constraint::Length(7),      // Occurrences
+                Constraint::Length(7),      // Hours
+            ],
+        )
+        .header(header)
+        .block(block);
+
+        f.render_widget(table, area);
+    }
+}
+
+// `qq config ui`: [ui.theme] and [working_hours] as a form. The whole screen is drawn with
+// the theme being edited, so changes show up as they're made.
+pub struct SettingsForm {
+    preset: ThemePreset,
+    accent: String,
+    selection: SelectionStyle,
+    start: String,
+    end: String,
+    days: String,
+    field: usize,
+    changed: bool,
+    // Set by ESC with unsaved changes; a second ESC discards them
+    confirm_discard: bool,
+}
+
+impl SettingsForm {
+    const FIELDS: [&'static str; 6] = ["Theme", "Accent color", "Selected row", "Workday starts", "Workday ends", "Working days"];
+    const PRESETS: [ThemePreset; 2] = [ThemePreset::Dark, ThemePreset::Light];
+    const SELECTIONS: [SelectionStyle; 4] = [SelectionStyle::Arrow, SelectionStyle::Bold, SelectionStyle::Reverse, SelectionStyle::Underline];
+
+    // Returns the new settings when they're saved with Enter, None when the form is closed
+    pub fn show(theme: &ThemeConfig, hours: &crate::google::WorkingHours) -> Result<Option<(ThemeConfig, crate::google::WorkingHours)>> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut form = Self {
+            preset: theme.preset,
+            accent: theme.accent.clone().unwrap_or_default(),
+            selection: theme.selection,
+            start: hours.start.format("%H:%M").to_string(),
+            end: hours.end.format("%H:%M").to_string(),
+            days: hours.days.iter().map(|day| day.to_string()).collect::<Vec<_>>().join(" "),
+            field: 0,
+            changed: false,
+            confirm_discard: false,
+        };
+
+        let mut saved = None;
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| form.draw(f))?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.code == KeyCode::Esc {
+                    if form.changed && !form.confirm_discard {
+                        form.confirm_discard = true;
+                    } else {
+                        should_quit = true;
+                    }
+                    continue;
+                }
+                form.confirm_discard = false;
+                if key.code == KeyCode::Enter {
+                    if let Ok(settings) = form.settings() {
+                        saved = Some(settings);
+                        should_quit = true;
+                    }
+                    continue;
+                }
+                form.handle_key(&key);
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(saved)
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) {
+        let cycle = |len: usize, index: usize| match key.code {
+            KeyCode::Left => (index + len - 1) % len,
+            _ => (index + 1) % len,
+        };
+        match (key.code, Self::FIELDS[self.field]) {
+            (KeyCode::Tab | KeyCode::Down, _) => self.field = (self.field + 1) % Self::FIELDS.len(),
+            (KeyCode::BackTab | KeyCode::Up, _) => self.field = (self.field + Self::FIELDS.len() - 1) % Self::FIELDS.len(),
+            (KeyCode::Left | KeyCode::Right, "Theme") => {
+                let index = Self::PRESETS.iter().position(|p| *p == self.preset).unwrap_or(0);
+                self.preset = Self::PRESETS[cycle(Self::PRESETS.len(), index)];
+                self.changed = true;
+            }
+            (KeyCode::Left | KeyCode::Right, "Selected row") => {
+                let index = Self::SELECTIONS.iter().position(|s| *s == self.selection).unwrap_or(0);
+                self.selection = Self::SELECTIONS[cycle(Self::SELECTIONS.len(), index)];
+                self.changed = true;
+            }
+            (KeyCode::Backspace, name) => {
+                if let Some(text) = self.text_mut(name) {
+                    text.pop();
+                    self.changed = true;
+                }
+            }
+            (KeyCode::Char(c), name) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(text) = self.text_mut(name) {
+                    text.push(c);
+                    self.changed = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text_mut(&mut self, field: &str) -> Option<&mut String> {
+        match field {
+            "Accent color" => Some(&mut self.accent),
+            "Workday starts" => Some(&mut self.start),
+            "Workday ends" => Some(&mut self.end),
+            "Working days" => Some(&mut self.days),
+            _ => None,
+        }
+    }
+
+    fn theme_config(&self) -> ThemeConfig {
+        let accent = self.accent.trim();
+        ThemeConfig {
+            preset: self.preset,
+            accent: (!accent.is_empty()).then(|| accent.to_string()),
+            selection: self.selection,
+        }
+    }
+
+    // The same checks `qq config validate` makes, so a saved form always loads
+    fn settings(&self) -> Result<(ThemeConfig, crate::google::WorkingHours), String> {
+        let theme = self.theme_config();
+        Theme::new(&theme).map_err(|e| e.to_string())?;
+
+        let time = |label: &str, value: &str| {
+            chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M")
+                .map_err(|_| format!("{} '{}' isn't a 24-hour time like 09:00", label, value.trim()))
+        };
+        let start = time("Start", &self.start)?;
+        let end = time("End", &self.end)?;
+        if start >= end {
+            return Err(format!("The workday starts at {} but ends at {}", self.start.trim(), self.end.trim()));
+        }
+        let days = self.days
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|day| !day.is_empty())
+            .map(|day| day.parse::<chrono::Weekday>().map_err(|_| format!("'{}' isn't a day of the week", day)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if days.is_empty() {
+            return Err("Pick at least one working day".to_string());
+        }
+        Ok((theme, crate::google::WorkingHours { start, end, days }))
+    }
+
+    fn draw(&self, f: &mut Frame) {
+        // Falls back to the preset's accent while a color is still being typed
+        let draft = self.theme_config();
+        let Ok(t) = Theme::new(&draft).or_else(|_| Theme::new(&ThemeConfig { accent: None, ..draft })) else {
+            return;
+        };
+        let settings = self.settings();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(Self::FIELDS.len() as u16 + 5),    // Form
+                Constraint::Min(0),       // Preview
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        let choice = |value: &str| format!("◂ {} ▸", value);
+        let values = [
+            choice(match self.preset {
+                ThemePreset::Dark => "dark",
+                ThemePreset::Light => "light",
+            }),
+            if self.accent.is_empty() { "(preset)".to_string() } else { self.accent.clone() },
+            choice(match self.selection {
+                SelectionStyle::Arrow => "arrow",
+                SelectionStyle::Bold => "bold",
+                SelectionStyle::Reverse => "reverse",
+                SelectionStyle::Underline => "underline",
+            }),
+            self.start.clone(),
+            self.end.clone(),
+            self.days.clone(),
+        ];
+        let mut lines: Vec<Line> = Self::FIELDS.iter()
+            .zip(values)
+            .enumerate()
+            .map(|(idx, (label, value))| {
+                let selected = idx == self.field;
+                let mut spans = vec![
+                    Span::styled(if selected { "➤ " } else { "  " }, Style::default().fg(t.accent)),
+                    Span::styled(format!("{:<16}", label), Style::default().fg(t.label)),
+                    Span::styled(value, Style::default().fg(t.text)),
+                ];
+                if selected && !matches!(*label, "Theme" | "Selected row") {
+                    spans.push(Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(match (&settings, self.confirm_discard) {
+            (_, true) => Line::from(Span::styled("Unsaved changes: ESC again to discard them, Enter to save", Style::default().fg(t.highlight))),
+            (Err(error), _) => Line::from(Span::styled(error.clone(), Style::default().fg(t.error))),
+            (Ok(_), _) => Line::from(""),
+        });
+        let hint = match Self::FIELDS[self.field] {
+            "Theme" => "←/→: Dark or light terminal background",
+            "Accent color" => "A name (magenta), 256-color index (208), or hex (#ff8800); empty for the preset's",
+            "Selected row" => "←/→: Extra style for the selected row, next to the ➤ arrow",
+            "Working days" => "Days separated by spaces, e.g. Mon Tue Wed Thu Fri",
+            _ => "24-hour time, e.g. 09:00",
+        };
+        lines.push(Line::from(Span::styled(hint, Style::default().fg(t.muted))));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Settings ")
+            .title_style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD));
+        f.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: false }), chunks[0]);
+
+        // Sample rows and messages in each of the theme's roles
+        let row = |selected: bool, key: &str, summary: &str, status: &str, status_color: Color| {
+            let style = if selected {
+                Style::default().fg(t.highlight).add_modifier(Modifier::BOLD).patch(t.selection)
+            } else {
+                Style::default().fg(t.text)
+            };
+            Line::from(vec![
+                Span::styled(if selected { "➤ " } else { "  " }, Style::default().fg(t.accent)),
+                Span::styled(format!("{:<10}{:<32}", key, summary), style),
+                Span::styled(status.to_string(), Style::default().fg(status_color)),
+            ])
+        };
+        let mut preview = vec![
+            row(false, "PROJ-101", "Fix the login redirect", "Done", t.success),
+            row(true, "PROJ-102", "Retry failed webhooks", "In Progress", t.highlight),
+            row(false, "PROJ-103", "Update the onboarding docs", "To Do", t.muted),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Reviewer: ", Style::default().fg(t.label)),
+                Span::styled("@you", Style::default().fg(t.special)),
+                Span::styled("  Link: ", Style::default().fg(t.label)),
+                Span::styled("https://example.atlassian.net/browse/PROJ-102", Style::default().fg(t.link).add_modifier(Modifier::UNDERLINED)),
+            ]),
+            Line::from(Span::styled("✓ Moved PROJ-101 to Done", Style::default().fg(t.success))),
+            Line::from(Span::styled("✗ Failed to reach Jira", Style::default().fg(t.error))),
+        ];
+        if let Ok((_, hours)) = &settings {
+            let days: Vec<String> = hours.days.iter().map(|day| day.to_string()).collect();
+            preview.push(Line::from(vec![
+                Span::styled("Working day: ", Style::default().fg(t.label)),
+                Span::styled(
+                    format!("{}, {}", crate::timesheet::format_duration(crate::timesheet::expected_seconds(hours)), days.join(" ")),
+                    Style::default().fg(t.text),
+                ),
+            ]));
+        }
+        if t.no_color {
+            preview.push(Line::from(""));
+            preview.push(Line::from(Span::styled("NO_COLOR is set, so colors stay off whatever the theme", Style::default().fg(t.muted))));
+        }
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Preview ")
+            .title_style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD));
+        f.render_widget(Paragraph::new(preview).block(block), chunks[1]);
+
+        let help = Paragraph::new("Tab/↑/↓: Field | ←/→: Change | Enter: Save | ESC: Cancel")
+            .style(Style::default().fg(t.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+    }
+}
+
+pub struct TimesheetDisplay;
+
+impl TimesheetDisplay {
+    pub fn show(sheet: &crate::timesheet::Timesheet, hours: &crate::google::WorkingHours, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| Self::draw(f, sheet, hours, theme))?;
+
+            if let Event::Key(key) = event::read()?
+                && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+            {
+                should_quit = true;
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn draw(f: &mut Frame, sheet: &crate::timesheet::Timesheet, hours: &crate::google::WorkingHours, theme: &Theme) {
+        use crate::timesheet::{expected_seconds, format_duration};
+        use chrono::Datelike;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(0),       // Timesheet table
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " Timesheet: {} → {} · {} logged ",
+                sheet.from.format("%b %d"),
+                sheet.days.last().unwrap_or(&sheet.from).format("%b %d"),
+                format_duration(sheet.total())
+            ))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let header_style = Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD);
+        let mut header_cells = vec![Cell::from("Key").style(header_style), Cell::from("Summary").style(header_style)];
+        header_cells.extend(sheet.days.iter().map(|day| Cell::from(day.format("%a %d").to_string()).style(header_style)));
+        header_cells.push(Cell::from("Total").style(header_style));
+
+        let mut rows: Vec<Row> = sheet.rows.iter()
+            .map(|row| {
+                let mut cells = vec![Cell::from(row.key.clone()), Cell::from(row.summary.clone())];
+                cells.extend(row.seconds.iter().map(|seconds| {
+                    let style = if *seconds == 0 { Style::default().fg(theme.muted) } else { Style::default() };
+                    Cell::from(format_duration(*seconds)).style(style)
+                }));
+                cells.push(Cell::from(format_duration(row.total())).style(Style::default().add_modifier(Modifier::BOLD)));
+                Row::new(cells)
+            })
+            .collect();
+
+        // Day totals: green for a full working day, yellow when short, dim on days off
+        let today = chrono::Local::now().date_naive();
+        let expected = expected_seconds(hours);
+        let mut totals = vec![
+            Cell::from("Total").style(Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+            Cell::from(""),
+        ];
+        totals.extend(sheet.days.iter().enumerate().map(|(idx, day)| {
+            let logged = sheet.day_total(idx);
+            let color = if !hours.days.contains(&day.weekday()) || *day > today {
+                theme.muted
+            } else if logged >= expected {
+                theme.success
+            } else {
+                theme.highlight
+            };
+            Cell::from(format_duration(logged)).style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+        }));
+        totals.push(Cell::from(format_duration(sheet.total())).style(Style::default().add_modifier(Modifier::BOLD)));
+        rows.push(Row::new(Vec::<Cell>::new()));
+        rows.push(Row::new(totals));
+
+        let mut widths = vec![Constraint::Length(12), Constraint::Min(20)];
+        widths.extend(sheet.days.iter().map(|_| Constraint::Length(8)));
+        widths.push(Constraint::Length(8));
+
+        if sheet.rows.is_empty() {
+            let empty = Paragraph::new("(No work logged this week)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(empty, chunks[0]);
+        } else {
+            let table = Table::new(rows, widths)
+                .header(Row::new(header_cells).height(1))
+                .block(block);
+            f.render_widget(table, chunks[0]);
+        }
+
+        let help = Paragraph::new("q/ESC: Quit | Use --csv to export, --fill to log missing time")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+    }
+}
+
+// `qq jira sla`: open issues against their resolution targets, worst first
+pub struct SlaDisplay {
+    rows: Vec<(JiraIssue, SlaStatus)>,
+    selected_index: usize,
+    scroll_offset: usize,
+    viewport_height: usize,
+    search: Search,
+}
+
+impl SlaDisplay {
+    pub fn show(rows: Vec<(JiraIssue, SlaStatus)>, client: &JiraClient, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self {
+            rows,
+            selected_index: 0,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+            search: Search::default(),
+        };
+        let mut toasts = Toasts::default();
+        let mut should_quit = false;
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+                let navigated = app.search.navigate(&key, &mut app.selected_index, app.rows.len(), app.viewport_height, || {
+                    app.rows.iter().map(|(issue, _)| issue_search_text(issue)).collect()
+                });
+                match key.code {
+                    _ if navigated => {}
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Enter | KeyCode::Char('o') => {
+                        if let Some((issue, _)) = app.rows.get(app.selected_index)
+                            && let Err(e) = webbrowser::open(&client.browse_url(&issue.key))
+                        {
+                            toasts.error(format!("✗ Failed to open browser: {}", e));
+                        }
+                    }
+                    _ => {}
+                }
+                if app.selected_index < app.scroll_offset {
+                    app.scroll_offset = app.selected_index;
+                } else if app.selected_index >= app.scroll_offset + app.viewport_height {
+                    app.scroll_offset = app.selected_index + 1 - app.viewport_height;
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(0),       // SLA table
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        let breached = self.rows.iter().filter(|(_, sla)| sla.state == SlaState::Breached).count();
+        let at_risk = self.rows.iter().filter(|(_, sla)| sla.state == SlaState::AtRisk).count();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" SLA · {} breached · {} at risk ", breached, at_risk))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        if self.rows.is_empty() {
+            let empty = Paragraph::new("(No open issues are breaching or close to their SLA)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(empty, chunks[0]);
+        } else {
+            let inner = block.inner(chunks[0]);
+            f.render_widget(block, chunks[0]);
+            self.viewport_height = list_capacity(inner.width, inner.height);
+
+            let rows: Vec<ListRow> = self.rows.iter()
+                .enumerate()
+                .skip(self.scroll_offset)
+                .take(self.viewport_height)
+                .map(|(idx, (issue, sla))| {
+                    let selected = idx == self.selected_index;
+                    let remaining_color = match sla.state {
+                        SlaState::Breached => theme.error,
+                        SlaState::AtRisk => theme.highlight,
+                        SlaState::OnTrack => theme.muted,
+                    };
+                    let age = issue.fields.created_at()
+                        .map(|created| format!("{}d", (chrono::Utc::now() - created.with_timezone(&chrono::Utc)).num_days()))
+                        .unwrap_or_default();
+                    ListRow::new(vec![
+                        Line::from(row_indicator(selected, false, false)).style(Style::default().fg(theme.accent)),
+                        key_line(issue, theme),
+                        Line::from(sla.rule.clone()),
+                        Line::from(issue.fields.priority.as_ref().map(|p| p.name.clone()).unwrap_or_default()),
+                        Line::from(age).style(Style::default().fg(theme.muted)),
+                        Line::from(sla.remaining_label()).style(Style::default().fg(remaining_color)),
+                        Line::from(issue.fields.assignee.as_ref().map(|a| a.display_name.clone()).unwrap_or_else(|| "Unassigned".to_string())),
+                        Line::from(issue.fields.summary.clone()),
+                    ])
+                    .style(theme.selected(selected))
+                })
+                .collect();
+
+            const COLUMNS: [ListColumn; 8] = [
+                ListColumn::new("", Constraint::Length(2), 0),        // Arrow
+                ListColumn::new("Key", Constraint::Length(12), 0),
+                ListColumn::new("Rule", Constraint::Length(18), 3),
+                ListColumn::new("Priority", Constraint::Length(10), 4),
+                ListColumn::new("Age", Constraint::Length(6), 2),
+                ListColumn::new("Deadline", Constraint::Length(14), 0),
+                ListColumn::new("Assignee", Constraint::Length(18), 5),
+                ListColumn::new("Summary", Constraint::Min(20), 0),
+            ];
+            f.render_widget(fitted_table(&COLUMNS, rows, inner.width, theme), inner);
+        }
+
+        let help = Paragraph::new("↑/↓ j/k: Navigate | /: Search | Enter/o: Open in browser | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+        self.search.render(f, theme);
+        toasts.render(f, theme);
+    }
+}
+
+// Results of `qq jira search`, shown while later pages are still loading so large result
+// sets can be browsed without waiting for the last page
+pub struct PokerDisplay {
+    epic: JiraIssue,
+    issues: Vec<JiraIssue>,
+    // Points entered so far, by issue key; nothing is written until the session ends
+    estimates: HashMap<String, f64>,
+    selected_index: usize,
+    input: String,
+    description: TextScroll,
+    // Set by the first q/ESC when there are estimates that would be lost
+    confirm_discard: bool,
+}
+
+// Shown as a reminder; any number can be typed
+const POKER_SCALE: [&str; 7] = ["1", "2", "3", "5", "8", "13", "21"];
+
+impl PokerDisplay {
+    // Returns the estimates to write, in ticket order, or None when the session was abandoned
+    pub fn show(epic: &JiraIssue, issues: Vec<JiraIssue>, theme: &Theme) -> Result<Option<Vec<(String, f64)>>> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self {
+            epic: epic.clone(),
+            issues,
+            estimates: HashMap::new(),
+            selected_index: 0,
+            input: String::new(),
+            description: TextScroll::default(),
+            confirm_discard: false,
+        };
+        let mut toasts = Toasts::default();
+
+        // Main loop
+        let outcome = loop {
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if toasts.handle_key(&key) {
+                continue;
+            }
+            let confirm_discard = std::mem::take(&mut app.confirm_discard);
+
+            match key.code {
+                KeyCode::Char(c) if (c.is_ascii_digit() || (c == '.' && !app.input.contains('.'))) && app.input.len() < 5 => {
+                    app.input.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input.pop();
+                }
+                KeyCode::Enter => {
+                    if !app.input.is_empty() {
+                        match app.input.parse::<f64>() {
+                            Ok(points) => {
+                                let key = app.issues[app.selected_index].key.clone();
+                                app.estimates.insert(key, points);
+                            }
+                            Err(_) => {
+                                toasts.error(format!("✗ '{}' isn't a number of points", app.input));
+                                continue;
+                            }
+                        }
+                    }
+                    if app.selected_index + 1 == app.issues.len() {
+                        toasts.info(format!("That's every ticket. w: Write {} estimate(s)", app.estimates.len()));
+                    }
+                    app.select(app.selected_index + 1);
+                }
+                KeyCode::Tab | KeyCode::Right | KeyCode::Char('n') => app.select(app.selected_index + 1),
+                KeyCode::BackTab | KeyCode::Left | KeyCode::Char('p') => app.select(app.selected_index.saturating_sub(1)),
+                KeyCode::Char('x') => {
+                    app.estimates.remove(&app.issues[app.selected_index].key);
+                    app.input.clear();
+                }
+                KeyCode::Up | KeyCode::Char('k') => app.description.scroll_by(-1),
+                KeyCode::Down | KeyCode::Char('j') => app.description.scroll_by(1),
+                KeyCode::PageUp => app.description.scroll_by(-(app.description.page as i16 / 2).max(1)),
+                KeyCode::PageDown => app.description.scroll_by((app.description.page as i16 / 2).max(1)),
+                KeyCode::Char('w') => {
+                    let estimates = app.issues.iter()
+                        .filter_map(|issue| app.estimates.get(&issue.key).map(|points| (issue.key.clone(), *points)))
+                        .collect();
+                    break Some(estimates);
+                }
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    if app.estimates.is_empty() || confirm_discard {
+                        break None;
+                    }
+                    app.confirm_discard = true;
+                    toasts.info(format!("Press q again to discard {} estimate(s), or w to write them", app.estimates.len()));
+                }
+                _ => {}
+            }
+        };
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(outcome)
+    }
+
+    // Moves to another ticket, starting the input from its estimate so far
+    fn select(&mut self, index: usize) {
+        self.selected_index = index.min(self.issues.len().saturating_sub(1));
+        self.input = self.estimates.get(&self.issues[self.selected_index].key)
+            .map(|points| format_points(*points))
+            .unwrap_or_default();
+        self.description = TextScroll::default();
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(0),        // Tickets and the current card
+                Constraint::Length(3),     // Points input
+                Constraint::Length(2),     // Help text
+            ])
+            .split(f.area());
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+            .split(chunks[0]);
+
+        self.render_list(f, columns[0], theme);
+        self.render_card(f, columns[1], theme);
+        self.render_input(f, chunks[1], theme);
+
+        let help = Paragraph::new("0-9: Points | Enter: Save & next | Tab/n: Skip | p: Back | x: Clear | j/k: Scroll | w: Write all | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+        render_jira_label(f, theme);
+        toasts.render(f, theme);
+    }
+
+    fn render_list(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let total: f64 = self.estimates.values().sum();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {}/{} estimated · {} pts ", self.estimates.len(), self.issues.len(), format_points(total)))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let items: Vec<ListItem> = self.issues.iter()
+            .enumerate()
+            .map(|(idx, issue)| {
+                let selected = idx == self.selected_index;
+                let points = match self.estimates.get(&issue.key) {
+                    Some(points) => Span::styled(format!("{:>4}", format_points(*points)), Style::default().fg(theme.success)),
+                    None => Span::styled(format!("{:>4}", "·"), Style::default().fg(theme.muted)),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(row_indicator(selected, false, false), Style::default().fg(theme.accent)),
+                    Span::raw(format!(" {} ", issue.key)),
+                    points,
+                    Span::raw(format!("  {}", issue.fields.summary)),
+                ]))
+                .style(theme.selected(selected))
+            })
+            .collect();
+
+        let mut state = ListState::default();
+        state.select(Some(self.selected_index));
+        f.render_stateful_widget(List::new(items).block(block), area, &mut state);
+    }
+
+    fn render_card(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let issue = &self.issues[self.selected_index];
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} · {} ", self.epic.key, self.epic.fields.summary))
+            .title_style(Style::default().fg(theme.muted));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let label = Style::default().fg(theme.label).add_modifier(Modifier::BOLD);
+        let issue_type = issue.fields.issuetype.as_ref().map(|t| t.name.as_str()).unwrap_or("Issue");
+        let header = vec![
+            Line::from(vec![
+                Span::styled(issue.key.clone(), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  {} · {}", issue_type, issue.fields.status.name), Style::default().fg(theme.muted)),
+            ]),
+            Line::from(Span::styled(issue.fields.summary.clone(), Style::default().add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(Span::styled("Description", label)),
+        ];
+        let header_height = header.len() as u16;
+        f.render_widget(
+            Paragraph::new(header).wrap(Wrap { trim: false }),
+            Rect { height: header_height.min(inner.height), ..inner },
+        );
+
+        let body = Rect {
+            y: inner.y + header_height,
+            height: inner.height.saturating_sub(header_height),
+            ..inner
+        };
+        let lines = match &issue.fields.description {
+            Some(description) => crate::jira::adf::to_text(description, theme).lines,
+            None => vec![Line::from(Span::styled("(No description)", Style::default().fg(theme.muted)))],
+        };
+        let paragraph = self.description.paragraph(lines, body);
+        f.render_widget(paragraph, body);
+    }
+
+    fn render_input(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Points ")
+            .title_style(Style::default().fg(theme.label));
+        let line = Line::from(vec![
+            Span::styled(self.input.clone(), Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)),
+            Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            Span::styled(format!("    {}", POKER_SCALE.join(" · ")), Style::default().fg(theme.muted)),
+        ]);
+        f.render_widget(Paragraph::new(line).block(block), area);
+    }
+}
+
+pub struct SearchResultsDisplay {
+    jql: String,
+    issues: Vec<JiraIssue>,
+    // Jira's estimate of the matches, capped at --limit
+    expected: u64,
+    loader: Option<mpsc::Receiver<Result<Vec<JiraIssue>>>>,
+    failed: Option<String>,
+    selected_index: usize,
+    scroll_offset: usize,
+    viewport_height: usize,
+    search: Search,
+}
+
+impl SearchResultsDisplay {
+    // Returns the issues loaded before the view was closed
+    pub fn show(jql: &str, limit: Option<usize>, client: &JiraClient, theme: &Theme) -> Result<Vec<JiraIssue>> {
+        // Counting first reports a bad query before the terminal is taken over
+        let total = client.count_jql(jql)?;
+        let expected = limit.map_or(total, |limit| total.min(limit as u64));
+
+        // The loader stops at the next page once the receiver is dropped
+        let (sender, receiver) = mpsc::channel();
+        let loader_client = client.clone();
+        let loader_jql = jql.to_string();
+        std::thread::spawn(move || {
+            let result = loader_client.search_jql_pages(&loader_jql, limit, |page| sender.send(Ok(page)).is_ok());
+            if let Err(e) = result {
+                let _ = sender.send(Err(e));
+            }
+        });
+
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self {
+            jql: jql.to_string(),
+            issues: Vec::new(),
+            expected,
+            loader: Some(receiver),
+            failed: None,
+            selected_index: 0,
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+            search: Search::default(),
+        };
+        let mut toasts = Toasts::default();
+        let mut should_quit = false;
+        let mut clipboard = crate::clipboard::Clipboard::default();
+
+        // Main loop
+        while !should_quit {
+            if let Some(loader) = &app.loader {
+                let mut finished = false;
+                loop {
+                    match loader.try_recv() {
+                        Ok(Ok(page)) => app.issues.extend(page),
+                        Ok(Err(e)) => {
+                            toasts.error(format!("✗ Search stopped: {}", e));
+                            app.failed = Some(e.to_string());
+                        }
+                        Err(mpsc::TryRecvError::Empty) => break,
+                        Err(mpsc::TryRecvError::Disconnected) => {
+                            finished = true;
+                            break;
+                        }
+                    }
+                }
+                if finished {
+                    app.loader = None;
+                }
+            }
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+                let navigated = app.search.navigate(&key, &mut app.selected_index, app.issues.len(), app.viewport_height, || {
+                    app.issues.iter().map(issue_search_text).collect()
+                });
+                match key.code {
+                    _ if navigated => {}
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Enter | KeyCode::Char('o') => {
+                        if let Some(issue) = app.issues.get(app.selected_index)
+                            && let Err(e) = webbrowser::open(&client.browse_url(&issue.key))
+                        {
+                            toasts.error(format!("✗ Failed to open browser: {}", e));
+                        }
+                    }
+                    KeyCode::Char('y') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            match clipboard.copy(&issue.key) {
+                                Ok(_) => toasts.success(format!("✓ {} copied to clipboard", issue.key)),
+                                Err(e) => toasts.error(format!("✗ {}", e)),
+                            }
+                        }
+                    }
+                    KeyCode::Char('v') => {
+                        if let Some(issue) = app.issues.get(app.selected_index) {
+                            let issue = issue.clone();
+                            // Temporarily restore terminal for nested UI
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            terminal.show_cursor()?;
+                            
+                            let _ = JiraIssueDisplay::show(&issue, client, theme);
+                            
+                            // Re-setup terminal
+                            enable_raw_mode()?;
+                            let mut stdout = io::stdout();
+                            execute!(stdout, EnterAlternateScreen)?;
+                            let backend = CrosstermBackend::new(stdout);
+                            terminal = Terminal::new(backend)?;
+                        }
+                    }
+                    _ => {}
+                }
+                if app.selected_index < app.scroll_offset {
+                    app.scroll_offset = app.selected_index;
+                } else if app.selected_index >= app.scroll_offset + app.viewport_height {
+                    app.scroll_offset = app.selected_index + 1 - app.viewport_height;
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(app.issues)
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(0),       // Results table
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        // The count grows as pages arrive; Jira's total is an estimate, so it's marked as one
+        let progress = if self.loader.is_some() {
+            format!("{} of ~{} · loading...", self.issues.len(), self.expected.max(self.issues.len() as u64))
+        } else if self.failed.is_some() {
+            format!("{} loaded · stopped early", self.issues.len())
+        } else {
+            format!("{} issue(s)", self.issues.len())
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Search · {} ", progress))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(format!(" {} ", self.jql)).style(Style::default().fg(theme.muted)));
+
+        if self.issues.is_empty() {
+            let message = match (&self.loader, &self.failed) {
+                (Some(_), _) => "Loading the first page...".to_string(),
+                (None, Some(e)) => format!("Search failed: {}", e),
+                (None, None) => "(No issues match this search)".to_string(),
+            };
+            let empty = Paragraph::new(message)
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(empty, chunks[0]);
+        } else {
+            let inner = block.inner(chunks[0]);
+            f.render_widget(block, chunks[0]);
+            self.viewport_height = list_capacity(inner.width, inner.height);
+
+            let rows: Vec<ListRow> = self.issues.iter()
+                .enumerate()
+                .skip(self.scroll_offset)
+                .take(self.viewport_height)
+                .map(|(idx, issue)| {
+                    let selected = idx == self.selected_index;
+                    let status_style = match issue.fields.status.name.to_lowercase().as_str() {
+                        s if s.contains("done") || s.contains("closed") => Style::default().fg(theme.success),
+                        s if s.contains("progress") => Style::default().fg(theme.highlight),
+                        s if s.contains("review") => Style::default().fg(theme.special),
+                        _ => Style::default().fg(theme.text),
+                    };
+                    ListRow::new(vec![
+                        Line::from(row_indicator(selected, false, false)).style(Style::default().fg(theme.accent)),
+                        key_line(issue, theme),
+                        Line::from(issue.fields.issuetype.as_ref().map(|t| t.name.clone()).unwrap_or_default()),
+                        Line::from(issue.fields.status.name.clone()).style(status_style),
+                        Line::from(issue.fields.assignee.as_ref().map(|a| a.display_name.clone()).unwrap_or_else(|| "Unassigned".to_string())),
+                        Line::from(issue.fields.summary.clone()),
+                    ])
+                    .style(theme.selected(selected))
+                })
+                .collect();
+
+            const COLUMNS: [ListColumn; 6] = [
+                ListColumn::new("", Constraint::Length(2), 0),        // Arrow
+                ListColumn::new("Key", Constraint::Length(12), 0),
+                ListColumn::new("Type", Constraint::Length(10), 1),
+                ListColumn::new("Status", Constraint::Length(16), 0),
+                ListColumn::new("Assignee", Constraint::Length(18), 2),
+                ListColumn::new("Summary", Constraint::Min(20), 0),
+            ];
+            f.render_widget(fitted_table(&COLUMNS, rows, inner.width, theme), inner);
+        }
+
+        let help = Paragraph::new("↑/↓ j/k: Navigate | /: Search | v: View | Enter/o: Open in browser | y: Copy key | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+        self.search.render(f, theme);
+        toasts.render(f, theme);
+    }
+}
+
+pub struct ActivityFeedDisplay {
+    scope: String,
+    events: Vec<FeedEvent>,
+    loader: Option<mpsc::Receiver<Result<Vec<FeedEvent>>>>,
+    failed: Option<String>,
+    selected_index: usize,
+    scroll_offset: usize,
+    viewport_height: usize,
+    search: Search,
+}
+
+impl ActivityFeedDisplay {
+    pub fn show(scope: Scope, days: i64, client: &JiraClient, theme: &Theme) -> Result<()> {
+        // The loader stops at the next page once the receiver is dropped
+        let (sender, receiver) = mpsc::channel();
+        let loader_client = client.clone();
+        let label = scope.label();
+        std::thread::spawn(move || {
+            let result = crate::activity::load(&loader_client, &scope, days, |events| sender.send(Ok(events)).is_ok());
+            if let Err(e) = result {
+                let _ = sender.send(Err(e));
+            }
+        });
+
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
 
         let mut app = Self {
+            scope: format!("{} · last {} day(s)", label, days),
+            events: Vec::new(),
+            loader: Some(receiver),
+            failed: None,
             selected_index: 0,
-            children,
             scroll_offset: 0,
             viewport_height: 20, // Will be updated during first render
+            search: Search::default(),
         };
-        
+        let mut toasts = Toasts::default();
         let mut should_quit = false;
-        let mut message: Option<String> = None;
+        let mut clipboard = crate::clipboard::Clipboard::default();
 
         // Main loop
         while !should_quit {
-            terminal.draw(|f| app.draw(f, epic, &message))?;
+            let mut pages = Vec::new();
+            if let Some(loader) = &app.loader {
+                let mut finished = false;
+                loop {
+                    match loader.try_recv() {
+                        Ok(Ok(events)) => pages.push(events),
+                        Ok(Err(e)) => {
+                            toasts.error(format!("✗ Loading stopped: {}", e));
+                            app.failed = Some(e.to_string());
+                        }
+                        Err(mpsc::TryRecvError::Empty) => break,
+                        Err(mpsc::TryRecvError::Disconnected) => {
+                            finished = true;
+                            break;
+                        }
+                    }
+                }
+                if finished {
+                    app.loader = None;
+                }
+            }
+            for page in pages {
+                app.merge(page);
+            }
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
 
+            if !toasts.poll()? {
+                continue;
+            }
             if let Event::Key(key) = event::read()? {
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+                let navigated = app.search.navigate(&key, &mut app.selected_index, app.events.len(), app.viewport_height, || {
+                    app.events.iter().map(|event| format!("{} {} {} {}", event.key, event.author, event.describe(), event.summary)).collect()
+                });
                 match key.code {
+                    _ if navigated => {}
                     KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
-                    KeyCode::Up => {
-                        if app.selected_index > 0 {
-                            app.selected_index -= 1;
-                            // Estimate viewport height - can be refined based on terminal size
-                            app.update_scroll_offset(app.viewport_height);
+                    KeyCode::Enter | KeyCode::Char('v') => {
+                        if let Some(event) = app.events.get(app.selected_index) {
+                            let issue_key = event.key.clone();
+                            toasts.info(format!("Loading {}...", issue_key));
+                            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+                            match client.get_issue(&issue_key) {
+                                Ok(issue) => {
+                                    // Temporarily restore terminal for nested UI
+                                    disable_raw_mode()?;
+                                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                                    terminal.show_cursor()?;
+
+                                    let _ = JiraIssueDisplay::show(&issue, client, theme);
+
+                                    // Re-setup terminal
+                                    enable_raw_mode()?;
+                                    let mut stdout = io::stdout();
+                                    execute!(stdout, EnterAlternateScreen)?;
+                                    let backend = CrosstermBackend::new(stdout);
+                                    terminal = Terminal::new(backend)?;
+                                }
+                                Err(e) => toasts.error(format!("✗ Failed to load {}: {}", issue_key, e)),
+                            }
                         }
                     }
-                    KeyCode::Down => {
-                        if app.selected_index < app.children.len().saturating_sub(1) {
-                            app.selected_index += 1;
-                            // Estimate viewport height - can be refined based on terminal size
-                            app.update_scroll_offset(app.viewport_height);
+                    KeyCode::Char('o') => {
+                        if let Some(event) = app.events.get(app.selected_index)
+                            && let Err(e) = webbrowser::open(&client.browse_url(&event.key))
+                        {
+                            toasts.error(format!("✗ Failed to open browser: {}", e));
                         }
                     }
-                    KeyCode::Char('a') => {
-                        if let Some(issue) = app.children.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            // Temporarily restore terminal for assignee selection
+                    KeyCode::Char('y') => {
+                        if let Some(event) = app.events.get(app.selected_index) {
+                            match clipboard.copy(&event.key) {
+                                Ok(_) => toasts.success(format!("✓ {} copied to clipboard", event.key)),
+                                Err(e) => toasts.error(format!("✗ {}", e)),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                if app.selected_index < app.scroll_offset {
+                    app.scroll_offset = app.selected_index;
+                } else if app.selected_index >= app.scroll_offset + app.viewport_height {
+                    app.scroll_offset = app.selected_index + 1 - app.viewport_height;
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    // Later pages can hold newer events than earlier ones, so each page is sorted in. The
+    // selection stays on the event it was on.
+    fn merge(&mut self, events: Vec<FeedEvent>) {
+        let selected = self.events.get(self.selected_index).map(|event| (event.key.clone(), event.at));
+        self.events.extend(events);
+        self.events.sort_by_key(|event| std::cmp::Reverse(event.at));
+        self.selected_index = selected
+            .and_then(|(key, at)| self.events.iter().position(|event| event.key == key && event.at == at))
+            .unwrap_or(0);
+        if self.selected_index >= self.scroll_offset + self.viewport_height {
+            self.scroll_offset = self.selected_index + 1 - self.viewport_height;
+        }
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Min(0),       // Feed
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        let progress = if self.loader.is_some() {
+            format!("{} event(s) · loading...", self.events.len())
+        } else if self.failed.is_some() {
+            format!("{} event(s) · stopped early", self.events.len())
+        } else {
+            format!("{} event(s)", self.events.len())
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Activity · {} ", progress))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+            .title_bottom(Line::from(format!(" {} ", self.scope)).style(Style::default().fg(theme.muted)));
+
+        if self.events.is_empty() {
+            let message = match (&self.loader, &self.failed) {
+                (Some(_), _) => "Loading recent activity...".to_string(),
+                (None, Some(e)) => format!("Loading failed: {}", e),
+                (None, None) => "(No activity in this window)".to_string(),
+            };
+            let empty = Paragraph::new(message)
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center)
+                .block(block);
+            f.render_widget(empty, chunks[0]);
+        } else {
+            let inner = block.inner(chunks[0]);
+            f.render_widget(block, chunks[0]);
+            self.viewport_height = list_capacity(inner.width, inner.height);
+
+            let rows: Vec<ListRow> = self.events.iter()
+                .enumerate()
+                .skip(self.scroll_offset)
+                .take(self.viewport_height)
+                .map(|(idx, event)| {
+                    let selected = idx == self.selected_index;
+                    let event_style = match &event.kind {
+                        EventKind::Created => Style::default().fg(theme.success),
+                        EventKind::Status { .. } => Style::default().fg(theme.highlight),
+                        EventKind::Comment { .. } => Style::default().fg(theme.text),
+                    };
+                    let when = event.at.with_timezone(&chrono::Local).format("%a %b %-d %H:%M").to_string();
+                    ListRow::new(vec![
+                        Line::from(row_indicator(selected, false, false)).style(Style::default().fg(theme.accent)),
+                        Line::from(when).style(Style::default().fg(theme.muted)),
+                        Line::from(event.key.clone()).style(Style::default().fg(theme.accent)),
+                        Line::from(event.author.clone()),
+                        Line::from(event.describe()).style(event_style),
+                        Line::from(event.summary.clone()),
+                    ])
+                    .style(theme.selected(selected))
+                })
+                .collect();
+
+            const COLUMNS: [ListColumn; 6] = [
+                ListColumn::new("", Constraint::Length(2), 0),        // Arrow
+                ListColumn::new("When", Constraint::Length(17), 0),
+                ListColumn::new("Key", Constraint::Length(12), 0),
+                ListColumn::new("Who", Constraint::Length(18), 2),
+                ListColumn::new("Event", Constraint::Min(24), 0),
+                ListColumn::new("Summary", Constraint::Min(20), 1),
+            ];
+            f.render_widget(fitted_table(&COLUMNS, rows, inner.width, theme), inner);
+        }
+
+        let help = Paragraph::new("↑/↓ j/k: Navigate | /: Search | Enter/v: View issue | o: Open in browser | y: Copy key | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+        self.search.render(f, theme);
+        toasts.render(f, theme);
+    }
+}
+
+struct Lane {
+    assignee: String,
+    issues: Vec<JiraIssue>,
+}
+
+impl Lane {
+    fn open_issues(&self) -> impl Iterator<Item = &JiraIssue> {
+        self.issues.iter().filter(|i| !i.fields.is_done())
+    }
+}
+
+pub struct SwimlaneDisplay {
+    lanes: Vec<Lane>,
+    selected_lane: usize,
+    selected_issue: usize,
+    // First lane shown when there are more lanes than fit across the screen
+    lane_offset: usize,
+    visible_lanes: usize,
+    // Issues that fit in a lane, for half-page moves
+    lane_rows: usize,
+    search: Search,
+}
+
+impl SwimlaneDisplay {
+    const MIN_LANE_WIDTH: u16 = 30;
+
+    pub fn show(epic: &JiraIssue, children: Vec<JiraIssue>, client: &JiraClient, theme: &Theme) -> Result<()> {
+        let mut by_assignee: HashMap<String, Vec<JiraIssue>> = HashMap::new();
+        for issue in children {
+            let assignee = issue.fields.assignee.as_ref()
+                .map(|a| a.display_name.clone())
+                .unwrap_or_else(|| "Unassigned".to_string());
+            by_assignee.entry(assignee).or_default().push(issue);
+        }
+
+        let mut lanes: Vec<Lane> = by_assignee.into_iter()
+            .map(|(assignee, mut issues)| {
+                // Open work first within each lane
+                issues.sort_by_key(|i| i.fields.is_done());
+                Lane { assignee, issues }
+            })
+            .collect();
+        // Heaviest workload first, with unassigned work collected at the end
+        lanes.sort_by_key(|lane| (lane.assignee == "Unassigned", std::cmp::Reverse(lane.open_issues().count()), lane.assignee.clone()));
+
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let mut app = Self {
+            lanes,
+            selected_lane: 0,
+            selected_issue: 0,
+            lane_offset: 0,
+            visible_lanes: 1, // Will be updated during first render
+            lane_rows: 10,
+            search: Search::default(),
+        };
+        let mut should_quit = false;
+        let mut toasts = Toasts::default();
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, epic, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                // Searches every lane, left to right
+                match app.search.handle_key(&key) {
+                    SearchStep::Handled => continue,
+                    SearchStep::Jump { forward } => {
+                        let positions: Vec<(usize, usize)> = app.lanes.iter()
+                            .enumerate()
+                            .flat_map(|(lane, l)| (0..l.issues.len()).map(move |idx| (lane, idx)))
+                            .collect();
+                        let texts: Vec<String> = positions.iter()
+                            .map(|&(lane, idx)| issue_search_text(&app.lanes[lane].issues[idx]))
+                            .collect();
+                        let current = positions.iter()
+                            .position(|&p| p == (app.selected_lane, app.selected_issue))
+                            .unwrap_or(0);
+                        if let Some(found) = app.search.find(&texts, current, forward) {
+                            (app.selected_lane, app.selected_issue) = positions[found];
+                            app.clamp_selection();
+                        }
+                        continue;
+                    }
+                    SearchStep::Ignored => {}
+                }
+
+                if let Some(motion) = Motion::from_key(&key) {
+                    let lane_len = app.lanes.get(app.selected_lane).map_or(0, |l| l.issues.len());
+                    app.selected_issue = motion.apply(app.selected_issue, lane_len, app.lane_rows);
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Left if app.selected_lane > 0 => {
+                        app.selected_lane -= 1;
+                        app.clamp_selection();
+                    }
+                    KeyCode::Right if app.selected_lane + 1 < app.lanes.len() => {
+                        app.selected_lane += 1;
+                        app.clamp_selection();
+                    }
+                    KeyCode::Char('v') => {
+                        if let Some(issue) = app.selected() {
+                            let issue = issue.clone();
+                            // Temporarily restore terminal for nested UI
                             disable_raw_mode()?;
                             execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
                             terminal.show_cursor()?;
                             
-                            println!("Fetching assignable users for {}...", issue_key);
-                            
-                            // Get current user and assignable users
-                            let (current_user_id, selected_account_id) = match (
-                                client.get_current_user(),
-                                client.get_assignable_users(&issue_key)
-                            ) {
-                                (Ok(current_user), Ok(users)) => {
-                                    let current_id = current_user.account_id.clone();
-                                    let selected = AssigneeSelector::show(users, current_user.account_id)?;
-                                    (current_id, selected)
-                                }
-                                (Err(e), _) | (_, Err(e)) => {
-                                    eprintln!("Failed to fetch users: {}", e);
-                                    (String::new(), None)
-                                }
-                            };
+                            let _ = JiraIssueDisplay::show(&issue, client, theme);
                             
                             // Re-setup terminal
                             enable_raw_mode()?;
@@ -415,169 +8027,415 @@ impl EpicListDisplay {
                             execute!(stdout, EnterAlternateScreen)?;
                             let backend = CrosstermBackend::new(stdout);
                             terminal = Terminal::new(backend)?;
-                            
-                            if let Some(account_id) = selected_account_id {
-                                if account_id == "UNASSIGN" {
-                                    // Handle unassignment
-                                    message = Some(format!("Unassigning {} ...", issue_key));
-                                    terminal.draw(|f| app.draw(f, epic, &message))?;
-                                    
-                                    match client.assign_issue(&issue_key, None) {
-                                        Ok(_) => {
-                                            message = Some(format!("✓ {} unassigned", issue_key));
-                                            // Refresh the issue data
-                                            if let Ok(updated_issue) = client.get_issue(&issue_key) {
-                                                app.children[app.selected_index] = updated_issue;
-                                            }
-                                        }
-                                        Err(e) => {
-                                            message = Some(format!("✗ Failed to unassign {}: {}", issue_key, e));
-                                        }
-                                    }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn selected(&self) -> Option<&JiraIssue> {
+        self.lanes.get(self.selected_lane)?.issues.get(self.selected_issue)
+    }
+
+    // Keeps the issue cursor inside the new lane and the lane on screen
+    fn clamp_selection(&mut self) {
+        let lane_len = self.lanes.get(self.selected_lane).map_or(0, |l| l.issues.len());
+        self.selected_issue = self.selected_issue.min(lane_len.saturating_sub(1));
+
+        if self.selected_lane < self.lane_offset {
+            self.lane_offset = self.selected_lane;
+        } else if self.selected_lane >= self.lane_offset + self.visible_lanes {
+            self.lane_offset = self.selected_lane + 1 - self.visible_lanes;
+        }
+    }
+
+    fn draw(&mut self, f: &mut Frame, epic: &JiraIssue, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),    // Header
+                Constraint::Min(0),       // Lanes
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        let open_total: usize = self.lanes.iter().map(|l| l.open_issues().count()).sum();
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(format!("{} ", epic.key), Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+            Span::raw(epic.fields.summary.clone()),
+            Span::styled(
+                format!("  ({} open across {} lane(s))", open_total, self.lanes.len()),
+                Style::default().fg(theme.muted),
+            ),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title(" Swimlanes ").title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)));
+        f.render_widget(header, chunks[0]);
+
+        self.render_lanes(f, chunks[1], theme);
+
+        let help = Paragraph::new("←/→: Lane | ↑/↓ j/k: Issue | /: Search | v: View | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+        self.search.render(f, theme);
+        toasts.render(f, theme);
+    }
+
+    fn render_lanes(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        if self.lanes.is_empty() {
+            let empty = Paragraph::new("(This epic has no child issues)")
+                .style(Style::default().fg(theme.muted))
+                .alignment(Alignment::Center);
+            f.render_widget(empty, area);
+            return;
+        }
+
+        self.visible_lanes = ((area.width / Self::MIN_LANE_WIDTH) as usize).clamp(1, self.lanes.len());
+        // Two lines per issue inside the lane's borders
+        self.lane_rows = (area.height.saturating_sub(2) / 2).max(1) as usize;
+        self.clamp_selection();
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, self.visible_lanes as u32); self.visible_lanes])
+            .split(area);
+
+        for (column, (lane_idx, lane)) in columns.iter().zip(self.lanes.iter().enumerate().skip(self.lane_offset)) {
+            let is_selected_lane = lane_idx == self.selected_lane;
+            let open = lane.open_issues().count();
+            let points: f64 = lane.open_issues().filter_map(|i| i.fields.story_points).sum();
+            let title = if points > 0.0 {
+                format!(" {} · {} open · {} pts ", lane.assignee, open, format_points(points))
+            } else {
+                format!(" {} · {} open ", lane.assignee, open)
+            };
+            let border_style = if is_selected_lane {
+                Style::default().fg(theme.highlight)
+            } else {
+                Style::default()
+            };
+
+            let items: Vec<ListItem> = lane.issues.iter()
+                .map(|issue| {
+                    let style = if issue.fields.is_done() {
+                        Style::default().fg(theme.muted)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(vec![
+                        Line::from(vec![
+                            Span::styled(format!("{} ", issue.key), Style::default().fg(theme.label)),
+                            Span::styled(issue.fields.status.name.clone(), Style::default().fg(theme.highlight)),
+                        ]),
+                        Line::from(format!("  {}", issue.fields.summary)),
+                    ]).style(style)
+                })
+                .collect();
+
+            let list = List::new(items)
+                .block(Block::default().borders(Borders::ALL).title(title).border_style(border_style))
+                .highlight_style(Style::default().bg(theme.muted).add_modifier(Modifier::BOLD));
+
+            let mut state = ListState::default();
+            if is_selected_lane {
+                state.select(Some(self.selected_issue));
+            }
+            f.render_stateful_widget(list, *column, &mut state);
+        }
+    }
+}
+
+pub struct MeetingsListDisplay {
+    // Index into the meetings of the day being shown
+    selected_index: usize,
+    meetings: Vec<crate::google::Meeting>,
+    day: chrono::NaiveDate,
+    scroll_offset: usize,
+    viewport_height: usize,
+    note_mode: bool,
+    note_input: String,
+    search: Search,
+    // Tickets the meetings link to, keyed by issue key; filled in by a background load
+    tickets: HashMap<String, JiraIssue>,
+    ticket_loader: Option<mpsc::Receiver<HashMap<String, JiraIssue>>>,
+}
+
+// Days the list pages through: today and the rest of the week the calendar query covers
+const MEETING_DAYS: i64 = 7;
+
+fn meeting_day(meeting: &crate::google::Meeting) -> chrono::NaiveDate {
+    meeting.start_time.with_timezone(&chrono::Local).date_naive()
+}
+
+impl MeetingsListDisplay {
+    fn today() -> chrono::NaiveDate {
+        chrono::Local::now().date_naive()
+    }
+
+    fn day_meetings(&self) -> Vec<&crate::google::Meeting> {
+        self.meetings.iter().filter(|m| meeting_day(m) == self.day).collect()
+    }
+
+    fn selected(&self) -> Option<&crate::google::Meeting> {
+        self.day_meetings().get(self.selected_index).copied()
+    }
+
+    fn remove_selected(&mut self) {
+        let Some(id) = self.selected().map(|m| m.id.clone()) else {
+            return;
+        };
+        self.meetings.retain(|m| m.id != id);
+        self.selected_index = self.selected_index.min(self.day_meetings().len().saturating_sub(1));
+        self.update_scroll();
+    }
+
+    // Moves by whole days, staying within the week the calendar was queried for
+    fn go_to_day(&mut self, day: chrono::NaiveDate) {
+        let today = Self::today();
+        self.day = day.clamp(today, today + chrono::Duration::days(MEETING_DAYS - 1));
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    // The linked ticket, fetched now if the background load hasn't got to it
+    fn linked_issue(&mut self, key: &str, jira: Option<&JiraClient>) -> Result<JiraIssue> {
+        if let Some(issue) = self.tickets.get(key) {
+            return Ok(issue.clone());
+        }
+        let jira = jira.ok_or_else(|| anyhow::anyhow!("Jira is not configured. Run 'qq config jira' first."))?;
+        let issue = jira.get_issue(key)?;
+        self.tickets.insert(key.to_string(), issue.clone());
+        Ok(issue)
+    }
+
+    pub fn show(meetings: Vec<crate::google::Meeting>, client: &BlockingCalendarClient, jira: Option<&JiraClient>, theme: &Theme) -> Result<()> {
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        // One request per linked ticket, so show the list while they run. Keys that don't
+        // resolve (a title mentioning "UTF-8") are left out and show without a status.
+        let ticket_loader = jira.map(|jira| {
+            let (sender, receiver) = mpsc::channel();
+            let loader_client = jira.clone();
+            let mut keys: Vec<String> = meetings.iter().filter_map(|m| m.ticket.clone()).collect();
+            keys.sort();
+            keys.dedup();
+            std::thread::spawn(move || {
+                let tickets = keys.into_iter()
+                    .filter_map(|key| loader_client.get_issue(&key).ok().map(|issue| (key, issue)))
+                    .collect();
+                let _ = sender.send(tickets);
+            });
+            receiver
+        });
+
+        let mut app = Self {
+            selected_index: 0,
+            meetings,
+            day: Self::today(),
+            scroll_offset: 0,
+            viewport_height: 20, // Will be updated during first render
+            note_mode: false,
+            note_input: String::new(),
+            search: Search::default(),
+            tickets: HashMap::new(),
+            ticket_loader,
+        };
+        
+        let mut should_quit = false;
+        let mut toasts = Toasts::default();
+        let mut clipboard = crate::clipboard::Clipboard::default();
+
+        // Main loop
+        while !should_quit {
+            if let Some(loader) = &app.ticket_loader {
+                match loader.try_recv() {
+                    Ok(tickets) => {
+                        app.tickets.extend(tickets);
+                        app.ticket_loader = None;
+                    }
+                    Err(mpsc::TryRecvError::Disconnected) => app.ticket_loader = None,
+                    Err(mpsc::TryRecvError::Empty) => {}
+                }
+            }
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if app.note_mode {
+                    match key.code {
+                        KeyCode::Esc => {
+                            app.note_mode = false;
+                            app.note_input.clear();
+                            toasts.info("Decline cancelled".to_string());
+                        }
+                        KeyCode::Enter => {
+                            app.note_mode = false;
+                            let note = std::mem::take(&mut app.note_input);
+                            
+                            if let Some(meeting) = app.selected() {
+                                let meeting_id = meeting.id.clone();
+                                let meeting_summary = meeting.summary.clone();
+                                let is_organizer = meeting.is_organizer;
+                                
+                                toasts.info(if is_organizer {
+                                    format!("Cancelling {}...", meeting_summary)
                                 } else {
-                                    // Handle normal assignment
-                                    message = Some(format!("Assigning {} ...", issue_key));
-                                    terminal.draw(|f| app.draw(f, epic, &message))?;
-                                    
-                                    match client.assign_issue(&issue_key, Some(&account_id)) {
-                                        Ok(_) => {
-                                            let assignee_text = if account_id == current_user_id {
-                                                "you".to_string()
-                                            } else {
-                                                "selected user".to_string()
-                                            };
-                                            message = Some(format!("✓ {} assigned to {}", issue_key, assignee_text));
-                                            // Refresh the issue data
-                                            if let Ok(updated_issue) = client.get_issue(&issue_key) {
-                                                app.children[app.selected_index] = updated_issue;
-                                            }
-                                        }
-                                        Err(e) => {
-                                            message = Some(format!("✗ Failed to assign {}: {}", issue_key, e));
-                                        }
+                                    format!("Declining {}...", meeting_summary)
+                                });
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                
+                                let result = if is_organizer {
+                                    client.cancel_meeting(&meeting_id)
+                                } else {
+                                    let note = if note.trim().is_empty() { None } else { Some(note.trim()) };
+                                    client.decline_meeting(&meeting_id, note)
+                                };
+                                
+                                match result {
+                                    Ok(_) => {
+                                        let action = if is_organizer { "cancelled" } else { "declined" };
+                                        toasts.success(format!("✓ {} {}", meeting_summary, action));
+                                        app.remove_selected();
+                                    }
+                                    Err(e) => {
+                                        toasts.error(format!("✗ Failed to update {}: {}", meeting_summary, e));
                                     }
                                 }
-                            } else {
-                                message = Some("Assignment cancelled".to_string());
                             }
                         }
+                        KeyCode::Backspace => {
+                            app.note_input.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            app.note_input.push(c);
+                        }
+                        _ => {}
                     }
-                    KeyCode::Char('p') => {
-                        if let Some(issue) = app.children.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            message = Some(format!("Moving {} to In Progress...", issue_key));
-                            terminal.draw(|f| app.draw(f, epic, &message))?;
+                    continue;
+                }
+                
+                if !app.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                let summaries: Vec<String> = app.day_meetings().iter().map(|m| m.summary.clone()).collect();
+                if app.search.navigate(&key, &mut app.selected_index, summaries.len(), app.viewport_height, || summaries) {
+                    app.update_scroll();
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Left | KeyCode::Char('[') => app.go_to_day(app.day - chrono::Duration::days(1)),
+                    KeyCode::Right | KeyCode::Char(']') => app.go_to_day(app.day + chrono::Duration::days(1)),
+                    KeyCode::Char('t') => app.go_to_day(MeetingsListDisplay::today()),
+                    KeyCode::Enter | KeyCode::Char('o') => {
+                        if let Some(meeting) = app.selected() {
+                            let meeting_summary = meeting.summary.clone();
+                            let meeting_url = meeting.meeting_url.clone();
                             
-                            match client.transition_to_in_progress(&issue_key) {
-                                Ok(_) => {
-                                    message = Some(format!("✓ {} moved to In Progress", issue_key));
-                                    // Refresh the issue data
-                                    if let Ok(updated_issue) = client.get_issue(&issue_key) {
-                                        app.children[app.selected_index] = updated_issue;
-                                    }
-                                }
-                                Err(e) => {
-                                    message = Some(format!("✗ Failed to move {} to In Progress: {}", issue_key, e));
+                            if let Some(url) = meeting_url {
+                                toasts.info(format!("Opening meeting: {}", meeting_summary));
+                                terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                                
+                                if let Err(e) = webbrowser::open(&url) {
+                                    toasts.error(format!("✗ Failed to open browser: {}", e));
+                                } else {
+                                    toasts.success("✓ Opened meeting in browser".to_string());
                                 }
+                            } else {
+                                toasts.info(format!("No meeting URL available for: {}", meeting_summary));
                             }
                         }
                     }
-                    KeyCode::Char('s') => {
-                        if let Some(issue) = app.children.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            message = Some(format!("Starting {}...", issue_key));
-                            terminal.draw(|f| app.draw(f, epic, &message))?;
-                            
-                            // Create feature branch
-                            use git2::Repository;
-                            match Repository::open(".") {
-                                Ok(repo) => {
-                                    let branch_name = format!("feature/{}", issue_key);
-                                    
-                                    // Get the current HEAD commit
-                                    match repo.head()
-                                        .and_then(|head| head.target().ok_or_else(|| git2::Error::from_str("No HEAD target")))
-                                        .and_then(|target| repo.find_commit(target))
-                                    {
-                                        Ok(commit) => {
-                                            // Create and checkout the new branch
-                                            match repo.branch(&branch_name, &commit, false) {
-                                                Ok(_) => {
-                                                    if let Ok(obj) = repo.revparse_single(&format!("refs/heads/{}", branch_name)) {
-                                                        let _ = repo.checkout_tree(&obj, None);
-                                                        let _ = repo.set_head(&format!("refs/heads/{}", branch_name));
-                                                        
-                                                        // Now pickup the issue
-                                                        match client.pickup_issue(&issue_key) {
-                                                            Ok(_) => {
-                                                                message = Some(format!("✓ Created branch '{}' and picked up {}", branch_name, issue_key));
-                                                                should_quit = true; // Exit after successful start
-                                                            }
-                                                            Err(e) => {
-                                                                message = Some(format!("✗ Branch created but failed to pickup: {}", e));
-                                                            }
-                                                        }
-                                                    } else {
-                                                        message = Some(format!("✗ Failed to checkout branch '{}'", branch_name));
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    message = Some(format!("✗ Failed to create branch: {}", e));
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            message = Some(format!("✗ Failed to get HEAD commit: {}", e));
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    message = Some(format!("✗ Failed to open git repository: {}", e));
-                                }
+                    KeyCode::Char('y') => {
+                        if let Some(meeting) = app.selected() {
+                            match &meeting.meeting_url {
+                                Some(url) => match clipboard.copy(url) {
+                                    Ok(_) => toasts.success("✓ Meeting URL copied to clipboard"),
+                                    Err(e) => toasts.error(format!("✗ {}", e)),
+                                },
+                                None => toasts.info(format!("No meeting URL available for: {}", meeting.summary)),
                             }
                         }
                     }
-                    KeyCode::Char('v') => {
-                        if let Some(issue) = app.children.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            // Temporarily restore terminal for nested UI
-                            disable_raw_mode()?;
-                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-                            terminal.show_cursor()?;
-                            
-                            // Show the issue details
-                            println!("Viewing issue: {}", issue_key);
-                            let _ = JiraIssueDisplay::show(issue);
-                            
-                            // Re-setup terminal for epic list
-                            enable_raw_mode()?;
-                            let mut stdout = io::stdout();
-                            execute!(stdout, EnterAlternateScreen)?;
-                            let backend = CrosstermBackend::new(stdout);
-                            terminal = Terminal::new(backend)?;
-                            
-                            message = Some(format!("Returned from viewing {}", issue_key));
+                    KeyCode::Char('Y') => {
+                        if let Some(meeting) = app.selected() {
+                            match clipboard.copy(&meeting.invite_text()) {
+                                Ok(_) => toasts.success("✓ Invite copied to clipboard"),
+                                Err(e) => toasts.error(format!("✗ {}", e)),
+                            }
                         }
                     }
-                    KeyCode::Char('c') => {
-                        if let Some(issue) = app.children.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            message = Some(format!("Closing {}...", issue_key));
-                            terminal.draw(|f| app.draw(f, epic, &message))?;
-                            
-                            match client.close_issue(&issue_key) {
-                                Ok(_) => {
-                                    message = Some(format!("✓ {} closed successfully", issue_key));
-                                    // Refresh the issue data
-                                    if let Ok(updated_issue) = client.get_issue(&issue_key) {
-                                        app.children[app.selected_index] = updated_issue;
-                                    }
+                    // v opens the ticket the meeting links to, i always shows the meeting itself
+                    KeyCode::Char('v') | KeyCode::Char('i') => {
+                        let Some(meeting) = app.selected().cloned() else {
+                            continue;
+                        };
+                        let issue = match &meeting.ticket {
+                            Some(ticket) if key.code == KeyCode::Char('v') => {
+                                if !app.tickets.contains_key(ticket) {
+                                    toasts.info(format!("Loading {}...", ticket));
+                                    terminal.draw(|f| app.draw(f, &toasts, theme))?;
                                 }
-                                Err(e) => {
-                                    message = Some(format!("✗ Failed to close {}: {}", issue_key, e));
+                                match app.linked_issue(ticket, jira) {
+                                    Ok(issue) => Some(issue),
+                                    Err(e) => {
+                                        toasts.error(format!("✗ Couldn't load {}: {}", ticket, e));
+                                        continue;
+                                    }
                                 }
                             }
+                            _ => None,
+                        };
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        // Linked tickets only load with a Jira client
+                        match (&issue, jira) {
+                            (Some(issue), Some(jira)) => {
+                                let _ = JiraIssueDisplay::show(issue, jira, theme);
+                            }
+                            _ => {
+                                let _ = MeetingDetailDisplay::show(&meeting, theme);
+                            }
                         }
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        let viewed = issue.map(|issue| issue.key).unwrap_or(meeting.summary);
+                        toasts.info(format!("Returned from viewing {}", viewed));
+                    }
+                    KeyCode::Char('d') if app.selected().is_some() => {
+                        app.note_mode = true;
+                        app.note_input.clear();
                     }
                     _ => {}
                 }
@@ -592,184 +8450,416 @@ impl EpicListDisplay {
         Ok(())
     }
 
-    fn draw(&mut self, f: &mut Frame, epic: &JiraIssue, message: &Option<String>) {
+    fn update_scroll(&mut self) {
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + self.viewport_height {
+            self.scroll_offset = self.selected_index - self.viewport_height + 1;
+        }
+    }
+
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
-                Constraint::Length(5),    // Epic header
-                Constraint::Min(0),       // Children table
-                Constraint::Length(2),    // Message area
-                Constraint::Length(2),    // Help text
+                Constraint::Length(3),     // Header
+                Constraint::Min(0),        // Meetings table
+                Constraint::Length(1),     // Message line
+                Constraint::Length(2),     // Help text
             ])
             .split(f.area());
 
-        self.render_epic_header(f, chunks[0], epic);
-        self.render_children_table(f, chunks[1]);
-        self.render_message(f, chunks[2], message);
-        self.render_help(f, chunks[3]);
+        self.render_header(f, chunks[0], theme);
+        self.render_meetings_table(f, chunks[1], theme);
+        self.render_input(f, chunks[2], theme);
+        self.render_help(f, chunks[3], theme);
+        self.search.render(f, theme);
+        toasts.render(f, theme);
     }
 
-    fn render_epic_header(&self, f: &mut Frame, area: Rect, epic: &JiraIssue) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Epic Details ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+    // One tab per day with its meeting count; the title names the day being shown
+    fn render_header(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let today = Self::today();
+        let day_name = match (self.day - today).num_days() {
+            0 => "Today".to_string(),
+            1 => "Tomorrow".to_string(),
+            _ => self.day.format("%A").to_string(),
+        };
         
-        let inner = block.inner(area);
-        f.render_widget(block, area);
-
-        let header_text = vec![
-            Line::from(vec![
-                Span::styled("Epic: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(&epic.key),
-                Span::raw(" - "),
-                Span::raw(&epic.fields.summary),
-            ]),
-            Line::from(vec![
-                Span::styled("Status: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(&epic.fields.status.name),
-            ]),
-        ];
-
-        let paragraph = Paragraph::new(header_text);
-        f.render_widget(paragraph, inner);
+        let days: Vec<chrono::NaiveDate> = (0..MEETING_DAYS)
+            .map(|offset| today + chrono::Duration::days(offset))
+            .collect();
+        let titles: Vec<Line> = days.iter()
+            .map(|day| {
+                let count = self.meetings.iter().filter(|m| meeting_day(m) == *day).count();
+                Line::from(format!("{} ({})", day.format("%a %-d"), count))
+            })
+            .collect();
+        
+        let tabs = Tabs::new(titles)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {}, {} · {} this week ", day_name, self.day.format("%b %-d"), self.meetings.len()))
+                .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)))
+            .select(days.iter().position(|day| *day == self.day).unwrap_or(0))
+            .highlight_style(Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD));
+        f.render_widget(tabs, area);
     }
 
-    fn render_children_table(&mut self, f: &mut Frame, area: Rect) {
+    fn render_meetings_table(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
         let block = Block::default()
-            .borders(Borders::ALL)
-            .title(format!(" Child Issues ({}) ", self.children.len()))
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+            .borders(Borders::ALL);
         
         let inner = block.inner(area);
         f.render_widget(block, area);
 
-        if self.children.is_empty() {
-            let text = Paragraph::new("(No child issues in this epic)")
-                .style(Style::default().fg(Color::DarkGray))
+        // Update viewport height
+        self.viewport_height = list_capacity(inner.width, inner.height.saturating_sub(1));
+
+        // Create table rows
+        let now = chrono::Utc::now().with_timezone(&New_York);
+        let day_meetings = self.day_meetings();
+        if day_meetings.is_empty() {
+            let empty = Paragraph::new("No meetings this day. ←/→ to see other days.")
+                .style(Style::default().fg(theme.muted))
                 .alignment(Alignment::Center);
-            f.render_widget(text, inner);
+            f.render_widget(empty, inner);
             return;
         }
-
-        // Create table headers
-        let header_cells: Vec<Cell> = vec!["", "Key", "Status", "Summary", "Assignee"]
-            .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)))
-            .collect();
-        let header = Row::new(header_cells).height(1);
-
-        // Calculate viewport dimensions for table (accounting for header)
-        let viewport_height = inner.height.saturating_sub(2) as usize; // -2 for header and border
-        self.viewport_height = viewport_height; // Store for use in key handlers
-        
-        // Use the persisted scroll_offset
-        let visible_start = self.scroll_offset;
-        let visible_end = (self.scroll_offset + viewport_height).min(self.children.len());
-        
-        let rows: Vec<Row> = self.children[visible_start..visible_end]
-            .iter()
+        let rows: Vec<ListRow> = day_meetings
+            .into_iter()
             .enumerate()
-            .map(|(visible_idx, issue)| {
-                let actual_idx = visible_start + visible_idx;
-                let assignee = issue.fields.assignee.as_ref()
-                    .map(|u| u.display_name.clone())
-                    .unwrap_or_else(|| "Unassigned".to_string());
+            .skip(self.scroll_offset)
+            .take(self.viewport_height)
+            .map(|(idx, meeting)| {
+                let time_str = format!(
+                    "{}:{:02} {} - {}:{:02} {}",
+                    meeting.start_time.format("%l").to_string().trim(),
+                    meeting.start_time.minute(),
+                    meeting.start_time.format("%p"),
+                    meeting.end_time.format("%l").to_string().trim(),
+                    meeting.end_time.minute(),
+                    meeting.end_time.format("%p")
+                );
                 
-                // Color code the status
-                let status_style = match issue.fields.status.name.to_lowercase().as_str() {
-                    s if s.contains("done") || s.contains("closed") => Style::default().fg(Color::Green),
-                    s if s.contains("progress") => Style::default().fg(Color::Yellow),
-                    s if s.contains("review") => Style::default().fg(Color::Magenta),
-                    _ => Style::default().fg(Color::White),
+                let status = if now >= meeting.start_time && now <= meeting.end_time {
+                    "In Progress"
+                } else if now < meeting.start_time {
+                    "Upcoming"
+                } else {
+                    "Ended"
+                };
+                
+                let status_color = match status {
+                    "In Progress" => theme.success,
+                    "Upcoming" => theme.label,
+                    _ => theme.muted,
+                };
+                
+                let url_status = if meeting.meeting_url.is_some() {
+                    "Available"
+                } else {
+                    "Not Available"
                 };
                 
                 // Selection indicator
-                let indicator = if actual_idx == self.selected_index { "➤" } else { "" };
+                let selected = idx == self.selected_index;
+                let indicator = if selected { "➤" } else { "" };
+                
+                // Red time flags meetings outside working hours or during time off
+                let time_cell = if meeting.hours_conflict.is_some() {
+                    Line::from(time_str).style(Style::default().fg(theme.error))
+                } else {
+                    Line::from(time_str)
+                };
+                
+                // Linked ticket with its status, or just the key while loading or when Jira doesn't know it
+                let ticket_cell = match meeting.ticket.as_ref().map(|key| (key, self.tickets.get(key))) {
+                    Some((_, Some(issue))) => {
+                        let status_style = match issue.fields.status.name.to_lowercase().as_str() {
+                            s if s.contains("done") || s.contains("closed") => Style::default().fg(theme.success),
+                            s if s.contains("progress") => Style::default().fg(theme.highlight),
+                            s if s.contains("review") => Style::default().fg(theme.special),
+                            _ => Style::default().fg(theme.text),
+                        };
+                        Line::from(vec![
+                            Span::raw(format!("{} ", issue.key)),
+                            Span::styled(issue.fields.status.name.clone(), status_style),
+                        ])
+                    }
+                    Some((key, None)) => Line::from(key.clone()).style(Style::default().fg(theme.muted)),
+                    None => Line::from(""),
+                };
+                
+                let color_dot = match meeting.color_rgb() {
+                    Some((r, g, b)) => Line::from("●").style(Style::default().fg(theme.paint(Color::Rgb(r, g, b)))),
+                    None => Line::from(""),
+                };
                 
                 let cells = vec![
-                    Cell::from(indicator).style(Style::default().fg(Color::Green)),
-                    Cell::from(issue.key.clone()),
-                    Cell::from(issue.fields.status.name.clone()).style(status_style),
-                    Cell::from(issue.fields.summary.clone()),
-                    Cell::from(assignee),
+                    Line::from(indicator).style(Style::default().fg(theme.accent)),
+                    color_dot,
+                    time_cell,
+                    Line::from(meeting.summary.clone()),
+                    Line::from(status).style(Style::default().fg(status_color)),
+                    ticket_cell,
+                    Line::from(url_status),
                 ];
                 
-                Row::new(cells).height(1)
+                ListRow::new(cells).style(meeting_style(meeting, theme).patch(theme.selected(selected)))
             })
             .collect();
 
-        // Add scroll indicators in the title
-        let title = if self.children.len() > viewport_height {
-            format!(" Child Issues ({}) [{}-{} of {}] ", 
-                self.children.len(),
-                visible_start + 1,
-                visible_end,
-                self.children.len()
-            )
+        const COLUMNS: [ListColumn; 7] = [
+            ListColumn::new("", Constraint::Length(3), 0),                // Arrow indicator
+            ListColumn::new("", Constraint::Length(2), 0),                // Event color
+            ListColumn::new("Time", Constraint::Length(15), 0),
+            ListColumn::new("Meeting Name", Constraint::Min(30), 0),
+            ListColumn::new("Status", Constraint::Length(12), 2),
+            ListColumn::new("Ticket", Constraint::Length(24), 1),
+            ListColumn::new("URL", Constraint::Length(15), 3),
+        ];
+        let table = fitted_table(&COLUMNS, rows, inner.width, theme)
+            .row_highlight_style(Style::default().add_modifier(Modifier::BOLD));
+
+        f.render_widget(table, inner);
+    }
+
+    fn render_input(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        if !self.note_mode {
+            return;
+        }
+        let Some(meeting) = self.selected() else {
+            return;
+        };
+        
+        let line = if meeting.is_organizer {
+            Line::from(Span::styled(
+                format!("Cancel '{}' for all attendees? Enter: Confirm | ESC: Abort", meeting.summary),
+                Style::default().fg(theme.highlight),
+            ))
+        } else {
+            Line::from(vec![
+                Span::styled("Decline note (optional): ", Style::default().fg(theme.label)),
+                Span::raw(&self.note_input),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ])
+        };
+        f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+    }
+
+    fn render_help(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let help_text = if self.note_mode {
+            "Type a note | Enter: Confirm | ESC: Cancel"
+        } else {
+            "↑/↓ j/k: Navigate | /: Search | ←/→: Day | t: Today | v: Ticket/Details | i: Details | o/Enter: Join | y: Copy URL | Y: Copy invite | d: Decline/Cancel | q/ESC: Quit"
+        };
+        
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, area);
+    }
+}
+
+pub struct MeetingsWeekDisplay {
+    // Monday of the week on screen
+    week_start: chrono::NaiveDate,
+    meetings: Vec<Meeting>,
+    working_hours: crate::google::WorkingHours,
+    selected_id: Option<String>,
+    // First half-hour row shown, when the day is taller than the screen
+    first_slot: usize,
+    visible_slots: usize,
+}
+
+// Each row of the week grid is half an hour
+const WEEK_SLOT_MINUTES: u32 = 30;
+// Width of the time labels down the left side
+const WEEK_GUTTER: u16 = 8;
+
+fn monday_of(day: chrono::NaiveDate) -> chrono::NaiveDate {
+    day - chrono::Duration::days(day.weekday().num_days_from_monday() as i64)
+}
+
+fn minutes_of_day(time: chrono::DateTime<chrono::Local>) -> u32 {
+    time.hour() * 60 + time.minute()
+}
+
+// Meetings of the day as (meeting, lane, lanes): overlapping meetings split their column
+// into side-by-side lanes, and a meeting nothing overlaps gets the whole width
+fn day_lanes<'a>(meetings: &[&'a Meeting]) -> Vec<(&'a Meeting, usize, usize)> {
+    let mut placed = Vec::new();
+    let mut cluster: Vec<(&Meeting, usize)> = Vec::new();
+    let mut lane_ends: Vec<chrono::DateTime<chrono::FixedOffset>> = Vec::new();
+    for meeting in meetings {
+        // Nothing still running, so the overlapping group so far is complete
+        if lane_ends.iter().all(|end| *end <= meeting.start_time) {
+            placed.extend(cluster.drain(..).map(|(m, lane)| (m, lane, lane_ends.len())));
+            lane_ends.clear();
+        }
+        let lane = match lane_ends.iter().position(|end| *end <= meeting.start_time) {
+            Some(lane) => {
+                lane_ends[lane] = meeting.end_time;
+                lane
+            }
+            None => {
+                lane_ends.push(meeting.end_time);
+                lane_ends.len() - 1
+            }
+        };
+        cluster.push((*meeting, lane));
+    }
+    placed.extend(cluster.drain(..).map(|(m, lane)| (m, lane, lane_ends.len())));
+    placed
+}
+
+impl MeetingsWeekDisplay {
+    fn load(client: &BlockingCalendarClient, week_start: chrono::NaiveDate) -> Result<Vec<Meeting>> {
+        let from = crate::meeting_stats::start_of(week_start).with_timezone(&chrono::Utc);
+        let to = crate::meeting_stats::start_of(week_start + chrono::Duration::days(7)).with_timezone(&chrono::Utc);
+        client.list_meetings_between(from, to)
+    }
+
+    // Working days, plus any other day of the week that has something on it
+    fn days(&self) -> Vec<chrono::NaiveDate> {
+        (0..7)
+            .map(|offset| self.week_start + chrono::Duration::days(offset))
+            .filter(|day| self.working_hours.days.contains(&day.weekday()) || !self.timed_meetings(*day).is_empty())
+            .collect()
+    }
+
+    // Meetings drawn in the grid; all-day events are listed above it instead
+    fn timed_meetings(&self, day: chrono::NaiveDate) -> Vec<&Meeting> {
+        self.meetings.iter()
+            .filter(|m| !m.is_all_day && meeting_day(m) == day)
+            .collect()
+    }
+
+    fn all_day_meetings(&self, day: chrono::NaiveDate) -> Vec<&Meeting> {
+        self.meetings.iter()
+            .filter(|m| m.is_all_day && meeting_day(m) == day)
+            .collect()
+    }
+
+    fn selected(&self) -> Option<&Meeting> {
+        let id = self.selected_id.as_ref()?;
+        self.meetings.iter().find(|m| &m.id == id)
+    }
+
+    // Working hours, stretched to fit meetings that start earlier or end later, in minutes since midnight
+    fn time_range(&self) -> (u32, u32) {
+        let mut start = self.working_hours.start.hour() * 60 + self.working_hours.start.minute();
+        let mut end = self.working_hours.end.hour() * 60 + self.working_hours.end.minute();
+        for meeting in self.meetings.iter().filter(|m| !m.is_all_day) {
+            let local_start = meeting.start_time.with_timezone(&chrono::Local);
+            let local_end = meeting.end_time.with_timezone(&chrono::Local);
+            start = start.min(minutes_of_day(local_start));
+            // Meetings running past midnight end at the bottom of their day
+            end = end.max(if local_end.date_naive() > local_start.date_naive() { 24 * 60 } else { minutes_of_day(local_end) });
+        }
+        let start = start / WEEK_SLOT_MINUTES * WEEK_SLOT_MINUTES;
+        let end = end.div_ceil(WEEK_SLOT_MINUTES) * WEEK_SLOT_MINUTES;
+        (start, end.max(start + WEEK_SLOT_MINUTES))
+    }
+
+    // Rows a meeting covers, relative to the top of the range
+    fn slots(&self, meeting: &Meeting) -> (usize, usize) {
+        let (range_start, range_end) = self.time_range();
+        let local_start = meeting.start_time.with_timezone(&chrono::Local);
+        let local_end = meeting.end_time.with_timezone(&chrono::Local);
+        let start = minutes_of_day(local_start).max(range_start) - range_start;
+        let end = if local_end.date_naive() > local_start.date_naive() {
+            range_end
         } else {
-            format!(" Child Issues ({}) ", self.children.len())
-        };
+            minutes_of_day(local_end).min(range_end)
+        } - range_start;
+        let first = (start / WEEK_SLOT_MINUTES) as usize;
+        let last = (end.div_ceil(WEEK_SLOT_MINUTES) as usize).max(first + 1);
+        (first, last)
+    }
 
-        let table = Table::new(
-            rows,
-            vec![
-                Constraint::Length(3),      // Arrow indicator
-                Constraint::Length(12),     // Key
-                Constraint::Length(15),     // Status
-                Constraint::Min(20),        // Summary (takes remaining space)
-                Constraint::Length(20),     // Assignee
-            ]
-        )
-        .header(header)
-        .block(Block::default().title(title));
+    // The meeting on now or next this week, or the first one in it
+    fn select_default(&mut self) {
+        let now = chrono::Utc::now();
+        let timed: Vec<&Meeting> = self.meetings.iter().filter(|m| !m.is_all_day).collect();
+        self.selected_id = timed.iter()
+            .find(|m| m.end_time > now)
+            .or(timed.first())
+            .map(|m| m.id.clone());
+        // Start at the top of the working day rather than at midnight
+        let (range_start, _) = self.time_range();
+        let work_start = self.working_hours.start.hour() * 60 + self.working_hours.start.minute();
+        self.first_slot = (work_start.saturating_sub(range_start) / WEEK_SLOT_MINUTES) as usize;
+        self.scroll_to_selected();
+    }
 
-        f.render_widget(table, inner);
+    fn go_to_week(&mut self, client: &BlockingCalendarClient, week_start: chrono::NaiveDate) -> Result<()> {
+        self.meetings = Self::load(client, week_start)?;
+        self.week_start = week_start;
+        self.select_default();
+        Ok(())
     }
 
-    fn render_message(&self, f: &mut Frame, area: Rect, message: &Option<String>) {
-        if let Some(msg) = message {
-            let style = if msg.starts_with('✓') {
-                Style::default().fg(Color::Green)
-            } else if msg.starts_with('✗') {
-                Style::default().fg(Color::Red)
-            } else {
-                Style::default().fg(Color::Yellow)
-            };
-            
-            let text = Paragraph::new(msg.as_str())
-                .style(style)
-                .alignment(Alignment::Center);
-            f.render_widget(text, area);
+    // Previous or next meeting on the same day
+    fn move_vertical(&mut self, forward: bool) {
+        let Some(selected) = self.selected() else {
+            return;
+        };
+        let day_meetings = self.timed_meetings(meeting_day(selected));
+        let Some(idx) = day_meetings.iter().position(|m| m.id == selected.id) else {
+            return;
+        };
+        let target = if forward { day_meetings.get(idx + 1) } else { idx.checked_sub(1).and_then(|i| day_meetings.get(i)) };
+        if let Some(meeting) = target {
+            self.selected_id = Some(meeting.id.clone());
+            self.scroll_to_selected();
         }
     }
 
-    fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help = Paragraph::new("↑/↓: Navigate | v: View | a: Assign to... | p: In Progress | c: Close | s: Start | q/ESC: Quit")
-            .style(Style::default().fg(Color::DarkGray))
-            .alignment(Alignment::Center);
-        f.render_widget(help, area);
+    // The nearest day that has meetings, landing on the one closest in time of day
+    fn move_horizontal(&mut self, forward: bool) {
+        let days = self.days();
+        let (current_day, minutes) = match self.selected() {
+            Some(m) => (meeting_day(m), minutes_of_day(m.start_time.with_timezone(&chrono::Local))),
+            None => return,
+        };
+        let Some(idx) = days.iter().position(|day| *day == current_day) else {
+            return;
+        };
+        let candidates: Vec<chrono::NaiveDate> = if forward {
+            days[idx + 1..].to_vec()
+        } else {
+            days[..idx].iter().rev().copied().collect()
+        };
+        for day in candidates {
+            let closest = self.timed_meetings(day).into_iter()
+                .min_by_key(|m| minutes_of_day(m.start_time.with_timezone(&chrono::Local)).abs_diff(minutes));
+            if let Some(meeting) = closest {
+                self.selected_id = Some(meeting.id.clone());
+                self.scroll_to_selected();
+                return;
+            }
+        }
     }
-}
 
-pub struct MyIssuesDisplay {
-    selected_index: usize,
-    issues: Vec<JiraIssue>,
-    scroll_offset: usize,
-    viewport_height: usize,
-}
-
-impl MyIssuesDisplay {
-    fn update_scroll_offset(&mut self, viewport_height: usize) {
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
-        } else if self.selected_index >= self.scroll_offset + viewport_height {
-            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
+    fn scroll_to_selected(&mut self) {
+        let Some((first, last)) = self.selected().map(|m| self.slots(m)) else {
+            return;
+        };
+        if first < self.first_slot {
+            self.first_slot = first;
+        } else if last > self.first_slot + self.visible_slots {
+            self.first_slot = last.saturating_sub(self.visible_slots).min(first);
         }
     }
-    
-    pub fn show(issues: Vec<JiraIssue>, client: &JiraClient) -> Result<()> {
+
+    pub fn show(client: &BlockingCalendarClient, theme: &Theme) -> Result<()> {
+        // Fetch before taking over the terminal so an auth or network error prints normally
+        let week_start = monday_of(chrono::Local::now().date_naive());
+        let meetings = Self::load(client, week_start)?;
+
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -778,179 +8868,90 @@ impl MyIssuesDisplay {
         let mut terminal = Terminal::new(backend)?;
 
         let mut app = Self {
-            selected_index: 0,
-            issues,
-            scroll_offset: 0,
-            viewport_height: 20, // Will be updated during first render
+            week_start,
+            meetings,
+            working_hours: client.working_hours().clone(),
+            selected_id: None,
+            first_slot: 0,
+            visible_slots: 16, // Will be updated during first render
         };
-        
+        app.select_default();
+
         let mut should_quit = false;
-        let mut message: Option<String> = None;
+        let mut toasts = Toasts::default();
+        let mut clipboard = crate::clipboard::Clipboard::default();
 
         // Main loop
         while !should_quit {
-            terminal.draw(|f| app.draw(f, &message))?;
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
 
             if let Event::Key(key) = event::read()? {
+                if toasts.handle_key(&key) {
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
-                    KeyCode::Up => {
-                        if app.selected_index > 0 {
-                            app.selected_index -= 1;
-                            app.update_scroll_offset(app.viewport_height); // Typical terminal height
-                        }
-                    }
-                    KeyCode::Down => {
-                        if app.selected_index < app.issues.len().saturating_sub(1) {
-                            app.selected_index += 1;
-                            app.update_scroll_offset(app.viewport_height); // Typical terminal height
-                        }
-                    }
-                    KeyCode::Char('v') => {
-                        if let Some(issue) = app.issues.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            // Temporarily restore terminal for nested UI
-                            disable_raw_mode()?;
-                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-                            terminal.show_cursor()?;
-                            
-                            // Show the issue details
-                            println!("Viewing issue: {}", issue_key);
-                            let _ = JiraIssueDisplay::show(issue);
-                            
-                            // Re-setup terminal
-                            enable_raw_mode()?;
-                            let mut stdout = io::stdout();
-                            execute!(stdout, EnterAlternateScreen)?;
-                            let backend = CrosstermBackend::new(stdout);
-                            terminal = Terminal::new(backend)?;
-                            
-                            message = Some(format!("Returned from viewing {}", issue_key));
-                        }
-                    }
-                    KeyCode::Char('p') => {
-                        if let Some(issue) = app.issues.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            message = Some(format!("Moving {} to In Progress...", issue_key));
-                            terminal.draw(|f| app.draw(f, &message))?;
-                            
-                            match client.transition_to_in_progress(&issue_key) {
-                                Ok(_) => {
-                                    message = Some(format!("✓ {} moved to In Progress", issue_key));
-                                    // Refresh the issue data
-                                    if let Ok(updated_issue) = client.get_issue(&issue_key) {
-                                        app.issues[app.selected_index] = updated_issue;
-                                    }
-                                }
-                                Err(e) => {
-                                    message = Some(format!("✗ Failed to move {}: {}", issue_key, e));
-                                }
-                            }
+                    KeyCode::Up | KeyCode::Char('k') => app.move_vertical(false),
+                    KeyCode::Down | KeyCode::Char('j') => app.move_vertical(true),
+                    KeyCode::Left | KeyCode::Char('h') => app.move_horizontal(false),
+                    KeyCode::Right | KeyCode::Char('l') => app.move_horizontal(true),
+                    KeyCode::Char('[') | KeyCode::Char(']') | KeyCode::Char('t') => {
+                        let week_start = match key.code {
+                            KeyCode::Char('[') => app.week_start - chrono::Duration::weeks(1),
+                            KeyCode::Char(']') => app.week_start + chrono::Duration::weeks(1),
+                            _ => monday_of(chrono::Local::now().date_naive()),
+                        };
+                        toasts.info(format!("Loading week of {}...", week_start.format("%b %-d")));
+                        terminal.draw(|f| app.draw(f, &toasts, theme))?;
+                        if let Err(e) = app.go_to_week(client, week_start) {
+                            toasts.error(format!("✗ Couldn't load the week: {}", e));
                         }
                     }
-                    KeyCode::Char('s') => {
-                        if let Some(issue) = app.issues.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            message = Some(format!("Starting {}...", issue_key));
-                            terminal.draw(|f| app.draw(f, &message))?;
-                            
-                            // Create feature branch
-                            use git2::Repository;
-                            match Repository::open(".") {
-                                Ok(repo) => {
-                                    let branch_name = format!("feature/{}", issue_key);
-                                    
-                                    // Get the current HEAD commit
-                                    match repo.head()
-                                        .and_then(|head| head.target().ok_or_else(|| git2::Error::from_str("No HEAD target")))
-                                        .and_then(|target| repo.find_commit(target))
-                                    {
-                                        Ok(commit) => {
-                                            // Create and checkout the new branch
-                                            match repo.branch(&branch_name, &commit, false) {
-                                                Ok(_) => {
-                                                    if let Ok(obj) = repo.revparse_single(&format!("refs/heads/{}", branch_name)) {
-                                                        let _ = repo.checkout_tree(&obj, None);
-                                                        let _ = repo.set_head(&format!("refs/heads/{}", branch_name));
-                                                        
-                                                        // Now pickup the issue
-                                                        match client.pickup_issue(&issue_key) {
-                                                            Ok(_) => {
-                                                                message = Some(format!("✓ Created branch '{}' and picked up {}", branch_name, issue_key));
-                                                                should_quit = true; // Exit after successful start
-                                                            }
-                                                            Err(e) => {
-                                                                message = Some(format!("✗ Branch created but failed to pickup: {}", e));
-                                                            }
-                                                        }
-                                                    } else {
-                                                        message = Some(format!("✗ Failed to checkout branch '{}'", branch_name));
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    message = Some(format!("✗ Failed to create branch: {}", e));
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            message = Some(format!("✗ Failed to get HEAD commit: {}", e));
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    message = Some(format!("✗ Failed to open git repository: {}", e));
-                                }
+                    KeyCode::Enter | KeyCode::Char('o') => {
+                        if let Some(meeting) = app.selected() {
+                            match &meeting.meeting_url {
+                                Some(url) => match webbrowser::open(url) {
+                                    Ok(_) => toasts.success("✓ Opened meeting in browser"),
+                                    Err(e) => toasts.error(format!("✗ Failed to open browser: {}", e)),
+                                },
+                                None => toasts.info(format!("No meeting URL available for: {}", meeting.summary)),
                             }
                         }
                     }
-                    KeyCode::Char('c') => {
-                        if let Some(issue) = app.issues.get(app.selected_index) {
-                            let issue_key = issue.key.clone();
-                            message = Some(format!("Closing {}...", issue_key));
-                            terminal.draw(|f| app.draw(f, &message))?;
-                            
-                            match client.close_issue(&issue_key) {
-                                Ok(_) => {
-                                    message = Some(format!("✓ {} closed successfully", issue_key));
-                                    // Refresh the issue data
-                                    if let Ok(updated_issue) = client.get_issue(&issue_key) {
-                                        app.issues[app.selected_index] = updated_issue;
-                                    }
-                                }
-                                Err(e) => {
-                                    message = Some(format!("✗ Failed to close {}: {}", issue_key, e));
-                                }
+                    KeyCode::Char('y') => {
+                        if let Some(meeting) = app.selected() {
+                            match &meeting.meeting_url {
+                                Some(url) => match clipboard.copy(url) {
+                                    Ok(_) => toasts.success("✓ Meeting URL copied to clipboard"),
+                                    Err(e) => toasts.error(format!("✗ {}", e)),
+                                },
+                                None => toasts.info(format!("No meeting URL available for: {}", meeting.summary)),
                             }
                         }
                     }
-                    KeyCode::Char('e') => {
-                        if let Some(issue) = app.issues.get(app.selected_index) {
-                            if let Some(parent) = &issue.fields.parent {
-                                let parent_key = parent.key.clone();
-                                // Temporarily restore terminal
-                                disable_raw_mode()?;
-                                execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-                                terminal.show_cursor()?;
-                                
-                                // Show the parent epic
-                                println!("Fetching epic details for: {}", parent_key);
-                                if let Ok(children) = client.get_epic_children(&parent_key) {
-                                    println!("Fetching child issues...");
-                                    let _ = EpicListDisplay::show(parent, children, client);
-                                }
-                                
-                                // Re-setup terminal
-                                enable_raw_mode()?;
-                                let mut stdout = io::stdout();
-                                execute!(stdout, EnterAlternateScreen)?;
-                                let backend = CrosstermBackend::new(stdout);
-                                terminal = Terminal::new(backend)?;
-                                
-                                message = Some(format!("Returned from viewing epic {}", parent_key));
-                            } else {
-                                message = Some("This issue is not part of an epic".to_string());
-                            }
-                        }
+                    KeyCode::Char('i') | KeyCode::Char('v') => {
+                        let Some(meeting) = app.selected().cloned() else {
+                            continue;
+                        };
+
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+
+                        let _ = MeetingDetailDisplay::show(&meeting, theme);
+
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
                     }
                     _ => {}
                 }
@@ -965,173 +8966,186 @@ impl MyIssuesDisplay {
         Ok(())
     }
 
-    fn draw(&mut self, f: &mut Frame, message: &Option<String>) {
+    fn draw(&mut self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
-                Constraint::Length(3),    // Header
-                Constraint::Min(0),       // Issues table
-                Constraint::Length(2),    // Message area
-                Constraint::Length(2),    // Help text
+                Constraint::Min(0),        // Week grid
+                Constraint::Length(1),     // Selected meeting
+                Constraint::Length(2),     // Help text
             ])
             .split(f.area());
 
-        self.render_header(f, chunks[0]);
-        self.render_issues_table(f, chunks[1]);
-        self.render_message(f, chunks[2], message);
-        self.render_help(f, chunks[3]);
-    }
-
-    fn render_header(&self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" My Issues ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
-
-        let header_text = vec![
-            Line::from(vec![
-                Span::styled("Total Issues: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(self.issues.len().to_string()),
-            ]),
-        ];
-
-        let paragraph = Paragraph::new(header_text);
-        f.render_widget(paragraph, inner);
-    }
-
-    fn render_issues_table(&mut self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Issues ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
-
-        if self.issues.is_empty() {
-            let text = Paragraph::new("(No issues assigned to you)")
-                .style(Style::default().fg(Color::DarkGray))
-                .alignment(Alignment::Center);
-            f.render_widget(text, inner);
-            return;
-        }
-
-        // Create table headers
-        let header_cells: Vec<Cell> = vec!["", "Key", "Parent", "Status", "Summary"]
-            .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)))
-            .collect();
-        let header = Row::new(header_cells).height(1);
-
-        // Calculate viewport dimensions for table (accounting for header)
-        let viewport_height = inner.height.saturating_sub(2) as usize; // -2 for header and border
-        self.viewport_height = viewport_height; // Store for use in key handlers
-        
-        // Use the persisted scroll_offset
-        let visible_start = self.scroll_offset;
-        let visible_end = (self.scroll_offset + viewport_height).min(self.issues.len());
-        
-        let rows: Vec<Row> = self.issues[visible_start..visible_end]
-            .iter()
-            .enumerate()
-            .map(|(visible_idx, issue)| {
-                let actual_idx = visible_start + visible_idx;
-                let parent = issue.fields.parent.as_ref()
-                    .map(|p| p.key.clone())
-                    .unwrap_or_else(|| "—".to_string());
-                
-                // Color code the status
-                let status_style = match issue.fields.status.name.to_lowercase().as_str() {
-                    s if s.contains("done") || s.contains("closed") => Style::default().fg(Color::Green),
-                    s if s.contains("progress") => Style::default().fg(Color::Yellow),
-                    s if s.contains("review") => Style::default().fg(Color::Magenta),
-                    _ => Style::default().fg(Color::White),
-                };
-                
-                // Selection indicator
-                let indicator = if actual_idx == self.selected_index { "➤" } else { "" };
-                
-                let cells = vec![
-                    Cell::from(indicator).style(Style::default().fg(Color::Green)),
-                    Cell::from(issue.key.clone()),
-                    Cell::from(parent),
-                    Cell::from(issue.fields.status.name.clone()).style(status_style),
-                    Cell::from(issue.fields.summary.clone()),
-                ];
-                
-                Row::new(cells).height(1)
-            })
-            .collect();
-
-        // Update title with scroll indicators
-        let title = if self.issues.len() > viewport_height {
-            format!(" Issues [{}-{} of {}] ", 
-                visible_start + 1,
-                visible_end,
-                self.issues.len()
-            )
-        } else {
-            " Issues ".to_string()
-        };
-
-        let table = Table::new(
-            rows,
-            vec![
-                Constraint::Length(3),      // Arrow indicator
-                Constraint::Length(12),     // Key
-                Constraint::Length(12),     // Parent
-                Constraint::Length(15),     // Status
-                Constraint::Min(20),        // Summary (takes remaining space)
-            ]
-        )
-        .header(header)
-        .block(Block::default().title(title));
+        self.render_grid(f, chunks[0], theme);
+        self.render_selected(f, chunks[1], theme);
 
-        f.render_widget(table, inner);
+        let help = Paragraph::new("↑/↓ j/k: Meeting | ←/→ h/l: Day | [/]: Week | t: This week | o/Enter: Join | i/v: Details | y: Copy URL | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+        toasts.render(f, theme);
     }
 
-    fn render_message(&self, f: &mut Frame, area: Rect, message: &Option<String>) {
-        if let Some(msg) = message {
-            let style = if msg.starts_with('✓') {
-                Style::default().fg(Color::Green)
-            } else if msg.starts_with('✗') {
-                Style::default().fg(Color::Red)
+    fn render_grid(&mut self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let week_end = self.week_start + chrono::Duration::days(6);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " Week of {} – {} · {} meetings ",
+                self.week_start.format("%b %-d"),
+                week_end.format("%b %-d"),
+                self.meetings.iter().filter(|m| !m.is_all_day).count()
+            ))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let days = self.days();
+        if days.is_empty() || inner.width <= WEEK_GUTTER || inner.height < 3 {
+            return;
+        }
+
+        // Day names, then all-day events, then the half-hour rows
+        let (range_start, range_end) = self.time_range();
+        let total_slots = ((range_end - range_start) / WEEK_SLOT_MINUTES) as usize;
+        self.visible_slots = inner.height.saturating_sub(2) as usize;
+        self.first_slot = self.first_slot.min(total_slots.saturating_sub(self.visible_slots));
+        let grid_top = inner.y + 2;
+        let column_width = (inner.width - WEEK_GUTTER) / days.len() as u16;
+        let today = chrono::Local::now().date_naive();
+        let now_slot = (minutes_of_day(chrono::Local::now()).checked_sub(range_start))
+            .map(|minutes| (minutes / WEEK_SLOT_MINUTES) as usize)
+            .filter(|slot| *slot < total_slots);
+
+        for row in 0..self.visible_slots.min(total_slots - self.first_slot) {
+            let minutes = range_start + (self.first_slot + row) as u32 * WEEK_SLOT_MINUTES;
+            let label = chrono::NaiveTime::from_hms_opt(minutes / 60, minutes % 60, 0)
+                .filter(|time| time.minute() == 0)
+                .map(|time| time.format("%l%P").to_string())
+                .unwrap_or_default();
+            let is_now = today >= self.week_start && today <= week_end && now_slot == Some(self.first_slot + row);
+            let style = Style::default().fg(if is_now { theme.error } else { theme.muted });
+            let label_area = Rect { x: inner.x, y: grid_top + row as u16, width: WEEK_GUTTER, height: 1 };
+            f.render_widget(Paragraph::new(label).style(style), label_area);
+        }
+
+        for (column, day) in days.iter().enumerate() {
+            let x = inner.x + WEEK_GUTTER + column as u16 * column_width;
+            let header_style = if *day == today {
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme.label).add_modifier(Modifier::BOLD)
             };
-            
-            let text = Paragraph::new(msg.as_str())
-                .style(style)
-                .alignment(Alignment::Center);
-            f.render_widget(text, area);
+            f.render_widget(
+                Paragraph::new(day.format("%a %-d").to_string()).style(header_style),
+                Rect { x, y: inner.y, width: column_width, height: 1 },
+            );
+
+            let all_day = self.all_day_meetings(*day);
+            let all_day_text = match all_day.as_slice() {
+                [] => String::new(),
+                [only] => only.summary.clone(),
+                [first, rest @ ..] => format!("{} +{}", first.summary, rest.len()),
+            };
+            f.render_widget(
+                Paragraph::new(all_day_text).style(Style::default().fg(theme.muted)),
+                Rect { x, y: inner.y + 1, width: column_width.saturating_sub(1), height: 1 },
+            );
+
+            // A line across today's column at the current time
+            if *day == today
+                && let Some(slot) = now_slot
+                && slot >= self.first_slot
+                && slot < self.first_slot + self.visible_slots
+            {
+                let line_area = Rect { x, y: grid_top + (slot - self.first_slot) as u16, width: column_width.saturating_sub(1), height: 1 };
+                f.render_widget(
+                    Paragraph::new("─".repeat(line_area.width as usize)).style(Style::default().fg(theme.error)),
+                    line_area,
+                );
+            }
+
+            let day_meetings = self.timed_meetings(*day);
+            for (meeting, lane, lanes) in day_lanes(&day_meetings) {
+                let lane_width = (column_width.saturating_sub(1) / lanes as u16).max(1);
+                let (first, last) = self.slots(meeting);
+                let first = first.max(self.first_slot);
+                let last = last.min(self.first_slot + self.visible_slots);
+                if first >= last {
+                    continue;
+                }
+                let block_area = Rect {
+                    x: x + lane as u16 * lane_width,
+                    y: grid_top + (first - self.first_slot) as u16,
+                    width: lane_width,
+                    height: (last - first) as u16,
+                };
+                self.render_meeting_block(f, meeting, block_area, theme);
+            }
         }
     }
 
-    fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help = Paragraph::new("↑/↓: Navigate | v: View | c: Close | e: Epic | p: In Progress | s: Start | q/ESC: Quit")
-            .style(Style::default().fg(Color::DarkGray))
-            .alignment(Alignment::Center);
-        f.render_widget(help, area);
+    // A block with a bar in the event's color, its title, and its start time when there's room
+    fn render_meeting_block(&self, f: &mut Frame, meeting: &Meeting, area: Rect, theme: &Theme) {
+        let selected = self.selected_id.as_deref() == Some(meeting.id.as_str());
+        let bar_color = match meeting.color_rgb() {
+            Some((r, g, b)) => theme.paint(Color::Rgb(r, g, b)),
+            None if meeting.hours_conflict.is_some() => theme.error,
+            None => theme.label,
+        };
+        let mut style = meeting_style(meeting, theme);
+        if selected {
+            // There's no arrow column in a grid, so the selected block is always reversed
+            style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+        }
+
+        let start = meeting.start_time.with_timezone(&chrono::Local);
+        let mut lines = vec![Line::from(meeting.summary.clone())];
+        if area.height > 1 {
+            lines.push(Line::from(Span::styled(
+                start.format("%l:%M%P").to_string().trim().to_string(),
+                Style::default().fg(theme.muted),
+            )));
+        }
+
+        let block = Block::default()
+            .borders(Borders::LEFT)
+            .border_style(Style::default().fg(bar_color));
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Paragraph::new(lines).block(block).style(style).wrap(Wrap { trim: true }),
+            area,
+        );
+    }
+
+    fn render_selected(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let line = match self.selected() {
+            Some(meeting) => {
+                let mut spans = vec![
+                    Span::styled(meeting.time_range(), Style::default().fg(theme.label)),
+                    Span::raw(format!("  {}", meeting.summary)),
+                ];
+                if let Some(ticket) = &meeting.ticket {
+                    spans.push(Span::styled(format!("  {}", ticket), Style::default().fg(theme.special)));
+                }
+                if let Some(conflict) = meeting.hours_conflict {
+                    spans.push(Span::styled(format!("  ⚠ {}", conflict.label()), Style::default().fg(theme.error)));
+                }
+                Line::from(spans)
+            }
+            None => Line::from(Span::styled("No meetings this week. [/] to see other weeks.", Style::default().fg(theme.muted))),
+        };
+        f.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
     }
 }
 
-pub struct AssigneeSelector {
-    selected_index: usize,
-    users: Vec<User>,
-    filtered_indices: Vec<usize>,
-    current_user_id: String,
-    search_query: String,
-    search_mode: bool,
-    scroll_offset: usize,
-    viewport_height: usize,
+pub struct MeetingDetailDisplay {
+    scroll: TextScroll,
 }
 
-impl AssigneeSelector {
-    pub fn show(users: Vec<User>, current_user_id: String) -> Result<Option<String>> {
+impl MeetingDetailDisplay {
+    pub fn show(meeting: &crate::google::Meeting, theme: &Theme) -> Result<()> {
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -1139,79 +9153,65 @@ impl AssigneeSelector {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        let filtered_indices: Vec<usize> = (0..users.len()).collect();
-        let mut app = Self {
-            selected_index: 0,
-            users,
-            filtered_indices,
-            current_user_id,
-            search_query: String::new(),
-            search_mode: false,
-            scroll_offset: 0,
-            viewport_height: 20, // Will be updated during first render
-        };
-        
-        let mut selected_account_id: Option<String> = None;
+        let mut app = Self { scroll: TextScroll::default() };
         let mut should_quit = false;
+        let mut toasts = Toasts::default();
+        let mut clipboard = crate::clipboard::Clipboard::default();
 
         // Main loop
         while !should_quit {
-            terminal.draw(|f| app.draw(f))?;
+            terminal.draw(|f| app.draw(f, meeting, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
 
             if let Event::Key(key) = event::read()? {
-                if app.search_mode {
-                    match key.code {
-                        KeyCode::Esc => {
-                            app.search_mode = false;
-                        }
-                        KeyCode::Enter => {
-                            app.search_mode = false;
-                        }
-                        KeyCode::Backspace => {
-                            app.search_query.pop();
-                            app.update_filter();
-                        }
-                        KeyCode::Char(c) => {
-                            app.search_query.push(c);
-                            app.update_filter();
+                if !app.scroll.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                if app.scroll.handle_key(&key) {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char('y') => {
+                        match &meeting.meeting_url {
+                            Some(url) => match clipboard.copy(url) {
+                                Ok(_) => toasts.success("✓ Meeting URL copied to clipboard"),
+                                Err(e) => toasts.error(format!("✗ {}", e)),
+                            },
+                            None => toasts.info("No meeting URL available"),
                         }
-                        _ => {}
                     }
-                } else {
-                    match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
-                        KeyCode::Char('/') => {
-                            app.search_mode = true;
-                        }
-                        KeyCode::Up => {
-                            if app.selected_index > 0 {
-                                app.selected_index -= 1;
-                                app.update_scroll_offset(app.viewport_height); // Typical terminal height
-                            }
+                    KeyCode::Char('Y') => {
+                        match clipboard.copy(&meeting.invite_text()) {
+                            Ok(_) => toasts.success("✓ Invite copied to clipboard"),
+                            Err(e) => toasts.error(format!("✗ {}", e)),
                         }
-                        KeyCode::Down => {
-                            let total_items = app.filtered_indices.len() + 2; // +2 for "Myself" and "None"
-                            if app.selected_index < total_items.saturating_sub(1) {
-                                app.selected_index += 1;
-                                app.update_scroll_offset(app.viewport_height);
-                            }
+                    }
+                    KeyCode::Enter | KeyCode::Char('o') => {
+                        match &meeting.meeting_url {
+                            Some(url) => match webbrowser::open(url) {
+                                Ok(_) => toasts.success("✓ Opened meeting in browser"),
+                                Err(e) => toasts.error(format!("✗ Failed to open browser: {}", e)),
+                            },
+                            None => toasts.info("No meeting URL available"),
                         }
-                        KeyCode::Enter => {
-                            if app.selected_index == 0 {
-                                // "Myself" selected
-                                selected_account_id = Some(app.current_user_id.clone());
-                            } else if app.selected_index == 1 {
-                                // "None" selected - return a special marker
-                                selected_account_id = Some("UNASSIGN".to_string());
-                            } else if let Some(&user_idx) = app.filtered_indices.get(app.selected_index - 2) {
-                                if let Some(user) = app.users.get(user_idx) {
-                                    selected_account_id = Some(user.account_id.clone());
-                                }
-                            }
-                            should_quit = true;
+                    }
+                    KeyCode::Char(c @ ('n' | 'r')) => {
+                        let kind = if c == 'n' { AttachmentKind::Notes } else { AttachmentKind::Recording };
+                        match meeting.attachment(kind) {
+                            Some(attachment) => match webbrowser::open(&attachment.url) {
+                                Ok(_) => toasts.success(format!("✓ Opened {}", attachment.title)),
+                                Err(e) => toasts.error(format!("✗ Failed to open browser: {}", e)),
+                            },
+                            None => toasts.info(format!("No {} attached", kind.label().to_lowercase())),
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
             }
         }
@@ -1221,202 +9221,120 @@ impl AssigneeSelector {
         execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
         terminal.show_cursor()?;
 
-        Ok(selected_account_id)
-    }
-    
-    fn update_filter(&mut self) {
-        self.filtered_indices.clear();
-        let query_lower = self.search_query.to_lowercase();
-        
-        for (idx, user) in self.users.iter().enumerate() {
-            if user.display_name.to_lowercase().contains(&query_lower) {
-                self.filtered_indices.push(idx);
-            }
-        }
-        
-        // Reset selection if current selection is out of bounds
-        let total_items = self.filtered_indices.len() + 1; // +1 for "Myself"
-        if self.selected_index >= total_items {
-            self.selected_index = 0;
-        }
-        
-        // Reset scroll to show selected item
-        self.scroll_offset = 0;
-        self.update_scroll_offset(self.viewport_height);
-    }
-    
-    fn update_scroll_offset(&mut self, viewport_height: usize) {
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
-        } else if self.selected_index >= self.scroll_offset + viewport_height {
-            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
-        }
+        Ok(())
     }
 
-    fn draw(&mut self, f: &mut Frame) {
+    fn draw(&mut self, f: &mut Frame, meeting: &crate::google::Meeting, toasts: &Toasts, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
-                Constraint::Length(if self.search_mode { 5 } else { 3 }),    // Header (bigger in search mode)
-                Constraint::Min(0),       // User list
+                Constraint::Min(0),       // Details
                 Constraint::Length(2),    // Help text
             ])
             .split(f.area());
 
-        self.render_header(f, chunks[0]);
-        self.render_user_list(f, chunks[1]);
-        self.render_help(f, chunks[2]);
-    }
-
-    fn render_header(&self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Select Assignee ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+        let label = Style::default().fg(theme.label).add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::from(vec![Span::styled("Meeting: ", label), Span::raw(&meeting.summary)]),
+            Line::from(vec![Span::styled("When: ", label), Span::raw(meeting.time_range())]),
+            Line::from(vec![
+                Span::styled("Link: ", label),
+                Span::raw(meeting.meeting_url.as_deref().unwrap_or("(none)")),
+            ]),
+        ];
 
-        if self.search_mode {
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                    Constraint::Length(1),
-                ])
-                .split(inner);
-                
-            let header_text = vec![
-                Line::from(vec![
-                    Span::styled("Search for users", Style::default().fg(Color::Cyan)),
-                ]),
-            ];
-            let paragraph = Paragraph::new(header_text);
-            f.render_widget(paragraph, chunks[0]);
-            
-            let search_text = vec![
-                Line::from(vec![
-                    Span::styled("Filter: ", Style::default().fg(Color::Yellow)),
-                    Span::raw(&self.search_query),
-                    Span::styled("_", Style::default().fg(Color::Yellow).add_modifier(Modifier::SLOW_BLINK)),
-                ]),
-            ];
-            let search_para = Paragraph::new(search_text);
-            f.render_widget(search_para, chunks[1]);
-            
-            let found_count = self.filtered_indices.len();
-            let count_text = vec![
-                Line::from(vec![
-                    Span::styled(format!("{} user(s) found", found_count), Style::default().fg(Color::DarkGray)),
-                ]),
-            ];
-            let count_para = Paragraph::new(count_text);
-            f.render_widget(count_para, chunks[2]);
-        } else {
-            let header_text = vec![
-                Line::from(vec![
-                    Span::styled("Select a user to assign the issue to", Style::default().fg(Color::Cyan)),
-                ]),
-            ];
-            let paragraph = Paragraph::new(header_text);
-            f.render_widget(paragraph, inner);
+        if let Some(follow_up) = meeting.follow_up_label() {
+            lines.push(Line::from(Span::styled(
+                format!("● {}", follow_up),
+                Style::default().fg(theme.success).add_modifier(Modifier::BOLD),
+            )));
         }
-    }
 
-    fn render_user_list(&mut self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Users ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+        if let Some(conflict) = meeting.hours_conflict {
+            lines.push(Line::from(Span::styled(
+                format!("⚠ Scheduled {}", conflict.label()),
+                Style::default().fg(theme.error).add_modifier(Modifier::BOLD),
+            )));
+        }
         
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+        let attendance = if meeting.is_optional { "Optional" } else { "Required" };
+        let importance = match meeting.importance {
+            Importance::Low => "Low",
+            Importance::Normal => "Normal",
+            Importance::High => "High",
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Attendance: ", label),
+            Span::raw(format!("{} ({} priority)", attendance, importance)),
+        ]));
+        if let (Some(name), Some((r, g, b))) = (meeting.color_name(), meeting.color_rgb()) {
+            lines.push(Line::from(vec![
+                Span::styled("Color: ", label),
+                Span::styled("● ", Style::default().fg(theme.paint(Color::Rgb(r, g, b)))),
+                Span::raw(name),
+            ]));
+        }
 
-        let viewport_height = inner.height as usize;
-        self.viewport_height = viewport_height; // Store for use in key handlers
-        let mut items: Vec<ListItem> = Vec::new();
-        
-        // Calculate total items and adjust scroll
-        let total_items = self.filtered_indices.len() + 2; // +2 for "Myself" and "None"
-        
-        // Use the persisted scroll_offset
-        let scroll_offset = self.scroll_offset;
-        
-        // Show scroll indicators
-        if scroll_offset > 0 {
-            items.push(ListItem::new("↑ more above ↑").style(Style::default().fg(Color::DarkGray)));
+        if let Some(location) = &meeting.location {
+            lines.push(Line::from(vec![Span::styled("Location: ", label), Span::raw(location)]));
         }
-        
-        let start_idx = if scroll_offset > 0 { scroll_offset + 1 } else { scroll_offset };
-        let end_idx = (start_idx + viewport_height.saturating_sub(if scroll_offset > 0 { 2 } else { 1 }))
-            .min(total_items);
-        
-        // Add visible items
-        for visible_idx in start_idx..end_idx {
-            if visible_idx == 0 {
-                // "Myself" option
-                let indicator = if self.selected_index == 0 { "➤ " } else { "  " };
-                let text = format!("{} Myself", indicator);
-                items.push(ListItem::new(text).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
-            } else if visible_idx == 1 {
-                // "None" option (unassign)
-                let indicator = if self.selected_index == 1 { "➤ " } else { "  " };
-                let text = format!("{} None (unassign)", indicator);
-                items.push(ListItem::new(text).style(Style::default().fg(Color::Red)));
-            } else if let Some(&user_idx) = self.filtered_indices.get(visible_idx - 2) {
-                if let Some(user) = self.users.get(user_idx) {
-                    let indicator = if visible_idx == self.selected_index { "➤ " } else { "  " };
-                    
-                    let display_text = if user.account_id == self.current_user_id {
-                        format!("{}{} (you)", indicator, user.display_name)
-                    } else {
-                        format!("{}{}", indicator, user.display_name)
-                    };
-                    
-                    items.push(ListItem::new(display_text));
-                }
-            }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Dial-in", label)));
+        if meeting.dial_ins.is_empty() {
+            lines.push(Line::from(Span::styled("  (No phone dial-in available)", Style::default().fg(theme.muted))));
         }
-        
-        // Show scroll indicator at bottom
-        if end_idx < total_items {
-            items.push(ListItem::new("↓ more below ↓").style(Style::default().fg(Color::DarkGray)));
+        for dial_in in &meeting.dial_ins {
+            let pin = dial_in.pin.as_ref()
+                .map(|p| format!("  PIN: {}", p))
+                .unwrap_or_default();
+            lines.push(Line::from(format!("  {}{}", dial_in.number, pin)));
         }
 
-        let list = List::new(items)
-            .block(Block::default());
+        if !meeting.attachments.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Attachments", label)));
+            for attachment in &meeting.attachments {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("  {:<11} {}  ", attachment.kind().label(), attachment.title)),
+                    Span::styled(attachment.url.clone(), Style::default().fg(theme.muted)),
+                ]));
+            }
+        }
 
-        f.render_widget(list, inner);
-    }
+        if let Some(description) = &meeting.description {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Description", label)));
+            for line in description.lines() {
+                lines.push(Line::from(line.to_string()));
+            }
+        }
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Meeting Details ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        let inner = block.inner(chunks[0]);
+        f.render_widget(block, chunks[0]);
+        let paragraph = self.scroll.paragraph(lines, inner);
+        f.render_widget(paragraph, inner);
 
-    fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help_text = if self.search_mode {
-            "Type to filter | Enter: Confirm | ESC: Cancel search"
-        } else {
-            "↑/↓: Navigate | /: Search | Enter: Select | q/ESC: Cancel"
-        };
-        
-        let help = Paragraph::new(help_text)
-            .style(Style::default().fg(Color::DarkGray))
+        let help = Paragraph::new("↑/↓ j/k: Scroll | /: Search | o/Enter: Join | n/r: Open notes/recording | y: Copy URL | Y: Copy invite | q/ESC: Back")
+            .style(Style::default().fg(theme.muted))
             .alignment(Alignment::Center);
-        f.render_widget(help, area);
+        f.render_widget(help, chunks[1]);
+        self.scroll.search.render(f, theme);
+        toasts.render(f, theme);
     }
 }
 
-pub struct AllEpicsDisplay {
-    selected_index: usize,
-    epics: Vec<JiraIssue>,
-    filtered_indices: Vec<usize>,
-    search_query: String,
-    search_mode: bool,
-    scroll_offset: usize,
-    viewport_height: usize,
+pub struct MeetingPrepDisplay {
+    scroll: TextScroll,
 }
 
-impl AllEpicsDisplay {
-    pub fn show(epics: Vec<JiraIssue>, client: &JiraClient) -> Result<()> {
+impl MeetingPrepDisplay {
+    pub fn show(prep: &crate::prep::MeetingPrep, theme: &Theme) -> Result<()> {
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -1424,91 +9342,59 @@ impl AllEpicsDisplay {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        let epic_count = epics.len();
-        let filtered_indices: Vec<usize> = (0..epic_count).collect();
-        
-        let mut app = Self {
-            selected_index: 0,
-            epics,
-            filtered_indices,
-            search_query: String::new(),
-            search_mode: false,
-            scroll_offset: 0,
-            viewport_height: 20, // Will be updated during first render
-        };
-        
+        let mut app = Self { scroll: TextScroll::default() };
         let mut should_quit = false;
-        let mut message: Option<String> = None;
+        let mut toasts = Toasts::default();
+        let notes = prep.previous.as_ref().and_then(|m| m.attachments.first());
+        let mut clipboard = crate::clipboard::Clipboard::default();
 
         // Main loop
         while !should_quit {
-            terminal.draw(|f| app.draw(f, &message))?;
+            terminal.draw(|f| app.draw(f, prep, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
 
             if let Event::Key(key) = event::read()? {
-                if app.search_mode {
-                    match key.code {
-                        KeyCode::Esc => {
-                            app.search_mode = false;
-                        }
-                        KeyCode::Enter => {
-                            app.search_mode = false;
-                        }
-                        KeyCode::Backspace => {
-                            app.search_query.pop();
-                            app.update_filter();
-                        }
-                        KeyCode::Char(c) => {
-                            app.search_query.push(c);
-                            app.update_filter();
+                if !app.scroll.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+
+                if app.scroll.handle_key(&key) {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Enter | KeyCode::Char('o') => {
+                        match &prep.meeting.meeting_url {
+                            Some(url) => match webbrowser::open(url) {
+                                Ok(_) => toasts.success("✓ Opened meeting in browser"),
+                                Err(e) => toasts.error(format!("✗ Failed to open browser: {}", e)),
+                            },
+                            None => toasts.info("No meeting URL available"),
                         }
-                        _ => {}
                     }
-                } else {
-                    match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
-                        KeyCode::Char('/') => {
-                            app.search_mode = true;
-                        }
-                        KeyCode::Up => {
-                            if app.selected_index > 0 {
-                                app.selected_index -= 1;
-                                app.update_scroll_offset(app.viewport_height);
-                            }
+                    KeyCode::Char('n') => {
+                        match notes {
+                            Some(notes) => match webbrowser::open(&notes.url) {
+                                Ok(_) => toasts.success(format!("✓ Opened {}", notes.title)),
+                                Err(e) => toasts.error(format!("✗ Failed to open browser: {}", e)),
+                            },
+                            None => toasts.info("No notes from last time"),
                         }
-                        KeyCode::Down => {
-                            if app.selected_index < app.filtered_indices.len().saturating_sub(1) {
-                                app.selected_index += 1;
-                                app.update_scroll_offset(app.viewport_height);
-                            }
-                        }
-                        KeyCode::Char('v') => {
-                            if let Some(&epic_idx) = app.filtered_indices.get(app.selected_index) {
-                                if let Some(epic) = app.epics.get(epic_idx) {
-                                    let epic_key = epic.key.clone();
-                                    // Temporarily restore terminal for nested UI
-                                    disable_raw_mode()?;
-                                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-                                    terminal.show_cursor()?;
-                                    
-                                    // Fetch and show the epic with its children
-                                    println!("Fetching child issues for {}...", epic_key);
-                                    if let Ok(children) = client.get_epic_children(&epic_key) {
-                                        let _ = EpicListDisplay::show(epic, children, client);
-                                    }
-                                    
-                                    // Re-setup terminal
-                                    enable_raw_mode()?;
-                                    let mut stdout = io::stdout();
-                                    execute!(stdout, EnterAlternateScreen)?;
-                                    let backend = CrosstermBackend::new(stdout);
-                                    terminal = Terminal::new(backend)?;
-                                    
-                                    message = Some(format!("Returned from viewing {}", epic_key));
-                                }
-                            }
+                    }
+                    KeyCode::Char('y') => {
+                        match &prep.meeting.meeting_url {
+                            Some(url) => match clipboard.copy(url) {
+                                Ok(_) => toasts.success("✓ Meeting URL copied to clipboard"),
+                                Err(e) => toasts.error(format!("✗ {}", e)),
+                            },
+                            None => toasts.info("No meeting URL available"),
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
             }
         }
@@ -1520,258 +9406,350 @@ impl AllEpicsDisplay {
 
         Ok(())
     }
-    
-    fn update_scroll_offset(&mut self, viewport_height: usize) {
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
-        } else if self.selected_index >= self.scroll_offset + viewport_height {
-            self.scroll_offset = self.selected_index.saturating_sub(viewport_height - 1);
-        }
-    }
-    
-    fn update_filter(&mut self) {
-        self.filtered_indices.clear();
-        let query_lower = self.search_query.to_lowercase();
-        
-        for (idx, epic) in self.epics.iter().enumerate() {
-            let key_matches = epic.key.to_lowercase().contains(&query_lower);
-            let summary_matches = epic.fields.summary.to_lowercase().contains(&query_lower);
-            
-            if key_matches || summary_matches {
-                self.filtered_indices.push(idx);
-            }
-        }
-        
-        // Reset selection if current selection is out of bounds
-        if self.selected_index >= self.filtered_indices.len() {
-            self.selected_index = 0;
-        }
-        
-        // Reset scroll to show selected item
-        self.scroll_offset = 0;
-        self.update_scroll_offset(self.viewport_height);
-    }
 
-    fn draw(&mut self, f: &mut Frame, message: &Option<String>) {
-        let constraints = if self.search_mode {
-            vec![
-                Constraint::Length(3),     // Header
-                Constraint::Length(3),     // Search bar
-                Constraint::Min(0),        // Epic list
-                Constraint::Length(1),     // Message line
-                Constraint::Length(2),     // Help text
-            ]
-        } else {
-            vec![
-                Constraint::Length(3),     // Header
-                Constraint::Min(0),        // Epic list
-                Constraint::Length(1),     // Message line
-                Constraint::Length(2),     // Help text
-            ]
-        };
-        
+    fn draw(&mut self, f: &mut Frame, prep: &crate::prep::MeetingPrep, toasts: &Toasts, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
-            .constraints(constraints)
+            .constraints([
+                Constraint::Min(0),       // Prep notes
+                Constraint::Length(2),    // Help text
+            ])
             .split(f.area());
 
-        if self.search_mode {
-            self.render_header(f, chunks[0]);
-            self.render_search_bar(f, chunks[1]);
-            self.render_epics_table(f, chunks[2]);
-            self.render_message(f, chunks[3], message);
-            self.render_help(f, chunks[4]);
-        } else {
-            self.render_header(f, chunks[0]);
-            self.render_epics_table(f, chunks[1]);
-            self.render_message(f, chunks[2], message);
-            self.render_help(f, chunks[3]);
+        let meeting = &prep.meeting;
+        let label = Style::default().fg(theme.label).add_modifier(Modifier::BOLD);
+        let muted = Style::default().fg(theme.muted);
+        let empty = |text: &str| Line::from(Span::styled(format!("  {}", text), muted));
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled("When: ", label), Span::raw(meeting.time_range())]),
+            Line::from(vec![
+                Span::styled("Link: ", label),
+                Span::raw(meeting.meeting_url.as_deref().unwrap_or("(none)")),
+            ]),
+        ];
+        if !meeting.attendees.is_empty() {
+            let names: Vec<&str> = meeting.attendees.iter().map(|a| a.label()).collect();
+            lines.push(Line::from(vec![Span::styled("With: ", label), Span::raw(names.join(", "))]));
+        }
+
+        if prep.has_jira {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Tickets in the invite", label)));
+            if prep.tickets.is_empty() {
+                lines.push(empty("(No ticket keys in the title or description)"));
+            }
+            for issue in &prep.tickets {
+                lines.push(Self::issue_line(issue, theme));
+            }
+        }
+
+        lines.push(Line::from(""));
+        match &prep.previous {
+            None if meeting.recurring_event_id.is_none() => {
+                lines.push(Line::from(Span::styled("Last time", label)));
+                lines.push(empty("(Not a recurring meeting)"));
+            }
+            None => {
+                lines.push(Line::from(Span::styled("Last time", label)));
+                lines.push(empty("(No earlier occurrence in the last five weeks)"));
+            }
+            Some(previous) => {
+                lines.push(Line::from(vec![
+                    Span::styled("Last time ", label),
+                    Span::styled(previous.start_time.format("%a %b %-d").to_string(), muted),
+                ]));
+                if previous.attachments.is_empty() {
+                    lines.push(empty("(No notes attached)"));
+                }
+                for attachment in &previous.attachments {
+                    lines.push(Line::from(vec![
+                        Span::raw(format!("  {}  ", attachment.title)),
+                        Span::styled(attachment.url.clone(), muted),
+                    ]));
+                }
+                // The series description repeats on every occurrence; only an edited one is news
+                if let Some(description) = &previous.description
+                    && previous.description != meeting.description
+                {
+                    for line in description.lines() {
+                        lines.push(Line::from(format!("  {}", line)));
+                    }
+                }
+            }
+        }
+
+        if prep.has_jira {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Attendees' shared tickets", label)));
+            if prep.attendee_tickets.is_empty() {
+                lines.push(empty("(No open tickets of theirs that you reported, watch, or share an epic with)"));
+            }
+            for group in &prep.attendee_tickets {
+                lines.push(Line::from(Span::styled(format!("  {}", group.attendee.label()), Style::default().fg(theme.accent))));
+                for issue in &group.issues {
+                    lines.push(Self::issue_line(issue, theme));
+                }
+            }
+        }
+
+        if let Some(description) = &meeting.description {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Description", label)));
+            for line in description.lines() {
+                lines.push(Line::from(format!("  {}", line)));
+            }
         }
-    }
 
-    fn render_header(&self, f: &mut Frame, area: Rect) {
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(" All Epics ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+            .title(format!(" Prep: {} ", meeting.summary))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+        let inner = block.inner(chunks[0]);
+        f.render_widget(block, chunks[0]);
+        let paragraph = self.scroll.paragraph(lines, inner);
+        f.render_widget(paragraph, inner);
 
-        let header_text = if !self.search_query.is_empty() {
-            vec![
-                Line::from(vec![
-                    Span::styled("Filtered Epics: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-                    Span::raw(format!("{} / {}", self.filtered_indices.len(), self.epics.len())),
-                ]),
-            ]
-        } else {
-            vec![
-                Line::from(vec![
-                    Span::styled("Total Epics: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                    Span::raw(self.epics.len().to_string()),
-                ]),
-            ]
-        };
+        let help = Paragraph::new("↑/↓ j/k: Scroll | /: Search | o/Enter: Join | n: Open last notes | y: Copy URL | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+        self.scroll.search.render(f, theme);
+        toasts.render(f, theme);
+    }
 
-        let paragraph = Paragraph::new(header_text);
-        f.render_widget(paragraph, inner);
+    fn issue_line(issue: &JiraIssue, theme: &Theme) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("  {:<10} ", issue.key), Style::default().fg(theme.highlight)),
+            Span::styled(format!("[{}] ", issue.fields.status.name), Style::default().fg(theme.muted)),
+            Span::raw(issue.fields.summary.clone()),
+        ])
     }
-    
-    fn render_search_bar(&self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Search ")
-            .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
-        
-        let search_text = vec![
-            Line::from(vec![
-                Span::styled("Filter: ", Style::default().fg(Color::Cyan)),
-                Span::raw(&self.search_query),
-                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
-            ]),
-        ];
+}
+
+pub struct TodayDisplay {
+    summary: crate::today::TodaySummary,
+}
+
+impl TodayDisplay {
+    pub fn show(summary: crate::today::TodaySummary, client: &JiraClient, calendar: Option<&BlockingCalendarClient>, theme: &Theme) -> Result<()> {
+        use crate::today::Badge;
         
-        let paragraph = Paragraph::new(search_text);
-        f.render_widget(paragraph, inner);
+        // Setup terminal
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+
+        let app = Self { summary };
+        let mut should_quit = false;
+        let mut toasts = Toasts::default();
+
+        // Main loop
+        while !should_quit {
+            terminal.draw(|f| app.draw(f, &toasts, theme))?;
+
+            if !toasts.poll()? {
+                continue;
+            }
+
+            if let Event::Key(key) = event::read()? {
+                if toasts.handle_key(&key) {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
+                    KeyCode::Char(c) => {
+                        let Some(badge) = Badge::from_hotkey(c) else {
+                            continue;
+                        };
+                        
+                        if app.summary.count(badge) == Some(0) {
+                            toasts.info(format!("Nothing {}", badge.label().to_lowercase()));
+                            continue;
+                        }
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        match (badge, calendar, &app.summary.meetings) {
+                            (Badge::MeetingsLeft, Some(calendar), Some(meetings)) => {
+                                let _ = MeetingsListDisplay::show(meetings.clone(), calendar, Some(client), theme);
+                            }
+                            (Badge::MeetingsLeft, _, _) => {
+                                toasts.info("Google Calendar is not configured. Run 'qq config google' first.");
+                            }
+                            _ => {
+                                let _ = MyIssuesDisplay::show(app.summary.issues_for(badge), client, theme);
+                            }
+                        }
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Restore terminal
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        Ok(())
+    }
+
+    fn draw(&self, f: &mut Frame, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3),    // Badges
+                Constraint::Min(0),       // Meetings and issues
+                Constraint::Length(2),    // Help text
+            ])
+            .split(f.area());
+
+        self.render_badges(f, chunks[0], theme);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(chunks[1]);
+        self.render_meetings(f, body[0], theme);
+        self.render_issues(f, body[1], theme);
+
+        let help = Paragraph::new("1-5: Open list | :messages: History | q/ESC: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+        toasts.render(f, theme);
     }
 
-    fn render_epics_table(&mut self, f: &mut Frame, area: Rect) {
+    fn render_badges(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        use crate::today::Badge;
+        
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(" Epics ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
-
-        if self.filtered_indices.is_empty() {
-            let text = if self.search_query.is_empty() {
-                "(No epics found)"
-            } else {
-                "(No epics match your search)"
+            .title(format!(" Today - {} ", chrono::Local::now().format("%A, %B %-d")))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+
+        let mut spans = Vec::new();
+        for badge in Badge::ALL {
+            let (count, style) = match self.summary.count(badge) {
+                Some(0) => ("0".to_string(), Style::default().fg(theme.muted)),
+                Some(n) => {
+                    let color = match badge {
+                        Badge::Blocked => theme.error,
+                        Badge::DueThisWeek => theme.highlight,
+                        _ => theme.label,
+                    };
+                    (n.to_string(), Style::default().fg(color).add_modifier(Modifier::BOLD))
+                }
+                None => ("—".to_string(), Style::default().fg(theme.muted)),
             };
-            let paragraph = Paragraph::new(text)
-                .style(Style::default().fg(Color::DarkGray))
-                .alignment(Alignment::Center);
-            f.render_widget(paragraph, inner);
-            return;
+            spans.push(Span::styled(format!("[{}] ", badge.hotkey()), Style::default().fg(theme.muted)));
+            spans.push(Span::raw(format!("{} ", badge.label())));
+            spans.push(Span::styled(count, style));
+            spans.push(Span::raw("   "));
         }
 
-        // Create table headers
-        let header_cells: Vec<Cell> = vec!["", "Key", "Status", "Summary"]
-            .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)))
-            .collect();
-        let header = Row::new(header_cells).height(1);
+        let paragraph = Paragraph::new(Line::from(spans))
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+    }
 
-        // Calculate viewport dimensions for table (accounting for header)
-        let viewport_height = inner.height.saturating_sub(2) as usize; // -2 for header and border
-        self.viewport_height = viewport_height; // Store for use in key handlers
-        
-        // Use the persisted scroll_offset with filtered indices
-        let visible_start = self.scroll_offset;
-        let visible_end = (self.scroll_offset + viewport_height).min(self.filtered_indices.len());
-        
-        let rows: Vec<Row> = self.filtered_indices[visible_start..visible_end]
-            .iter()
-            .enumerate()
-            .map(|(visible_idx, &epic_idx)| {
-                let epic = &self.epics[epic_idx];
-                let actual_idx = visible_start + visible_idx;
-                
-                // Color code the status
-                let status_style = match epic.fields.status.name.to_lowercase().as_str() {
-                    s if s.contains("done") || s.contains("closed") => Style::default().fg(Color::Green),
-                    s if s.contains("progress") => Style::default().fg(Color::Yellow),
-                    s if s.contains("review") => Style::default().fg(Color::Magenta),
-                    _ => Style::default().fg(Color::White),
-                };
-                
-                // Selection indicator
-                let indicator = if actual_idx == self.selected_index { "➤" } else { "" };
-                
-                let cells = vec![
-                    Cell::from(indicator).style(Style::default().fg(Color::Green)),
-                    Cell::from(epic.key.clone()),
-                    Cell::from(epic.fields.status.name.clone()).style(status_style),
-                    Cell::from(epic.fields.summary.clone()),
-                ];
-                
-                Row::new(cells).height(1)
-            })
-            .collect();
+    fn render_meetings(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Remaining Meetings ")
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
 
-        // Update title with scroll indicators
-        let title = if self.epics.len() > viewport_height {
-            format!(" Epics [{}-{} of {}] ", 
-                visible_start + 1,
-                visible_end,
-                self.epics.len()
-            )
-        } else {
-            " Epics ".to_string()
+        let items: Vec<ListItem> = match &self.summary.meetings {
+            None => vec![ListItem::new("(Google Calendar not configured)").style(Style::default().fg(theme.muted))],
+            Some(meetings) if meetings.is_empty() => {
+                vec![ListItem::new("(No more meetings today)").style(Style::default().fg(theme.muted))]
+            }
+            Some(meetings) => meetings.iter()
+                .map(|m| {
+                    let start = m.start_time.with_timezone(&chrono::Local).format("%H:%M");
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("{}  ", start), Style::default().fg(theme.label)),
+                        Span::raw(m.summary.clone()),
+                    ])).style(meeting_style(m, theme))
+                })
+                .collect(),
         };
 
-        let table = Table::new(
-            rows,
-            vec![
-                Constraint::Length(3),      // Arrow indicator
-                Constraint::Length(12),     // Key
-                Constraint::Length(15),     // Status
-                Constraint::Min(20),        // Summary (takes remaining space)
-            ]
-        )
-        .header(header)
-        .block(Block::default().title(title));
-
-        f.render_widget(table, inner);
+        f.render_widget(List::new(items).block(block), area);
     }
 
-    fn render_message(&self, f: &mut Frame, area: Rect, message: &Option<String>) {
-        if let Some(msg) = message {
-            let style = if msg.starts_with('✓') {
-                Style::default().fg(Color::Green)
-            } else if msg.starts_with('✗') {
-                Style::default().fg(Color::Red)
-            } else {
-                Style::default().fg(Color::Yellow)
-            };
-            
-            let text = Paragraph::new(msg.as_str())
-                .style(style)
-                .alignment(Alignment::Center);
-            f.render_widget(text, area);
-        }
-    }
+    fn render_issues(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" My Issues ({}) ", self.summary.issues.len()))
+            .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
 
-    fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help_text = if self.search_mode {
-            "Type to search | Enter/ESC: Exit search | Backspace: Delete"
-        } else {
-            "↑/↓: Navigate | v: View Epic | /: Search | q/ESC: Quit"
-        };
-        
-        let help = Paragraph::new(help_text)
-            .style(Style::default().fg(Color::DarkGray))
-            .alignment(Alignment::Center);
-        f.render_widget(help, area);
+        let rows: Vec<ListRow> = self.summary.issues.iter()
+            .map(|issue| {
+                let status_style = if issue.fields.is_blocked() {
+                    Style::default().fg(theme.error)
+                } else {
+                    match issue.fields.status.name.to_lowercase().as_str() {
+                        s if s.contains("progress") => Style::default().fg(theme.highlight),
+                        s if s.contains("review") => Style::default().fg(theme.special),
+                        _ => Style::default().fg(theme.text),
+                    }
+                };
+                let due = issue.fields.due_date()
+                    .map(|d| d.format("%b %-d").to_string())
+                    .unwrap_or_default();
+
+                ListRow::new(vec![
+                    key_line(issue, theme),
+                    Line::from(issue.fields.status.name.clone()).style(status_style),
+                    Line::from(issue.fields.summary.clone()),
+                    Line::from(due),
+                ])
+            })
+            .collect();
+
+        const COLUMNS: [ListColumn; 4] = [
+            ListColumn::new("Key", Constraint::Length(12), 0),
+            ListColumn::new("Status", Constraint::Length(15), 0),
+            ListColumn::new("Summary", Constraint::Min(20), 0),
+            ListColumn::new("Due", Constraint::Length(7), 1),
+        ];
+        let table = fitted_table(&COLUMNS, rows, block.inner(area).width, theme).block(block);
+
+        f.render_widget(table, area);
     }
 }
 
-pub struct MeetingsListDisplay {
-    selected_index: usize,
-    meetings: Vec<crate::google::Meeting>,
-    scroll_offset: usize,
-    viewport_height: usize,
+pub struct ContextDisplay {
+    tab: usize,
+    scroll: TextScroll,
+    // Local scratch notes for the ticket, reread after editing
+    notes: Option<String>,
 }
 
-impl MeetingsListDisplay {
-    pub fn show(meetings: Vec<crate::google::Meeting>) -> Result<()> {
+impl ContextDisplay {
+    const TABS: [&'static str; 8] = ["Details", "Comments", "History", "Pull Requests", "Branches", "Meetings", "Time", "Scratch"];
+    const SCRATCH_TAB: usize = 7;
+    
+    pub fn show(context: &crate::context::TicketContext, theme: &Theme) -> Result<()> {
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -1779,52 +9757,93 @@ impl MeetingsListDisplay {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        let mut app = Self {
-            selected_index: 0,
-            meetings,
-            scroll_offset: 0,
-            viewport_height: 20, // Will be updated during first render
-        };
-        
+        let mut app = Self { tab: 0, scroll: TextScroll::default(), notes: crate::scratch::read(&context.issue.key) };
+        let mut toasts = Toasts::default();
         let mut should_quit = false;
-        let mut message: Option<String> = None;
 
         // Main loop
         while !should_quit {
-            terminal.draw(|f| app.draw(f, &message))?;
+            terminal.draw(|f| app.draw(f, context, &toasts, theme))?;
 
+            if !toasts.poll()? {
+                continue;
+            }
             if let Event::Key(key) = event::read()? {
+                if !app.scroll.search.is_typing() && toasts.handle_key(&key) {
+                    continue;
+                }
+                if app.scroll.handle_key(&key) {
+                    continue;
+                }
+
                 match key.code {
                     KeyCode::Char('q') | KeyCode::Esc => should_quit = true,
-                    KeyCode::Up => {
-                        if app.selected_index > 0 {
-                            app.selected_index -= 1;
-                            app.update_scroll();
+                    KeyCode::Right | KeyCode::Tab => {
+                        app.tab = (app.tab + 1) % Self::TABS.len();
+                        app.scroll.offset = 0;
+                    }
+                    KeyCode::Left | KeyCode::BackTab => {
+                        app.tab = (app.tab + Self::TABS.len() - 1) % Self::TABS.len();
+                        app.scroll.offset = 0;
+                    }
+                    KeyCode::Char(c @ '1'..='8') => {
+                        app.tab = c as usize - '1' as usize;
+                        app.scroll.offset = 0;
+                    }
+                    KeyCode::Char('n') => {
+                        // Temporarily restore terminal for the editor
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let edited = crate::scratch::edit(&context.issue.key);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        match edited {
+                            Ok(()) => {
+                                app.notes = crate::scratch::read(&context.issue.key);
+                                app.tab = Self::SCRATCH_TAB;
+                                app.scroll.offset = 0;
+                            }
+                            Err(e) => toasts.error(format!("✗ {}", e)),
                         }
                     }
-                    KeyCode::Down => {
-                        if app.selected_index < app.meetings.len().saturating_sub(1) {
-                            app.selected_index += 1;
-                            app.update_scroll();
+                    KeyCode::PageUp => app.scroll.scroll_by(-10),
+                    KeyCode::PageDown => app.scroll.scroll_by(10),
+                    KeyCode::Char('o') if app.tab == 3 => {
+                        if let Some(pr) = context.pull_requests.first() {
+                            let _ = webbrowser::open(&pr.url);
                         }
                     }
-                    KeyCode::Char('j') => {
-                        if let Some(meeting) = app.meetings.get(app.selected_index) {
-                            let meeting_summary = meeting.summary.clone();
-                            let meeting_url = meeting.meeting_url.clone();
-                            
-                            if let Some(url) = meeting_url {
-                                message = Some(format!("Opening meeting: {}", meeting_summary));
-                                terminal.draw(|f| app.draw(f, &message))?;
-                                
-                                if let Err(e) = webbrowser::open(&url) {
-                                    message = Some(format!("Failed to open browser: {}", e));
-                                } else {
-                                    message = Some(format!("✓ Opened meeting in browser"));
-                                }
-                            } else {
-                                message = Some(format!("No meeting URL available for: {}", meeting_summary));
-                            }
+                    KeyCode::Char('u') => {
+                        let links = Self::links(context);
+                        if links.is_empty() {
+                            toasts.info("No links in the description or comments");
+                            continue;
+                        }
+                        
+                        // Temporarily restore terminal for nested UI
+                        disable_raw_mode()?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        terminal.show_cursor()?;
+                        
+                        let picked = ListPicker::show(" Links ", links.clone(), theme);
+                        
+                        // Re-setup terminal
+                        enable_raw_mode()?;
+                        let mut stdout = io::stdout();
+                        execute!(stdout, EnterAlternateScreen)?;
+                        let backend = CrosstermBackend::new(stdout);
+                        terminal = Terminal::new(backend)?;
+                        
+                        if let Ok(Some(idx)) = picked {
+                            open_link(&links[idx], &mut toasts);
                         }
                     }
                     _ => {}
@@ -1840,162 +9859,239 @@ impl MeetingsListDisplay {
         Ok(())
     }
 
-    fn update_scroll(&mut self) {
-        if self.selected_index < self.scroll_offset {
-            self.scroll_offset = self.selected_index;
-        } else if self.selected_index >= self.scroll_offset + self.viewport_height {
-            self.scroll_offset = self.selected_index - self.viewport_height + 1;
-        }
-    }
-
-    fn draw(&mut self, f: &mut Frame, message: &Option<String>) {
+    fn draw(&mut self, f: &mut Frame, context: &crate::context::TicketContext, toasts: &Toasts, theme: &Theme) {
+        render_jira_label(f, theme);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
             .constraints([
-                Constraint::Length(3),     // Header
-                Constraint::Min(0),        // Meetings table
-                Constraint::Length(1),     // Message line
-                Constraint::Length(2),     // Help text
+                Constraint::Length(3),    // Tabs
+                Constraint::Min(0),       // Tab content
+                Constraint::Length(2),    // Help text
             ])
             .split(f.area());
 
-        self.render_header(f, chunks[0]);
-        self.render_meetings_table(f, chunks[1]);
-        self.render_message(f, chunks[2], message);
-        self.render_help(f, chunks[3]);
+        let meeting_count = context.meetings.as_ref().map(|m| m.len());
+        let counts = [
+            None,
+            Some(context.comments.len()),
+            Some(context.changes.len()),
+            Some(context.pull_requests.len()),
+            Some(context.branches.len()),
+            meeting_count,
+            None,
+            None,
+        ];
+        let titles: Vec<Line> = Self::TABS.iter()
+            .zip(counts)
+            .enumerate()
+            .map(|(idx, (name, count))| match count {
+                Some(n) => Line::from(format!("{} {} ({})", idx + 1, name, n)),
+                None => Line::from(format!("{} {}", idx + 1, name)),
+            })
+            .collect();
+        let tabs = Tabs::new(titles)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} - {} ", context.issue.key, context.issue.fields.summary))
+                .title_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)))
+            .select(self.tab)
+            .highlight_style(Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD));
+        f.render_widget(tabs, chunks[0]);
+
+        let lines = match self.tab {
+            0 => Self::details_lines(context, theme),
+            1 => Self::comment_lines(context, theme),
+            2 => Self::history_lines(context, theme),
+            3 => Self::pull_request_lines(context, theme),
+            4 => Self::branch_lines(context, theme),
+            5 => Self::meeting_lines(context, theme),
+            6 => Self::time_lines(context, theme),
+            _ => self.scratch_lines(theme),
+        };
+        let block = Block::default().borders(Borders::ALL);
+        let inner = block.inner(chunks[1]);
+        f.render_widget(block, chunks[1]);
+        let content = self.scroll.paragraph(lines, inner);
+        f.render_widget(content, inner);
+
+        let help_text = if self.tab == 3 {
+            "←/→ or 1-8: Switch tab | ↑/↓ j/k: Scroll | /: Search | o: Open first PR | u: Links | n: Scratch notes | q/ESC: Quit"
+        } else {
+            "←/→ or 1-8: Switch tab | ↑/↓ j/k: Scroll | /: Search | u: Links | n: Scratch notes | q/ESC: Quit"
+        };
+        let help = Paragraph::new(help_text)
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+        self.scroll.search.render(f, theme);
+        toasts.render(f, theme);
     }
 
-    fn render_header(&self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Today's Meetings ")
-            .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+    fn label(name: &str, theme: &Theme) -> Span<'static> {
+        Span::styled(format!("{}: ", name), Style::default().fg(theme.label).add_modifier(Modifier::BOLD))
+    }
 
-        let header_text = vec![
+    fn empty(text: &str, theme: &Theme) -> Vec<Line<'static>> {
+        vec![Line::from(Span::styled(text.to_string(), Style::default().fg(theme.muted)))]
+    }
+
+    fn details_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        let fields = &context.issue.fields;
+        let mut lines = vec![
+            Line::from(vec![Self::label("Status", theme), Span::raw(fields.status.name.clone())]),
             Line::from(vec![
-                Span::styled("Total Meetings: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(self.meetings.len().to_string()),
+                Self::label("Assignee", theme),
+                Span::raw(fields.assignee.as_ref().map(|u| u.display_name.clone()).unwrap_or_else(|| "Unassigned".to_string())),
             ]),
         ];
+        if let Some(priority) = &fields.priority {
+            lines.push(Line::from(vec![Self::label("Priority", theme), Span::raw(priority.name.clone())]));
+        }
+        if let Some(parent) = &fields.parent {
+            lines.push(Line::from(vec![Self::label("Parent", theme), Span::raw(format!("{} - {}", parent.key, parent.fields.summary))]));
+        }
+        if let Some(due) = fields.due_date() {
+            lines.push(Line::from(vec![Self::label("Due", theme), Span::raw(due.format("%a %b %-d").to_string())]));
+        }
+        for link in &fields.issuelinks {
+            let (relation, other) = match (&link.inward_issue, &link.outward_issue) {
+                (Some(issue), _) => (&link.link_type.inward, issue),
+                (None, Some(issue)) => (&link.link_type.outward, issue),
+                (None, None) => continue,
+            };
+            lines.push(Line::from(vec![
+                Self::label("Link", theme),
+                Span::raw(format!("{} {} - {} [{}]", relation, other.key, other.fields.summary, other.fields.status.name)),
+            ]));
+        }
 
-        let paragraph = Paragraph::new(header_text);
-        f.render_widget(paragraph, inner);
+        lines.push(Line::from(""));
+        match &fields.description {
+            Some(description) => lines.extend(crate::jira::adf::to_text(description, theme).lines),
+            None => lines.extend(Self::empty("(No description provided)", theme)),
+        }
+        lines.extend(link_footnotes(&Self::links(context), false, theme));
+        lines
     }
 
-    fn render_meetings_table(&mut self, f: &mut Frame, area: Rect) {
-        let block = Block::default()
-            .borders(Borders::ALL);
-        
-        let inner = block.inner(area);
-        f.render_widget(block, area);
+    // Links in the description, then in the comments
+    fn links(context: &crate::context::TicketContext) -> Vec<String> {
+        let mut links: Vec<String> = context.issue.fields.description.iter()
+            .chain(context.comments.iter().filter_map(|comment| comment.body.as_ref()))
+            .flat_map(crate::jira::adf::links)
+            .collect();
+        let mut seen = HashSet::new();
+        links.retain(|url| seen.insert(url.clone()));
+        links
+    }
 
-        // Update viewport height
-        self.viewport_height = inner.height.saturating_sub(2) as usize;
+    fn comment_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        if context.comments.is_empty() {
+            return Self::empty("(No comments)", theme);
+        }
 
-        // Create table headers
-        let header_cells = ["", "Day", "Time", "Meeting Name", "Status", "URL"]
-            .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
-        let header = Row::new(header_cells).height(1);
+        let mut lines = Vec::new();
+        for comment in &context.comments {
+            let author = comment.author.as_ref().map(|a| a.display_name.clone()).unwrap_or_else(|| "Unknown".to_string());
+            let created = parse_jira_date(&comment.created);
+            lines.push(Line::from(vec![
+                Span::styled(author, Style::default().fg(theme.label).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  {}", created), Style::default().fg(theme.muted)),
+            ]));
+            if let Some(body) = &comment.body {
+                lines.extend(crate::jira::adf::to_text(body, theme).lines);
+            }
+            lines.push(Line::from(""));
+        }
+        lines
+    }
 
-        // Create table rows
-        let now = chrono::Utc::now().with_timezone(&New_York);
-        let rows: Vec<Row> = self.meetings
-            .iter()
-            .enumerate()
-            .skip(self.scroll_offset)
-            .take(self.viewport_height)
-            .map(|(idx, meeting)| {
-                let day_str = if meeting.start_time.date_naive() == now.date_naive() {
-                    "Today".to_string()
-                } else if meeting.start_time.date_naive() == (now + chrono::Duration::days(1)).date_naive() {
-                    "Tomorrow".to_string()
-                } else {
-                    meeting.start_time.format("%a %b %d").to_string()
-                };
-                
-                let time_str = format!(
-                    "{}:{:02} {} - {}:{:02} {}",
-                    meeting.start_time.format("%l").to_string().trim(),
-                    meeting.start_time.minute(),
-                    meeting.start_time.format("%p"),
-                    meeting.end_time.format("%l").to_string().trim(),
-                    meeting.end_time.minute(),
-                    meeting.end_time.format("%p")
-                );
-                
-                let status = if now >= meeting.start_time && now <= meeting.end_time {
-                    "In Progress"
-                } else if now < meeting.start_time {
-                    "Upcoming"
-                } else {
-                    "Ended"
-                };
-                
-                let status_color = match status {
-                    "In Progress" => Color::Green,
-                    "Upcoming" => Color::Cyan,
-                    _ => Color::DarkGray,
-                };
-                
-                let url_status = if meeting.meeting_url.is_some() {
-                    "Available"
-                } else {
-                    "Not Available"
-                };
-                
-                // Selection indicator
-                let indicator = if idx + self.scroll_offset == self.selected_index { "➤" } else { "" };
-                
-                let cells = vec![
-                    Cell::from(indicator).style(Style::default().fg(Color::Green)),
-                    Cell::from(day_str),
-                    Cell::from(time_str),
-                    Cell::from(meeting.summary.clone()),
-                    Cell::from(status).style(Style::default().fg(status_color)),
-                    Cell::from(url_status),
-                ];
-                
-                Row::new(cells)
+    fn history_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        if context.changes.is_empty() {
+            return Self::empty("(No changes recorded)", theme);
+        }
+
+        context.changes.iter()
+            .map(|change| {
+                let when = change.created
+                    .map(|c| c.format("%b %d %H:%M").to_string())
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::styled(format!("{:<13}", when), Style::default().fg(theme.muted)),
+                    Span::styled(format!("{:<20}", change.author), Style::default().fg(theme.label)),
+                    Span::styled(format!("{}: ", change.field), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!("{} → {}", if change.from.is_empty() { "∅" } else { &change.from }, if change.to.is_empty() { "∅" } else { &change.to })),
+                ])
             })
-            .collect();
+            .collect()
+    }
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Length(3),   // Arrow indicator
-                Constraint::Length(12),  // Day
-                Constraint::Length(15),  // Time
-                Constraint::Min(30),     // Meeting Name
-                Constraint::Length(12),  // Status
-                Constraint::Length(15),  // URL
-            ],
-        )
-        .header(header)
-        .row_highlight_style(Style::default().add_modifier(Modifier::BOLD));
+    fn pull_request_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        if context.pull_requests.is_empty() {
+            return Self::empty("(No linked pull requests)", theme);
+        }
 
-        f.render_widget(table, inner);
+        let mut lines = Vec::new();
+        for pr in &context.pull_requests {
+            let status_style = match pr.status.to_uppercase().as_str() {
+                "MERGED" => Style::default().fg(theme.special),
+                "OPEN" => Style::default().fg(theme.success),
+                _ => Style::default().fg(theme.muted),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("[{}] ", pr.status), status_style),
+                Span::raw(pr.name.clone()),
+            ]));
+            let mut detail = pr.url.clone();
+            if let Some(author) = &pr.author {
+                detail = format!("{}  by {}", detail, author.name);
+            }
+            lines.push(Line::from(Span::styled(format!("    {}", detail), Style::default().fg(theme.muted))));
+        }
+        lines
     }
 
-    fn render_message(&self, f: &mut Frame, area: Rect, message: &Option<String>) {
-        if let Some(msg) = message {
-            let paragraph = Paragraph::new(msg.as_str())
-                .style(Style::default().fg(Color::Yellow))
-                .alignment(Alignment::Center);
-            f.render_widget(paragraph, area);
+    fn branch_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        if context.branches.is_empty() {
+            return Self::empty("(No local branches mention this ticket)", theme);
         }
+        context.branches.iter().map(|b| Line::from(b.clone())).collect()
     }
 
-    fn render_help(&self, f: &mut Frame, area: Rect) {
-        let help_text = "↑/↓: Navigate | j: Join Meeting | q/ESC: Quit";
-        
-        let help = Paragraph::new(help_text)
-            .style(Style::default().fg(Color::DarkGray))
-            .alignment(Alignment::Center);
-        f.render_widget(help, area);
+    fn meeting_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        match &context.meetings {
+            None => Self::empty("(Google Calendar not configured)", theme),
+            Some(meetings) if meetings.is_empty() => Self::empty("(No upcoming meetings mention this ticket)", theme),
+            Some(meetings) => meetings.iter()
+                .map(|m| Line::from(vec![
+                    Span::styled(format!("{}  ", m.time_range()), Style::default().fg(theme.label)),
+                    Span::raw(m.summary.clone()),
+                ]))
+                .collect(),
+        }
     }
-}
\ No newline at end of file
+
+    fn time_lines(context: &crate::context::TicketContext, theme: &Theme) -> Vec<Line<'static>> {
+        let time = &context.issue.fields.timetracking;
+        let value = |v: &Option<String>| v.clone().unwrap_or_else(|| "—".to_string());
+        vec![
+            Line::from(vec![Self::label("Logged", theme), Span::raw(value(&time.time_spent))]),
+            Line::from(vec![Self::label("Original estimate", theme), Span::raw(value(&time.original_estimate))]),
+            Line::from(vec![Self::label("Remaining", theme), Span::raw(value(&time.remaining_estimate))]),
+        ]
+    }
+
+    fn scratch_lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        match &self.notes {
+            Some(notes) => notes.lines().map(|line| Line::from(line.to_string())).collect(),
+            None => Self::empty("(No scratch notes; press n to start some)", theme),
+        }
+    }
+}
+
+fn parse_jira_date(value: &str) -> String {
+    crate::jira::parse_jira_datetime(value)
+        .map(|d| d.format("%b %d, %Y %H:%M").to_string())
+        .unwrap_or_else(|| value.to_string())
+}
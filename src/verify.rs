@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::jira::{JiraClient, JiraIssue};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Off,
+    Warn,
+    Block,
+}
+
+// How much each pre-close check matters. Blocking checks stop `close` unless --force is given;
+// warnings ask for confirmation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyConfig {
+    #[serde(default = "block")]
+    pub open_subtasks: Severity,
+    #[serde(default = "block")]
+    pub blocking_links: Severity,
+    #[serde(default = "warn")]
+    pub pull_request: Severity,
+    #[serde(default = "off")]
+    pub fix_version: Severity,
+    #[serde(default = "off")]
+    pub time_logged: Severity,
+}
+
+fn block() -> Severity {
+    Severity::Block
+}
+
+fn warn() -> Severity {
+    Severity::Warn
+}
+
+fn off() -> Severity {
+    Severity::Off
+}
+
+impl Default for VerifyConfig {
+    fn default() -> Self {
+        Self {
+            open_subtasks: block(),
+            blocking_links: block(),
+            pull_request: warn(),
+            fix_version: off(),
+            time_logged: off(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    // The check couldn't be run, e.g. no development integration; never blocks
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub label: &'static str,
+    pub severity: Severity,
+    pub outcome: Outcome,
+    pub detail: String,
+}
+
+impl CheckResult {
+    pub fn blocks(&self) -> bool {
+        self.outcome == Outcome::Fail && self.severity == Severity::Block
+    }
+
+    pub fn warns(&self) -> bool {
+        self.outcome == Outcome::Fail && self.severity == Severity::Warn
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Pass => "✓",
+            Outcome::Unknown => "?",
+            Outcome::Fail if self.severity == Severity::Block => "✗",
+            Outcome::Fail => "!",
+        }
+    }
+
+    pub fn line(&self) -> String {
+        format!("{} {}: {}", self.symbol(), self.label, self.detail)
+    }
+}
+
+pub struct Report {
+    pub key: String,
+    pub results: Vec<CheckResult>,
+}
+
+impl Report {
+    pub fn is_blocked(&self) -> bool {
+        self.results.iter().any(|r| r.blocks())
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.results.iter().any(|r| r.warns())
+    }
+}
+
+// Runs every check that isn't turned off. One issue fetch covers everything except pull
+// requests, which come from the development panel.
+pub fn run(client: &JiraClient, issue_key: &str, config: &VerifyConfig) -> Result<Report> {
+    let raw = client.get_issue_raw(issue_key, None)?;
+    let issue: JiraIssue = serde_json::from_value(raw.clone())
+        .context("Failed to parse JIRA issue")?;
+    let fields = raw.get("fields").unwrap_or(&Value::Null);
+
+    let mut results = Vec::new();
+    let mut check = |label: &'static str, severity: Severity, evaluate: &dyn Fn() -> (Outcome, String)| {
+        if severity != Severity::Off {
+            let (outcome, detail) = evaluate();
+            results.push(CheckResult { label, severity, outcome, detail });
+        }
+    };
+
+    check("Subtasks", config.open_subtasks, &|| {
+        let open: Vec<&str> = issue.fields.subtasks.iter()
+            .filter(|s| !s.fields.is_done())
+            .map(|s| s.key.as_str())
+            .collect();
+        match (issue.fields.subtasks.len(), open.len()) {
+            (0, _) => (Outcome::Pass, "no subtasks".to_string()),
+            (total, 0) => (Outcome::Pass, format!("all {} done", total)),
+            (_, _) => (Outcome::Fail, format!("{} still open: {}", open.len(), open.join(", "))),
+        }
+    });
+
+    check("Blockers", config.blocking_links, &|| {
+        let blockers: Vec<String> = issue.fields.issuelinks.iter()
+            .filter(|link| link.link_type.inward.to_lowercase().contains("blocked by"))
+            .filter_map(|link| link.inward_issue.as_ref())
+            .filter(|blocker| !blocker.fields.is_done())
+            .map(|blocker| format!("{} ({})", blocker.key, blocker.fields.status.name))
+            .collect();
+        if blockers.is_empty() {
+            (Outcome::Pass, "no unresolved blocking issues".to_string())
+        } else {
+            (Outcome::Fail, format!("blocked by {}", blockers.join(", ")))
+        }
+    });
+
+    check("Pull request", config.pull_request, &|| {
+        match client.get_pull_requests(&issue.id) {
+            Ok(prs) if prs.is_empty() => (Outcome::Fail, "no linked pull request".to_string()),
+            Ok(prs) => {
+                let states: Vec<String> = prs.iter()
+                    .map(|pr| {
+                        let name = if pr.id.is_empty() { &pr.name } else { &pr.id };
+                        format!("{} {}", name, pr.status.to_lowercase())
+                    })
+                    .collect();
+                (Outcome::Pass, states.join(", "))
+            }
+            Err(_) => (Outcome::Unknown, "development info unavailable".to_string()),
+        }
+    });
+
+    check("Fix version", config.fix_version, &|| {
+        let versions: Vec<&str> = fields.get("fixVersions")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.get("name").and_then(|n| n.as_str()))
+            .collect();
+        if versions.is_empty() {
+            (Outcome::Fail, "no fix version set".to_string())
+        } else {
+            (Outcome::Pass, versions.join(", "))
+        }
+    });
+
+    check("Time logged", config.time_logged, &|| {
+        let spent = fields.get("timetracking")
+            .and_then(|t| t.get("timeSpent"))
+            .and_then(|t| t.as_str());
+        match spent {
+            Some(spent) => (Outcome::Pass, format!("{} logged", spent)),
+            None => (Outcome::Fail, "no time logged".to_string()),
+        }
+    });
+
+    Ok(Report { key: issue.key, results })
+}
@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::jira::{JiraClient, TransitionOption};
+use crate::store;
+
+// How often each status moves to each other status, per project, learned from changelogs so the
+// move picker can list the likeliest next status first instead of Jira's arbitrary order
+
+// Learned counts are refreshed from Jira after this long
+const RELEARN_AFTER_DAYS: i64 = 7;
+// Changelogs of issues updated within this window are counted
+const HISTORY_DAYS: i64 = 90;
+const MAX_ISSUES: usize = 200;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WorkflowStore {
+    #[serde(default)]
+    projects: HashMap<String, ProjectWorkflow>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProjectWorkflow {
+    #[serde(default)]
+    learned_at: Option<DateTime<Utc>>,
+    // Lowercased from status -> lowercased to status -> times seen
+    #[serde(default)]
+    moves: HashMap<String, HashMap<String, u32>>,
+}
+
+impl WorkflowStore {
+    fn store_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .context("Failed to determine data directory")?;
+        Ok(data_dir.join("qq").join("workflow.json"))
+    }
+
+    fn load() -> Self {
+        Self::store_path().ok()
+            .map(|path| store::read_json(&path))
+            .unwrap_or_default()
+    }
+
+    // Counts a move made through qq, so the order adapts between relearns
+    pub fn record(project: &str, from: &str, to: &str) -> Result<()> {
+        store::edit_json(&Self::store_path()?, |store: &mut Self| {
+            let project = store.projects.entry(project.to_uppercase()).or_default();
+            *project.moves.entry(from.to_lowercase()).or_default().entry(to.to_lowercase()).or_default() += 1;
+        })
+    }
+}
+
+pub fn project_of(issue_key: &str) -> &str {
+    issue_key.split('-').next().unwrap_or(issue_key)
+}
+
+// Times each status was reached from `from` in the project, relearning from Jira when the counts
+// are missing or stale. A failed relearn falls back to whatever was learned before.
+pub fn next_status_counts(client: &JiraClient, project: &str, from: &str) -> HashMap<String, u32> {
+    let project = project.to_uppercase();
+    let mut store = WorkflowStore::load();
+    let stale = store.projects.get(&project)
+        .and_then(|workflow| workflow.learned_at)
+        .is_none_or(|at| Utc::now() - at > Duration::days(RELEARN_AFTER_DAYS));
+
+    if stale
+        && let Ok(moves) = learn(client, &project)
+        && let Ok(path) = WorkflowStore::store_path()
+    {
+        let learned = ProjectWorkflow { learned_at: Some(Utc::now()), moves };
+        let _ = store::edit_json(&path, |stored: &mut WorkflowStore| {
+            stored.projects.insert(project.clone(), learned);
+        });
+        store = WorkflowStore::load();
+    }
+
+    store.projects.remove(&project)
+        .and_then(|mut workflow| workflow.moves.remove(&from.to_lowercase()))
+        .unwrap_or_default()
+}
+
+fn learn(client: &JiraClient, project: &str) -> Result<HashMap<String, HashMap<String, u32>>> {
+    let jql = format!(
+        "project = {} AND updated >= -{}d ORDER BY updated DESC",
+        crate::jql::quote(project),
+        HISTORY_DAYS
+    );
+    let mut moves: HashMap<String, HashMap<String, u32>> = HashMap::new();
+    let mut seen = 0;
+    client.search_raw_pages(&jql, "status", Some("changelog"), |page| {
+        seen += page.len();
+        for issue in &page {
+            let histories = issue.pointer("/changelog/histories").and_then(Value::as_array);
+            let items = histories.into_iter().flatten()
+                .filter_map(|history| history.get("items").and_then(Value::as_array))
+                .flatten()
+                .filter(|item| item.get("field").and_then(Value::as_str) == Some("status"));
+            for item in items {
+                let (Some(from), Some(to)) = (
+                    item.get("fromString").and_then(Value::as_str),
+                    item.get("toString").and_then(Value::as_str),
+                ) else {
+                    continue;
+                };
+                *moves.entry(from.to_lowercase()).or_default().entry(to.to_lowercase()).or_default() += 1;
+            }
+        }
+        Ok(seen < MAX_ISSUES)
+    })?;
+    Ok(moves)
+}
+
+// Likeliest first; transitions never seen keep Jira's order after the rest. Returns each
+// option's share of the moves seen from the current status, for display.
+pub fn rank(options: Vec<TransitionOption>, counts: &HashMap<String, u32>) -> Vec<(TransitionOption, Option<f64>)> {
+    let total: u32 = options.iter()
+        .filter_map(|option| counts.get(&option.status.to_lowercase()))
+        .sum();
+    let mut ranked: Vec<(TransitionOption, Option<f64>)> = options.into_iter()
+        .map(|option| {
+            let share = counts.get(&option.status.to_lowercase())
+                .filter(|_| total > 0)
+                .map(|count| *count as f64 / total as f64);
+            (option, share)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.unwrap_or(0.0).total_cmp(&a.1.unwrap_or(0.0)));
+    ranked
+}